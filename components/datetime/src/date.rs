@@ -5,8 +5,8 @@
 //! A collection of utilities for representing and working with dates as an input to
 //! formatting operations.
 
+use crate::provider::week_data::WeekDataV1;
 use icu_calendar::{AsCalendar, Date, DateTime, Gregorian};
-use icu_locid::Locale;
 use tinystr::TinyStr8;
 
 // TODO (Manishearth) fix up imports to directly import from icu_calendar
@@ -96,16 +96,23 @@ pub trait LocalizedDateTimeInput<T: DateTimeInput> {
 
     /// The year number according to week numbering.
     ///
-    /// For example, December 31, 2020 is part of the first week of 2021.
-    fn year_week(&self) -> Year;
+    /// For example, December 31, 2024 is part of the first week of 2025.
+    ///
+    /// Returns [`None`] if the week data or an input field is missing.
+    fn year_week(&self) -> Option<Year>;
 
-    /// The week of the month according to UTS 35.
-    fn week_of_month(&self) -> WeekOfMonth;
+    /// The week of the month according to UTS 35. The days of the month before its first
+    /// week are part of week 0.
+    ///
+    /// Returns [`None`] if the week data or an input field is missing.
+    fn week_of_month(&self) -> Option<WeekOfMonth>;
 
     /// The week number of the year.
     ///
-    /// For example, December 31, 2020 is part of the first week of 2021.
-    fn week_of_year(&self) -> WeekOfYear;
+    /// For example, December 31, 2024 is part of the first week of 2025.
+    ///
+    /// Returns [`None`] if the week data or an input field is missing.
+    fn week_of_year(&self) -> Option<WeekOfYear>;
 
     /// TODO(#487): Implement flexible day periods.
     fn flexible_day_period(&self);
@@ -113,36 +120,116 @@ pub trait LocalizedDateTimeInput<T: DateTimeInput> {
 
 pub(crate) struct DateTimeInputWithLocale<'data, T: DateTimeInput> {
     data: &'data T,
-    _first_weekday: u8,
-    _anchor_weekday: u8,
+    week_data: Option<&'data WeekDataV1>,
 }
 
 impl<'data, T: DateTimeInput> DateTimeInputWithLocale<'data, T> {
-    pub fn new(data: &'data T, _locale: &Locale) -> Self {
-        Self {
-            data,
-            // TODO(#488): Implement week calculations.
-            _first_weekday: 1,
-            _anchor_weekday: 4,
-        }
+    /// Creates the input with the week data of the locale, which is only needed by the week
+    /// fields of a pattern.
+    pub fn new(data: &'data T, week_data: Option<&'data WeekDataV1>) -> Self {
+        Self { data, week_data }
     }
 }
 
 pub(crate) struct ZonedDateTimeInputWithLocale<'data, T: ZonedDateTimeInput> {
     data: &'data T,
-    _first_weekday: u8,
-    _anchor_weekday: u8,
+    week_data: Option<&'data WeekDataV1>,
 }
 
 impl<'data, T: ZonedDateTimeInput> ZonedDateTimeInputWithLocale<'data, T> {
-    pub fn new(data: &'data T, _locale: &Locale) -> Self {
-        Self {
-            data,
-            // TODO(#488): Implement week calculations.
-            _first_weekday: 1,
-            _anchor_weekday: 4,
+    /// Creates the input with the week data of the locale, which is only needed by the week
+    /// fields of a pattern.
+    pub fn new(data: &'data T, week_data: Option<&'data WeekDataV1>) -> Self {
+        Self { data, week_data }
+    }
+}
+
+/// The year a week belongs to, relative to the year of a day in the week.
+#[derive(Debug, PartialEq)]
+enum RelativeYear {
+    Previous,
+    Current,
+    Next,
+}
+
+/// Computes the week of the year of a day according to the week data, along with the year
+/// the week belongs to.
+fn week_of_year_info(
+    info: &DayOfYearInfo,
+    iso_weekday: IsoWeekday,
+    week_data: &WeekDataV1,
+) -> (RelativeYear, WeekOfYear) {
+    let min_days = u32::from(week_data.min_days_in_first_week);
+    // The weekday relative to the first day of the week, from 0 to 6.
+    let weekday = (iso_weekday as u32 + 7 - u32::from(week_data.first_weekday)) % 7;
+    let day = info.day_of_year - 1;
+
+    // The week may contain the first days of the next year, and be its first week.
+    if day + (6 - weekday) >= info.days_in_year {
+        let next_year_weekday = (weekday + info.days_in_year - day) % 7;
+        if 7 - next_year_weekday >= min_days {
+            return (RelativeYear::Next, WeekOfYear(1));
         }
     }
+
+    match week_number(day, weekday, min_days) {
+        0 => (
+            RelativeYear::Previous,
+            WeekOfYear(week_number(
+                day + days_in_iso_year(info.prev_year.related_iso),
+                weekday,
+                min_days,
+            )),
+        ),
+        week => (RelativeYear::Current, WeekOfYear(week)),
+    }
+}
+
+/// Returns the number of days in an ISO year, which is also the length of a Gregorian year.
+fn days_in_iso_year(year: i32) -> u32 {
+    if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) {
+        366
+    } else {
+        365
+    }
+}
+
+/// Returns the week number of a 0-based day of the year with the given relative weekday, or 0
+/// if the day is part of the last week of the previous year.
+fn week_number(day: u32, weekday: u32, min_days: u32) -> u32 {
+    let first_day_weekday = (weekday + 7 - day % 7) % 7;
+    let week = (day + first_day_weekday) / 7;
+    if 7 - first_day_weekday >= min_days {
+        week + 1
+    } else {
+        week
+    }
+}
+
+fn year_week<T: DateInput>(datetime: &T, week_data: &WeekDataV1) -> Option<Year> {
+    let info = datetime.day_of_year_info()?;
+    Some(
+        match week_of_year_info(&info, datetime.iso_weekday()?, week_data).0 {
+            RelativeYear::Previous => info.prev_year,
+            RelativeYear::Current => datetime.year()?,
+            RelativeYear::Next => info.next_year,
+        },
+    )
+}
+
+fn week_of_month<T: DateInput>(datetime: &T, week_data: &WeekDataV1) -> Option<WeekOfMonth> {
+    let day = datetime.day_of_month()?.0 - 1;
+    let weekday = (datetime.iso_weekday()? as u32 + 7 - u32::from(week_data.first_weekday)) % 7;
+    Some(WeekOfMonth(week_number(
+        day,
+        weekday,
+        u32::from(week_data.min_days_in_first_week),
+    )))
+}
+
+fn week_of_year<T: DateInput>(datetime: &T, week_data: &WeekDataV1) -> Option<WeekOfYear> {
+    let info = datetime.day_of_year_info()?;
+    Some(week_of_year_info(&info, datetime.iso_weekday()?, week_data).1)
 }
 
 impl<'data, T: DateTimeInput> LocalizedDateTimeInput<T> for DateTimeInputWithLocale<'data, T> {
@@ -150,16 +237,16 @@ impl<'data, T: DateTimeInput> LocalizedDateTimeInput<T> for DateTimeInputWithLoc
         self.data
     }
 
-    fn year_week(&self) -> Year {
-        todo!("#488")
+    fn year_week(&self) -> Option<Year> {
+        year_week(self.data, self.week_data?)
     }
 
-    fn week_of_month(&self) -> WeekOfMonth {
-        todo!("#488")
+    fn week_of_month(&self) -> Option<WeekOfMonth> {
+        week_of_month(self.data, self.week_data?)
     }
 
-    fn week_of_year(&self) -> WeekOfYear {
-        todo!("#488")
+    fn week_of_year(&self) -> Option<WeekOfYear> {
+        week_of_year(self.data, self.week_data?)
     }
 
     fn flexible_day_period(&self) {
@@ -174,16 +261,16 @@ impl<'data, T: ZonedDateTimeInput> LocalizedDateTimeInput<T>
         self.data
     }
 
-    fn year_week(&self) -> Year {
-        todo!("#488")
+    fn year_week(&self) -> Option<Year> {
+        year_week(self.data, self.week_data?)
     }
 
-    fn week_of_month(&self) -> WeekOfMonth {
-        todo!("#488")
+    fn week_of_month(&self) -> Option<WeekOfMonth> {
+        week_of_month(self.data, self.week_data?)
     }
 
-    fn week_of_year(&self) -> WeekOfYear {
-        todo!("#488")
+    fn week_of_year(&self) -> Option<WeekOfYear> {
+        week_of_year(self.data, self.week_data?)
     }
 
     fn flexible_day_period(&self) {
@@ -266,3 +353,71 @@ impl<A: AsCalendar<Calendar = Gregorian>> IsoTimeInput for DateTime<A> {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_week_of_year() {
+        // Weeks start on Sunday in the US, and on Monday in Germany.
+        let us = WeekDataV1 {
+            first_weekday: 7,
+            min_days_in_first_week: 1,
+        };
+        let de = WeekDataV1 {
+            first_weekday: 1,
+            min_days_in_first_week: 4,
+        };
+
+        let samples = [
+            ((2021, 1, 3), us, 2021, 2),
+            ((2021, 1, 3), de, 2020, 53),
+            ((2022, 1, 1), de, 2021, 52),
+            ((2021, 6, 15), us, 2021, 25),
+            ((2021, 6, 15), de, 2021, 24),
+            ((2024, 12, 31), us, 2025, 1),
+            ((2024, 12, 31), de, 2025, 1),
+        ];
+        for ((year, month, day), week_data, year_week, week) in samples {
+            let datetime =
+                DateTime::new_gregorian_datetime_from_integers(year, month, day, 0, 0, 0).unwrap();
+            let input = DateTimeInputWithLocale::new(&datetime, Some(&week_data));
+            assert_eq!(input.week_of_year(), Some(WeekOfYear(week)));
+            assert_eq!(input.year_week().map(|y| y.number), Some(year_week));
+        }
+
+        let datetime = DateTime::new_gregorian_datetime_from_integers(2021, 1, 3, 0, 0, 0).unwrap();
+        let input = DateTimeInputWithLocale::new(&datetime, None);
+        assert_eq!(input.week_of_year(), None);
+        assert_eq!(input.year_week(), None);
+    }
+
+    #[test]
+    fn test_week_of_month() {
+        let us = WeekDataV1 {
+            first_weekday: 7,
+            min_days_in_first_week: 1,
+        };
+        let de = WeekDataV1 {
+            first_weekday: 1,
+            min_days_in_first_week: 4,
+        };
+
+        let samples = [
+            ((2021, 5, 1), us, 1),
+            // Saturday, May 1 is part of the last week of April in Germany.
+            ((2021, 5, 1), de, 0),
+            ((2021, 6, 1), us, 1),
+            ((2021, 6, 1), de, 1),
+            ((2021, 6, 15), us, 3),
+            ((2021, 6, 15), de, 3),
+        ];
+        for ((year, month, day), week_data, week) in samples {
+            let datetime =
+                DateTime::new_gregorian_datetime_from_integers(year, month, day, 0, 0, 0).unwrap();
+            let input = DateTimeInputWithLocale::new(&datetime, Some(&week_data));
+            assert_eq!(input.week_of_month(), Some(WeekOfMonth(week)));
+        }
+    }
+}
@@ -11,15 +11,16 @@ use crate::{
     provider::{
         gregory::{DatePatternsV1Marker, DateSymbolsV1Marker},
         helpers::DateTimePatterns,
+        week_data::WeekDataV1Marker,
     },
 };
 use alloc::string::String;
 use icu_locid::Locale;
+use icu_plurals::{provider::PluralRuleStringsV1Marker, PluralRuleType, PluralRules};
 use icu_provider::prelude::*;
 
 use crate::{
-    date::DateTimeInput, pattern::reference::Pattern, provider, DateTimeFormatError,
-    FormattedDateTime,
+    date::DateTimeInput, pattern::PatternPlurals, provider, DateTimeFormatError, FormattedDateTime,
 };
 
 /// [`DateTimeFormat`] is the main structure of the [`icu_datetime`] component.
@@ -64,8 +65,10 @@ use crate::{
 /// when we introduce asynchronous [`DataProvider`] and corresponding asynchronous constructor.
 pub struct DateTimeFormat<'data> {
     pub(super) locale: Locale,
-    pub(super) pattern: Pattern,
+    pub(super) patterns: PatternPlurals,
     pub(super) symbols: Option<DataPayload<'data, DateSymbolsV1Marker>>,
+    pub(super) week_data: Option<DataPayload<'data, WeekDataV1Marker>>,
+    pub(super) plural_rules: Option<PluralRules>,
 }
 
 impl<'data> DateTimeFormat<'data> {
@@ -94,6 +97,8 @@ impl<'data> DateTimeFormat<'data> {
         T: Into<Locale>,
         D: DataProvider<'data, DateSymbolsV1Marker>
             + DataProvider<'data, DatePatternsV1Marker>
+            + DataProvider<'data, WeekDataV1Marker>
+            + DataProvider<'data, PluralRuleStringsV1Marker>
             + ?Sized,
     >(
         locale: T,
@@ -115,13 +120,16 @@ impl<'data> DateTimeFormat<'data> {
                 })?
                 .take_payload()?;
 
-        let pattern = patterns_data
+        let patterns = patterns_data
             .get()
             .get_pattern_for_options(options)?
             .unwrap_or_default();
 
-        let requires_data = datetime::analyze_pattern(&pattern, false)
-            .map_err(|field| DateTimeFormatError::UnsupportedField(field.symbol))?;
+        let mut requires_data = false;
+        for pattern in patterns.patterns_iter() {
+            requires_data |= datetime::analyze_pattern(pattern, false)
+                .map_err(|field| DateTimeFormatError::UnsupportedField(field.symbol))?;
+        }
 
         let symbols_data = if requires_data {
             Some(
@@ -141,7 +149,42 @@ impl<'data> DateTimeFormat<'data> {
             None
         };
 
-        Ok(Self::new(locale, pattern, symbols_data))
+        let week_data = if patterns.patterns_iter().any(datetime::requires_week_data) {
+            Some(
+                data_provider
+                    .load_payload(&DataRequest {
+                        resource_path: ResourcePath {
+                            key: provider::key::WEEK_DATA_V1,
+                            options: ResourceOptions {
+                                variant: None,
+                                langid: Some(locale.clone().into()),
+                            },
+                        },
+                    })?
+                    .take_payload()?,
+            )
+        } else {
+            None
+        };
+
+        // The variants of the patterns are selected by the plural category of a week number.
+        let plural_rules = if patterns.requires_plural_rules() {
+            Some(PluralRules::try_new(
+                locale.clone().into(),
+                data_provider,
+                PluralRuleType::Cardinal,
+            )?)
+        } else {
+            None
+        };
+
+        Ok(Self::new(
+            locale,
+            patterns,
+            symbols_data,
+            week_data,
+            plural_rules,
+        ))
     }
 
     /// Creates a new [`DateTimeFormat`] regardless of whether there are time-zone symbols in the pattern.
@@ -159,15 +202,19 @@ impl<'data> DateTimeFormat<'data> {
     /// [`ZonedDateTimeFormat`]: crate::zoned_datetime::ZonedDateTimeFormat
     pub(super) fn new<T: Into<Locale>>(
         locale: T,
-        pattern: Pattern,
+        patterns: PatternPlurals,
         symbols: Option<DataPayload<'data, DateSymbolsV1Marker>>,
+        week_data: Option<DataPayload<'data, WeekDataV1Marker>>,
+        plural_rules: Option<PluralRules>,
     ) -> Self {
         let locale = locale.into();
 
         Self {
             locale,
-            pattern,
+            patterns,
             symbols,
+            week_data,
+            plural_rules,
         }
     }
 
@@ -204,10 +251,11 @@ impl<'data> DateTimeFormat<'data> {
         T: DateTimeInput,
     {
         FormattedDateTime {
-            pattern: &self.pattern,
+            patterns: &self.patterns,
             symbols: self.symbols.as_ref().map(|s| s.get()),
             datetime: value,
-            locale: &self.locale,
+            week_data: self.week_data.as_ref().map(|w| w.get()),
+            plural_rules: self.plural_rules.as_ref(),
         }
     }
 
@@ -242,11 +290,12 @@ impl<'data> DateTimeFormat<'data> {
         w: &mut impl core::fmt::Write,
         value: &impl DateTimeInput,
     ) -> core::fmt::Result {
-        datetime::write_pattern(
-            &self.pattern,
+        datetime::write_pattern_plurals(
+            &self.patterns,
+            self.plural_rules.as_ref(),
             self.symbols.as_ref().map(|s| s.get()),
+            self.week_data.as_ref().map(|w| w.get()),
             value,
-            &self.locale,
             w,
         )
         .map_err(|_| core::fmt::Error)
@@ -6,6 +6,7 @@ use crate::fields::FieldSymbol;
 use crate::pattern::PatternError;
 use crate::skeleton::SkeletonError;
 use displaydoc::Display;
+use icu_plurals::PluralRulesError;
 use icu_provider::prelude::DataError;
 
 /// A list of possible error outcomes for the [`DateTimeFormat`](crate::DateTimeFormat) struct.
@@ -30,6 +31,20 @@ pub enum DateTimeFormatError {
     /// An error originating from an unsupported field in a datetime format.
     #[displaydoc("Unsupported field: {0:?}")]
     UnsupportedField(FieldSymbol),
+    /// An error originating from a value of the datetime input that has no symbol, such as a
+    /// month number out of range.
+    #[displaydoc("Missing symbol for the field: {0:?}")]
+    MissingSymbol(FieldSymbol),
+    /// An error originating from [`PluralRules`](icu_plurals::PluralRules), which select the
+    /// variant of a pattern that varies by plural category.
+    #[displaydoc("{0}")]
+    PluralRules(PluralRulesError),
+    /// The plural rules are missing to select the variant of a pattern.
+    #[displaydoc("Missing plural rules")]
+    MissingPluralRules,
+    /// A pattern which varies by plural category has no week field to select its variant with.
+    #[displaydoc("Missing week field in a plural pattern")]
+    MissingPluralField,
 }
 
 #[cfg(feature = "std")]
@@ -58,3 +73,9 @@ impl From<SkeletonError> for DateTimeFormatError {
         DateTimeFormatError::Skeleton(e)
     }
 }
+
+impl From<PluralRulesError> for DateTimeFormatError {
+    fn from(e: PluralRulesError) -> Self {
+        DateTimeFormatError::PluralRules(e)
+    }
+}
@@ -14,7 +14,7 @@ pub enum LengthError {
 #[cfg(feature = "std")]
 impl std::error::Error for LengthError {}
 
-#[derive(Debug, Eq, PartialEq, Clone, Copy, Ord, PartialOrd)]
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Ord, PartialOrd, Hash)]
 #[cfg_attr(
     feature = "provider_serde",
     derive(serde::Serialize, serde::Deserialize)
@@ -21,7 +21,7 @@ pub enum SymbolError {
 #[cfg(feature = "std")]
 impl std::error::Error for SymbolError {}
 
-#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
 #[cfg_attr(
     feature = "provider_serde",
     derive(serde::Serialize, serde::Deserialize)
@@ -36,6 +36,7 @@ pub enum FieldSymbol {
     Minute,
     Second(Second),
     TimeZone(TimeZone),
+    Week(Week),
 }
 
 impl FieldSymbol {
@@ -87,6 +88,7 @@ impl FieldSymbol {
             6 => symbol == 0,
             7 => Second::idx_in_range(&symbol),
             8 => TimeZone::idx_in_range(&symbol),
+            9 => Week::idx_in_range(&symbol),
             _ => false,
         }
     }
@@ -103,6 +105,7 @@ impl FieldSymbol {
             FieldSymbol::Minute => (6, 0),
             FieldSymbol::Second(second) => (7, second.idx()),
             FieldSymbol::TimeZone(tz) => (8, tz.idx()),
+            FieldSymbol::Week(week) => (9, week.idx()),
         };
         let result = high << 4;
         result | low
@@ -125,6 +128,7 @@ impl FieldSymbol {
             6 if low == 0 => Self::Minute,
             7 => Self::Second(Second::from_idx(low)?),
             8 => Self::TimeZone(TimeZone::from_idx(low)?),
+            9 => Self::Week(Week::from_idx(low)?),
             _ => return Err(SymbolError::InvalidIndex(idx)),
         })
     }
@@ -155,32 +159,35 @@ impl FieldSymbol {
         match self {
             Self::Year(Year::Calendar) => 0,
             Self::Year(Year::WeekOf) => 1,
-            Self::Month(Month::Format) => 2,
-            Self::Month(Month::StandAlone) => 3,
-            Self::Day(Day::DayOfMonth) => 4,
-            Self::Day(Day::DayOfYear) => 5,
-            Self::Day(Day::DayOfWeekInMonth) => 6,
-            Self::Day(Day::ModifiedJulianDay) => 7,
-            Self::Weekday(Weekday::Format) => 8,
-            Self::Weekday(Weekday::Local) => 9,
-            Self::Weekday(Weekday::StandAlone) => 10,
-            Self::DayPeriod(DayPeriod::AmPm) => 11,
-            Self::DayPeriod(DayPeriod::NoonMidnight) => 12,
-            Self::Hour(Hour::H11) => 13,
-            Self::Hour(Hour::H12) => 14,
-            Self::Hour(Hour::H23) => 15,
-            Self::Hour(Hour::H24) => 16,
-            Self::Minute => 17,
-            Self::Second(Second::Second) => 18,
-            Self::Second(Second::FractionalSecond) => 19,
-            Self::Second(Second::Millisecond) => 20,
-            Self::TimeZone(TimeZone::LowerZ) => 21,
-            Self::TimeZone(TimeZone::UpperZ) => 22,
-            Self::TimeZone(TimeZone::UpperO) => 23,
-            Self::TimeZone(TimeZone::LowerV) => 24,
-            Self::TimeZone(TimeZone::UpperV) => 25,
-            Self::TimeZone(TimeZone::LowerX) => 26,
-            Self::TimeZone(TimeZone::UpperX) => 27,
+            Self::Year(Year::Cyclic) => 2,
+            Self::Month(Month::Format) => 3,
+            Self::Month(Month::StandAlone) => 4,
+            Self::Week(Week::WeekOfYear) => 5,
+            Self::Week(Week::WeekOfMonth) => 6,
+            Self::Day(Day::DayOfMonth) => 7,
+            Self::Day(Day::DayOfYear) => 8,
+            Self::Day(Day::DayOfWeekInMonth) => 9,
+            Self::Day(Day::ModifiedJulianDay) => 10,
+            Self::Weekday(Weekday::Format) => 11,
+            Self::Weekday(Weekday::Local) => 12,
+            Self::Weekday(Weekday::StandAlone) => 13,
+            Self::DayPeriod(DayPeriod::AmPm) => 14,
+            Self::DayPeriod(DayPeriod::NoonMidnight) => 15,
+            Self::Hour(Hour::H11) => 16,
+            Self::Hour(Hour::H12) => 17,
+            Self::Hour(Hour::H23) => 18,
+            Self::Hour(Hour::H24) => 19,
+            Self::Minute => 20,
+            Self::Second(Second::Second) => 21,
+            Self::Second(Second::FractionalSecond) => 22,
+            Self::Second(Second::Millisecond) => 23,
+            Self::TimeZone(TimeZone::LowerZ) => 24,
+            Self::TimeZone(TimeZone::UpperZ) => 25,
+            Self::TimeZone(TimeZone::UpperO) => 26,
+            Self::TimeZone(TimeZone::LowerV) => 27,
+            Self::TimeZone(TimeZone::UpperV) => 28,
+            Self::TimeZone(TimeZone::LowerX) => 29,
+            Self::TimeZone(TimeZone::UpperX) => 30,
         }
     }
 }
@@ -194,6 +201,7 @@ impl TryFrom<char> for FieldSymbol {
         Year::try_from(ch)
             .map(Self::Year)
             .or_else(|_| Month::try_from(ch).map(Self::Month))
+            .or_else(|_| Week::try_from(ch).map(Self::Week))
             .or_else(|_| Day::try_from(ch).map(Self::Day))
             .or_else(|_| Weekday::try_from(ch).map(Self::Weekday))
             .or_else(|_| DayPeriod::try_from(ch).map(Self::DayPeriod))
@@ -215,6 +223,7 @@ impl From<FieldSymbol> for char {
         match symbol {
             FieldSymbol::Year(year) => year.into(),
             FieldSymbol::Month(month) => month.into(),
+            FieldSymbol::Week(week) => week.into(),
             FieldSymbol::Day(day) => day.into(),
             FieldSymbol::Weekday(weekday) => weekday.into(),
             FieldSymbol::DayPeriod(dayperiod) => dayperiod.into(),
@@ -240,8 +249,18 @@ impl Ord for FieldSymbol {
 
 field_type!(Year; {
     'y' => Calendar,
-    'Y' => WeekOf
-}; Numeric);
+    'Y' => WeekOf,
+    'U' => Cyclic
+});
+
+impl LengthType for Year {
+    fn get_length_type(&self, _length: FieldLength) -> TextOrNumeric {
+        match self {
+            Self::Calendar | Self::WeekOf => TextOrNumeric::Numeric,
+            Self::Cyclic => TextOrNumeric::Text,
+        }
+    }
+}
 
 field_type!(Month; {
     'M' => Format,
@@ -261,6 +280,11 @@ impl LengthType for Month {
     }
 }
 
+field_type!(Week; {
+    'w' => WeekOfYear,
+    'W' => WeekOfMonth
+}; Numeric);
+
 field_type!(Day; {
     'd' => DayOfMonth,
     'D' => DayOfYear,
@@ -5,13 +5,14 @@
 use crate::date::{DateTimeInput, DateTimeInputWithLocale, LocalizedDateTimeInput};
 use crate::error::DateTimeFormatError as Error;
 use crate::fields::{self, Field, FieldLength, FieldSymbol};
-use crate::pattern::{reference::Pattern, PatternItem};
+use crate::pattern::{reference::Pattern, PatternItem, PatternPlurals};
 use crate::provider;
 use crate::provider::helpers::DateTimeSymbols;
+use crate::provider::week_data::WeekDataV1;
+use icu_plurals::PluralRules;
 
 use alloc::string::ToString;
 use core::fmt;
-use icu_locid::Locale;
 use writeable::Writeable;
 
 /// [`FormattedDateTime`] is a intermediate structure which can be retrieved as
@@ -46,10 +47,11 @@ pub struct FormattedDateTime<'l, T>
 where
     T: DateTimeInput,
 {
-    pub(crate) pattern: &'l Pattern,
+    pub(crate) patterns: &'l PatternPlurals,
     pub(crate) symbols: Option<&'l provider::gregory::DateSymbolsV1>,
     pub(crate) datetime: &'l T,
-    pub(crate) locale: &'l Locale,
+    pub(crate) week_data: Option<&'l WeekDataV1>,
+    pub(crate) plural_rules: Option<&'l PluralRules>,
 }
 
 impl<'l, T> Writeable for FormattedDateTime<'l, T>
@@ -57,8 +59,15 @@ where
     T: DateTimeInput,
 {
     fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
-        write_pattern(self.pattern, self.symbols, self.datetime, self.locale, sink)
-            .map_err(|_| core::fmt::Error)
+        write_pattern_plurals(
+            self.patterns,
+            self.plural_rules,
+            self.symbols,
+            self.week_data,
+            self.datetime,
+            sink,
+        )
+        .map_err(|_| core::fmt::Error)
     }
 
     // TODO(#489): Implement write_len
@@ -69,8 +78,15 @@ where
     T: DateTimeInput,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write_pattern(self.pattern, self.symbols, self.datetime, self.locale, f)
-            .map_err(|_| core::fmt::Error)
+        write_pattern_plurals(
+            self.patterns,
+            self.plural_rules,
+            self.symbols,
+            self.week_data,
+            self.datetime,
+            f,
+        )
+        .map_err(|_| core::fmt::Error)
     }
 }
 
@@ -94,21 +110,43 @@ where
     }
 }
 
+/// Writes the variant of the `patterns` selected by the `plural_rules`, see
+/// [`PatternPlurals::select`].
+pub fn write_pattern_plurals<T, W>(
+    patterns: &PatternPlurals,
+    plural_rules: Option<&PluralRules>,
+    symbols: Option<&provider::gregory::DateSymbolsV1>,
+    week_data: Option<&WeekDataV1>,
+    datetime: &T,
+    w: &mut W,
+) -> Result<(), Error>
+where
+    T: DateTimeInput,
+    W: fmt::Write + ?Sized,
+{
+    let loc_datetime = DateTimeInputWithLocale::new(datetime, week_data);
+    let pattern = patterns.select(&loc_datetime, plural_rules)?;
+    write_pattern(pattern, symbols, week_data, datetime, w)
+}
+
 pub fn write_pattern<T, W>(
     pattern: &crate::pattern::reference::Pattern,
     symbols: Option<&provider::gregory::DateSymbolsV1>,
+    week_data: Option<&WeekDataV1>,
     datetime: &T,
-    locale: &Locale,
     w: &mut W,
 ) -> Result<(), Error>
 where
     T: DateTimeInput,
     W: fmt::Write + ?Sized,
 {
-    let loc_datetime = DateTimeInputWithLocale::new(datetime, locale);
+    let loc_datetime = DateTimeInputWithLocale::new(datetime, week_data);
+    let only_field = is_only_field(pattern);
     for item in pattern.items() {
         match item {
-            PatternItem::Field(field) => write_field(pattern, field, symbols, &loc_datetime, w)?,
+            PatternItem::Field(field) => {
+                write_field(pattern, only_field, field, symbols, &loc_datetime, w)?
+            }
             PatternItem::Literal(ch) => w.write_char(*ch)?,
         }
     }
@@ -120,8 +158,11 @@ where
 //
 // When modifying the list of fields using symbols,
 // update the matching query in `analyze_pattern` function.
+//
+// `only_field` is whether the field is the only one in the pattern, see `is_only_field`.
 pub(super) fn write_field<T, W>(
     pattern: &crate::pattern::reference::Pattern,
+    only_field: bool,
     field: &fields::Field,
     symbols: Option<&crate::provider::gregory::DateSymbolsV1>,
     datetime: &impl LocalizedDateTimeInput<T>,
@@ -132,7 +173,22 @@ where
     W: fmt::Write + ?Sized,
 {
     match field.symbol {
-        FieldSymbol::Year(..) => format_number(
+        FieldSymbol::Year(fields::Year::Cyclic) => {
+            let year = datetime
+                .datetime()
+                .year()
+                .ok_or(Error::MissingInputField)?
+                .related_iso;
+            // The related ISO year 4 is the first year of a cycle.
+            let cyclic_year = (year - 4).rem_euclid(60) as usize + 1;
+            match symbols
+                .and_then(|symbols| symbols.get_symbol_for_cyclic_year(field.length, cyclic_year))
+            {
+                Some(symbol) => w.write_str(symbol)?,
+                None => format_number(w, year as isize, field.length)?,
+            }
+        }
+        FieldSymbol::Year(fields::Year::Calendar) => format_number(
             w,
             datetime
                 .datetime()
@@ -141,6 +197,21 @@ where
                 .number as isize,
             field.length,
         )?,
+        FieldSymbol::Year(fields::Year::WeekOf) => format_number(
+            w,
+            datetime.year_week().ok_or(Error::MissingInputField)?.number as isize,
+            field.length,
+        )?,
+        FieldSymbol::Week(fields::Week::WeekOfYear) => format_number(
+            w,
+            datetime.week_of_year().ok_or(Error::MissingInputField)?.0 as isize,
+            field.length,
+        )?,
+        FieldSymbol::Week(fields::Week::WeekOfMonth) => format_number(
+            w,
+            datetime.week_of_month().ok_or(Error::MissingInputField)?.0 as isize,
+            field.length,
+        )?,
         FieldSymbol::Month(month) => match field.length {
             FieldLength::One | FieldLength::TwoDigit => format_number(
                 w,
@@ -152,18 +223,22 @@ where
                 field.length,
             )?,
             length => {
+                let month = if only_field {
+                    fields::Month::StandAlone
+                } else {
+                    month
+                };
+                // The month numbers start at 1.
+                let index = (datetime
+                    .datetime()
+                    .month()
+                    .ok_or(Error::MissingInputField)?
+                    .number as usize)
+                    .checked_sub(1)
+                    .ok_or(Error::MissingSymbol(FieldSymbol::Month(month)))?;
                 let symbol = symbols
                     .expect("Expect symbols to be present")
-                    .get_symbol_for_month(
-                        month,
-                        length,
-                        datetime
-                            .datetime()
-                            .month()
-                            .ok_or(Error::MissingInputField)?
-                            .number as usize
-                            - 1,
-                    );
+                    .get_symbol_for_month(month, length, index)?;
                 w.write_str(symbol)?
             }
         },
@@ -172,9 +247,13 @@ where
                 .datetime()
                 .iso_weekday()
                 .ok_or(Error::MissingInputField)?;
+            let weekday = match weekday {
+                fields::Weekday::Format if only_field => fields::Weekday::StandAlone,
+                weekday => weekday,
+            };
             let symbol = symbols
                 .expect("Expect symbols to be present")
-                .get_symbol_for_weekday(weekday, field.length, dow);
+                .get_symbol_for_weekday(weekday, field.length, dow)?;
             w.write_str(symbol)?
         }
         FieldSymbol::Day(..) => format_number(
@@ -249,6 +328,18 @@ where
     Ok(())
 }
 
+// UTS 35 uses the stand-alone form of a month or weekday name when it is displayed by
+// itself, and the format form when it is combined with other fields, so a field that is
+// alone in the pattern uses the stand-alone symbols even if it is written in the format form.
+pub(super) fn is_only_field(pattern: &Pattern) -> bool {
+    pattern
+        .items()
+        .iter()
+        .filter(|item| matches!(item, PatternItem::Field(_)))
+        .count()
+        == 1
+}
+
 // This function determins whether the struct will load symbols data.
 // Keep it in sync with the `write_field` use of symbols.
 pub fn analyze_pattern(pattern: &Pattern, supports_time_zones: bool) -> Result<bool, &Field> {
@@ -265,7 +356,9 @@ pub fn analyze_pattern(pattern: &Pattern, supports_time_zones: bool) -> Result<b
                 FieldSymbol::Month(_) => {
                     !matches!(field.length, FieldLength::One | FieldLength::TwoDigit)
                 }
-                FieldSymbol::Weekday(_) | FieldSymbol::DayPeriod(_) => true,
+                FieldSymbol::Year(fields::Year::Cyclic)
+                | FieldSymbol::Weekday(_)
+                | FieldSymbol::DayPeriod(_) => true,
                 _ => false,
             }
         }
@@ -286,6 +379,20 @@ pub fn analyze_pattern(pattern: &Pattern, supports_time_zones: bool) -> Result<b
     Ok(requires_symbols)
 }
 
+// This function determines whether the struct will load week data.
+// Keep it in sync with the `write_field` use of the week data.
+pub fn requires_week_data(pattern: &Pattern) -> bool {
+    pattern.items().iter().any(|item| {
+        matches!(
+            item,
+            PatternItem::Field(Field {
+                symbol: FieldSymbol::Year(fields::Year::WeekOf) | FieldSymbol::Week(_),
+                ..
+            })
+        )
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -315,17 +422,125 @@ mod tests {
         let datetime =
             DateTime::new_gregorian_datetime_from_integers(2020, 8, 1, 12, 34, 28).unwrap();
         let mut sink = String::new();
-        write_pattern(
-            &pattern,
-            Some(data.get()),
-            &datetime,
-            &"und".parse().unwrap(),
-            &mut sink,
-        )
-        .unwrap();
+        write_pattern(&pattern, Some(data.get()), None, &datetime, &mut sink).unwrap();
         println!("{}", sink);
     }
 
+    #[test]
+    fn test_cyclic_year() {
+        use crate::provider::gregory::{cyclic_years, DateSymbolsV1};
+        use alloc::borrow::Cow;
+        use icu_calendar::DateTime;
+
+        let pattern = crate::pattern::reference::Pattern::from_bytes("UUUU").unwrap();
+        let datetime =
+            DateTime::new_gregorian_datetime_from_integers(2020, 8, 1, 12, 34, 28).unwrap();
+
+        // Without cyclic year names, fall back to the numeric year.
+        let mut symbols = DateSymbolsV1::default();
+        let mut sink = String::new();
+        write_pattern(&pattern, Some(&symbols), None, &datetime, &mut sink).unwrap();
+        assert_eq!(sink, "2020");
+
+        let names: Vec<Cow<str>> = (1..=60)
+            .map(|i| Cow::Owned(format!("year {}", i)))
+            .collect();
+        symbols.cyclic_years = Some(cyclic_years::ContextsV1 {
+            format: cyclic_years::FormatWidthsV1 {
+                abbreviated: cyclic_years::SymbolsV1(names.clone()),
+                narrow: cyclic_years::SymbolsV1(names.clone()),
+                short: None,
+                wide: cyclic_years::SymbolsV1(names),
+            },
+            stand_alone: None,
+        });
+        let mut sink = String::new();
+        write_pattern(&pattern, Some(&symbols), None, &datetime, &mut sink).unwrap();
+        assert_eq!(sink, "year 37");
+    }
+
+    #[test]
+    fn test_month_context() {
+        use crate::provider::gregory::{months, DateSymbolsV1};
+        use alloc::borrow::Cow;
+        use icu_calendar::DateTime;
+
+        fn symbols(context: &str) -> months::SymbolsV1 {
+            let mut symbols = months::SymbolsV1::default();
+            for (i, symbol) in symbols.0.iter_mut().enumerate() {
+                *symbol = Cow::Owned(format!("{} {}", context, i + 1));
+            }
+            symbols
+        }
+
+        let data = DateSymbolsV1 {
+            months: months::ContextsV1 {
+                format: months::FormatWidthsV1 {
+                    abbreviated: symbols("format abbreviated"),
+                    narrow: symbols("format narrow"),
+                    short: None,
+                    wide: symbols("format wide"),
+                },
+                stand_alone: Some(months::StandAloneWidthsV1 {
+                    abbreviated: None,
+                    narrow: None,
+                    short: None,
+                    wide: Some(symbols("stand-alone wide")),
+                }),
+            },
+            ..Default::default()
+        };
+        let datetime =
+            DateTime::new_gregorian_datetime_from_integers(2020, 8, 1, 12, 34, 28).unwrap();
+
+        let samples = [
+            ("LLLL", "stand-alone wide 8"),
+            ("MMMM", "stand-alone wide 8"),
+            ("d MMMM", "1 format wide 8"),
+            ("d LLLL", "1 stand-alone wide 8"),
+            // Missing stand-alone widths fall back to the format symbols.
+            ("LLL", "format abbreviated 8"),
+        ];
+        for (pattern, expected) in samples.iter() {
+            let pattern = crate::pattern::reference::Pattern::from_bytes(pattern).unwrap();
+            let mut sink = String::new();
+            write_pattern(&pattern, Some(&data), None, &datetime, &mut sink).unwrap();
+            assert_eq!(sink, *expected);
+        }
+    }
+
+    #[test]
+    fn test_week_fields() {
+        use icu_calendar::DateTime;
+
+        let week_data = WeekDataV1 {
+            first_weekday: 7,
+            min_days_in_first_week: 1,
+        };
+        let datetime =
+            DateTime::new_gregorian_datetime_from_integers(2024, 12, 31, 12, 34, 28).unwrap();
+
+        let samples = [
+            ("'week' w 'of' Y", "week 1 of 2025"),
+            ("ww Y", "01 2025"),
+            ("'week' W 'of' y", "week 5 of 2024"),
+        ];
+        for (pattern, expected) in samples.iter() {
+            let pattern = crate::pattern::reference::Pattern::from_bytes(pattern).unwrap();
+            assert!(requires_week_data(&pattern));
+            let mut sink = String::new();
+            write_pattern(&pattern, None, Some(&week_data), &datetime, &mut sink).unwrap();
+            assert_eq!(sink, *expected);
+        }
+
+        let pattern = crate::pattern::reference::Pattern::from_bytes("w").unwrap();
+        let mut sink = String::new();
+        assert!(matches!(
+            write_pattern(&pattern, None, None, &datetime, &mut sink),
+            Err(Error::MissingInputField)
+        ));
+    }
+
     #[test]
     fn test_format_number() {
         let values = &[2, 20, 201, 2017, 20173];
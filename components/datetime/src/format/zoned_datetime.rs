@@ -7,7 +7,7 @@
 use crate::date::{LocalizedDateTimeInput, ZonedDateTimeInputWithLocale};
 use crate::error::DateTimeFormatError as Error;
 use crate::fields::{self, FieldSymbol};
-use crate::pattern::PatternItem;
+use crate::pattern::{reference::Pattern, PatternItem};
 use crate::{date::ZonedDateTimeInput, zoned_datetime::ZonedDateTimeFormat};
 use core::fmt;
 use writeable::Writeable;
@@ -55,14 +55,23 @@ where
     T: ZonedDateTimeInput,
     W: fmt::Write + ?Sized,
 {
-    let locale = &zoned_datetime_format.datetime_format.locale;
-    let pattern = &zoned_datetime_format.datetime_format.pattern;
-    let loc_datetime = ZonedDateTimeInputWithLocale::new(zoned_datetime, locale);
+    let datetime_format = &zoned_datetime_format.datetime_format;
+    let week_data = datetime_format.week_data.as_ref().map(|w| w.get());
+    let loc_datetime = ZonedDateTimeInputWithLocale::new(zoned_datetime, week_data);
+    let pattern = datetime_format
+        .patterns
+        .select(&loc_datetime, datetime_format.plural_rules.as_ref())?;
+    let only_field = datetime::is_only_field(pattern);
     for item in pattern.items() {
         match item {
-            PatternItem::Field(field) => {
-                write_field(field, zoned_datetime_format, &loc_datetime, w)?
-            }
+            PatternItem::Field(field) => write_field(
+                pattern,
+                only_field,
+                field,
+                zoned_datetime_format,
+                &loc_datetime,
+                w,
+            )?,
             PatternItem::Literal(ch) => w.write_char(*ch)?,
         }
     }
@@ -70,6 +79,8 @@ where
 }
 
 fn write_field<T, W>(
+    pattern: &Pattern,
+    only_field: bool,
     field: &fields::Field,
     zoned_datetime_format: &ZonedDateTimeFormat,
     loc_datetime: &impl LocalizedDateTimeInput<T>,
@@ -79,7 +90,6 @@ where
     T: ZonedDateTimeInput,
     W: fmt::Write + ?Sized,
 {
-    let pattern = &zoned_datetime_format.datetime_format.pattern;
     let symbols = zoned_datetime_format
         .datetime_format
         .symbols
@@ -93,7 +103,7 @@ where
             loc_datetime.datetime(),
             w,
         )?,
-        _ => datetime::write_field(pattern, field, symbols, loc_datetime, w)?,
+        _ => datetime::write_field(pattern, only_field, field, symbols, loc_datetime, w)?,
     }
     Ok(())
 }
@@ -90,6 +90,8 @@ pub struct Bag {
     pub year: Option<Numeric>,
     /// Include the month, such as "April" or "Apr".
     pub month: Option<Month>,
+    /// Include the week number, such as "51st" or "51" for week 51.
+    pub week: Option<Week>,
     /// Include the day, such as "07" or "7".
     pub day: Option<Numeric>,
     /// Include the weekday, such as "Wednesday" or "Wed".
@@ -164,9 +166,24 @@ impl Bag {
             });
         }
 
-        // TODO(#502) - Unimplemented week fields:
-        // w - Week of year
-        // W - Week of month
+        if let Some(week) = self.week {
+            fields.push(Field {
+                symbol: FieldSymbol::Week(match week {
+                    Week::WeekOfMonth => fields::Week::WeekOfMonth,
+                    Week::NumericWeekOfYear | Week::TwoDigitWeekOfYear => fields::Week::WeekOfYear,
+                }),
+                length: match week {
+                    // Week of month (numeric).
+                    // W    1      Numeric: 1 digit
+                    //
+                    // Week of year (numeric).
+                    // w    8, 27  Numeric: minimum digits
+                    // ww   08, 27 Numeric: 2 digits, zero pad if needed
+                    Week::WeekOfMonth | Week::NumericWeekOfYear => FieldLength::One,
+                    Week::TwoDigitWeekOfYear => FieldLength::TwoDigit,
+                },
+            });
+        }
 
         if let Some(day) = self.day {
             // TODO(#591,#592) Unimplemented day fields:
@@ -302,6 +319,7 @@ impl Default for Bag {
             era: None,
             year: None,
             month: None,
+            week: None,
             day: None,
             weekday: None,
 
@@ -365,6 +383,23 @@ pub enum Month {
     Narrow,
 }
 
+/// Options for displaying the current week number for the `components::`[`Bag`].
+///
+/// Week numbers are relative to either a month or year, e.g. 'week 3 of January' or 'week 40 of 2000'.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Week {
+    /// The week of the month, such as the "3" in "week 3 of January".
+    #[cfg_attr(feature = "serde", serde(rename = "week-of-month"))]
+    WeekOfMonth,
+    /// The numeric value of the week of the year, such as the "8" in "week 8 of 2000".
+    #[cfg_attr(feature = "serde", serde(rename = "numeric-week-of-year"))]
+    NumericWeekOfYear,
+    /// The two-digit value of the week of the year, such as the "08" in "2000-W08".
+    #[cfg_attr(feature = "serde", serde(rename = "two-digit-week-of-year"))]
+    TwoDigitWeekOfYear,
+}
+
 // Each enum variant is documented with the UTS 35 field information from:
 // https://unicode.org/reports/tr35/tr35-dates.html#Date_Field_Symbol_Table
 //
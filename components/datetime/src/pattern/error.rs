@@ -3,6 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::fields;
+use crate::options::length;
 use displaydoc::Display;
 
 /// These strings follow the recommendations for the serde::de::Unexpected::Other type.
@@ -22,6 +23,8 @@ pub enum PatternError {
     UnclosedLiteral,
     #[displaydoc("unclosed placeholder in pattern")]
     UnclosedPlaceholder,
+    #[displaydoc("missing date time glue for the {0:?} length")]
+    MissingGlue(length::Date),
 }
 
 #[cfg(feature = "std")]
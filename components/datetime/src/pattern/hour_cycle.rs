@@ -2,6 +2,8 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+#[cfg(feature = "provider_transform_internals")]
+use super::PatternPlurals;
 use super::{reference::Pattern, PatternItem};
 use crate::fields;
 #[cfg(feature = "provider_transform_internals")]
@@ -90,17 +92,23 @@ impl CoarseHourCycle {
         let skeleton = skeleton::Skeleton::from(&pattern);
 
         match skeleton::create_best_pattern_for_fields(
-            &datetime.skeletons,
-            &datetime.length_patterns,
+            datetime,
             skeleton.as_slice(),
             &Default::default(),
             // Prefer using the matched pattern directly, rather than mutating it to match the
             // requested fields.
             true,
-        ) {
-            skeleton::BestSkeleton::AllFieldsMatch(pattern)
-            | skeleton::BestSkeleton::MissingOrExtraFields(pattern) => Some(format!("{}", pattern)),
-            skeleton::BestSkeleton::NoMatch => None,
+        )
+        .ok()?
+        {
+            skeleton::BestSkeleton::AllFieldsMatch(PatternPlurals::SinglePattern(pattern))
+            | skeleton::BestSkeleton::MissingOrExtraFields(PatternPlurals::SinglePattern(
+                pattern,
+            )) => Some(format!("{}", pattern)),
+            // The time patterns have no week fields to select a plural variant.
+            skeleton::BestSkeleton::AllFieldsMatch(PatternPlurals::MultipleVariants(_))
+            | skeleton::BestSkeleton::MissingOrExtraFields(PatternPlurals::MultipleVariants(_))
+            | skeleton::BestSkeleton::NoMatch => None,
         }
     }
 }
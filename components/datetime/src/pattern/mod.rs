@@ -5,6 +5,7 @@
 mod error;
 mod hour_cycle;
 mod item;
+mod plurals;
 pub mod reference;
 pub mod runtime;
 
@@ -12,6 +13,7 @@ use crate::fields;
 pub use error::PatternError;
 pub use hour_cycle::CoarseHourCycle;
 pub use item::{GenericPatternItem, PatternItem};
+pub use plurals::PatternPlurals;
 
 /// The granularity of time represented in a pattern item.
 /// Ordered from least granular to most granular for comparsion.
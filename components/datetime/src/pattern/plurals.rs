@@ -0,0 +1,154 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::{reference::Pattern, PatternItem};
+use crate::date::{DateTimeInput, LocalizedDateTimeInput};
+use crate::error::DateTimeFormatError;
+use crate::fields::{self, FieldSymbol};
+use crate::provider::gregory::patterns::PluralPatternsV1;
+use icu_plurals::PluralRules;
+
+/// A pattern, or the variants of a pattern which vary by the plural category of the week number
+/// in it, such as the `yw-count-one` and `yw-count-other` skeletons of CLDR.
+#[derive(Debug, PartialEq, Clone)]
+pub enum PatternPlurals {
+    /// A pattern which does not vary.
+    SinglePattern(Pattern),
+    /// The variants of a pattern, selected by the week field in it.
+    MultipleVariants(PluralPatternsV1),
+}
+
+impl Default for PatternPlurals {
+    fn default() -> Self {
+        Self::SinglePattern(Pattern::default())
+    }
+}
+
+impl From<Pattern> for PatternPlurals {
+    fn from(pattern: Pattern) -> Self {
+        Self::SinglePattern(pattern)
+    }
+}
+
+impl PatternPlurals {
+    /// Returns an iterator over all the patterns, such as for finding the data they require.
+    /// The last one is the `other` variant.
+    pub fn patterns_iter(&self) -> impl Iterator<Item = &Pattern> {
+        let (single, variants) = match self {
+            Self::SinglePattern(pattern) => (Some(pattern), None),
+            Self::MultipleVariants(variants) => (None, Some(variants)),
+        };
+        single
+            .into_iter()
+            .chain(variants.into_iter().flat_map(|v| v.patterns_iter()))
+    }
+
+    /// Returns a mutable iterator over all the patterns, such as for adjusting their fields.
+    pub fn patterns_iter_mut(&mut self) -> impl Iterator<Item = &mut Pattern> {
+        let (single, variants) = match self {
+            Self::SinglePattern(pattern) => (Some(pattern), None),
+            Self::MultipleVariants(variants) => (None, Some(variants)),
+        };
+        single
+            .into_iter()
+            .chain(variants.into_iter().flat_map(|v| v.patterns_iter_mut()))
+    }
+
+    /// Returns `true` if the patterns vary by plural category, and so require the
+    /// [`PluralRules`] of the locale to be formatted.
+    pub fn requires_plural_rules(&self) -> bool {
+        matches!(self, Self::MultipleVariants(_))
+    }
+
+    /// Returns the pattern to format the `datetime` with.
+    ///
+    /// The variant is selected by the plural category of the week of the year or of the month,
+    /// whichever is in the `other` variant, with the cardinal `plural_rules` of the locale.
+    pub fn select<T: DateTimeInput>(
+        &self,
+        datetime: &impl LocalizedDateTimeInput<T>,
+        plural_rules: Option<&PluralRules>,
+    ) -> Result<&Pattern, DateTimeFormatError> {
+        let variants = match self {
+            Self::SinglePattern(pattern) => return Ok(pattern),
+            Self::MultipleVariants(variants) => variants,
+        };
+        let plural_rules = plural_rules.ok_or(DateTimeFormatError::MissingPluralRules)?;
+
+        let week = variants
+            .other
+            .0
+            .items()
+            .iter()
+            .find_map(|item| match item {
+                PatternItem::Field(fields::Field {
+                    symbol: FieldSymbol::Week(week),
+                    length: _,
+                }) => Some(*week),
+                _ => None,
+            })
+            .ok_or(DateTimeFormatError::MissingPluralField)?;
+        let number = match week {
+            fields::Week::WeekOfYear => datetime.week_of_year().map(|week| week.0),
+            fields::Week::WeekOfMonth => datetime.week_of_month().map(|week| week.0),
+        }
+        .ok_or(DateTimeFormatError::MissingInputField)?;
+
+        Ok(&variants.get(plural_rules.select(number)).0)
+    }
+}
+
+#[cfg(all(test, feature = "provider_serde"))]
+mod test {
+    use super::*;
+    use crate::date::DateTimeInputWithLocale;
+    use crate::provider::gregory::patterns::PatternV1;
+    use crate::provider::week_data::WeekDataV1;
+    use alloc::string::{String, ToString};
+    use core::convert::TryFrom;
+    use icu_calendar::DateTime;
+    use icu_locid_macros::langid;
+    use icu_plurals::{PluralCategory, PluralRuleType};
+
+    #[test]
+    fn test_select_plural_variant() {
+        let provider = icu_testdata::get_provider();
+        let plural_rules =
+            PluralRules::try_new(langid!("en"), &provider, PluralRuleType::Cardinal).unwrap();
+        let mut variants = PluralPatternsV1::new(PatternV1::try_from("'weeks' w").unwrap());
+        variants.set(
+            PluralCategory::One,
+            PatternV1::try_from("'week' w").unwrap(),
+        );
+        let patterns = PatternPlurals::MultipleVariants(variants);
+        // Weeks start on Sunday in the US.
+        let week_data = WeekDataV1 {
+            first_weekday: 7,
+            min_days_in_first_week: 1,
+        };
+
+        let select =
+            |day: u8, plural_rules: Option<&PluralRules>| -> Result<String, DateTimeFormatError> {
+                let datetime =
+                    DateTime::new_gregorian_datetime_from_integers(2021, 1, day, 0, 0, 0).unwrap();
+                let input = DateTimeInputWithLocale::new(&datetime, Some(&week_data));
+                Ok(patterns.select(&input, plural_rules)?.to_string())
+            };
+
+        // January 2 is in the first week of 2021, and January 5 in the second one.
+        assert_eq!(select(2, Some(&plural_rules)).unwrap(), "'week' w");
+        assert_eq!(select(5, Some(&plural_rules)).unwrap(), "'weeks' w");
+        assert!(matches!(
+            select(5, None),
+            Err(DateTimeFormatError::MissingPluralRules)
+        ));
+
+        // A single pattern doesn't need the plural rules.
+        let pattern = Pattern::from_bytes("'week' w").unwrap();
+        let single = PatternPlurals::from(pattern.clone());
+        let datetime = DateTime::new_gregorian_datetime_from_integers(2021, 1, 5, 0, 0, 0).unwrap();
+        let input = DateTimeInputWithLocale::new(&datetime, Some(&week_data));
+        assert_eq!(single.select(&input, None).unwrap(), &pattern);
+    }
+}
@@ -343,6 +343,50 @@ mod tests {
         }
     }
 
+    #[test]
+    fn pattern_quoted_literals_roundtrip() {
+        use crate::provider::gregory::patterns::PatternV1;
+        use alloc::string::ToString;
+
+        let samples = vec![
+            ("h 'o''clock'", "h 'o''clock'"),
+            ("h 'o''clock' a", "h 'o''clock' a"),
+            ("h''", "h''"),
+            ("'''h'''", "'''h'''"),
+            ("h'''h'''", "h'''h'''"),
+            // An apostrophe followed by letters is not quoted along with them.
+            ("h'''o'", "h'''o'"),
+            // The quotes are only kept around the letters.
+            ("h'.'m", "h.m"),
+            ("h' 'm", "h m"),
+            ("''''", "''''"),
+        ];
+
+        for (string, expected) in samples {
+            let pattern = Pattern::from_bytes(string).expect("Parsing pattern failed.");
+            assert_eq!(pattern.to_string(), expected, "{}", string);
+            assert_eq!(
+                Pattern::from_bytes(expected).expect("Parsing pattern failed."),
+                pattern,
+                "{}",
+                string
+            );
+        }
+
+        let pattern = PatternV1::try_from("h 'o''clock'").expect("Parsing pattern failed.");
+        assert_eq!(pattern.0.items()[1..], str2pis(" o'clock")[..],);
+
+        let broken = vec![
+            ("h 'o''clock", PatternError::UnclosedLiteral),
+            ("'''", PatternError::UnclosedLiteral),
+            ("h'''", PatternError::UnclosedLiteral),
+        ];
+
+        for (string, error) in broken {
+            assert_eq!(Parser::new(string).parse(), Err(error));
+        }
+    }
+
     #[test]
     fn pattern_parse_symbols() {
         let samples = vec![
@@ -6,6 +6,7 @@ use super::{
     super::{PatternError, PatternItem, TimeGranularity},
     Parser,
 };
+use crate::fields::Field;
 use alloc::fmt::{self, Write};
 #[cfg(feature = "provider_serde")]
 use alloc::format;
@@ -36,6 +37,20 @@ impl Pattern {
         &mut self.items
     }
 
+    /// Returns the distinct fields of the pattern in the order of their first use, ignoring
+    /// the literals, including quoted letters.
+    pub fn fields(&self) -> Vec<Field> {
+        let mut fields: Vec<Field> = Vec::new();
+        for item in self.items.iter() {
+            if let PatternItem::Field(field) = item {
+                if !fields.contains(field) {
+                    fields.push(*field);
+                }
+            }
+        }
+        fields
+    }
+
     pub fn from_bytes(input: &str) -> Result<Self, PatternError> {
         Parser::new(input).parse().map(Self::from)
     }
@@ -74,19 +89,13 @@ fn dump_buffer_into_formatter(literal: &str, formatter: &mut fmt::Formatter) ->
     if literal.is_empty() {
         return Ok(());
     }
-    // Determine if the literal contains any characters that would need to be escaped.
-    let mut needs_escaping = false;
-    for ch in literal.chars() {
-        if ch.is_ascii_alphabetic() || ch == '\'' {
-            needs_escaping = true;
-            break;
-        }
-    }
+    // Determine if the literal contains any characters that would need to be quoted.
+    let needs_quoting = literal.chars().any(|ch| ch.is_ascii_alphabetic());
 
-    if needs_escaping {
+    if needs_quoting {
         let mut ch_iter = literal.trim_end().chars().peekable();
 
-        // Do not escape the leading whitespace.
+        // Do not quote the leading whitespace.
         while let Some(ch) = ch_iter.peek() {
             if ch.is_whitespace() {
                 formatter.write_char(*ch)?;
@@ -96,12 +105,19 @@ fn dump_buffer_into_formatter(literal: &str, formatter: &mut fmt::Formatter) ->
             }
         }
 
+        // Do not quote the leading "'" either, since an opening quote followed by an escaped
+        // quote would be read as an escaped quote followed by an opening quote.
+        while ch_iter.peek() == Some(&'\'') {
+            formatter.write_str("''")?;
+            ch_iter.next();
+        }
+
         // Wrap in "'" and escape "'".
         formatter.write_char('\'')?;
         for ch in ch_iter {
             if ch == '\'' {
                 // Escape a single quote.
-                formatter.write_char('\\')?;
+                formatter.write_char('\'')?;
             }
             formatter.write_char(ch)?;
         }
@@ -116,7 +132,13 @@ fn dump_buffer_into_formatter(literal: &str, formatter: &mut fmt::Formatter) ->
             }
         }
     } else {
-        formatter.write_str(literal)?;
+        for ch in literal.chars() {
+            if ch == '\'' {
+                // Escape a single quote.
+                formatter.write_char('\'')?;
+            }
+            formatter.write_char(ch)?;
+        }
     }
     Ok(())
 }
@@ -234,3 +256,41 @@ impl Serialize for Pattern {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fields::{self, FieldLength};
+
+    #[test]
+    fn pattern_fields() {
+        let year = Field::from((fields::Year::Calendar.into(), FieldLength::One));
+        let month = Field::from((fields::Month::Format.into(), FieldLength::Wide));
+        let day = Field::from((fields::Day::DayOfMonth.into(), FieldLength::One));
+
+        let samples: Vec<(&str, Vec<Field>)> = vec![
+            ("y MMMM", vec![year, month]),
+            ("d MMMM y", vec![day, month, year]),
+            // Repeated fields are reported once.
+            ("d MMMM y, d", vec![day, month, year]),
+            // The same symbol with another length is another field.
+            (
+                "y yy",
+                vec![
+                    year,
+                    (fields::Year::Calendar.into(), FieldLength::TwoDigit).into(),
+                ],
+            ),
+            // Quoted letters are literals.
+            ("'year'", vec![]),
+            ("y 'year' d", vec![year, day]),
+            ("", vec![]),
+            // There is no era field symbol yet, so `G` is read as a literal.
+            ("y G", vec![year]),
+        ];
+        for (string, expected) in samples {
+            let pattern = Pattern::from_bytes(string).expect("Parsing pattern failed.");
+            assert_eq!(pattern.fields(), expected, "{}", string);
+        }
+    }
+}
@@ -17,8 +17,20 @@ pub struct Pattern<'data> {
     pub(crate) time_granularity: TimeGranularity,
 }
 
+/// The runtime [`Pattern`] is the [`reference::Pattern`] with its items stored in a
+/// [`ZeroVec`], so it can be deserialized without allocations.
+///
+/// The conversion keeps the items as they are: each literal character stays a separate
+/// item, and adjacent literals are not coalesced. The time granularity is carried over,
+/// so nothing is lost when converting back into a [`reference::Pattern`].
 impl From<reference::Pattern> for Pattern<'_> {
     fn from(input: reference::Pattern) -> Self {
+        Self::from(&input)
+    }
+}
+
+impl From<&reference::Pattern> for Pattern<'_> {
+    fn from(input: &reference::Pattern) -> Self {
         Self {
             items: ZeroVec::clone_from_slice(&input.items),
             time_granularity: input.time_granularity,
@@ -26,6 +38,15 @@ impl From<reference::Pattern> for Pattern<'_> {
     }
 }
 
+impl From<&Pattern<'_>> for reference::Pattern {
+    fn from(input: &Pattern<'_>) -> Self {
+        Self {
+            items: input.items.to_vec(),
+            time_granularity: input.time_granularity,
+        }
+    }
+}
+
 impl Default for Pattern<'_> {
     fn default() -> Self {
         Self {
@@ -34,3 +55,37 @@ impl Default for Pattern<'_> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::format::datetime::write_pattern;
+    use alloc::string::{String, ToString};
+    use icu_calendar::DateTime;
+
+    #[test]
+    fn test_runtime_roundtrip() {
+        let datetime =
+            DateTime::new_gregorian_datetime_from_integers(2020, 8, 1, 12, 34, 28).unwrap();
+
+        let samples = [
+            ("y-MM-dd HH:mm:ss", "2020-08-01 12:34:28"),
+            ("d.M.y 'at' h:mm", "1.8.2020 at 12:34"),
+            ("HH 'o''clock'", "12 o'clock"),
+        ];
+        for (pattern_str, formatted) in samples.iter() {
+            let reference = reference::Pattern::from_bytes(pattern_str).unwrap();
+            let runtime = Pattern::from(&reference);
+            let roundtrip = reference::Pattern::from(&runtime);
+            assert_eq!(reference, roundtrip);
+            assert_eq!(reference.to_string(), roundtrip.to_string());
+
+            let mut expected = String::new();
+            write_pattern(&reference, None, None, &datetime, &mut expected).unwrap();
+            assert_eq!(expected, *formatted);
+            let mut actual = String::new();
+            write_pattern(&roundtrip, None, None, &datetime, &mut actual).unwrap();
+            assert_eq!(expected, actual);
+        }
+    }
+}
@@ -0,0 +1,382 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! A compact binary encoding of [`SkeletonsV1`], which can be searched without deserializing
+//! the whole map.
+//!
+//! The encoding is laid out as follows, with all integers in little endian:
+//!
+//! ```text
+//! count:   u32                 the number of skeletons
+//! offsets: [u32; count]        the byte offset of each entry from the start of the encoding
+//! entries: [entry; count]      sorted by skeleton, in the canonical order of SkeletonV1
+//!
+//! entry:
+//!   field count:  u8
+//!   fields:       [(symbol: u8, length: u8); field count]
+//!   pattern size: u32
+//!   pattern:      [u8; pattern size]    the UTS 35 pattern string, in UTF-8
+//! ```
+//!
+//! The fields use the same one-byte symbol and length representation as the serialized
+//! patterns. Since the offsets have a fixed size, a skeleton is looked up with a binary search,
+//! and only the pattern that is found is parsed.
+//!
+//! # Examples
+//!
+//! ```
+//! use core::convert::TryFrom;
+//! use icu_datetime::provider::encoded_skeletons::EncodedSkeletonsV1;
+//! use icu_datetime::provider::gregory::patterns::{PatternV1, SkeletonV1, SkeletonsV1};
+//!
+//! let mut skeletons = SkeletonsV1::default();
+//! skeletons.0.insert(
+//!     SkeletonV1::try_from("yMMMd").unwrap(),
+//!     PatternV1::try_from("MMM d, y").unwrap(),
+//! );
+//!
+//! let bytes = skeletons.encode().expect("Too large to encode");
+//! let encoded = EncodedSkeletonsV1::try_from_bytes(&bytes).expect("Invalid encoding");
+//!
+//! let skeleton = SkeletonV1::try_from("yMMMd").unwrap();
+//! assert_eq!(encoded.get_str(&skeleton), Ok(Some("MMM d, y")));
+//! assert_eq!(
+//!     encoded.get(&skeleton).expect("Invalid encoding").as_ref(),
+//!     skeletons.0.get(&skeleton)
+//! );
+//! ```
+//!
+//! [`SkeletonsV1`]: crate::provider::gregory::patterns::SkeletonsV1
+
+use crate::fields::{Field, FieldLength, FieldSymbol};
+use crate::pattern::{reference::Pattern, PatternError};
+use crate::provider::gregory::patterns::{PatternV1, SkeletonV1, SkeletonsV1};
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::convert::{TryFrom, TryInto};
+use displaydoc::Display;
+
+/// An error in the bytes given to [`EncodedSkeletonsV1::try_from_bytes`], or in the skeletons
+/// given to [`SkeletonsV1::encode`].
+#[derive(Display, Debug, PartialEq)]
+pub enum EncodedSkeletonsError {
+    /// The bytes end in the middle of the header or of an entry.
+    #[displaydoc("unexpected end of the encoded skeletons")]
+    UnexpectedEnd,
+    /// A field of a skeleton has an invalid symbol or length.
+    #[displaydoc("invalid field in the encoded skeletons")]
+    InvalidField,
+    /// The skeletons are not sorted, so they can't be binary searched.
+    #[displaydoc("skeleton out of order in the encoded skeletons")]
+    OutOfOrder,
+    /// A pattern is not valid UTF-8.
+    #[displaydoc("invalid UTF-8 in a pattern of the encoded skeletons")]
+    InvalidUtf8,
+    /// A pattern failed to parse.
+    #[displaydoc("{0}")]
+    Pattern(PatternError),
+    /// A skeleton or the whole encoding doesn't fit the sizes of the format.
+    #[displaydoc("too many fields or too large a pattern to encode the skeletons")]
+    TooLarge,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EncodedSkeletonsError {}
+
+impl From<PatternError> for EncodedSkeletonsError {
+    fn from(e: PatternError) -> Self {
+        EncodedSkeletonsError::Pattern(e)
+    }
+}
+
+impl SkeletonsV1 {
+    /// Encodes the skeletons and their patterns into the format of [`EncodedSkeletonsV1`].
+    ///
+    /// Returns [`EncodedSkeletonsError::TooLarge`] if a skeleton has more than 255 fields, or if
+    /// the encoding doesn't fit the 32-bit sizes and offsets.
+    pub fn encode(&self) -> Result<Vec<u8>, EncodedSkeletonsError> {
+        let entries: Vec<Vec<u8>> = self
+            .0
+            .iter()
+            .map(|(skeleton, pattern)| encode_entry(skeleton, pattern))
+            .collect::<Result<_, _>>()?;
+
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&to_u32(entries.len())?.to_le_bytes());
+        let mut offset = 4 + 4 * entries.len();
+        for entry in entries.iter() {
+            bytes.extend_from_slice(&to_u32(offset)?.to_le_bytes());
+            offset += entry.len();
+        }
+        for entry in entries.iter() {
+            bytes.extend_from_slice(entry);
+        }
+        Ok(bytes)
+    }
+}
+
+/// The skeletons of [`SkeletonsV1`] in a compact binary encoding, borrowing the encoded bytes.
+///
+/// See the [module documentation](self) for the layout of the encoding.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EncodedSkeletonsV1<'data> {
+    bytes: &'data [u8],
+    len: usize,
+}
+
+struct Entry<'data> {
+    fields: &'data [u8],
+    pattern: &'data str,
+}
+
+impl<'data> Entry<'data> {
+    fn fields(&self) -> impl Iterator<Item = Result<Field, EncodedSkeletonsError>> + 'data {
+        let fields: &'data [u8] = self.fields;
+        fields.chunks_exact(2).map(|field| {
+            let symbol =
+                FieldSymbol::from_idx(field[0]).map_err(|_| EncodedSkeletonsError::InvalidField)?;
+            let length =
+                FieldLength::from_idx(field[1]).map_err(|_| EncodedSkeletonsError::InvalidField)?;
+            Ok(Field::from((symbol, length)))
+        })
+    }
+
+    fn skeleton(&self) -> Result<SkeletonV1, EncodedSkeletonsError> {
+        let fields = self
+            .fields()
+            .map(|field| field.map(|field| (field.symbol, field.length)))
+            .collect::<Result<Vec<_>, _>>()?;
+        SkeletonV1::try_from_fields(fields).map_err(|_| EncodedSkeletonsError::InvalidField)
+    }
+
+    fn cmp_skeleton(&self, skeleton: &SkeletonV1) -> Ordering {
+        self.fields()
+            .map(Result::ok)
+            .cmp(skeleton.0.as_slice().iter().copied().map(Some))
+    }
+}
+
+impl<'data> EncodedSkeletonsV1<'data> {
+    /// Checks the encoding of `bytes`, as returned by [`SkeletonsV1::encode`], and wraps them.
+    ///
+    /// All of the skeletons and patterns are validated, so that the lookups don't fail.
+    pub fn try_from_bytes(bytes: &'data [u8]) -> Result<Self, EncodedSkeletonsError> {
+        let len = read_u32(bytes, 0)? as usize;
+        let encoded = Self { bytes, len };
+        let mut previous: Option<SkeletonV1> = None;
+        for index in 0..len {
+            let entry = encoded.entry(index)?;
+            let skeleton = entry.skeleton()?;
+            if matches!(&previous, Some(previous) if *previous >= skeleton) {
+                return Err(EncodedSkeletonsError::OutOfOrder);
+            }
+            Pattern::from_bytes(entry.pattern)?;
+            previous = Some(skeleton);
+        }
+        Ok(encoded)
+    }
+
+    /// Returns the number of skeletons.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no skeletons.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Looks up the UTS 35 pattern string of a skeleton, without parsing it.
+    pub fn get_str(
+        &self,
+        skeleton: &SkeletonV1,
+    ) -> Result<Option<&'data str>, EncodedSkeletonsError> {
+        let (mut low, mut high) = (0, self.len);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            let entry = self.entry(middle)?;
+            match entry.cmp_skeleton(skeleton) {
+                Ordering::Less => low = middle + 1,
+                Ordering::Greater => high = middle,
+                Ordering::Equal => return Ok(Some(entry.pattern)),
+            }
+        }
+        Ok(None)
+    }
+
+    /// Looks up the pattern of a skeleton.
+    pub fn get(&self, skeleton: &SkeletonV1) -> Result<Option<PatternV1>, EncodedSkeletonsError> {
+        match self.get_str(skeleton)? {
+            Some(pattern) => Ok(Some(PatternV1(Pattern::from_bytes(pattern)?))),
+            None => Ok(None),
+        }
+    }
+
+    /// Decodes all of the skeletons and their patterns into a [`SkeletonsV1`].
+    pub fn decode(&self) -> Result<SkeletonsV1, EncodedSkeletonsError> {
+        let mut skeletons = SkeletonsV1::default();
+        for index in 0..self.len {
+            let entry = self.entry(index)?;
+            let skeleton = entry.skeleton()?;
+            let pattern = PatternV1(Pattern::from_bytes(entry.pattern)?);
+            skeletons.0.insert(skeleton, pattern);
+        }
+        Ok(skeletons)
+    }
+
+    fn entry(&self, index: usize) -> Result<Entry<'data>, EncodedSkeletonsError> {
+        let offset = read_u32(self.bytes, 4 + 4 * index)? as usize;
+        let field_count = *self
+            .bytes
+            .get(offset)
+            .ok_or(EncodedSkeletonsError::UnexpectedEnd)? as usize;
+        let fields_start = offset + 1;
+        let fields_end = fields_start + 2 * field_count;
+        let fields = self
+            .bytes
+            .get(fields_start..fields_end)
+            .ok_or(EncodedSkeletonsError::UnexpectedEnd)?;
+        let pattern_size = read_u32(self.bytes, fields_end)? as usize;
+        let pattern_start = fields_end + 4;
+        let pattern = self
+            .bytes
+            .get(pattern_start..pattern_start.saturating_add(pattern_size))
+            .ok_or(EncodedSkeletonsError::UnexpectedEnd)?;
+        let pattern =
+            core::str::from_utf8(pattern).map_err(|_| EncodedSkeletonsError::InvalidUtf8)?;
+        Ok(Entry { fields, pattern })
+    }
+}
+
+fn encode_entry(
+    skeleton: &SkeletonV1,
+    pattern: &PatternV1,
+) -> Result<Vec<u8>, EncodedSkeletonsError> {
+    let fields = skeleton.0.as_slice();
+    let pattern = pattern.0.to_string();
+    let mut entry = Vec::with_capacity(1 + 2 * fields.len() + 4 + pattern.len());
+    entry.push(to_u8(fields.len())?);
+    for field in fields {
+        entry.push(field.symbol.idx());
+        entry.push(field.length.idx());
+    }
+    entry.extend_from_slice(&to_u32(pattern.len())?.to_le_bytes());
+    entry.extend_from_slice(pattern.as_bytes());
+    Ok(entry)
+}
+
+fn to_u8(value: usize) -> Result<u8, EncodedSkeletonsError> {
+    u8::try_from(value).map_err(|_| EncodedSkeletonsError::TooLarge)
+}
+
+fn to_u32(value: usize) -> Result<u32, EncodedSkeletonsError> {
+    u32::try_from(value).map_err(|_| EncodedSkeletonsError::TooLarge)
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> Result<u32, EncodedSkeletonsError> {
+    let bytes = bytes
+        .get(offset..offset.saturating_add(4))
+        .ok_or(EncodedSkeletonsError::UnexpectedEnd)?;
+    // The slice has a length of 4.
+    Ok(u32::from_le_bytes(bytes.try_into().unwrap_or_default()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn skeletons() -> SkeletonsV1 {
+        let mut skeletons = SkeletonsV1::default();
+        for (skeleton, pattern) in [
+            ("yMMMd", "MMM d, y"),
+            ("yMd", "M/d/y"),
+            ("MMMMd", "MMMM d"),
+            ("Ehm", "E h:mm a"),
+            ("Hm", "HH:mm"),
+            ("d", "d"),
+            ("yMMMMd", "d 'de' MMMM 'de' y"),
+        ]
+        .iter()
+        {
+            skeletons.0.insert(
+                SkeletonV1::try_from(*skeleton).unwrap(),
+                PatternV1::try_from(*pattern).unwrap(),
+            );
+        }
+        skeletons
+    }
+
+    #[test]
+    fn test_encoded_skeletons_lookup() {
+        let skeletons = skeletons();
+        let bytes = skeletons.encode().expect("Too large to encode");
+        let encoded = EncodedSkeletonsV1::try_from_bytes(&bytes).expect("Invalid encoding");
+        assert_eq!(encoded.len(), skeletons.len());
+
+        for (skeleton, pattern) in skeletons.0.iter() {
+            assert_eq!(
+                encoded.get(skeleton).expect("Invalid encoding").as_ref(),
+                Some(pattern),
+                "{:?}",
+                skeleton
+            );
+        }
+        assert_eq!(
+            encoded.get_str(&SkeletonV1::try_from("yMMMd").unwrap()),
+            Ok(Some("MMM d, y"))
+        );
+        for missing in ["y", "yMMM", "yMMMMEd", "Hms"].iter() {
+            assert_eq!(
+                encoded.get(&SkeletonV1::try_from(*missing).unwrap()),
+                Ok(None)
+            );
+        }
+
+        assert_eq!(encoded.decode(), Ok(skeletons));
+    }
+
+    #[test]
+    fn test_encoded_skeletons_empty() {
+        let bytes = SkeletonsV1::default()
+            .encode()
+            .expect("Too large to encode");
+        assert_eq!(bytes, [0, 0, 0, 0]);
+        let encoded = EncodedSkeletonsV1::try_from_bytes(&bytes).expect("Invalid encoding");
+        assert!(encoded.is_empty());
+        assert_eq!(encoded.get(&SkeletonV1::try_from("d").unwrap()), Ok(None));
+    }
+
+    #[test]
+    fn test_encoded_skeletons_invalid() {
+        let bytes = skeletons().encode().expect("Too large to encode");
+        for len in 0..bytes.len() {
+            assert_eq!(
+                EncodedSkeletonsV1::try_from_bytes(&bytes[..len]),
+                Err(EncodedSkeletonsError::UnexpectedEnd),
+                "{}",
+                len
+            );
+        }
+
+        // The first entry starts after the count and the offsets.
+        let first = 4 + 4 * skeletons().len();
+        let mut invalid = bytes.clone();
+        invalid[first + 1] = 0xFF;
+        assert_eq!(
+            EncodedSkeletonsV1::try_from_bytes(&invalid),
+            Err(EncodedSkeletonsError::InvalidField)
+        );
+
+        // Swap the offsets of the first two entries.
+        let mut invalid = bytes;
+        let (offsets, _) = invalid[4..].split_at_mut(8);
+        let (a, b) = offsets.split_at_mut(4);
+        a.swap_with_slice(b);
+        assert_eq!(
+            EncodedSkeletonsV1::try_from_bytes(&invalid),
+            Err(EncodedSkeletonsError::OutOfOrder)
+        );
+    }
+}
@@ -4,8 +4,11 @@
 
 #![allow(missing_docs)] // TODO(#686) - Add missing docs.
 
-use crate::pattern;
+use crate::options::preferences;
+use crate::{fields, pattern};
 use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 use icu_provider::yoke::{self, *};
 
 #[icu_provider::data_struct]
@@ -21,6 +24,14 @@ pub struct DateSymbolsV1 {
     pub weekdays: weekdays::ContextsV1,
 
     pub day_periods: day_periods::ContextsV1,
+
+    /// The names of the years in the 60-year cycle of calendars like Chinese and Dangi.
+    /// When they are missing, cyclic years are formatted as numeric years.
+    pub cyclic_years: Option<cyclic_years::ContextsV1>,
+
+    /// The patterns for the names of leap months, and of months named with their number, in
+    /// calendars like Chinese and Dangi. They are missing for calendars without leap months.
+    pub month_patterns: Option<month_patterns::ContextsV1>,
 }
 
 #[icu_provider::data_struct]
@@ -49,6 +60,53 @@ pub struct DatePatternsV1 {
     pub datetime: patterns::DateTimeFormatsV1,
 }
 
+impl DatePatternsV1 {
+    /// Returns `true` if the locale prefers a 24-hour clock by default, i.e. its preferred hour
+    /// cycle is either h23 or h24.
+    pub fn is_24_hour_default(&self) -> bool {
+        self.preferred_hour_cycle == pattern::CoarseHourCycle::H23H24
+    }
+
+    /// Returns the hour cycle used by the preferred time patterns of the locale, such as
+    /// [`HourCycle::H12`](preferences::HourCycle::H12) for `h:mm a`, or [`None`] if none of them
+    /// has an hour field.
+    pub fn default_hour_cycle(&self) -> Option<preferences::HourCycle> {
+        let time = match self.preferred_hour_cycle {
+            pattern::CoarseHourCycle::H11H12 => &self.time_h11_h12,
+            pattern::CoarseHourCycle::H23H24 => &self.time_h23_h24,
+        };
+        [&time.short, &time.medium, &time.long, &time.full]
+            .iter()
+            .filter_map(|bytes| pattern::reference::Pattern::from_bytes(bytes).ok())
+            .find_map(|time_pattern| {
+                time_pattern.items().iter().find_map(|item| match item {
+                    pattern::PatternItem::Field(fields::Field {
+                        symbol: fields::FieldSymbol::Hour(hour),
+                        length: _,
+                    }) => Some(match hour {
+                        fields::Hour::H11 => preferences::HourCycle::H11,
+                        fields::Hour::H12 => preferences::HourCycle::H12,
+                        fields::Hour::H23 => preferences::HourCycle::H23,
+                        fields::Hour::H24 => preferences::HourCycle::H24,
+                    }),
+                    _ => None,
+                })
+            })
+    }
+}
+
+/// The width of a set of symbols, such as the names of the months.
+///
+/// Not all widths are present in the data, see [`months::ContextsV1::get`] for the fallback
+/// between them.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Width {
+    Abbreviated,
+    Narrow,
+    Short,
+    Wide,
+}
+
 macro_rules! symbols {
         ($name: ident, $expr: ty) => {
             pub mod $name {
@@ -58,6 +116,13 @@ macro_rules! symbols {
                 #[cfg_attr(feature="provider_serde", derive(serde::Serialize, serde::Deserialize))]
                 pub struct SymbolsV1(pub $expr);
 
+                impl SymbolsV1 {
+                    /// Returns the symbol at `index`, or [`None`] if the index is out of range.
+                    pub fn get(&self, index: usize) -> Option<&str> {
+                        self.0.get(index).map(|symbol| symbol.as_ref())
+                    }
+                }
+
                 symbols!();
             }
         };
@@ -132,6 +197,49 @@ macro_rules! symbols {
                 pub format: FormatWidthsV1,
                 pub stand_alone: Option<StandAloneWidthsV1>,
             }
+
+            impl FormatWidthsV1 {
+                /// Returns the symbols for the [`Width`], falling back from `short` to
+                /// `abbreviated`.
+                pub fn get(&self, width: Width) -> &SymbolsV1 {
+                    match width {
+                        Width::Abbreviated => &self.abbreviated,
+                        Width::Narrow => &self.narrow,
+                        Width::Short => self.short.as_ref().unwrap_or(&self.abbreviated),
+                        Width::Wide => &self.wide,
+                    }
+                }
+            }
+
+            impl StandAloneWidthsV1 {
+                /// Returns the symbols for the [`Width`], falling back from `short` to
+                /// `abbreviated`, or [`None`] if they are missing.
+                pub fn get(&self, width: Width) -> Option<&SymbolsV1> {
+                    match width {
+                        Width::Abbreviated => self.abbreviated.as_ref(),
+                        Width::Narrow => self.narrow.as_ref(),
+                        Width::Short => self.short.as_ref().or_else(|| self.abbreviated.as_ref()),
+                        Width::Wide => self.wide.as_ref(),
+                    }
+                }
+            }
+
+            impl ContextsV1 {
+                /// Returns the symbols for the [`Width`] in the format or the stand-alone
+                /// context, following the fallback of UTS 35: `short` falls back to
+                /// `abbreviated`, and missing stand-alone symbols fall back to the format
+                /// symbols of the same width.
+                pub fn get(&self, width: Width, is_standalone: bool) -> &SymbolsV1 {
+                    if is_standalone {
+                        if let Some(symbols) =
+                            self.stand_alone.as_ref().and_then(|widths| widths.get(width))
+                        {
+                            return symbols;
+                        }
+                    }
+                    self.format.get(width)
+                }
+            }
         };
     }
 
@@ -139,6 +247,35 @@ symbols!(months, [Cow<'static, str>; 12]);
 
 symbols!(weekdays, [Cow<'static, str>; 7]);
 
+// A cycle has 60 years, but serde only supports arrays of up to 32 elements.
+symbols!(cyclic_years, Vec<Cow<'static, str>>);
+
+// The UTS 35 month patterns, where `{0}` is replaced with the name or the number of a month.
+symbols!(
+    month_patterns {
+        leap: Option<Cow<'static, str>>,
+        numbered: Option<Cow<'static, str>>,
+    }
+);
+
+impl month_patterns::SymbolsV1 {
+    /// Returns the name of a leap month from the name of the month it repeats, such as "闰二月"
+    /// for "二月", or [`None`] if there is no leap month pattern.
+    pub fn leap_month(&self, name: &str) -> Option<String> {
+        self.leap
+            .as_ref()
+            .map(|pattern| pattern.replacen("{0}", name, 1))
+    }
+
+    /// Returns the name of a month from its number, such as "3月" for `3`, or [`None`] if
+    /// months are not named with their number.
+    pub fn numbered_month(&self, number: usize) -> Option<String> {
+        self.numbered
+            .as_ref()
+            .map(|pattern| pattern.replacen("{0}", &number.to_string(), 1))
+    }
+}
+
 symbols!(
     day_periods {
         am: Cow<'static, str>,
@@ -151,10 +288,14 @@ symbols!(
 pub mod patterns {
     use super::*;
     use crate::{
-        pattern::{self, reference::Pattern},
+        fields::{FieldLength, FieldSymbol},
+        options::length,
+        pattern::{self, reference::Pattern, runtime},
         skeleton::{Skeleton, SkeletonError},
     };
+    use core::cmp::Ordering;
     use core::convert::TryFrom;
+    use icu_plurals::PluralCategory;
     use litemap::LiteMap;
 
     #[derive(Debug, PartialEq, Clone, Default)]
@@ -169,6 +310,41 @@ pub mod patterns {
         pub short: Cow<'static, str>,
     }
 
+    impl LengthPatternsV1 {
+        /// Returns the pattern used to combine a date and a time for the [`length::Date`].
+        pub fn get(&self, length: length::Date) -> &str {
+            match length {
+                length::Date::Full => &self.full,
+                length::Date::Long => &self.long,
+                length::Date::Medium => &self.medium,
+                length::Date::Short => &self.short,
+            }
+        }
+
+        /// Returns the pattern for the [`length::Date`], falling back to a neighboring length
+        /// when it is empty, or [`None`] if all of the patterns are empty.
+        ///
+        /// The longer lengths are tried first, from the closest one to `full`, followed by the
+        /// shorter ones, from the closest one to `short`. For instance, a missing `medium`
+        /// pattern falls back to `long`, `full`, and then `short`.
+        pub fn get_with_fallback(&self, length: length::Date) -> Option<&str> {
+            let patterns = [&self.full, &self.long, &self.medium, &self.short];
+            let index = match length {
+                length::Date::Full => 0,
+                length::Date::Long => 1,
+                length::Date::Medium => 2,
+                length::Date::Short => 3,
+            };
+            patterns[..=index]
+                .iter()
+                .rev()
+                .chain(patterns[index + 1..].iter())
+                .copied()
+                .map(|pattern| pattern.as_ref())
+                .find(|pattern| !pattern.is_empty())
+        }
+    }
+
     /// This struct is a public wrapper around the internal [`Pattern`] struct. This allows
     /// access to the serialization and deserialization capabilities, without exposing the
     /// internals of the pattern machinery.
@@ -182,6 +358,15 @@ pub mod patterns {
     )]
     pub struct PatternV1(pub Pattern);
 
+    impl PatternV1 {
+        /// Converts the pattern into the [`runtime::Pattern`] representation, so that tools can
+        /// precompute it instead of converting at format time. See the [`From`] implementations
+        /// of [`runtime::Pattern`] for how the pattern is transformed.
+        pub fn to_runtime(&self) -> runtime::Pattern<'static> {
+            runtime::Pattern::from(&self.0)
+        }
+    }
+
     impl From<Pattern> for PatternV1 {
         fn from(pattern: Pattern) -> Self {
             Self(pattern)
@@ -206,7 +391,17 @@ pub mod patterns {
     ///
     /// The `Skeleton` is an "exotic type" in the serialization process, and handles its own
     /// custom serialization practices.
-    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+    ///
+    /// Skeletons are ordered field by field, in the canonical order of their fields. Two fields
+    /// are compared by their symbol in the order of the
+    /// [UTS 35 Date Field Symbol Table](https://unicode.org/reports/tr35/tr35-dates.html#Date_Field_Symbol_Table),
+    /// then by their length. A skeleton which is a prefix of another one sorts first. This is the
+    /// order of the keys of [`SkeletonsV1`], which is used for looking up skeletons, see
+    /// [`SkeletonV1::canonical_cmp`].
+    ///
+    /// Skeletons can also be used as keys of a `HashMap`, since equal skeletons have the same
+    /// [`Hash`](core::hash::Hash).
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Hash)]
     #[cfg_attr(
         feature = "provider_serde",
         derive(serde::Serialize, serde::Deserialize)
@@ -224,6 +419,36 @@ pub mod patterns {
         }
     }
 
+    impl SkeletonV1 {
+        /// Creates a skeleton from a list of fields in the canonical sort order, see
+        /// [`Skeleton::try_from_fields`].
+        pub fn try_from_fields<I>(fields: I) -> Result<Self, SkeletonError>
+        where
+            I: IntoIterator<Item = (FieldSymbol, FieldLength)>,
+        {
+            Skeleton::try_from_fields(fields).map(Self)
+        }
+
+        /// Compares two skeletons in the canonical order, which is the same as their [`Ord`]
+        /// implementation. It can be passed to [`slice::sort_by`] to sort a list of skeletons
+        /// consistently with the lookup in [`SkeletonsV1`].
+        ///
+        /// ```
+        /// use core::convert::TryFrom;
+        /// use icu_datetime::provider::gregory::patterns::SkeletonV1;
+        ///
+        /// let mut skeletons: Vec<SkeletonV1> = ["Hm", "MMMd", "yMMMd"]
+        ///     .iter()
+        ///     .map(|s| SkeletonV1::try_from(*s).unwrap())
+        ///     .collect();
+        /// skeletons.sort_by(SkeletonV1::canonical_cmp);
+        /// assert_eq!(skeletons[0], SkeletonV1::try_from("yMMMd").unwrap());
+        /// ```
+        pub fn canonical_cmp(&self, other: &Self) -> Ordering {
+            self.cmp(other)
+        }
+    }
+
     #[derive(Debug, PartialEq, Clone, Default)]
     #[cfg_attr(
         feature = "provider_serde",
@@ -231,13 +456,617 @@ pub mod patterns {
     )]
     pub struct SkeletonsV1(pub LiteMap<SkeletonV1, PatternV1>);
 
+    impl SkeletonsV1 {
+        /// Looks up the pattern of a user-provided skeleton. The skeleton is canonicalized first
+        /// with [`Skeleton::try_from_loose`], so that the order of its fields doesn't matter.
+        #[doc(alias = "get_pattern_for_skeleton_str")]
+        pub fn get_loose(
+            &self,
+            skeleton_string: &str,
+        ) -> Result<Option<&PatternV1>, SkeletonError> {
+            let skeleton = SkeletonV1(Skeleton::try_from_loose(skeleton_string)?);
+            Ok(self.0.get(&skeleton))
+        }
+
+        /// Returns an iterator over the available skeletons, in the canonical sort order.
+        pub fn keys(&self) -> impl Iterator<Item = &SkeletonV1> {
+            self.0.iter_keys()
+        }
+
+        /// Returns the number of available skeletons.
+        pub fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        /// Returns `true` if there are no available skeletons.
+        pub fn is_empty(&self) -> bool {
+            self.0.is_empty()
+        }
+    }
+
+    /// A pattern which varies by the plural category of a number in it, such as the
+    /// `MMMMW-count-one` and `MMMMW-count-other` skeletons of CLDR.
+    ///
+    /// Only the `other` variant is required. The [`PluralCategory`] of a missing variant falls
+    /// back to the `other` variant.
+    #[derive(Debug, PartialEq, Clone)]
+    #[cfg_attr(
+        feature = "provider_serde",
+        derive(serde::Serialize, serde::Deserialize)
+    )]
+    pub struct PluralPatternsV1 {
+        pub zero: Option<PatternV1>,
+        pub one: Option<PatternV1>,
+        pub two: Option<PatternV1>,
+        pub few: Option<PatternV1>,
+        pub many: Option<PatternV1>,
+        pub other: PatternV1,
+    }
+
+    impl PluralPatternsV1 {
+        /// Creates the patterns with only the `other` variant, which all the categories fall
+        /// back to.
+        pub fn new(other: PatternV1) -> Self {
+            Self {
+                zero: None,
+                one: None,
+                two: None,
+                few: None,
+                many: None,
+                other,
+            }
+        }
+
+        /// Returns the pattern for the [`PluralCategory`], falling back to the `other` variant.
+        pub fn get(&self, category: PluralCategory) -> &PatternV1 {
+            let variant = match category {
+                PluralCategory::Zero => &self.zero,
+                PluralCategory::One => &self.one,
+                PluralCategory::Two => &self.two,
+                PluralCategory::Few => &self.few,
+                PluralCategory::Many => &self.many,
+                PluralCategory::Other => return &self.other,
+            };
+            variant.as_ref().unwrap_or(&self.other)
+        }
+
+        /// Sets the pattern for the [`PluralCategory`].
+        pub fn set(&mut self, category: PluralCategory, pattern: PatternV1) {
+            match category {
+                PluralCategory::Zero => self.zero = Some(pattern),
+                PluralCategory::One => self.one = Some(pattern),
+                PluralCategory::Two => self.two = Some(pattern),
+                PluralCategory::Few => self.few = Some(pattern),
+                PluralCategory::Many => self.many = Some(pattern),
+                PluralCategory::Other => self.other = pattern,
+            }
+        }
+
+        /// Returns an iterator over the patterns of all the variants, ending with `other`.
+        pub fn patterns_iter(&self) -> impl Iterator<Item = &Pattern> {
+            self.zero
+                .iter()
+                .chain(self.one.iter())
+                .chain(self.two.iter())
+                .chain(self.few.iter())
+                .chain(self.many.iter())
+                .chain(core::iter::once(&self.other))
+                .map(|pattern| &pattern.0)
+        }
+
+        /// Returns a mutable iterator over the patterns of all the variants, ending with `other`.
+        pub fn patterns_iter_mut(&mut self) -> impl Iterator<Item = &mut Pattern> {
+            self.zero
+                .iter_mut()
+                .chain(self.one.iter_mut())
+                .chain(self.two.iter_mut())
+                .chain(self.few.iter_mut())
+                .chain(self.many.iter_mut())
+                .chain(core::iter::once(&mut self.other))
+                .map(|pattern| &mut pattern.0)
+        }
+    }
+
     #[derive(Debug, PartialEq, Clone, Default)]
     #[cfg_attr(
         feature = "provider_serde",
         derive(serde::Serialize, serde::Deserialize)
     )]
+    pub struct PluralSkeletonsV1(pub LiteMap<SkeletonV1, PluralPatternsV1>);
+
+    #[derive(Debug, PartialEq, Clone)]
+    #[cfg_attr(
+        feature = "provider_serde",
+        derive(serde::Serialize, serde::Deserialize)
+    )]
     pub struct DateTimeFormatsV1 {
         pub length_patterns: LengthPatternsV1,
         pub skeletons: SkeletonsV1,
+        /// The skeletons whose patterns vary by plural category.
+        #[cfg_attr(feature = "provider_serde", serde(default))]
+        pub plural_skeletons: PluralSkeletonsV1,
+    }
+
+    /// The glue of the root locale, `"{1} {0}"`, for every length, and no skeletons.
+    impl Default for DateTimeFormatsV1 {
+        fn default() -> Self {
+            let glue = Cow::Borrowed("{1} {0}");
+            Self {
+                length_patterns: LengthPatternsV1 {
+                    full: glue.clone(),
+                    long: glue.clone(),
+                    medium: glue.clone(),
+                    short: glue,
+                },
+                skeletons: SkeletonsV1::default(),
+                plural_skeletons: PluralSkeletonsV1::default(),
+            }
+        }
+    }
+
+    impl DateTimeFormatsV1 {
+        /// Returns the length pattern, or glue, used to combine a date and a time for the
+        /// [`length::Date`], such as `"{1}, {0}"`, where `{0}` is the time and `{1}` the date.
+        ///
+        /// A missing length pattern falls back according to
+        /// [`LengthPatternsV1::get_with_fallback`], and `None` is returned if all of them are
+        /// missing. See [`DateTimeFormatsV1::validate`] for checking the placeholders.
+        pub fn glue(&self, length: length::Date) -> Option<&str> {
+            self.length_patterns.get_with_fallback(length)
+        }
+
+        /// Combines a date and a time pattern into a single pattern, using the length pattern
+        /// for the [`length::Date`].
+        ///
+        /// In the length pattern, `{0}` is replaced by the time and `{1}` by the date, so the
+        /// time may come before the date. Any literal text around the placeholders is kept.
+        /// A missing length pattern falls back according to
+        /// [`LengthPatternsV1::get_with_fallback`], and [`PatternError::MissingGlue`] is
+        /// returned if all of them are missing.
+        ///
+        /// [`PatternError::MissingGlue`]: pattern::PatternError::MissingGlue
+        pub fn combine(
+            &self,
+            date: Pattern,
+            time: Pattern,
+            length: length::Date,
+        ) -> Result<Pattern, pattern::PatternError> {
+            let glue = self
+                .glue(length)
+                .ok_or(pattern::PatternError::MissingGlue(length))?;
+            Pattern::from_bytes_combination(glue, date, time)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::patterns::{
+        DateTimeFormatsV1, LengthPatternsV1, PatternV1, PluralPatternsV1, SkeletonV1, SkeletonsV1,
+    };
+    use super::{months, weekdays, DatePatternsV1, Width};
+    use crate::options::{length, preferences};
+    use crate::pattern::reference::Pattern;
+    use crate::pattern::{CoarseHourCycle, PatternError};
+    use core::convert::TryFrom;
+    use icu_plurals::PluralCategory;
+
+    #[test]
+    fn test_plural_patterns() {
+        let one = PatternV1::try_from("d 'day'").unwrap();
+        let other = PatternV1::try_from("d 'days'").unwrap();
+        let mut patterns = PluralPatternsV1::new(other.clone());
+        patterns.set(PluralCategory::One, one.clone());
+
+        assert_eq!(patterns.get(PluralCategory::One), &one);
+        assert_eq!(patterns.get(PluralCategory::Other), &other);
+        // Missing categories fall back to `other`.
+        assert_eq!(patterns.get(PluralCategory::Few), &other);
+    }
+
+    #[test]
+    fn test_skeletons_keys() {
+        let mut skeletons = SkeletonsV1::default();
+        assert!(skeletons.is_empty());
+
+        for skeleton in ["yMMMd", "Hm", "MMMd"] {
+            skeletons.0.insert(
+                SkeletonV1::try_from(skeleton).unwrap(),
+                PatternV1::try_from("d").unwrap(),
+            );
+        }
+
+        assert_eq!(skeletons.len(), 3);
+        let keys: Vec<&SkeletonV1> = skeletons.keys().collect();
+        assert_eq!(
+            keys,
+            vec![
+                &SkeletonV1::try_from("yMMMd").unwrap(),
+                &SkeletonV1::try_from("MMMd").unwrap(),
+                &SkeletonV1::try_from("Hm").unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_skeletons_get_loose() {
+        let mut skeletons = SkeletonsV1::default();
+        skeletons.0.insert(
+            SkeletonV1::try_from("yMMMd").unwrap(),
+            PatternV1::try_from("MMM d, y").unwrap(),
+        );
+
+        let expected = PatternV1::try_from("MMM d, y").unwrap();
+        assert_eq!(skeletons.get_loose("yMMMd").unwrap(), Some(&expected));
+        assert_eq!(skeletons.get_loose("dMMMy").unwrap(), Some(&expected));
+        assert_eq!(skeletons.get_loose("yMMMMd").unwrap(), None);
+    }
+
+    #[test]
+    fn test_skeleton_hash_map() {
+        use std::collections::HashMap;
+
+        let mut patterns: HashMap<SkeletonV1, PatternV1> = HashMap::new();
+        patterns.insert(
+            SkeletonV1::try_from("yMMMd").unwrap(),
+            PatternV1::try_from("MMM d, y").unwrap(),
+        );
+        patterns.insert(
+            SkeletonV1::try_from("Hm").unwrap(),
+            PatternV1::try_from("HH:mm").unwrap(),
+        );
+
+        // The fields are sorted in the canonical order, so equal skeletons hash the same.
+        let expected = PatternV1::try_from("MMM d, y").unwrap();
+        assert_eq!(
+            patterns.get(&SkeletonV1::try_from("yMMMd").unwrap()),
+            Some(&expected)
+        );
+        assert_eq!(
+            patterns.get(&SkeletonV1::try_from("dMMMy").unwrap()),
+            Some(&expected)
+        );
+        assert_eq!(patterns.get(&SkeletonV1::try_from("yMMMMd").unwrap()), None);
+    }
+
+    #[test]
+    fn test_skeletons_canonical_order() {
+        let canonical = [
+            "yM", "yMd", "yMMMd", "yMMMMd", "Md", "MEd", "MMMd", "MMMMd", "d", "Ehm", "hm", "Hm",
+            "Hms", "ms",
+        ];
+        let shuffled = [
+            "MEd", "Hms", "yM", "ms", "hm", "yMMMMd", "d", "Md", "Ehm", "yMd", "MMMd", "Hm",
+            "yMMMd", "MMMMd",
+        ];
+        let mut skeletons: Vec<SkeletonV1> = shuffled
+            .iter()
+            .map(|skeleton| SkeletonV1::try_from(*skeleton).unwrap())
+            .collect();
+        skeletons.sort_by(SkeletonV1::canonical_cmp);
+
+        let expected: Vec<SkeletonV1> = canonical
+            .iter()
+            .map(|skeleton| SkeletonV1::try_from(*skeleton).unwrap())
+            .collect();
+        assert_eq!(skeletons, expected);
+
+        // The lookup uses the same order.
+        let mut map = SkeletonsV1::default();
+        for skeleton in skeletons.iter().rev() {
+            map.0
+                .insert(skeleton.clone(), PatternV1::try_from("d").unwrap());
+        }
+        assert!(map.keys().eq(expected.iter()));
+    }
+
+    #[test]
+    fn test_symbols_width_fallback() {
+        fn symbols(name: &'static str) -> weekdays::SymbolsV1 {
+            weekdays::SymbolsV1([
+                name.into(),
+                name.into(),
+                name.into(),
+                name.into(),
+                name.into(),
+                name.into(),
+                name.into(),
+            ])
+        }
+
+        let mut contexts = weekdays::ContextsV1 {
+            format: weekdays::FormatWidthsV1 {
+                abbreviated: symbols("format abbreviated"),
+                narrow: symbols("format narrow"),
+                short: None,
+                wide: symbols("format wide"),
+            },
+            stand_alone: None,
+        };
+        // `short` falls back to `abbreviated`.
+        assert_eq!(
+            contexts.get(Width::Short, false),
+            &symbols("format abbreviated")
+        );
+        // A missing stand-alone context falls back to the format context.
+        assert_eq!(contexts.get(Width::Wide, true), &symbols("format wide"));
+        assert_eq!(
+            contexts.get(Width::Short, true),
+            &symbols("format abbreviated")
+        );
+
+        contexts.stand_alone = Some(weekdays::StandAloneWidthsV1 {
+            abbreviated: Some(symbols("stand-alone abbreviated")),
+            narrow: None,
+            short: None,
+            wide: Some(symbols("stand-alone wide")),
+        });
+        assert_eq!(
+            contexts.get(Width::Wide, true),
+            &symbols("stand-alone wide")
+        );
+        assert_eq!(
+            contexts.get(Width::Short, true),
+            &symbols("stand-alone abbreviated")
+        );
+        // Missing stand-alone widths fall back to the format width.
+        assert_eq!(contexts.get(Width::Narrow, true), &symbols("format narrow"));
+        assert_eq!(contexts.get(Width::Wide, false), &symbols("format wide"));
+    }
+
+    #[test]
+    fn test_symbols_get() {
+        let mut months = months::SymbolsV1::default();
+        months.0[0] = "January".into();
+        months.0[11] = "December".into();
+        assert_eq!(months.get(0), Some("January"));
+        assert_eq!(months.get(11), Some("December"));
+        assert_eq!(months.get(12), None);
+
+        let weekdays = weekdays::SymbolsV1::default();
+        assert_eq!(weekdays.get(6), Some(""));
+        assert_eq!(weekdays.get(7), None);
+    }
+
+    #[test]
+    fn test_symbol_for_month_out_of_range() {
+        use super::DateSymbolsV1;
+        use crate::error::DateTimeFormatError;
+        use crate::fields::{FieldLength, FieldSymbol, Month};
+        use crate::provider::helpers::DateTimeSymbols;
+
+        let mut symbols = DateSymbolsV1::default();
+        symbols.months.format.wide.0[11] = "December".into();
+        assert_eq!(
+            symbols
+                .get_symbol_for_month(Month::Format, FieldLength::Wide, 11)
+                .ok(),
+            Some("December")
+        );
+        assert!(matches!(
+            symbols.get_symbol_for_month(Month::Format, FieldLength::Wide, 12),
+            Err(DateTimeFormatError::MissingSymbol(FieldSymbol::Month(
+                Month::Format
+            )))
+        ));
+    }
+
+    #[test]
+    fn test_length_patterns_fallback() {
+        let mut patterns = LengthPatternsV1 {
+            full: "{1} 'at' {0}".into(),
+            ..Default::default()
+        };
+        assert_eq!(
+            patterns.get_with_fallback(length::Date::Long),
+            Some("{1} 'at' {0}")
+        );
+        assert_eq!(
+            patterns.get_with_fallback(length::Date::Short),
+            Some("{1} 'at' {0}")
+        );
+
+        // Longer lengths are preferred over shorter ones.
+        patterns.short = "{1} {0}".into();
+        assert_eq!(
+            patterns.get_with_fallback(length::Date::Medium),
+            Some("{1} 'at' {0}")
+        );
+        assert_eq!(
+            patterns.get_with_fallback(length::Date::Short),
+            Some("{1} {0}")
+        );
+
+        // The shorter lengths are used when there are no longer ones.
+        patterns.full = "".into();
+        assert_eq!(
+            patterns.get_with_fallback(length::Date::Full),
+            Some("{1} {0}")
+        );
+
+        let patterns = LengthPatternsV1::default();
+        assert_eq!(patterns.get_with_fallback(length::Date::Medium), None);
+    }
+
+    #[test]
+    fn test_default_hour_cycle() {
+        let time_h11_h12 = LengthPatternsV1 {
+            full: "h:mm:ss a zzzz".into(),
+            long: "h:mm:ss a z".into(),
+            medium: "h:mm:ss a".into(),
+            short: "h:mm a".into(),
+        };
+        let time_h23_h24 = LengthPatternsV1 {
+            full: "HH:mm:ss zzzz".into(),
+            long: "HH:mm:ss z".into(),
+            medium: "HH:mm:ss".into(),
+            short: "HH:mm".into(),
+        };
+
+        // en prefers the 12-hour clock.
+        let en = DatePatternsV1 {
+            time_h11_h12: time_h11_h12.clone(),
+            time_h23_h24: time_h23_h24.clone(),
+            preferred_hour_cycle: CoarseHourCycle::H11H12,
+            ..Default::default()
+        };
+        assert!(!en.is_24_hour_default());
+        assert_eq!(en.default_hour_cycle(), Some(preferences::HourCycle::H12));
+
+        // de prefers the 24-hour clock.
+        let de = DatePatternsV1 {
+            preferred_hour_cycle: CoarseHourCycle::H23H24,
+            ..en.clone()
+        };
+        assert!(de.is_24_hour_default());
+        assert_eq!(de.default_hour_cycle(), Some(preferences::HourCycle::H23));
+
+        // ja uses an hour starting at 0 with the 12-hour clock.
+        let ja = DatePatternsV1 {
+            time_h11_h12: LengthPatternsV1 {
+                short: "aK:mm".into(),
+                ..time_h11_h12
+            },
+            ..en
+        };
+        assert_eq!(ja.default_hour_cycle(), Some(preferences::HourCycle::H11));
+
+        assert_eq!(DatePatternsV1::default().default_hour_cycle(), None);
+    }
+
+    #[test]
+    fn test_combine() {
+        let formats = DateTimeFormatsV1 {
+            length_patterns: LengthPatternsV1 {
+                full: "{1} 'at' {0}".into(),
+                long: "{1} 'at' {0}".into(),
+                medium: "{1}, {0}".into(),
+                short: "{0} {1}".into(),
+            },
+            ..Default::default()
+        };
+        let date = Pattern::from_bytes("MMM d, y").unwrap();
+        let time = Pattern::from_bytes("h:mm a").unwrap();
+
+        let combine = |length| {
+            formats
+                .combine(date.clone(), time.clone(), length)
+                .expect("Failed to combine patterns.")
+                .to_string()
+        };
+        assert_eq!(combine(length::Date::Medium), "MMM d, y, h:mm a");
+        assert_eq!(combine(length::Date::Long), "MMM d, y 'at' h:mm a");
+        // The time comes before the date.
+        assert_eq!(combine(length::Date::Short), "h:mm a MMM d, y");
+    }
+
+    #[test]
+    fn test_glue() {
+        let mut formats = DateTimeFormatsV1 {
+            length_patterns: LengthPatternsV1 {
+                full: "{1} 'at' {0}".into(),
+                long: "{1} 'at' {0}".into(),
+                medium: "{1}, {0}".into(),
+                short: "{0} {1}".into(),
+            },
+            ..Default::default()
+        };
+        assert_eq!(formats.glue(length::Date::Medium), Some("{1}, {0}"));
+        assert_eq!(formats.glue(length::Date::Short), Some("{0} {1}"));
+
+        // A missing glue falls back to a longer one.
+        formats.length_patterns.medium = "".into();
+        assert_eq!(formats.glue(length::Date::Medium), Some("{1} 'at' {0}"));
+
+        assert_eq!(
+            DateTimeFormatsV1::default().glue(length::Date::Medium),
+            Some("{1} {0}")
+        );
+
+        let formats = DateTimeFormatsV1 {
+            length_patterns: LengthPatternsV1::default(),
+            ..Default::default()
+        };
+        assert_eq!(formats.glue(length::Date::Medium), None);
+        assert_eq!(
+            formats.combine(Pattern::default(), Pattern::default(), length::Date::Medium),
+            Err(PatternError::MissingGlue(length::Date::Medium))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "provider_serde")]
+    fn test_deserialize_cyclic_years() {
+        use super::{cyclic_years, DateSymbolsV1};
+        use crate::fields::FieldLength;
+        use crate::provider::helpers::DateTimeSymbols;
+
+        let stems = ["甲", "乙", "丙", "丁", "戊", "己", "庚", "辛", "壬", "癸"];
+        let branches = [
+            "子", "丑", "寅", "卯", "辰", "巳", "午", "未", "申", "酉", "戌", "亥",
+        ];
+        let names: Vec<String> = (0..60)
+            .map(|i| format!("{}{}", stems[i % 10], branches[i % 12]))
+            .collect();
+        let json = serde_json::json!({
+            "format": {
+                "abbreviated": names,
+                "narrow": names,
+                "wide": names,
+            },
+        });
+        let cyclic_years: cyclic_years::ContextsV1 =
+            serde_json::from_value(json).expect("Failed to deserialize cyclic years.");
+        let symbols = DateSymbolsV1 {
+            cyclic_years: Some(cyclic_years),
+            ..Default::default()
+        };
+
+        let symbol = symbols.get_symbol_for_cyclic_year(FieldLength::Wide, 1);
+        assert_eq!(symbol.map(|s| s.as_ref()), Some("甲子"));
+        let symbol = symbols.get_symbol_for_cyclic_year(FieldLength::Wide, 60);
+        assert_eq!(symbol.map(|s| s.as_ref()), Some("癸亥"));
+
+        let symbols = DateSymbolsV1::default();
+        assert_eq!(
+            symbols.get_symbol_for_cyclic_year(FieldLength::Wide, 1),
+            None
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "provider_serde")]
+    fn test_deserialize_month_patterns() {
+        use super::{month_patterns, DateSymbolsV1, Width};
+
+        let json = serde_json::json!({
+            "format": {
+                "abbreviated": { "leap": "闰{0}" },
+                "narrow": { "leap": "闰{0}", "numbered": "{0}" },
+                "wide": { "leap": "闰{0}", "numbered": "{0}月" },
+            },
+        });
+        let month_patterns: month_patterns::ContextsV1 =
+            serde_json::from_value(json).expect("Failed to deserialize month patterns.");
+        let symbols = DateSymbolsV1 {
+            month_patterns: Some(month_patterns),
+            ..Default::default()
+        };
+
+        let patterns = symbols.month_patterns.as_ref().unwrap();
+        let wide = patterns.get(Width::Wide, false);
+        assert_eq!(wide.leap_month("二月").as_deref(), Some("闰二月"));
+        assert_eq!(wide.numbered_month(3).as_deref(), Some("3月"));
+
+        // The missing stand-alone patterns fall back to the format ones.
+        let narrow = patterns.get(Width::Narrow, true);
+        assert_eq!(narrow.numbered_month(12).as_deref(), Some("12"));
+
+        // The numbered pattern is optional.
+        let abbreviated = patterns.get(Width::Abbreviated, false);
+        assert_eq!(abbreviated.leap_month("二月").as_deref(), Some("闰二月"));
+        assert_eq!(abbreviated.numbered_month(3), None);
+
+        assert_eq!(DateSymbolsV1::default().month_patterns, None);
     }
 }
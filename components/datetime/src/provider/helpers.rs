@@ -6,19 +6,23 @@ use crate::date;
 use crate::error::DateTimeFormatError;
 use crate::fields;
 use crate::options::{components, length, preferences, DateTimeFormatOptions};
-use crate::pattern::{reference::Pattern, PatternItem};
+use crate::pattern::{reference::Pattern, PatternItem, PatternPlurals};
 use crate::provider;
+use crate::provider::gregory::Width;
 use crate::skeleton;
 use alloc::borrow::Cow;
 
 type Result<T> = core::result::Result<T, DateTimeFormatError>;
 
 pub trait DateTimePatterns {
-    fn get_pattern_for_options(&self, options: &DateTimeFormatOptions) -> Result<Option<Pattern>>;
+    fn get_pattern_for_options(
+        &self,
+        options: &DateTimeFormatOptions,
+    ) -> Result<Option<PatternPlurals>>;
     fn get_pattern_for_components_bag(
         &self,
         components: &components::Bag,
-    ) -> Result<Option<Pattern>>;
+    ) -> Result<Option<PatternPlurals>>;
     fn get_pattern_for_length_bag(&self, length: &length::Bag) -> Result<Option<Pattern>>;
     fn get_pattern_for_date_length(&self, length: length::Date) -> Result<Pattern>;
     fn get_pattern_for_time_length(
@@ -40,13 +44,13 @@ pub trait DateTimeSymbols {
         month: fields::Month,
         length: fields::FieldLength,
         num: usize,
-    ) -> &Cow<str>;
+    ) -> Result<&str>;
     fn get_symbol_for_weekday(
         &self,
         weekday: fields::Weekday,
         length: fields::FieldLength,
         day: date::IsoWeekday,
-    ) -> &Cow<str>;
+    ) -> Result<&str>;
     fn get_symbol_for_day_period(
         &self,
         day_period: fields::DayPeriod,
@@ -54,12 +58,24 @@ pub trait DateTimeSymbols {
         hour: date::IsoHour,
         is_top_of_hour: bool,
     ) -> &Cow<str>;
+    /// Returns the name of the year in a 60-year cycle, where `year` is in `1..=60`, or
+    /// [`None`] if the names are missing.
+    fn get_symbol_for_cyclic_year(
+        &self,
+        length: fields::FieldLength,
+        year: usize,
+    ) -> Option<&Cow<str>>;
 }
 
 impl DateTimePatterns for provider::gregory::DatePatternsV1 {
-    fn get_pattern_for_options(&self, options: &DateTimeFormatOptions) -> Result<Option<Pattern>> {
+    fn get_pattern_for_options(
+        &self,
+        options: &DateTimeFormatOptions,
+    ) -> Result<Option<PatternPlurals>> {
         match options {
-            DateTimeFormatOptions::Length(bag) => self.get_pattern_for_length_bag(bag),
+            DateTimeFormatOptions::Length(bag) => Ok(self
+                .get_pattern_for_length_bag(bag)?
+                .map(PatternPlurals::SinglePattern)),
             DateTimeFormatOptions::Components(bag) => self.get_pattern_for_components_bag(bag),
         }
     }
@@ -67,17 +83,16 @@ impl DateTimePatterns for provider::gregory::DatePatternsV1 {
     fn get_pattern_for_components_bag(
         &self,
         components: &components::Bag,
-    ) -> Result<Option<Pattern>> {
+    ) -> Result<Option<PatternPlurals>> {
         // Not all skeletons are currently supported.
         let requested_fields = components.to_vec_fields();
         Ok(
             match skeleton::create_best_pattern_for_fields(
-                &self.datetime.skeletons,
-                &self.datetime.length_patterns,
+                &self.datetime,
                 &requested_fields,
                 components,
                 false, // Prefer the requested fields over the matched pattern.
-            ) {
+            )? {
                 skeleton::BestSkeleton::AllFieldsMatch(pattern)
                 | skeleton::BestSkeleton::MissingOrExtraFields(pattern) => Some(pattern),
                 skeleton::BestSkeleton::NoMatch => None,
@@ -119,14 +134,7 @@ impl DateTimePatterns for provider::gregory::DatePatternsV1 {
         date: Pattern,
         time: Pattern,
     ) -> Result<Pattern> {
-        let datetime = &self.datetime;
-        let s = match length {
-            length::Date::Full => &datetime.length_patterns.full,
-            length::Date::Long => &datetime.length_patterns.long,
-            length::Date::Medium => &datetime.length_patterns.medium,
-            length::Date::Short => &datetime.length_patterns.short,
-        };
-        Ok(Pattern::from_bytes_combination(s, date, time)?)
+        Ok(self.datetime.combine(date, time, length)?)
     }
 
     /// Look up the proper pre-computed pattern for a given length. If a preference for an hour
@@ -186,38 +194,18 @@ impl DateTimeSymbols for provider::gregory::DateSymbolsV1 {
         weekday: fields::Weekday,
         length: fields::FieldLength,
         day: date::IsoWeekday,
-    ) -> &Cow<str> {
-        let widths = match weekday {
-            fields::Weekday::Format => &self.weekdays.format,
-            fields::Weekday::StandAlone => {
-                if let Some(ref widths) = self.weekdays.stand_alone {
-                    let symbols = match length {
-                        fields::FieldLength::Wide => widths.wide.as_ref(),
-                        fields::FieldLength::Narrow => widths.narrow.as_ref(),
-                        fields::FieldLength::Six => widths
-                            .short
-                            .as_ref()
-                            .or_else(|| widths.abbreviated.as_ref()),
-                        _ => widths.abbreviated.as_ref(),
-                    };
-                    if let Some(symbols) = symbols {
-                        return &symbols.0[(day as usize) % 7];
-                    } else {
-                        return self.get_symbol_for_weekday(fields::Weekday::Format, length, day);
-                    }
-                } else {
-                    return self.get_symbol_for_weekday(fields::Weekday::Format, length, day);
-                }
-            }
+    ) -> Result<&str> {
+        let is_standalone = match weekday {
+            fields::Weekday::Format => false,
+            fields::Weekday::StandAlone => true,
             fields::Weekday::Local => unimplemented!(),
         };
-        let symbols = match length {
-            fields::FieldLength::Wide => &widths.wide,
-            fields::FieldLength::Narrow => &widths.narrow,
-            fields::FieldLength::Six => widths.short.as_ref().unwrap_or(&widths.abbreviated),
-            _ => &widths.abbreviated,
-        };
-        &symbols.0[(day as usize) % 7]
+        let symbols = self.weekdays.get(symbols_width(length), is_standalone);
+        symbols
+            .get((day as usize) % 7)
+            .ok_or(DateTimeFormatError::MissingSymbol(
+                fields::FieldSymbol::Weekday(weekday),
+            ))
     }
 
     fn get_symbol_for_month(
@@ -225,34 +213,16 @@ impl DateTimeSymbols for provider::gregory::DateSymbolsV1 {
         month: fields::Month,
         length: fields::FieldLength,
         num: usize,
-    ) -> &Cow<str> {
+    ) -> Result<&str> {
         // TODO(#493): Support symbols for non-Gregorian calendars.
-        debug_assert!(num < 12);
-        let widths = match month {
-            fields::Month::Format => &self.months.format,
-            fields::Month::StandAlone => {
-                if let Some(ref widths) = self.months.stand_alone {
-                    let symbols = match length {
-                        fields::FieldLength::Wide => widths.wide.as_ref(),
-                        fields::FieldLength::Narrow => widths.narrow.as_ref(),
-                        _ => widths.abbreviated.as_ref(),
-                    };
-                    if let Some(symbols) = symbols {
-                        return &symbols.0[num];
-                    } else {
-                        return self.get_symbol_for_month(fields::Month::Format, length, num);
-                    }
-                } else {
-                    return self.get_symbol_for_month(fields::Month::Format, length, num);
-                }
-            }
+        let is_standalone = match month {
+            fields::Month::Format => false,
+            fields::Month::StandAlone => true,
         };
-        let symbols = match length {
-            fields::FieldLength::Wide => &widths.wide,
-            fields::FieldLength::Narrow => &widths.narrow,
-            _ => &widths.abbreviated,
-        };
-        &symbols.0[num]
+        let symbols = self.months.get(symbols_width(length), is_standalone);
+        symbols.get(num).ok_or(DateTimeFormatError::MissingSymbol(
+            fields::FieldSymbol::Month(month),
+        ))
     }
 
     fn get_symbol_for_day_period(
@@ -262,13 +232,8 @@ impl DateTimeSymbols for provider::gregory::DateSymbolsV1 {
         hour: date::IsoHour,
         is_top_of_hour: bool,
     ) -> &Cow<str> {
-        use fields::{DayPeriod::NoonMidnight, FieldLength};
-        let widths = &self.day_periods.format;
-        let symbols = match length {
-            FieldLength::Wide => &widths.wide,
-            FieldLength::Narrow => &widths.narrow,
-            _ => &widths.abbreviated,
-        };
+        use fields::DayPeriod::NoonMidnight;
+        let symbols = self.day_periods.get(symbols_width(length), false);
         match (day_period, u8::from(hour), is_top_of_hour) {
             (NoonMidnight, 00, true) => symbols.midnight.as_ref().unwrap_or(&symbols.am),
             (NoonMidnight, 12, true) => symbols.noon.as_ref().unwrap_or(&symbols.pm),
@@ -276,4 +241,27 @@ impl DateTimeSymbols for provider::gregory::DateSymbolsV1 {
             _ => &symbols.pm,
         }
     }
+
+    fn get_symbol_for_cyclic_year(
+        &self,
+        length: fields::FieldLength,
+        year: usize,
+    ) -> Option<&Cow<str>> {
+        debug_assert!((1..=60).contains(&year));
+        let symbols = self
+            .cyclic_years
+            .as_ref()?
+            .get(symbols_width(length), false);
+        symbols.0.get(year - 1)
+    }
+}
+
+/// Returns the [`Width`] of the symbols for the length of a field.
+fn symbols_width(length: fields::FieldLength) -> Width {
+    match length {
+        fields::FieldLength::Wide => Width::Wide,
+        fields::FieldLength::Narrow => Width::Narrow,
+        fields::FieldLength::Six => Width::Short,
+        _ => Width::Abbreviated,
+    }
 }
@@ -9,6 +9,9 @@
 #[cfg(doc)]
 use icu_provider::prelude::ResourceKey;
 
+/// A compact binary encoding of the skeletons of the Gregorian Calendar.
+pub mod encoded_skeletons;
+
 /// Data providers for the Gregorian Calendar.
 pub mod gregory;
 
@@ -17,10 +20,16 @@ pub(crate) mod helpers;
 /// Data providers for time zones.
 pub mod time_zones;
 
+/// Validation of the data loaded from data providers.
+pub mod validation;
+
+/// Data providers for week numbering.
+pub mod week_data;
+
 /// A collection of [`ResourceKey`] structs for DateTime providers.
 pub mod key {
     #[cfg(doc)]
-    use crate::provider::{gregory, time_zones};
+    use crate::provider::{gregory, time_zones, week_data};
 
     use icu_provider::{resource_key, ResourceKey};
 
@@ -53,4 +62,7 @@ pub mod key {
     /// A [`ResourceKey`] to [`time_zones::MetaZoneSpecificNamesShortV1`].
     pub const TIMEZONE_SPECIFIC_NAMES_SHORT_V1: ResourceKey =
         resource_key!(TimeZone, "specific_short", 1);
+
+    /// A [`ResourceKey`] to [`week_data::WeekDataV1`].
+    pub const WEEK_DATA_V1: ResourceKey = resource_key!(DateTime, "week_data", 1);
 }
@@ -42,6 +42,10 @@ macro_rules! map_access {
 
 /// An ICU4X mapping to the CLDR timeZoneNames format strings.
 /// See CLDR-JSON timeZoneNames.json for more context.
+///
+/// These are the zone format patterns needed by the `z`, `v`, `V` and `O` fields: the GMT offset
+/// format, the hour format of the offset, and the region and fallback formats. They are loaded
+/// with [`TIMEZONE_FORMATS_V1`](crate::provider::key::TIMEZONE_FORMATS_V1).
 #[icu_provider::data_struct]
 #[derive(PartialEq, Debug, Clone, Default)]
 #[cfg_attr(
@@ -50,18 +54,19 @@ macro_rules! map_access {
 )]
 #[yoke(cloning_zcf)]
 pub struct TimeZoneFormatsV1<'data> {
-    /// The hour format for displaying GMT offsets.
+    /// The hour format for displaying GMT offsets, for positive and negative offsets, such as
+    /// `("+HH:mm", "-HH:mm")`.
     pub hour_format: (Cow<'data, str>, Cow<'data, str>),
-    /// The localized GMT-offset format.
+    /// The localized GMT-offset format, such as `"GMT{0}"`.
     pub gmt_format: Cow<'data, str>,
-    /// The localized GMT format with no offset.
+    /// The localized GMT format with no offset, such as `"GMT"`.
     pub gmt_zero_format: Cow<'data, str>,
-    /// The format string for a region.
+    /// The format string for a region, such as `"{0} Time"`.
     pub region_format: Cow<'data, str>,
     /// The format strings for region format variants
     /// e.g. daylight, standard.
     pub region_format_variants: LiteMap<Cow<'data, TinyStr8>, Cow<'data, str>>,
-    /// The format string to fall back to if data is unavailable.
+    /// The format string to fall back to if data is unavailable, such as `"{1} ({0})"`.
     pub fallback_format: Cow<'data, str>,
 }
 
@@ -0,0 +1,279 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::gregory::{
+    patterns::DateTimeFormatsV1, DatePatternsV1Marker, DateSymbolsV1, DateSymbolsV1Marker,
+};
+use super::week_data::WeekDataV1Marker;
+use crate::options::length;
+use alloc::borrow::Cow;
+use displaydoc::Display;
+use icu_plurals::provider::PluralRuleStringsV1Marker;
+use icu_provider::prelude::*;
+
+/// An error returned by [`DateSymbolsV1::validate`].
+#[derive(Display, Debug, Clone, PartialEq)]
+#[displaydoc("Invalid date symbols: {path}[{index}] is empty")]
+pub struct EmptySymbolError {
+    /// The path to the empty symbol array, such as `months.format.wide`.
+    pub path: &'static str,
+    /// The index of the empty symbol in the array.
+    pub index: usize,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EmptySymbolError {}
+
+fn check_symbols(path: &'static str, symbols: &[Cow<str>]) -> Result<(), EmptySymbolError> {
+    match symbols.iter().position(|symbol| symbol.is_empty()) {
+        Some(index) => Err(EmptySymbolError { path, index }),
+        None => Ok(()),
+    }
+}
+
+macro_rules! check_contexts {
+    ($contexts: expr, $name: literal) => {{
+        let contexts = &$contexts;
+        let format = &contexts.format;
+        check_symbols(concat!($name, ".format.abbreviated"), &format.abbreviated.0)?;
+        check_symbols(concat!($name, ".format.narrow"), &format.narrow.0)?;
+        if let Some(short) = &format.short {
+            check_symbols(concat!($name, ".format.short"), &short.0)?;
+        }
+        check_symbols(concat!($name, ".format.wide"), &format.wide.0)?;
+        if let Some(stand_alone) = &contexts.stand_alone {
+            if let Some(abbreviated) = &stand_alone.abbreviated {
+                check_symbols(concat!($name, ".stand_alone.abbreviated"), &abbreviated.0)?;
+            }
+            if let Some(narrow) = &stand_alone.narrow {
+                check_symbols(concat!($name, ".stand_alone.narrow"), &narrow.0)?;
+            }
+            if let Some(short) = &stand_alone.short {
+                check_symbols(concat!($name, ".stand_alone.short"), &short.0)?;
+            }
+            if let Some(wide) = &stand_alone.wide {
+                check_symbols(concat!($name, ".stand_alone.wide"), &wide.0)?;
+            }
+        }
+    }};
+}
+
+impl DateSymbolsV1 {
+    /// Checks that all the month and weekday names are present.
+    ///
+    /// The names are stored in fixed-size arrays, so missing names in the source data are
+    /// deserialized as empty strings, which would otherwise be formatted silently.
+    pub fn validate(&self) -> Result<(), EmptySymbolError> {
+        check_contexts!(self.months, "months");
+        check_contexts!(self.weekdays, "weekdays");
+        Ok(())
+    }
+}
+
+/// An error returned by [`DateTimeFormatsV1::validate`].
+#[derive(Display, Debug, Clone, PartialEq)]
+#[displaydoc(
+    "Invalid date time glue: the {length:?} glue is missing the {placeholder} placeholder"
+)]
+pub struct GluePlaceholderError {
+    /// The length of the glue.
+    pub length: length::Date,
+    /// The missing placeholder, `{0}` or `{1}`.
+    pub placeholder: &'static str,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GluePlaceholderError {}
+
+impl DateTimeFormatsV1 {
+    /// Checks that the glue of each length, see [`DateTimeFormatsV1::glue`], contains both the
+    /// `{0}` placeholder for the time and the `{1}` placeholder for the date.
+    ///
+    /// An empty glue is missing rather than invalid, and falls back to the glue of another
+    /// length.
+    pub fn validate(&self) -> Result<(), GluePlaceholderError> {
+        for &length in [
+            length::Date::Full,
+            length::Date::Long,
+            length::Date::Medium,
+            length::Date::Short,
+        ]
+        .iter()
+        {
+            let glue = self.length_patterns.get(length);
+            if glue.is_empty() {
+                continue;
+            }
+            for &placeholder in ["{0}", "{1}"].iter() {
+                if !glue.contains(placeholder) {
+                    return Err(GluePlaceholderError {
+                        length,
+                        placeholder,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A data provider that validates the [`DateSymbolsV1`] loaded from the inner provider, see
+/// [`DateSymbolsV1::validate`].
+///
+/// Invalid symbols are returned as [`DataError::Resource`], which names the empty symbol. Requests
+/// for [`DatePatternsV1Marker`], [`WeekDataV1Marker`] and [`PluralRuleStringsV1Marker`] are passed
+/// through, so the provider can be used to create a [`DateTimeFormat`](crate::DateTimeFormat).
+pub struct DateSymbolsValidator<D> {
+    /// The data provider to which we delegate requests.
+    pub inner: D,
+}
+
+impl<'data, D> DataProvider<'data, DateSymbolsV1Marker> for DateSymbolsValidator<D>
+where
+    D: DataProvider<'data, DateSymbolsV1Marker>,
+{
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, DateSymbolsV1Marker>, DataError> {
+        let response = self.inner.load_payload(req)?;
+        if let Some(payload) = &response.payload {
+            payload
+                .get()
+                .validate()
+                .map_err(DataError::new_resc_error)?;
+        }
+        Ok(response)
+    }
+}
+
+impl<'data, D> DataProvider<'data, DatePatternsV1Marker> for DateSymbolsValidator<D>
+where
+    D: DataProvider<'data, DatePatternsV1Marker>,
+{
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, DatePatternsV1Marker>, DataError> {
+        self.inner.load_payload(req)
+    }
+}
+
+impl<'data, D> DataProvider<'data, WeekDataV1Marker> for DateSymbolsValidator<D>
+where
+    D: DataProvider<'data, WeekDataV1Marker>,
+{
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, WeekDataV1Marker>, DataError> {
+        self.inner.load_payload(req)
+    }
+}
+
+impl<'data, D> DataProvider<'data, PluralRuleStringsV1Marker> for DateSymbolsValidator<D>
+where
+    D: DataProvider<'data, PluralRuleStringsV1Marker>,
+{
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, PluralRuleStringsV1Marker>, DataError> {
+        self.inner.load_payload(req)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_glue_placeholders() {
+        use crate::provider::gregory::patterns::LengthPatternsV1;
+
+        let mut formats = DateTimeFormatsV1 {
+            length_patterns: LengthPatternsV1 {
+                full: "{1} 'at' {0}".into(),
+                long: "{1} 'at' {0}".into(),
+                medium: "{1}, {0}".into(),
+                short: "".into(),
+            },
+            ..Default::default()
+        };
+        assert_eq!(formats.validate(), Ok(()));
+
+        formats.length_patterns.medium = "{1},".into();
+        let error = formats.validate().expect_err("The glue is missing {0}");
+        assert_eq!(
+            error,
+            GluePlaceholderError {
+                length: length::Date::Medium,
+                placeholder: "{0}",
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "Invalid date time glue: the Medium glue is missing the {0} placeholder"
+        );
+
+        formats.length_patterns.medium = "{0}".into();
+        assert_eq!(
+            formats.validate(),
+            Err(GluePlaceholderError {
+                length: length::Date::Medium,
+                placeholder: "{1}",
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "provider_serde")]
+    fn test_empty_month() {
+        use crate::provider::key;
+        use icu_provider::struct_provider::StructProvider;
+
+        let request = DataRequest {
+            resource_path: ResourcePath {
+                key: key::GREGORY_DATE_SYMBOLS_V1,
+                options: ResourceOptions {
+                    variant: None,
+                    langid: Some("en".parse().unwrap()),
+                },
+            },
+        };
+        let provider = icu_testdata::get_provider();
+        let data: DataPayload<DateSymbolsV1Marker> = provider
+            .load_payload(&request)
+            .unwrap()
+            .take_payload()
+            .unwrap();
+        assert_eq!(data.get().validate(), Ok(()));
+
+        let mut symbols = data.get().clone();
+        symbols.months.format.wide.0[4] = "".into();
+        assert_eq!(
+            symbols.validate(),
+            Err(EmptySymbolError {
+                path: "months.format.wide",
+                index: 4,
+            })
+        );
+
+        let validator = DateSymbolsValidator {
+            inner: StructProvider {
+                key: key::GREGORY_DATE_SYMBOLS_V1,
+                data: DataPayload::<DateSymbolsV1Marker>::from_owned(symbols),
+            },
+        };
+        let result: Result<DataResponse<DateSymbolsV1Marker>, DataError> =
+            validator.load_payload(&request);
+        match result {
+            Err(err @ DataError::Resource(_)) => assert_eq!(
+                err.to_string(),
+                "Failed to load resource: Invalid date symbols: months.format.wide[4] is empty"
+            ),
+            _ => panic!("Expected an error for the empty month"),
+        }
+    }
+}
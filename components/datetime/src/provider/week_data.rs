@@ -0,0 +1,32 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_provider::yoke::{self, *};
+
+/// An ICU4X mapping to the CLDR weekData, which configures the week numbering of a region.
+/// See CLDR-JSON weekData.json for more context.
+#[icu_provider::data_struct]
+#[derive(PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct WeekDataV1 {
+    /// The first day of the week, as an ISO weekday number from 1 (Monday) to 7 (Sunday).
+    pub first_weekday: u8,
+    /// The minimal number of days of the year in a week for it to be the first week of the year.
+    pub min_days_in_first_week: u8,
+}
+
+/// The week numbering of ISO 8601: weeks start on Monday, and the first week of the year
+/// contains its first Thursday.
+impl Default for WeekDataV1 {
+    fn default() -> Self {
+        Self {
+            first_weekday: 1,
+            min_days_in_first_week: 4,
+        }
+    }
+}
@@ -15,10 +15,13 @@ use displaydoc::Display;
 use smallvec::SmallVec;
 
 use crate::{
+    date::DateTimeInput,
     fields::{self, Field, FieldLength, FieldSymbol},
     options::{components, length, preferences},
-    pattern::{reference::Pattern, PatternItem},
-    provider::gregory::patterns::{LengthPatternsV1, PatternV1, SkeletonV1, SkeletonsV1},
+    pattern::{reference::Pattern, PatternError, PatternItem, PatternPlurals},
+    provider::gregory::patterns::{
+        DateTimeFormatsV1, PatternV1, PluralPatternsV1, PluralSkeletonsV1, SkeletonV1, SkeletonsV1,
+    },
 };
 
 #[cfg(feature = "provider_serde")]
@@ -42,8 +45,9 @@ struct FieldIndex(usize);
 /// [UTS 35 Date Field Symbol Table](https://unicode.org/reports/tr35/tr35-dates.html#Date_Field_Symbol_Table)
 ///
 /// The `Field`s are only sorted in the [`Skeleton`] in order to provide a deterministic
-/// serialization strategy, and to provide a faster [`Skeleton`] matching operation.
-#[derive(Debug, Eq, PartialEq, Clone, Ord, PartialOrd)]
+/// serialization strategy, and to provide a faster [`Skeleton`] matching operation. Since equal
+/// skeletons have their fields in the same order, they also have the same [`Hash`].
+#[derive(Debug, Eq, PartialEq, Clone, Ord, PartialOrd, Hash)]
 pub struct Skeleton(SmallVec<[fields::Field; 5]>);
 
 impl Skeleton {
@@ -179,33 +183,101 @@ impl TryFrom<&str> for Skeleton {
     fn try_from(skeleton_string: &str) -> Result<Self, Self::Error> {
         let mut fields: SmallVec<[fields::Field; 5]> = SmallVec::new();
 
-        let mut iter = skeleton_string.chars().peekable();
-        while let Some(ch) = iter.next() {
-            // Convert the byte to a valid field symbol.
-            let field_symbol = FieldSymbol::try_from(ch)?;
+        for field in parse_fields(skeleton_string)? {
+            match fields.binary_search(&field) {
+                Ok(_) => return Err(SkeletonError::DuplicateField),
+                Err(pos) => fields.insert(pos, field),
+            }
+        }
 
-            // Go through the chars to count how often it's repeated.
-            let mut field_length: u8 = 1;
-            while let Some(next_ch) = iter.peek() {
-                if *next_ch != ch {
-                    break;
+        Ok(Self(fields))
+    }
+}
+
+impl Skeleton {
+    /// Parse a user-provided string into a skeleton, loosely. Like the [`TryFrom`] implementation,
+    /// the fields are sorted into the canonical sort order, but instead of returning an error for
+    /// duplicate fields, the fields of the same type are collapsed into the longest one. For
+    /// instance, "dMMMy", "yMMMd" and "yMMMdM" all result in the skeleton "yMMMd".
+    pub fn try_from_loose(skeleton_string: &str) -> Result<Self, SkeletonError> {
+        let mut fields: SmallVec<[fields::Field; 5]> = SmallVec::new();
+
+        for field in parse_fields(skeleton_string)? {
+            let same_type = fields.iter_mut().find(|existing| {
+                core::mem::discriminant(&existing.symbol) == core::mem::discriminant(&field.symbol)
+            });
+            match same_type {
+                Some(existing) => {
+                    if field.length > existing.length {
+                        *existing = field;
+                    }
                 }
-                field_length += 1;
-                iter.next();
+                None => fields.push(field),
             }
+        }
+        fields.sort();
 
-            let field = Field::from((field_symbol, FieldLength::from_idx(field_length)?));
+        Ok(Self(fields))
+    }
+}
 
-            match fields.binary_search(&field) {
-                Ok(_) => return Err(SkeletonError::DuplicateField),
-                Err(pos) => fields.insert(pos, field),
+impl Skeleton {
+    /// Creates a skeleton from a list of fields, without building and parsing a skeleton string.
+    ///
+    /// The fields must be in the canonical sort order, and each type of field may only appear
+    /// once, as in a UTS 35 skeleton. The result is equal to parsing the equivalent string.
+    pub fn try_from_fields<I>(fields: I) -> Result<Self, SkeletonError>
+    where
+        I: IntoIterator<Item = (FieldSymbol, FieldLength)>,
+    {
+        let mut result: SmallVec<[fields::Field; 5]> = SmallVec::new();
+
+        for field in fields.into_iter().map(Field::from) {
+            if let Some(prev_field) = result.last() {
+                if core::mem::discriminant(&prev_field.symbol)
+                    == core::mem::discriminant(&field.symbol)
+                {
+                    return Err(SkeletonError::DuplicateField);
+                }
+                if prev_field > &field {
+                    return Err(SkeletonError::FieldOutOfOrder);
+                }
             }
+            result.push(field);
         }
 
-        Ok(Self(fields))
+        Ok(Self(result))
     }
 }
 
+/// Parse a skeleton string into its fields, in the order they appear in the string.
+fn parse_fields(skeleton_string: &str) -> Result<SmallVec<[fields::Field; 5]>, SkeletonError> {
+    let mut fields: SmallVec<[fields::Field; 5]> = SmallVec::new();
+
+    let mut iter = skeleton_string.chars().peekable();
+    while let Some(ch) = iter.next() {
+        // Convert the byte to a valid field symbol.
+        let field_symbol = FieldSymbol::try_from(ch)?;
+
+        // Go through the chars to count how often it's repeated.
+        let mut field_length: u8 = 1;
+        while let Some(next_ch) = iter.peek() {
+            if *next_ch != ch {
+                break;
+            }
+            field_length += 1;
+            iter.next();
+        }
+
+        fields.push(Field::from((
+            field_symbol,
+            FieldLength::from_idx(field_length)?,
+        )));
+    }
+
+    Ok(fields)
+}
+
 /// Convert a Pattern into a Skeleton. This will remove all of the string literals, and sort
 /// the fields into the canonical sort order. Not all fields are supported by Skeletons, so map
 /// fields into skeleton-appropriate ones. For instance, in the "ja" locale the pattern "aK:mm"
@@ -252,6 +324,7 @@ impl From<&Pattern> for Skeleton {
                     | FieldSymbol::Second(_)
                     | FieldSymbol::TimeZone(_)
                     | FieldSymbol::Year(_)
+                    | FieldSymbol::Week(_)
                     | FieldSymbol::Day(_) => field.symbol,
                 };
 
@@ -265,6 +338,89 @@ impl From<&Pattern> for Skeleton {
     }
 }
 
+/// Returns the greatest field by which two date-times differ, which selects the interval
+/// pattern to use for the `skeleton`. See the
+/// [UTS 35 interval formats](https://unicode.org/reports/tr35/tr35-dates.html#intervalFormats).
+///
+/// The fields are compared from the most significant to the least significant: the era and
+/// year, the month, the day, the day period, the hour, the minute and the second. The fields that
+/// are less significant than all of the fields in the `skeleton` are ignored, e.g. the times
+/// don't matter for the skeleton "yMMMd". The day period only differs for a 12-hour `skeleton`.
+///
+/// The result is expressed with the symbol of the field in the `skeleton`, e.g. the hour is
+/// either `h` or `H`, or [`None`] if the date-times don't differ.
+pub fn get_greatest_difference<T>(skeleton: &Skeleton, first: &T, second: &T) -> Option<FieldSymbol>
+where
+    T: DateTimeInput,
+{
+    // The significance of each type of field, from the most significant.
+    fn significance(symbol: FieldSymbol) -> Option<u8> {
+        match symbol {
+            FieldSymbol::Year(_) => Some(0),
+            FieldSymbol::Month(_) => Some(1),
+            FieldSymbol::Week(_) | FieldSymbol::Day(_) | FieldSymbol::Weekday(_) => Some(2),
+            FieldSymbol::DayPeriod(_) | FieldSymbol::Hour(_) => Some(3),
+            FieldSymbol::Minute => Some(4),
+            FieldSymbol::Second(_) => Some(5),
+            FieldSymbol::TimeZone(_) => None,
+        }
+    }
+
+    let least_significant = skeleton
+        .fields_iter()
+        .filter_map(|field| significance(field.symbol))
+        .max()?;
+
+    // Use the symbol of the same type from the skeleton, if there is one.
+    let to_skeleton_symbol = |symbol: FieldSymbol| {
+        skeleton
+            .fields_iter()
+            .map(|field| field.symbol)
+            .find(|skeleton_symbol| {
+                core::mem::discriminant(skeleton_symbol) == core::mem::discriminant(&symbol)
+            })
+            .unwrap_or(symbol)
+    };
+
+    let hour = to_skeleton_symbol(FieldSymbol::Hour(fields::Hour::H23));
+    let is_12_hour = matches!(
+        hour,
+        FieldSymbol::Hour(fields::Hour::H11) | FieldSymbol::Hour(fields::Hour::H12)
+    );
+    let is_pm = |datetime: &T| datetime.hour().map(|hour| u8::from(hour) >= 12);
+
+    let differences = [
+        (
+            first.year() != second.year(),
+            FieldSymbol::Year(fields::Year::Calendar),
+        ),
+        (
+            first.month() != second.month(),
+            FieldSymbol::Month(fields::Month::Format),
+        ),
+        (
+            first.day_of_month().map(|day| day.0) != second.day_of_month().map(|day| day.0),
+            FieldSymbol::Day(fields::Day::DayOfMonth),
+        ),
+        (
+            is_12_hour && is_pm(first) != is_pm(second),
+            FieldSymbol::DayPeriod(fields::DayPeriod::AmPm),
+        ),
+        (first.hour() != second.hour(), hour),
+        (first.minute() != second.minute(), FieldSymbol::Minute),
+        (
+            first.second() != second.second(),
+            FieldSymbol::Second(fields::Second::Second),
+        ),
+    ];
+
+    differences
+        .iter()
+        .filter(|(_, symbol)| significance(*symbol) <= Some(least_significant))
+        .find(|(differs, _)| *differs)
+        .map(|(_, symbol)| to_skeleton_symbol(*symbol))
+}
+
 /// Represents a specific pattern that is available for a given locale.
 /// A [`Skeleton`] is used to match against to find the best pattern.
 #[derive(Debug, PartialEq, Clone)]
@@ -272,6 +428,9 @@ pub struct AvailableFormatPattern<'a> {
     /// The skeleton that is used to match against.
     skeleton: &'a Skeleton,
     pub pattern: &'a Pattern,
+    /// The variants of the pattern by plural category, if it has any. The `pattern` is then
+    /// their `other` variant.
+    pub plural_patterns: Option<&'a PluralPatternsV1>,
 }
 
 impl<'a> From<(&'a SkeletonV1, &'a PatternV1)> for AvailableFormatPattern<'a> {
@@ -281,6 +440,28 @@ impl<'a> From<(&'a SkeletonV1, &'a PatternV1)> for AvailableFormatPattern<'a> {
         AvailableFormatPattern {
             skeleton: &skeleton_v1.0,
             pattern: &pattern_v1.0,
+            plural_patterns: None,
+        }
+    }
+}
+
+impl<'a> From<(&'a SkeletonV1, &'a PluralPatternsV1)> for AvailableFormatPattern<'a> {
+    fn from(tuple: (&'a SkeletonV1, &'a PluralPatternsV1)) -> Self {
+        let (skeleton_v1, plural_patterns_v1) = tuple;
+
+        AvailableFormatPattern {
+            skeleton: &skeleton_v1.0,
+            pattern: &plural_patterns_v1.other.0,
+            plural_patterns: Some(plural_patterns_v1),
+        }
+    }
+}
+
+impl<'a> AvailableFormatPattern<'a> {
+    fn to_patterns(&self) -> PatternPlurals {
+        match self.plural_patterns {
+            Some(plural_patterns) => PatternPlurals::MultipleVariants(plural_patterns.clone()),
+            None => PatternPlurals::SinglePattern(self.pattern.clone()),
         }
     }
 }
@@ -296,6 +477,8 @@ pub enum SkeletonError {
     InvalidFieldLength,
     #[displaydoc("duplicate field in skeleton")]
     DuplicateField,
+    #[displaydoc("field out of order in skeleton")]
+    FieldOutOfOrder,
     #[displaydoc("symbol unknown {0} in skeleton")]
     SymbolUnknown(char),
     #[displaydoc("symbol invalid {0} in skeleton")]
@@ -337,12 +520,8 @@ impl From<fields::SymbolError> for SkeletonError {
                     'B'
                     // TODO(#486) - Era
                     | 'G'
-                    // TODO(#502) - Week of month
-                    | 'W'
                     // TODO(#501) - Quarters
                     | 'Q'
-                    // TODO (#488) - Week of year
-                    | 'w'
                     => Self::SymbolUnimplemented(ch),
                     _ => Self::SymbolUnknown(ch),
                 }
@@ -471,71 +650,93 @@ fn naively_apply_time_zone_name(
 /// Given a set of fields (which represents a skeleton), try to create a best localized pattern
 // for those fields.
 ///
-/// * `skeletons` - The skeletons that will be matched against
-/// * `length_patterns` - Contains information on how to combine date and time patterns.
+/// * `datetime` - The skeletons that will be matched against, including the ones whose patterns
+///         vary by plural category, and the length patterns to combine date and time patterns.
 /// * `fields` - The desired fields to match against.
 /// * `prefer_matched_pattern` - This algorithm does some extra steps of trying to respect
 ///         the desired fields, even if the provider data doesn't completely match. This
 ///         configuration option makes it so that the final pattern won't have additional work
 ///         done to mutate it to match the fields. It will prefer the actual matched pattern.
-pub fn create_best_pattern_for_fields<'a>(
-    skeletons: &'a SkeletonsV1,
-    length_patterns: &LengthPatternsV1,
+///
+/// Returns [`PatternError::MissingGlue`] if the date and time patterns need to be combined, but
+/// there is no length pattern to combine them with.
+pub fn create_best_pattern_for_fields(
+    datetime: &DateTimeFormatsV1,
     fields: &[Field],
     components: &components::Bag,
     prefer_matched_pattern: bool,
-) -> BestSkeleton<Pattern> {
-    let first_pattern_match =
-        get_best_available_format_pattern(skeletons, fields, prefer_matched_pattern);
+) -> Result<BestSkeleton<PatternPlurals>, PatternError> {
+    let skeletons = &datetime.skeletons;
+    let plural_skeletons = &datetime.plural_skeletons;
+    let first_pattern_match = get_best_available_format_pattern(
+        skeletons,
+        plural_skeletons,
+        fields,
+        prefer_matched_pattern,
+    );
 
     // Try to match a skeleton to all of the fields.
-    if let BestSkeleton::AllFieldsMatch(mut pattern) = first_pattern_match {
-        naively_apply_hour_cycle_preferences(&mut pattern, &components.preferences);
-        naively_apply_time_zone_name(&mut pattern, &components.time_zone_name);
-        return BestSkeleton::AllFieldsMatch(pattern);
+    if let BestSkeleton::AllFieldsMatch(mut patterns) = first_pattern_match {
+        for pattern in patterns.patterns_iter_mut() {
+            naively_apply_hour_cycle_preferences(pattern, &components.preferences);
+            naively_apply_time_zone_name(pattern, &components.time_zone_name);
+        }
+        return Ok(BestSkeleton::AllFieldsMatch(patterns));
     }
 
     let FieldsByType { date, time } = group_fields_by_type(fields);
 
     if date.is_empty() || time.is_empty() {
-        return match first_pattern_match {
+        return Ok(match first_pattern_match {
             BestSkeleton::AllFieldsMatch(_) => {
                 unreachable!("Logic error in implementation. AllFieldsMatch handled above.")
             }
-            BestSkeleton::MissingOrExtraFields(mut pattern) => {
+            BestSkeleton::MissingOrExtraFields(mut patterns) => {
                 if date.is_empty() {
-                    naively_apply_hour_cycle_preferences(&mut pattern, &components.preferences);
-                    naively_apply_time_zone_name(&mut pattern, &components.time_zone_name);
+                    for pattern in patterns.patterns_iter_mut() {
+                        naively_apply_hour_cycle_preferences(pattern, &components.preferences);
+                        naively_apply_time_zone_name(pattern, &components.time_zone_name);
+                    }
                 }
-                BestSkeleton::MissingOrExtraFields(pattern)
+                BestSkeleton::MissingOrExtraFields(patterns)
             }
             BestSkeleton::NoMatch => BestSkeleton::NoMatch,
-        };
+        });
     }
 
     // Match the date and time, and then simplify the combinatorial logic of the results into
     // an optional values of the results, and a boolean value.
-    let (date_pattern, date_missing_or_extra) =
-        match get_best_available_format_pattern(skeletons, &date, prefer_matched_pattern) {
-            BestSkeleton::MissingOrExtraFields(fields) => (Some(fields), true),
-            BestSkeleton::AllFieldsMatch(fields) => (Some(fields), false),
-            BestSkeleton::NoMatch => (None, true),
-        };
+    let (date_pattern, date_missing_or_extra) = match get_best_available_format_pattern(
+        skeletons,
+        plural_skeletons,
+        &date,
+        prefer_matched_pattern,
+    ) {
+        BestSkeleton::MissingOrExtraFields(fields) => (Some(fields), true),
+        BestSkeleton::AllFieldsMatch(fields) => (Some(fields), false),
+        BestSkeleton::NoMatch => (None, true),
+    };
 
-    let (mut time_pattern, time_missing_or_extra) =
-        match get_best_available_format_pattern(skeletons, &time, prefer_matched_pattern) {
-            BestSkeleton::MissingOrExtraFields(fields) => (Some(fields), true),
-            BestSkeleton::AllFieldsMatch(fields) => (Some(fields), false),
-            BestSkeleton::NoMatch => (None, true),
-        };
+    let (mut time_pattern, time_missing_or_extra) = match get_best_available_format_pattern(
+        skeletons,
+        plural_skeletons,
+        &time,
+        prefer_matched_pattern,
+    ) {
+        BestSkeleton::MissingOrExtraFields(fields) => (Some(fields), true),
+        BestSkeleton::AllFieldsMatch(fields) => (Some(fields), false),
+        BestSkeleton::NoMatch => (None, true),
+    };
 
-    if let Some(ref mut pattern) = time_pattern {
-        naively_apply_hour_cycle_preferences(pattern, &components.preferences);
-        naively_apply_time_zone_name(pattern, &components.time_zone_name);
+    if let Some(ref mut patterns) = time_pattern {
+        for pattern in patterns.patterns_iter_mut() {
+            naively_apply_hour_cycle_preferences(pattern, &components.preferences);
+            naively_apply_time_zone_name(pattern, &components.time_zone_name);
+        }
     }
 
     // Determine how to combine the date and time.
-    let pattern: Option<Pattern> = match (date_pattern, time_pattern) {
+    let pattern: Option<PatternPlurals> = match (date_pattern, time_pattern) {
         (Some(date_pattern), Some(time_pattern)) => {
             let month_field = fields
                 .iter()
@@ -567,24 +768,46 @@ pub fn create_best_pattern_for_fields<'a>(
                 None => length::Date::Short,
             };
 
-            let bytes = match length {
-                length::Date::Full => &length_patterns.full,
-                length::Date::Long => &length_patterns.long,
-                length::Date::Medium => &length_patterns.medium,
-                length::Date::Short => &length_patterns.short,
+            let combine = |date_pattern: Pattern, time_pattern: Pattern| {
+                datetime.combine(date_pattern, time_pattern, length)
             };
 
-            Some(
-                Pattern::from_bytes_combination(bytes, date_pattern, time_pattern)
-                    .expect("Failed to create a Pattern from bytes"),
-            )
+            // The week fields are date fields, so the variants by plural category are in the
+            // date pattern, and the time pattern is combined with each of them.
+            Some(match (date_pattern, time_pattern) {
+                (date_pattern, PatternPlurals::SinglePattern(time_pattern)) => {
+                    let mut patterns = date_pattern;
+                    for pattern in patterns.patterns_iter_mut() {
+                        *pattern = combine(core::mem::take(pattern), time_pattern.clone())?;
+                    }
+                    patterns
+                }
+                (PatternPlurals::SinglePattern(date_pattern), time_pattern) => {
+                    let mut patterns = time_pattern;
+                    for pattern in patterns.patterns_iter_mut() {
+                        *pattern = combine(date_pattern.clone(), core::mem::take(pattern))?;
+                    }
+                    patterns
+                }
+                (
+                    PatternPlurals::MultipleVariants(mut date_patterns),
+                    PatternPlurals::MultipleVariants(time_patterns),
+                ) => {
+                    // Only one week field can select the variant, which is the one of the date.
+                    for pattern in date_patterns.patterns_iter_mut() {
+                        *pattern =
+                            combine(core::mem::take(pattern), time_patterns.other.0.clone())?;
+                    }
+                    PatternPlurals::MultipleVariants(date_patterns)
+                }
+            })
         }
         (None, Some(pattern)) => Some(pattern),
         (Some(pattern), None) => Some(pattern),
         (None, None) => None,
     };
 
-    match pattern {
+    Ok(match pattern {
         Some(pattern) => {
             if date_missing_or_extra || time_missing_or_extra {
                 BestSkeleton::MissingOrExtraFields(pattern)
@@ -593,7 +816,7 @@ pub fn create_best_pattern_for_fields<'a>(
             }
         }
         None => BestSkeleton::NoMatch,
-    }
+    })
 }
 
 struct FieldsByType {
@@ -614,6 +837,7 @@ fn group_fields_by_type(fields: &[Field]) -> FieldsByType {
             //  - Solo example: "E"
             FieldSymbol::Year(_)
             | FieldSymbol::Month(_)
+            | FieldSymbol::Week(_)
             | FieldSymbol::Day(_)
             | FieldSymbol::Weekday(_) => date.push(*field),
 
@@ -651,14 +875,15 @@ fn group_fields_by_type(fields: &[Field]) -> FieldsByType {
 ///      much of this step to implement. See the issue for more information.
 pub fn get_best_available_format_pattern(
     skeletons: &SkeletonsV1,
+    plural_skeletons: &PluralSkeletonsV1,
     fields: &[Field],
     prefer_matched_pattern: bool,
-) -> BestSkeleton<Pattern> {
+) -> BestSkeleton<PatternPlurals> {
     let mut closest_format_pattern = None;
     let mut closest_distance: u32 = u32::MAX;
     let mut closest_missing_fields = 0;
 
-    for available_format_pattern in get_available_format_patterns(skeletons) {
+    for available_format_pattern in get_available_format_patterns(skeletons, plural_skeletons) {
         let skeleton = &available_format_pattern.skeleton;
         debug_assert!(
             skeleton.fields_len() <= MAX_SKELETON_FIELDS as usize,
@@ -731,7 +956,7 @@ pub fn get_best_available_format_pattern(
         }
 
         if distance < closest_distance {
-            closest_format_pattern = Some(available_format_pattern.pattern);
+            closest_format_pattern = Some(available_format_pattern);
             closest_distance = distance;
             closest_missing_fields = missing_fields;
         }
@@ -744,54 +969,52 @@ pub fn get_best_available_format_pattern(
         return BestSkeleton::NoMatch;
     }
 
+    let mut patterns = closest_format_pattern.to_patterns();
+
     if closest_distance == NO_DISTANCE {
-        return BestSkeleton::AllFieldsMatch(closest_format_pattern.clone());
+        return BestSkeleton::AllFieldsMatch(patterns);
     }
 
     // Modify the resulting pattern to have fields of the same length.
-    let expanded_pattern = if prefer_matched_pattern {
+    if prefer_matched_pattern {
         #[cfg(not(feature = "provider_transform_internals"))]
         panic!("This code branch should only be run when transforming provider code.");
-
-        #[cfg(feature = "provider_transform_internals")]
-        closest_format_pattern.clone()
     } else {
-        Pattern::from(
-            closest_format_pattern
-                .items
-                .iter()
-                .map(|item| {
-                    if let PatternItem::Field(pattern_field) = item {
-                        if let Some(requested_field) = fields
-                            .iter()
-                            .find(|field| field.symbol == pattern_field.symbol)
+        for pattern in patterns.patterns_iter_mut() {
+            for item in pattern.items_mut() {
+                if let PatternItem::Field(pattern_field) = item {
+                    if let Some(requested_field) = fields
+                        .iter()
+                        .find(|field| field.symbol == pattern_field.symbol)
+                    {
+                        if requested_field.length != pattern_field.length
+                            && requested_field.get_length_type() == pattern_field.get_length_type()
                         {
-                            if requested_field.length != pattern_field.length
-                                && requested_field.get_length_type()
-                                    == pattern_field.get_length_type()
-                            {
-                                return PatternItem::Field(*requested_field);
-                            }
+                            *pattern_field = *requested_field;
                         }
                     }
-                    // There's no match, or this is a string literal return the original item.
-                    *item
-                })
-                .collect::<Vec<PatternItem>>(),
-        )
-    };
+                }
+                // There's no match, or this is a string literal, keep the original item.
+            }
+        }
+    }
 
     if closest_distance >= SKELETON_EXTRA_SYMBOL {
-        return BestSkeleton::MissingOrExtraFields(expanded_pattern);
+        return BestSkeleton::MissingOrExtraFields(patterns);
     }
 
-    BestSkeleton::AllFieldsMatch(expanded_pattern)
+    BestSkeleton::AllFieldsMatch(patterns)
 }
 
 pub fn get_available_format_patterns<'a>(
     skeletons: &'a SkeletonsV1,
-) -> impl Iterator<Item = AvailableFormatPattern> + 'a {
-    skeletons.0.iter().map(AvailableFormatPattern::from)
+    plural_skeletons: &'a PluralSkeletonsV1,
+) -> impl Iterator<Item = AvailableFormatPattern<'a>> + 'a {
+    skeletons
+        .0
+        .iter()
+        .map(AvailableFormatPattern::from)
+        .chain(plural_skeletons.0.iter().map(AvailableFormatPattern::from))
 }
 
 #[cfg(all(test, feature = "provider_serde"))]
@@ -845,11 +1068,16 @@ mod test {
 
         match get_best_available_format_pattern(
             &data_provider.get().datetime.skeletons,
+            &data_provider.get().datetime.plural_skeletons,
             &requested_fields,
             false,
         ) {
-            BestSkeleton::AllFieldsMatch(available_format_pattern)
-            | BestSkeleton::MissingOrExtraFields(available_format_pattern) => {
+            BestSkeleton::AllFieldsMatch(PatternPlurals::SinglePattern(
+                available_format_pattern,
+            ))
+            | BestSkeleton::MissingOrExtraFields(PatternPlurals::SinglePattern(
+                available_format_pattern,
+            )) => {
                 assert_eq!(
                     available_format_pattern.to_string(),
                     String::from("MMMM d, y")
@@ -858,6 +1086,7 @@ mod test {
             BestSkeleton::NoMatch => {
                 panic!("No skeleton was found.")
             }
+            best => panic!("Unexpected {:?}", best),
         };
     }
 
@@ -873,16 +1102,47 @@ mod test {
 
         match get_best_available_format_pattern(
             &data_provider.get().datetime.skeletons,
+            &data_provider.get().datetime.plural_skeletons,
             &requested_fields,
             false,
         ) {
-            BestSkeleton::MissingOrExtraFields(available_format_pattern) => {
+            BestSkeleton::MissingOrExtraFields(PatternPlurals::SinglePattern(
+                available_format_pattern,
+            )) => {
                 assert_eq!(available_format_pattern.to_string(), String::from("L"))
             }
             best => panic!("Unexpected {:?}", best),
         };
     }
 
+    #[test]
+    fn test_skeleton_matching_plural_variants() {
+        let requested_fields = vec![
+            Field {
+                symbol: FieldSymbol::Year(fields::Year::Calendar),
+                length: FieldLength::One,
+            },
+            Field {
+                symbol: FieldSymbol::Week(fields::Week::WeekOfYear),
+                length: FieldLength::One,
+            },
+        ];
+        let data_provider = get_data_payload();
+
+        match get_best_available_format_pattern(
+            &data_provider.get().datetime.skeletons,
+            &data_provider.get().datetime.plural_skeletons,
+            &requested_fields,
+            false,
+        ) {
+            BestSkeleton::AllFieldsMatch(PatternPlurals::MultipleVariants(variants)) => {
+                assert_eq!(variants.other.0.to_string(), "'week' w 'of' Y");
+                assert!(variants.one.is_some());
+            }
+            best => panic!("Unexpected {:?}", best),
+        };
+    }
+
     // TODO(#586) - Append items support needs to be added.
     #[test]
     #[should_panic]
@@ -899,13 +1159,16 @@ mod test {
         let data_provider = get_data_payload();
 
         match create_best_pattern_for_fields(
-            &data_provider.get().datetime.skeletons,
-            &data_provider.get().datetime.length_patterns,
+            &data_provider.get().datetime,
             &requested_fields,
             &Default::default(),
             false,
-        ) {
-            BestSkeleton::AllFieldsMatch(available_format_pattern) => {
+        )
+        .expect("Failed to combine the date and time patterns")
+        {
+            BestSkeleton::AllFieldsMatch(PatternPlurals::SinglePattern(
+                available_format_pattern,
+            )) => {
                 // TODO - Append items are needed here.
                 assert_eq!(
                     available_format_pattern.to_string(),
@@ -925,6 +1188,7 @@ mod test {
         assert_eq!(
             get_best_available_format_pattern(
                 &data_provider.get().datetime.skeletons,
+                &data_provider.get().datetime.plural_skeletons,
                 &requested_fields,
                 false
             ),
@@ -947,6 +1211,7 @@ mod test {
         assert_eq!(
             get_best_available_format_pattern(
                 &data_provider.get().datetime.skeletons,
+                &data_provider.get().datetime.plural_skeletons,
                 &requested_fields,
                 false
             ),
@@ -960,12 +1225,14 @@ mod test {
     // https://gist.github.com/gregtatum/1d76bbdb87132f71a969a10f0c1d2d9c
 
     #[rustfmt::skip]
-    const SUPPORTED_STRING_SKELETONS: [&str; 60] = [
+    const SUPPORTED_STRING_SKELETONS: [&str; 62] = [
         "E", "dEEEE", "EHm", "EHms", "dE", "Ehm", "Ehms", "H", "HHmm", "HHmmss", "Hm", "Hms", "M",
         "MdEEEE", "MdE", "MMM", "MMMdEEEE", "MMMdE", "MMMM", "MMMMdEEEE", "MMMMdE", "MMMMd",
         "MMMMdd", "MMMd", "MMMdd", "MMd", "MMdd", "Md", "Mdd", "d", "h", "hm", "hms", "mmss", "ms",
         "y", "yM", "yMdEEEE", "yMdE", "yMM", "yMMM", "yMMMdEEEE", "yMMMdE", "yMMMM", "yMMMMdEEEE",
         "yMMMMdE", "yMMMMdcccc", "yMMMMd", "yMMMd", "yMMdd", "yMd",
+        // Weeks
+        "MMMMW", "yw",
         // Timezones
         "HHmmZ", "Hmsv", "Hmsvvvv", "Hmv", "Hmvvvv", "hmsv", "hmsvvvv", "hmv", "hmvvvv",
     ];
@@ -975,17 +1242,13 @@ mod test {
     //       and then regenerate the test data.
     //       https://github.com/unicode-org/icu4x/blob/main/provider/testdata/README.md
     #[rustfmt::skip]
-    const UNSUPPORTED_STRING_SKELETONS: [&str; 19] = [
+    const UNSUPPORTED_STRING_SKELETONS: [&str; 17] = [
         // TODO(#487) - Flexible day periods
         "Bh", "Bhm", "Bhms", "EBhm", "EBhms",
         // TODO(#486) - Era
         "Gy", "GyM", "GyMMM", "GyMMMdEEEE", "GyMMMdE", "GyMMMM", "GyMMMMdE", "GyMMMMd", "GyMMMd",
-        // TODO(#502) - Week of month
-        "MMMMW",
         // TODO(#501) - Quarters
         "yQ", "yQQQ", "yQQQQ",
-        // TODO (#488) - Week of year
-        "yw"
     ];
 
     #[test]
@@ -1048,6 +1311,63 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_greatest_difference() {
+        use crate::fields::{DayPeriod, Hour};
+        use crate::mock::parse_gregorian_from_str;
+
+        let difference = |skeleton: &str, first: &str, second: &str| {
+            let skeleton = Skeleton::try_from(skeleton).expect("Invalid skeleton");
+            let first = parse_gregorian_from_str(first).expect("Invalid date");
+            let second = parse_gregorian_from_str(second).expect("Invalid date");
+            get_greatest_difference(&skeleton, &first, &second)
+        };
+
+        assert_eq!(
+            difference("yMMMd", "2021-01-10T00:00:00", "2021-01-20T00:00:00"),
+            Some(FieldSymbol::Day(Day::DayOfMonth))
+        );
+        assert_eq!(
+            difference("yMMMd", "2021-01-10T00:00:00", "2021-02-10T00:00:00"),
+            Some(FieldSymbol::Month(Month::Format))
+        );
+        assert_eq!(
+            difference("yMMMd", "2021-01-10T00:00:00", "2022-01-10T00:00:00"),
+            Some(FieldSymbol::Year(fields::Year::Calendar))
+        );
+        // The month differs even if the day differs too.
+        assert_eq!(
+            difference("MMMd", "2021-01-31T00:00:00", "2021-02-01T00:00:00"),
+            Some(FieldSymbol::Month(Month::Format))
+        );
+
+        // The times are less significant than the skeleton.
+        assert_eq!(
+            difference("yMMMd", "2021-01-10T08:00:00", "2021-01-10T20:00:00"),
+            None
+        );
+        assert_eq!(
+            difference("hm", "2021-01-10T08:00:00", "2021-01-10T20:00:00"),
+            Some(FieldSymbol::DayPeriod(DayPeriod::AmPm))
+        );
+        assert_eq!(
+            difference("hm", "2021-01-10T08:00:00", "2021-01-10T09:00:00"),
+            Some(FieldSymbol::Hour(Hour::H12))
+        );
+        assert_eq!(
+            difference("Hm", "2021-01-10T08:00:00", "2021-01-10T20:00:00"),
+            Some(FieldSymbol::Hour(Hour::H23))
+        );
+        assert_eq!(
+            difference("Hm", "2021-01-10T08:00:00", "2021-01-10T08:30:00"),
+            Some(FieldSymbol::Minute)
+        );
+        assert_eq!(
+            difference("Hm", "2021-01-10T08:00:00", "2021-01-10T08:00:30"),
+            None
+        );
+    }
+
     #[test]
     fn test_skeleton_tuple_ordering() {
         let skeletons_strings = Vec::from([
@@ -1085,6 +1405,60 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_skeleton_from_fields() {
+        use crate::fields::{Day, Month, Year};
+
+        let skeleton = Skeleton::try_from_fields(vec![
+            (FieldSymbol::Year(Year::Calendar), FieldLength::One),
+            (FieldSymbol::Month(Month::Format), FieldLength::Abbreviated),
+            (FieldSymbol::Day(Day::DayOfMonth), FieldLength::One),
+        ])
+        .expect("Failed to create the skeleton.");
+        assert_eq!(skeleton, Skeleton::try_from("yMMMd").unwrap());
+
+        // The fields must be in the canonical sort order.
+        assert!(matches!(
+            Skeleton::try_from_fields(vec![
+                (FieldSymbol::Day(Day::DayOfMonth), FieldLength::One),
+                (FieldSymbol::Year(Year::Calendar), FieldLength::One),
+            ]),
+            Err(SkeletonError::FieldOutOfOrder)
+        ));
+
+        // Each type of field may only appear once.
+        assert!(matches!(
+            Skeleton::try_from_fields(vec![
+                (FieldSymbol::Month(Month::Format), FieldLength::Abbreviated),
+                (FieldSymbol::Month(Month::StandAlone), FieldLength::Wide),
+            ]),
+            Err(SkeletonError::DuplicateField)
+        ));
+    }
+
+    #[test]
+    fn test_skeleton_loose_matching() {
+        let data_provider = get_data_payload();
+        let skeletons = &data_provider.get().datetime.skeletons;
+
+        assert_eq!(
+            Skeleton::try_from_loose("dMMMy").unwrap(),
+            Skeleton::try_from("yMMMd").unwrap()
+        );
+        // Fields of the same type are collapsed into the longest one.
+        assert_eq!(
+            Skeleton::try_from_loose("yMMMdMd").unwrap(),
+            Skeleton::try_from("yMMMd").unwrap()
+        );
+
+        let pattern = skeletons
+            .get_loose("dMMMy")
+            .unwrap()
+            .expect("Unable to find the skeleton.");
+        assert_eq!(Some(pattern), skeletons.get_loose("yMMMd").unwrap());
+        assert_eq!(pattern.0.to_string(), "MMM d, y");
+    }
+
     /// This test handles a branch in the skeleton serialization code that takes into account
     /// duplicate field errors when deserializing from string.
     #[test]
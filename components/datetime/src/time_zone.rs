@@ -718,3 +718,29 @@ pub(super) enum ZeroPadding {
     /// Do not add zero-padding.
     Off,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::time_zone::MockTimeZone;
+
+    #[test]
+    #[cfg(feature = "provider_serde")]
+    fn test_localized_gmt_format() {
+        let provider = icu_testdata::get_provider();
+        let locale: Locale = "en".parse().unwrap();
+        let pattern = Pattern::from_bytes("OOOO").unwrap();
+        let time_zone_format = TimeZoneFormat::try_new(locale, pattern, &provider)
+            .expect("Failed to create TimeZoneFormat.");
+
+        let time_zone: MockTimeZone = "+05:30".parse().unwrap();
+        let mut sink = String::new();
+        time_zone_format
+            .localized_gmt_format(&mut sink, &time_zone)
+            .unwrap();
+        assert_eq!(sink, "GMT+05:30");
+
+        let time_zone: MockTimeZone = "Z".parse().unwrap();
+        assert_eq!(time_zone_format.format_to_string(&time_zone), "GMT");
+    }
+}
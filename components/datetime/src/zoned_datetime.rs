@@ -4,6 +4,7 @@
 
 use alloc::string::String;
 use icu_locid::Locale;
+use icu_plurals::{provider::PluralRuleStringsV1Marker, PluralRuleType, PluralRules};
 use icu_provider::{DataProvider, DataRequest, ResourceOptions, ResourcePath};
 
 use crate::{
@@ -102,6 +103,8 @@ impl<'data> ZonedDateTimeFormat<'data> {
         L: Into<Locale>,
         DP: DataProvider<'data, provider::gregory::DatePatternsV1Marker>
             + DataProvider<'data, provider::gregory::DateSymbolsV1Marker>
+            + DataProvider<'data, provider::week_data::WeekDataV1Marker>
+            + DataProvider<'data, PluralRuleStringsV1Marker>
             + ?Sized,
         ZP: DataProvider<'data, provider::time_zones::TimeZoneFormatsV1Marker>
             + DataProvider<'data, provider::time_zones::ExemplarCitiesV1Marker>
@@ -125,13 +128,16 @@ impl<'data> ZonedDateTimeFormat<'data> {
                 })?
                 .take_payload()?;
 
-        let pattern = pattern_data
+        let patterns = pattern_data
             .get()
             .get_pattern_for_options(options)?
             .unwrap_or_default();
 
-        let requires_data = datetime::analyze_pattern(&pattern, true)
-            .map_err(|field| DateTimeFormatError::UnsupportedField(field.symbol))?;
+        let mut requires_data = false;
+        for pattern in patterns.patterns_iter() {
+            requires_data |= datetime::analyze_pattern(pattern, true)
+                .map_err(|field| DateTimeFormatError::UnsupportedField(field.symbol))?;
+        }
 
         let symbols_data = if requires_data {
             Some(
@@ -151,10 +157,43 @@ impl<'data> ZonedDateTimeFormat<'data> {
             None
         };
 
-        let datetime_format = DateTimeFormat::new(locale, pattern, symbols_data);
+        let week_data = if patterns.patterns_iter().any(datetime::requires_week_data) {
+            Some(
+                date_provider
+                    .load_payload(&DataRequest {
+                        resource_path: ResourcePath {
+                            key: provider::key::WEEK_DATA_V1,
+                            options: ResourceOptions {
+                                variant: None,
+                                langid: Some(locale.clone().into()),
+                            },
+                        },
+                    })?
+                    .take_payload()?,
+            )
+        } else {
+            None
+        };
+
+        // The variants of the patterns are selected by the plural category of a week number.
+        let plural_rules = if patterns.requires_plural_rules() {
+            Some(PluralRules::try_new(
+                locale.clone().into(),
+                date_provider,
+                PluralRuleType::Cardinal,
+            )?)
+        } else {
+            None
+        };
+
+        // All the variants have the same time zone fields, so the data is loaded for `other`.
+        let time_zone_pattern = patterns.patterns_iter().last().cloned().unwrap_or_default();
+
+        let datetime_format =
+            DateTimeFormat::new(locale, patterns, symbols_data, week_data, plural_rules);
         let time_zone_format = TimeZoneFormat::try_new(
             datetime_format.locale.clone(),
-            datetime_format.pattern.clone(),
+            time_zone_pattern,
             zone_provider,
         )?;
 
@@ -15,10 +15,13 @@ use icu_datetime::{
     provider::{
         gregory::{DatePatternsV1Marker, DateSymbolsV1Marker},
         key::{GREGORY_DATE_PATTERNS_V1, GREGORY_DATE_SYMBOLS_V1},
+        week_data::WeekDataV1Marker,
     },
     DateTimeFormat,
 };
 use icu_locid::{LanguageIdentifier, Locale};
+use icu_plurals::provider::PluralRuleStringsV1Marker;
+use icu_provider::inv::InvariantDataProvider;
 use icu_provider::prelude::*;
 use icu_provider::struct_provider::StructProvider;
 use patterns::{
@@ -55,6 +58,26 @@ impl<'data> DataProvider<'data, DatePatternsV1Marker> for MultiKeyStructProvider
     }
 }
 
+// The patterns of these tests have no week fields, so the week data is never loaded.
+impl<'data> DataProvider<'data, WeekDataV1Marker> for MultiKeyStructProvider<'data> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, WeekDataV1Marker>, icu_provider::DataError> {
+        InvariantDataProvider.load_payload(req)
+    }
+}
+
+// Nor do they vary by plural category, so the plural rules are never loaded either.
+impl<'data> DataProvider<'data, PluralRuleStringsV1Marker> for MultiKeyStructProvider<'data> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, PluralRuleStringsV1Marker>, icu_provider::DataError> {
+        InvariantDataProvider.load_payload(req)
+    }
+}
+
 fn test_fixture(fixture_name: &str) {
     let provider = icu_testdata::get_provider();
 
@@ -362,6 +385,14 @@ fn test_components_combine_datetime() {
     test_fixture("components-combine-datetime");
 }
 
+/// Tests that component::Bags with a week match the skeletons whose patterns vary by plural
+/// category.
+#[test]
+fn test_components_week() {
+    // components/datetime/tests/fixtures/tests/components-week.json
+    test_fixture("components-week");
+}
+
 #[test]
 fn constructing_datetime_format_with_time_zone_pattern_symbols_is_err() {
     use icu_datetime::{
@@ -0,0 +1,14 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#![no_main]
+
+use icu_plurals::rules::parse_and_eval;
+use libfuzzer_sys::fuzz_target;
+
+// Run with `cargo +nightly fuzz run parse_and_eval` in `components/plurals`.
+fuzz_target!(|input: (&[u8], &[u8])| {
+    let (rule, operands) = input;
+    let _ = parse_and_eval(rule, operands);
+});
@@ -0,0 +1,33 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! A `no_std` crate which parses and evaluates plural rules, to make sure that the rules of
+//! [`icu_plurals`] only require `alloc`.
+//!
+//! This crate is built for a target without `std` in CI, see the `test-nostd-plurals` task.
+
+#![cfg_attr(not(test), no_std)]
+
+use icu_plurals::rules::{parse_condition, test_condition};
+use icu_plurals::PluralOperands;
+
+/// Returns whether `n` matches the condition of the plural rule `rule`, or `None` if the rule
+/// fails to parse.
+pub fn matches(rule: &str, n: u64) -> Option<bool> {
+    let condition = parse_condition(rule.as_bytes()).ok()?;
+    Some(test_condition(&condition, &PluralOperands::from(n)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::matches;
+
+    #[test]
+    fn test_matches() {
+        assert_eq!(matches("n % 10 = 3", 13), Some(true));
+        assert_eq!(matches("n % 10 = 3", 14), Some(false));
+        assert_eq!(matches("i = 1 and v = 0", 1), Some(true));
+        assert_eq!(matches("n % 0 = 1", 1), None);
+    }
+}
@@ -6,7 +6,7 @@ use crate::operands::PluralOperands;
 use crate::provider::PluralRuleStringsV1;
 use crate::rules;
 use crate::rules::ast;
-use crate::{PluralCategory, PluralRulesError};
+use crate::{PluralCategory, PluralRulesError, PLURAL_CATEGORY_ORDER};
 use alloc::borrow::Cow;
 use core::convert::TryInto;
 
@@ -91,16 +91,27 @@ pub enum RulesSelector {
 
 impl RulesSelector {
     pub fn select(&self, operands: &PluralOperands) -> PluralCategory {
+        self.matches(*operands)
+            .next()
+            .unwrap_or(PluralCategory::Other)
+    }
+
+    /// Returns an iterator over each [`PluralCategory`] whose condition matches the operands,
+    /// in CLDR order. The category [`PluralCategory::Other`] always matches, and comes last.
+    pub fn matches(&self, operands: PluralOperands) -> impl Iterator<Item = PluralCategory> + '_ {
         match self {
             // Self::Function(ptr) => ptr(operands),
-            Self::Conditions(conditions) => PluralCategory::all()
-                .find_map(|category| {
-                    conditions
-                        .get(*category)
-                        .filter(|cond| rules::test_condition(cond, operands))
-                        .map(|_| *category)
-                })
-                .unwrap_or(PluralCategory::Other),
+            Self::Conditions(conditions) => {
+                PLURAL_CATEGORY_ORDER
+                    .iter()
+                    .copied()
+                    .filter(move |category| {
+                        *category == PluralCategory::Other
+                            || conditions
+                                .get(*category)
+                                .map_or(false, |cond| rules::test_condition(cond, &operands))
+                    })
+            }
         }
     }
 
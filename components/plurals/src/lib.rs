@@ -229,6 +229,25 @@ impl PluralCategory {
     }
 }
 
+/// The order in which the conditions of the [`Plural Categories`] are tested when selecting a
+/// category, as in CLDR data: zero, one, two, few, many and other.
+///
+/// CLDR guarantees that at most one condition matches, but if a malformed rule list has
+/// overlapping conditions, the first category in this order wins. [`PluralCategory::Other`] is
+/// last, and always matches.
+///
+/// See [`rules::select_first_matching`].
+///
+/// [`Plural Categories`]: PluralCategory
+pub const PLURAL_CATEGORY_ORDER: &[PluralCategory] = &[
+    PluralCategory::Zero,
+    PluralCategory::One,
+    PluralCategory::Two,
+    PluralCategory::Few,
+    PluralCategory::Many,
+    PluralCategory::Other,
+];
+
 /// A struct which provides an ability to retrieve an appropriate
 /// [`Plural Category`] for a given number.
 ///
@@ -349,6 +368,39 @@ impl PluralRules {
         self.selector.select(&input.into())
     }
 
+    /// Returns all the [`Plural Categories`] whose rules match a given number, in the order
+    /// in which [`select()`](PluralRules::select()) tests them: zero, one, two, few, many and
+    /// other.
+    ///
+    /// The rules in CLDR data never overlap, so this returns the selected category, followed by
+    /// [`PluralCategory::Other`] if the selected category is not [`PluralCategory::Other`].
+    /// If the rules do overlap, [`select()`](PluralRules::select()) returns the first category.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::macros::langid;
+    /// use icu::plurals::{PluralRules, PluralRuleType, PluralCategory};
+    ///
+    /// let lid = langid!("en");
+    ///
+    /// let dp = icu_testdata::get_provider();
+    ///
+    /// let pr = PluralRules::try_new(lid, &dp, PluralRuleType::Cardinal)
+    ///     .expect("Failed to construct a PluralRules struct.");
+    ///
+    /// let categories: Vec<PluralCategory> = pr.matches(1_usize).collect();
+    /// assert_eq!(categories, vec![PluralCategory::One, PluralCategory::Other]);
+    /// ```
+    ///
+    /// [`Plural Categories`]: PluralCategory
+    pub fn matches<I: Into<PluralOperands>>(
+        &self,
+        input: I,
+    ) -> impl Iterator<Item = PluralCategory> + '_ {
+        self.selector.matches(input.into())
+    }
+
     /// Returns all [`Plural Categories`] appropriate for a [`PluralRules`] object
     /// based on the [`LanguageIdentifier`] and [`PluralRuleType`].
     ///
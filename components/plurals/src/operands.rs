@@ -40,6 +40,7 @@ use fixed_decimal::FixedDecimal;
 ///    f: 0,
 ///    t: 0,
 ///    c: 0,
+///    e: 0,
 /// }, PluralOperands::from(2_usize))
 /// ```
 ///
@@ -54,6 +55,7 @@ use fixed_decimal::FixedDecimal;
 ///    f: 45,
 ///    t: 45,
 ///    c: 0,
+///    e: 0,
 /// }), "123.45".parse())
 /// ```
 ///
@@ -69,6 +71,7 @@ use fixed_decimal::FixedDecimal;
 ///    f: 45,
 ///    t: 45,
 ///    c: 0,
+///    e: 0,
 /// }), FixedDecimal::from(12345).multiplied_pow10(-2).map(|d| (&d).into()))
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -85,6 +88,9 @@ pub struct PluralOperands {
     pub t: u64,
     /// Exponent of the power of 10 used in compact decimal formatting
     pub c: usize,
+    /// The operand `e`, which is currently a synonym for `c`, and is the same as it unless set
+    /// otherwise. It is kept separate so that the rules using it keep working if CLDR redefines it.
+    pub e: usize,
 }
 
 impl PluralOperands {
@@ -95,9 +101,104 @@ impl PluralOperands {
     /// This method requires the `"std"` feature be enabled
     #[cfg(feature = "std")]
     pub fn n(&self) -> f64 {
-        let fraction = self.t as f64 / 10_f64.powi(self.v as i32);
+        let fraction = self.f as f64 / 10_f64.powi(self.v as i32);
         self.i as f64 + fraction
     }
+
+    /// Creates [`PluralOperands`] for a number displayed in compact decimal notation, such
+    /// as "1.2M", where `significand` is the displayed number ("1.2") and `exponent` is the
+    /// exponent of the power of 10 used by the compact notation (6).
+    ///
+    /// The compact exponent is exposed as the operands `c` and `e`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// use icu::plurals::PluralOperands;
+    ///
+    /// let significand = FixedDecimal::from(12)
+    ///     .multiplied_pow10(-1)
+    ///     .expect("Bounds are small");
+    /// assert_eq!(PluralOperands {
+    ///    i: 1,
+    ///    v: 1,
+    ///    w: 1,
+    ///    f: 2,
+    ///    t: 2,
+    ///    c: 6,
+    ///    e: 6,
+    /// }, PluralOperands::from_compact(&significand, 6))
+    /// ```
+    pub fn from_compact(significand: &FixedDecimal, exponent: usize) -> Self {
+        Self {
+            c: exponent,
+            e: exponent,
+            ..Self::from_fixed_decimal(significand)
+        }
+    }
+
+    /// Computes the [`PluralOperands`] of a [`FixedDecimal`], so that they can be tested
+    /// against multiple rules. Retains at most 18 digits each from the integer and
+    /// fraction parts.
+    ///
+    /// The visible fraction digits include the trailing zeros of the decimal, and the
+    /// sign is ignored. The compact decimal exponent is `0`; see
+    /// [`PluralOperands::from_compact`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// use icu::plurals::PluralOperands;
+    ///
+    /// let dec = FixedDecimal::from(-150)
+    ///     .multiplied_pow10(-2)
+    ///     .expect("Bounds are small");
+    /// assert_eq!(PluralOperands {
+    ///    i: 1,
+    ///    v: 2,
+    ///    w: 1,
+    ///    f: 50,
+    ///    t: 5,
+    ///    c: 0,
+    ///    e: 0,
+    /// }, PluralOperands::from_fixed_decimal(&dec))
+    /// ```
+    pub fn from_fixed_decimal(dec: &FixedDecimal) -> Self {
+        let mag_range = dec.magnitude_range();
+        let mag_high = core::cmp::min(17, *mag_range.end());
+        let mag_low = core::cmp::max(-18, *mag_range.start());
+
+        let mut i: u64 = 0;
+        for magnitude in (0..=mag_high).rev() {
+            i *= 10;
+            i += dec.digit_at(magnitude) as u64;
+        }
+
+        let mut f: u64 = 0;
+        let mut t: u64 = 0;
+        let mut w: usize = 0;
+        for magnitude in (mag_low..=-1).rev() {
+            let digit = dec.digit_at(magnitude) as u64;
+            f *= 10;
+            f += digit;
+            if digit != 0 {
+                t = f;
+                w = (-magnitude) as usize;
+            }
+        }
+
+        Self {
+            i,
+            v: (-mag_low) as usize,
+            w,
+            f,
+            t,
+            c: 0,
+            e: 0,
+        }
+    }
 }
 
 #[derive(Display, Debug, PartialEq, Eq)]
@@ -192,6 +293,7 @@ impl FromStr for PluralOperands {
             f: fraction_digits0,
             t: fraction_digits,
             c: exponent,
+            e: exponent,
         })
     }
 }
@@ -207,6 +309,7 @@ macro_rules! impl_integer_type {
                     f: 0,
                     t: 0,
                     c: 0,
+                    e: 0,
                 }
             }
         }
@@ -229,6 +332,7 @@ macro_rules! impl_signed_integer_type {
                     f: 0,
                     t: 0,
                     c: 0,
+                    e: 0,
                 })
             }
         }
@@ -242,39 +346,9 @@ impl_integer_type!(u8 u16 u32 u64 u128 usize);
 impl_signed_integer_type!(i8 i16 i32 i64 i128 isize);
 
 impl From<&FixedDecimal> for PluralOperands {
-    /// Converts a [`fixed_decimal::FixedDecimal`] to [`PluralOperands`]. Retains at most 18
-    /// digits each from the integer and fraction parts.
+    /// Converts a [`fixed_decimal::FixedDecimal`] to [`PluralOperands`]; see
+    /// [`PluralOperands::from_fixed_decimal`].
     fn from(dec: &FixedDecimal) -> Self {
-        let mag_range = dec.magnitude_range();
-        let mag_high = core::cmp::min(17, *mag_range.end());
-        let mag_low = core::cmp::max(-18, *mag_range.start());
-
-        let mut i: u64 = 0;
-        for magnitude in (0..=mag_high).rev() {
-            i *= 10;
-            i += dec.digit_at(magnitude) as u64;
-        }
-
-        let mut f: u64 = 0;
-        let mut t: u64 = 0;
-        let mut w: usize = 0;
-        for magnitude in (mag_low..=-1).rev() {
-            let digit = dec.digit_at(magnitude) as u64;
-            f *= 10;
-            f += digit;
-            if digit != 0 {
-                t = f;
-                w = (-magnitude) as usize;
-            }
-        }
-
-        Self {
-            i,
-            v: (-mag_low) as usize,
-            w,
-            f,
-            t,
-            c: 0,
-        }
+        Self::from_fixed_decimal(dec)
     }
 }
@@ -39,9 +39,16 @@
 //! [`parse`]: super::parse()
 //! [`test_condition`]: super::test_condition()
 use alloc::boxed::Box;
+use alloc::format;
 use alloc::string::String;
+use alloc::vec;
+use core::convert::TryFrom;
 use core::ops::RangeInclusive;
 
+use super::parser::{parse, parse_condition, ParserError};
+use super::resolver::test_condition;
+use crate::{PluralCategory, PluralOperands};
+
 /// A complete AST representation of a plural rule.
 /// Comprises a vector of [`AndConditions`] and optionally a set of [`Samples`].
 ///
@@ -91,6 +98,162 @@ pub struct Rule {
     pub samples: Option<Samples>,
 }
 
+impl Rule {
+    /// Returns `true` if the number written in `sample` satisfies the condition of the rule.
+    ///
+    /// The sample is written like the sample values of CLDR, such as `"1.50"`. The visible
+    /// fraction digits are taken into account, and a compact decimal exponent can be added with
+    /// `c` or `e`. As specified in UTS #35, the exponent shifts the decimal point, so `"1.2c6"`
+    /// has the operands of 1200000 (`i = 1200000` and `v = 0`), with `c = 6`. An invalid sample
+    /// doesn't match any rule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::plurals::rules::parse;
+    ///
+    /// let rule = parse(b"i = 1 and v = 0").expect("Parsing failed");
+    /// assert!(rule.matches_sample("1"));
+    /// assert!(!rule.matches_sample("1.0"));
+    ///
+    /// let rule = parse(b"e = 0 and i != 0 and i % 1000000 = 0 and v = 0").expect("Parsing failed");
+    /// assert!(rule.matches_sample("1000000"));
+    /// assert!(!rule.matches_sample("1c6"));
+    /// ```
+    pub fn matches_sample(&self, sample: &str) -> bool {
+        match sample_operands(sample) {
+            Some(operands) => test_condition(&self.condition, &operands),
+            None => false,
+        }
+    }
+}
+
+/// Returns the [`PluralOperands`] of a sample value, with the compact decimal exponent applied
+/// to the decimal number.
+fn sample_operands(sample: &str) -> Option<PluralOperands> {
+    let (number, exponent) = split_exponent(sample);
+    let exponent = match exponent.get(1..) {
+        Some(exponent) => exponent.parse::<usize>().ok()?,
+        None => 0,
+    };
+    let (sign, number) = match number.strip_prefix('-') {
+        Some(number) => ("-", number),
+        None => ("", number),
+    };
+    let mut parts = number.splitn(2, '.');
+    let integer = parts.next().unwrap_or("");
+    let fraction = parts.next().unwrap_or("");
+
+    // Move the decimal point to the right by `exponent` digits, padding with zeros.
+    let digits = format!("{}{}", integer, fraction);
+    let point = integer.len() + exponent;
+    let shifted = if point >= digits.len() {
+        format!(
+            "{}{}{:0<width$}",
+            sign,
+            digits,
+            "",
+            width = point - digits.len()
+        )
+    } else {
+        format!("{}{}.{}", sign, &digits[..point], &digits[point..])
+    };
+
+    let mut operands = shifted.parse::<PluralOperands>().ok()?;
+    operands.c = exponent;
+    operands.e = exponent;
+    Some(operands)
+}
+
+/// Parses a [`Rule`] with [`parse`].
+///
+/// # Examples
+///
+/// ```
+/// use icu::plurals::rules::ast::Rule;
+/// use icu::plurals::rules::parse;
+/// use std::convert::TryFrom;
+///
+/// let rule = Rule::try_from("i = 1".as_bytes()).expect("Parsing failed");
+/// assert_eq!(rule, parse(b"i = 1").expect("Parsing failed"));
+/// assert!(Rule::try_from("i = ".as_bytes()).is_err());
+/// ```
+///
+/// [`parse`]: super::parse()
+impl TryFrom<&[u8]> for Rule {
+    type Error = ParserError;
+
+    fn try_from(input: &[u8]) -> Result<Self, Self::Error> {
+        parse(input)
+    }
+}
+
+/// Parses a [`Rule`] with [`parse`].
+///
+/// [`parse`]: super::parse()
+impl TryFrom<&str> for Rule {
+    type Error = ParserError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        parse(input.as_bytes())
+    }
+}
+
+/// A set of plural [`Rule`]s keyed by [`PluralCategory`], as found in CLDR plural data.
+///
+/// It is the output of [`parse_rules`]. A category without a rule is `None`.
+///
+/// # Examples
+///
+/// ```
+/// use icu::plurals::rules::parse_rules;
+/// use icu::plurals::PluralCategory;
+///
+/// let rules = parse_rules(br#"{ "one": "i = 1 and v = 0", "other": "" }"#)
+///     .expect("Parsing failed");
+///
+/// assert!(rules.get(PluralCategory::One).is_some());
+/// assert!(rules.get(PluralCategory::Few).is_none());
+/// ```
+///
+/// [`PluralCategory`]: crate::PluralCategory
+/// [`parse_rules`]: super::parse_rules()
+#[derive(Debug, Clone, PartialEq, Default)]
+#[allow(missing_docs)] // TODO(#1029) - Add missing docs.
+pub struct PluralRuleSet {
+    pub zero: Option<Rule>,
+    pub one: Option<Rule>,
+    pub two: Option<Rule>,
+    pub few: Option<Rule>,
+    pub many: Option<Rule>,
+    pub other: Option<Rule>,
+}
+
+impl PluralRuleSet {
+    /// Returns the [`Rule`] for `category`, if there is one.
+    pub fn get(&self, category: PluralCategory) -> Option<&Rule> {
+        match category {
+            PluralCategory::Zero => self.zero.as_ref(),
+            PluralCategory::One => self.one.as_ref(),
+            PluralCategory::Two => self.two.as_ref(),
+            PluralCategory::Few => self.few.as_ref(),
+            PluralCategory::Many => self.many.as_ref(),
+            PluralCategory::Other => self.other.as_ref(),
+        }
+    }
+
+    pub(crate) fn get_mut(&mut self, category: PluralCategory) -> &mut Option<Rule> {
+        match category {
+            PluralCategory::Zero => &mut self.zero,
+            PluralCategory::One => &mut self.one,
+            PluralCategory::Two => &mut self.two,
+            PluralCategory::Few => &mut self.few,
+            PluralCategory::Many => &mut self.many,
+            PluralCategory::Other => &mut self.other,
+        }
+    }
+}
+
 /// A complete AST representation of a plural rule's condition. Comprises a vector of [`AndConditions`].
 ///
 /// # Examples
@@ -129,6 +292,73 @@ pub struct Rule {
 #[derive(Debug, Clone, PartialEq)]
 pub struct Condition(pub Box<[AndCondition]>);
 
+impl Condition {
+    /// Returns `true` if the condition has no [`AndConditions`].
+    ///
+    /// An empty condition is the result of parsing an empty input, and matches all operands.
+    /// In CLDR data it is used by the `other` category.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::plurals::rules::parse_condition;
+    ///
+    /// assert!(parse_condition(b"").expect("Parsing failed").is_empty());
+    /// assert!(!parse_condition(b"i = 1").expect("Parsing failed").is_empty());
+    /// ```
+    ///
+    /// [`AndConditions`]: AndCondition
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Builds a condition which matches if any of the [`AndConditions`] match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::plurals::rules::ast::*;
+    /// use icu::plurals::rules::parse_condition;
+    ///
+    /// let condition = Condition::any_of(vec![
+    ///     AndCondition::all_of(vec![Relation::eq(Operand::I, vec![1.into()])]),
+    ///     AndCondition::all_of(vec![Relation::eq(Operand::I, vec![2.into()])]),
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     condition,
+    ///     parse_condition(b"i = 1 or i = 2").expect("Parsing failed")
+    /// );
+    /// ```
+    ///
+    /// [`AndConditions`]: AndCondition
+    pub fn any_of(and_conditions: impl IntoIterator<Item = AndCondition>) -> Self {
+        Condition(and_conditions.into_iter().collect())
+    }
+}
+
+/// Parses a [`Condition`] with [`parse_condition`], ignoring the samples.
+///
+/// [`parse_condition`]: super::parse_condition()
+impl TryFrom<&[u8]> for Condition {
+    type Error = ParserError;
+
+    fn try_from(input: &[u8]) -> Result<Self, Self::Error> {
+        parse_condition(input)
+    }
+}
+
+/// Parses a [`Condition`] with [`parse_condition`], ignoring the samples.
+///
+/// [`parse_condition`]: super::parse_condition()
+impl TryFrom<&str> for Condition {
+    type Error = ParserError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        parse_condition(input.as_bytes())
+    }
+}
+
 /// An incomplete AST representation of a plural rule. Comprises a vector of [`Relations`].
 ///
 /// # Examples
@@ -170,6 +400,32 @@ pub struct Condition(pub Box<[AndCondition]>);
 #[derive(Debug, Clone, PartialEq)]
 pub struct AndCondition(pub Box<[Relation]>);
 
+impl AndCondition {
+    /// Builds an [`AndCondition`] which matches if all of the [`Relations`] match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::plurals::rules::ast::*;
+    /// use icu::plurals::rules::parse_condition;
+    ///
+    /// let condition = Condition::any_of(vec![AndCondition::all_of(vec![
+    ///     Relation::eq(Operand::I, vec![1.into()]),
+    ///     Relation::eq(Operand::V, vec![0.into()]),
+    /// ])]);
+    ///
+    /// assert_eq!(
+    ///     condition,
+    ///     parse_condition(b"i = 1 and v = 0").expect("Parsing failed")
+    /// );
+    /// ```
+    ///
+    /// [`Relations`]: Relation
+    pub fn all_of(relations: impl IntoIterator<Item = Relation>) -> Self {
+        AndCondition(relations.into_iter().collect())
+    }
+}
+
 /// An incomplete AST representation of a plural rule. Comprises an [`Expression`], an [`Operator`], and a [`RangeList`].
 ///
 /// # Examples
@@ -204,6 +460,60 @@ pub struct Relation {
     pub range_list: RangeList,
 }
 
+impl Relation {
+    /// Builds a [`Relation`] which matches if the [`Expression`] is equal to any of the
+    /// [`RangeListItems`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::plurals::rules::ast::*;
+    /// use icu::plurals::rules::parse_condition;
+    ///
+    /// let expression = Expression {
+    ///     operand: Operand::N,
+    ///     modulus: Some(Value(10)),
+    /// };
+    /// let relation = Relation::eq(expression, vec![1.into(), (3..=4).into()]);
+    ///
+    /// assert_eq!(
+    ///     Condition::any_of(vec![AndCondition::all_of(vec![relation])]),
+    ///     parse_condition(b"n % 10 = 1, 3..4").expect("Parsing failed")
+    /// );
+    /// ```
+    ///
+    /// [`RangeListItems`]: RangeListItem
+    pub fn eq(
+        expression: impl Into<Expression>,
+        range_list: impl IntoIterator<Item = RangeListItem>,
+    ) -> Self {
+        Self::new(expression.into(), Operator::Eq, range_list)
+    }
+
+    /// Builds a [`Relation`] which matches if the [`Expression`] is not equal to any of the
+    /// [`RangeListItems`].
+    ///
+    /// [`RangeListItems`]: RangeListItem
+    pub fn not_eq(
+        expression: impl Into<Expression>,
+        range_list: impl IntoIterator<Item = RangeListItem>,
+    ) -> Self {
+        Self::new(expression.into(), Operator::NotEq, range_list)
+    }
+
+    fn new(
+        expression: Expression,
+        operator: Operator,
+        range_list: impl IntoIterator<Item = RangeListItem>,
+    ) -> Self {
+        Relation {
+            expression,
+            operator,
+            range_list: RangeList(range_list.into_iter().collect()),
+        }
+    }
+}
+
 /// An enum of [`Relation`] operators for plural rules.
 ///
 /// Each Operator enumeration belongs to the corresponding symbolic operators:
@@ -249,6 +559,16 @@ pub struct Expression {
     pub modulus: Option<Value>,
 }
 
+impl From<Operand> for Expression {
+    /// Builds an [`Expression`] of the operand without a modulus.
+    fn from(operand: Operand) -> Self {
+        Expression {
+            operand,
+            modulus: None,
+        }
+    }
+}
+
 /// An incomplete AST representation of a plural rule. Comprises a [`char`].
 ///
 /// # Examples
@@ -316,6 +636,23 @@ pub enum Operand {
 #[derive(Debug, Clone, PartialEq)]
 pub struct RangeList(pub Box<[RangeListItem]>);
 
+impl RangeList {
+    /// Returns `true` if the value is one of the values or in one of the ranges of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::plurals::rules::ast::*;
+    ///
+    /// let range_list = RangeList(Box::new([2.into(), (5..=7).into()]));
+    /// assert!(range_list.contains(&Value(6)));
+    /// assert!(!range_list.contains(&Value(3)));
+    /// ```
+    pub fn contains(&self, value: &Value) -> bool {
+        self.0.iter().any(|item| item.contains(value))
+    }
+}
+
 /// An enum of items that appear in a [`RangeList`]: `Range` or a `Value`.
 ///
 /// See [`RangeInclusive`] and [`Value`] for additional details.
@@ -336,15 +673,41 @@ pub struct RangeList(pub Box<[RangeListItem]>);
 /// let _ = RangeListItem::Value(Value(5));
 /// let _ = RangeListItem::Range(Value(11)..=Value(15));
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[allow(missing_docs)] // TODO(#1029) - Add missing docs.
 pub enum RangeListItem {
     Range(RangeInclusive<Value>),
     Value(Value),
 }
 
+impl RangeListItem {
+    /// Returns `true` if the value is equal to the value of the item, or in its inclusive range.
+    pub fn contains(&self, value: &Value) -> bool {
+        match self {
+            RangeListItem::Value(item) => item == value,
+            RangeListItem::Range(range) => range.contains(value),
+        }
+    }
+}
+
+impl From<u64> for RangeListItem {
+    fn from(value: u64) -> Self {
+        RangeListItem::Value(Value(value))
+    }
+}
+
+impl From<RangeInclusive<u64>> for RangeListItem {
+    fn from(range: RangeInclusive<u64>) -> Self {
+        RangeListItem::Range(Value(*range.start())..=Value(*range.end()))
+    }
+}
+
 /// An incomplete AST representation of a plural rule, representing one integer.
 ///
+/// The operands compared against a [`Value`] are non-negative integers of at most `u64`, so a
+/// `u64` can hold any value which may match. The parser rejects literals which don't fit into a
+/// `u64` with [`ParserError::ExpectedValue`], rather than wrapping them around.
+///
 /// # Examples
 ///
 /// All AST nodes can be built explicitly, as seen in the example. However, due to its complexity, it is preferred to build the
@@ -361,7 +724,9 @@ pub enum RangeListItem {
 ///
 /// RangeListItem::Value(Value(99));
 /// ```
-#[derive(Debug, Clone, PartialEq, PartialOrd)]
+///
+/// [`ParserError::ExpectedValue`]: crate::rules::ParserError::ExpectedValue
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Value(pub u64);
 
 /// A sample of example values that match the given rule.
@@ -447,6 +812,112 @@ pub struct SampleRange {
     pub upper_val: Option<DecimalValue>,
 }
 
+impl SampleRange {
+    /// Returns an iterator over all the values in the range, in increasing order.
+    ///
+    /// The values are produced lazily, so that ranges such as `0~4294967295` can be expanded
+    /// as far as needed.
+    ///
+    /// The increment between the values is inferred from the number of fraction digits of
+    /// `lower_val`, and all the values are written with that many fraction digits. If
+    /// `upper_val` has more fraction digits, the extra digits are truncated, so the last value
+    /// is the largest one which is not greater than `upper_val`.
+    ///
+    /// A range without `upper_val` expands to `lower_val` alone. If the endpoints have different
+    /// exponents or don't fit in a [`u64`], only the two endpoints are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::plurals::rules::ast::*;
+    ///
+    /// let range = SampleRange {
+    ///     lower_val: DecimalValue("0.8".to_string()),
+    ///     upper_val: Some(DecimalValue("1.1".to_string())),
+    /// };
+    ///
+    /// assert_eq!(
+    ///     range.expand().collect::<Vec<_>>(),
+    ///     vec![
+    ///         DecimalValue("0.8".to_string()),
+    ///         DecimalValue("0.9".to_string()),
+    ///         DecimalValue("1.0".to_string()),
+    ///         DecimalValue("1.1".to_string()),
+    ///     ]
+    /// );
+    /// ```
+    pub fn expand(&self) -> impl Iterator<Item = DecimalValue> + '_ {
+        let (range, endpoints) = match &self.upper_val {
+            Some(upper_val) => {
+                let (lower, exponent) = split_exponent(&self.lower_val.0);
+                let (upper, upper_exponent) = split_exponent(&upper_val.0);
+                let fraction_digits = lower.split('.').nth(1).map_or(0, str::len);
+
+                match (
+                    to_scaled_integer(lower, fraction_digits),
+                    to_scaled_integer(upper, fraction_digits),
+                ) {
+                    (Some(lower), Some(upper)) if exponent == upper_exponent => {
+                        (Some((lower..=upper, fraction_digits, exponent)), vec![])
+                    }
+                    _ => (None, vec![self.lower_val.clone(), upper_val.clone()]),
+                }
+            }
+            None => (None, vec![self.lower_val.clone()]),
+        };
+
+        range
+            .into_iter()
+            .flat_map(|(values, fraction_digits, exponent)| {
+                values.map(move |value| {
+                    DecimalValue(from_scaled_integer(value, fraction_digits, exponent))
+                })
+            })
+            .chain(endpoints)
+    }
+}
+
+/// Splits a sample value into the decimal number and the exponent suffix, such as `"e3"` or
+/// `"c3"`.
+fn split_exponent(value: &str) -> (&str, &str) {
+    match value.find(&['e', 'c'][..]) {
+        Some(index) => value.split_at(index),
+        None => (value, ""),
+    }
+}
+
+/// Converts a decimal number into an integer of its value multiplied by 10^`fraction_digits`,
+/// truncating the extra fraction digits.
+fn to_scaled_integer(value: &str, fraction_digits: usize) -> Option<u64> {
+    let mut parts = value.splitn(2, '.');
+    let integer = parts.next().unwrap_or("");
+    let fraction = parts.next().unwrap_or("");
+    let mut result: u64 = 0;
+    let fraction_padding = core::iter::repeat('0');
+    for c in integer.chars().chain(
+        fraction
+            .chars()
+            .chain(fraction_padding)
+            .take(fraction_digits),
+    ) {
+        let digit = c.to_digit(10)?;
+        result = result.checked_mul(10)?.checked_add(u64::from(digit))?;
+    }
+    Some(result)
+}
+
+/// Converts an integer of a value multiplied by 10^`fraction_digits` back into a decimal number
+/// with the `exponent` suffix. The decimal point is inserted into the digits, as 10^`fraction_digits`
+/// may not fit in a [`u64`].
+fn from_scaled_integer(value: u64, fraction_digits: usize, exponent: &str) -> String {
+    if fraction_digits == 0 {
+        return format!("{}{}", value, exponent);
+    }
+    let digits = format!("{:0width$}", value, width = fraction_digits + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - fraction_digits);
+    format!("{}.{}{}", integer, fraction, exponent)
+}
+
 /// A decimal value used in samples.
 ///
 /// # Examples
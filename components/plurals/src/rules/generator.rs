@@ -0,0 +1,84 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::ast;
+use super::resolver::test_condition;
+use crate::operands::PluralOperands;
+use alloc::vec::Vec;
+use fixed_decimal::FixedDecimal;
+
+/// The largest integer searched for examples without fraction digits.
+const MAX_INTEGER: u64 = 1000;
+/// The largest integer part searched for examples with fraction digits.
+const MAX_DECIMAL_INTEGER: u64 = 100;
+/// The largest number of visible fraction digits searched for examples.
+const MAX_FRACTION_DIGITS: usize = 3;
+
+impl ast::Rule {
+    /// Generates up to `limit` numbers which match the [`Condition`] of the rule.
+    ///
+    /// Integers from `0` to `1000` are searched first, followed by decimals from `0`
+    /// to `100` with one, two and three visible fraction digits, in that order.
+    /// The compact decimal exponent of the generated numbers is always `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::plurals::rules::parse;
+    ///
+    /// let rule = parse(b"i = 1 and v = 2").expect("Parsing failed.");
+    /// let examples: Vec<String> = rule
+    ///     .generate_examples(3)
+    ///     .iter()
+    ///     .map(|example| example.to_string())
+    ///     .collect();
+    ///
+    /// assert_eq!(examples, vec!["1.00", "1.01", "1.02"]);
+    /// ```
+    ///
+    /// [`Condition`]: super::ast::Condition
+    pub fn generate_examples(&self, limit: usize) -> Vec<FixedDecimal> {
+        let mut examples = Vec::new();
+        for v in 0..=MAX_FRACTION_DIGITS {
+            let max_integer = if v == 0 {
+                MAX_INTEGER
+            } else {
+                MAX_DECIMAL_INTEGER
+            };
+            let scale = 10_u64.pow(v as u32);
+            for i in 0..=max_integer {
+                for f in 0..scale {
+                    if examples.len() >= limit {
+                        return examples;
+                    }
+                    if test_condition(&self.condition, &get_operands(i, v, f)) {
+                        let example = FixedDecimal::from(i * scale + f)
+                            .multiplied_pow10(-(v as i16))
+                            .expect("Bounds are small");
+                        examples.push(example);
+                    }
+                }
+            }
+        }
+        examples
+    }
+}
+
+fn get_operands(i: u64, v: usize, f: u64) -> PluralOperands {
+    let mut w = v;
+    let mut t = f;
+    while w > 0 && t % 10 == 0 {
+        w -= 1;
+        t /= 10;
+    }
+    PluralOperands {
+        i,
+        v,
+        w,
+        f,
+        t,
+        c: 0,
+        e: 0,
+    }
+}
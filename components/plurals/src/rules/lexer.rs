@@ -9,7 +9,7 @@ use displaydoc::Display;
 pub enum Token {
     Operand(ast::Operand),
     Operator(ast::Operator),
-    Number(u32),
+    Number(u64),
     Zero,
     Dot,
     DotDot,
@@ -32,6 +32,8 @@ pub enum LexerError {
     ExpectedByte(u8),
     #[displaydoc("Unknown token: {0}")]
     UnknownToken(u8),
+    #[displaydoc("Number too large")]
+    NumberTooLarge,
 }
 
 #[cfg(feature = "std")]
@@ -52,6 +54,7 @@ impl std::error::Error for LexerError {}
 pub struct Lexer<'l> {
     chars: &'l [u8],
     ptr: usize,
+    lenient: bool,
 }
 
 impl<'l> Lexer<'l> {
@@ -68,17 +71,46 @@ impl<'l> Lexer<'l> {
         Self {
             chars: input,
             ptr: 0,
+            lenient: false,
         }
     }
 
-    fn bump(&mut self) -> Option<&u8> {
-        let ret = self.chars.get(self.ptr);
+    /// Constructs a new [`Lexer`] for a given input, which tolerates minor syntax variants: the
+    /// letters of operands and keywords may be uppercase, tabs and line breaks are skipped like
+    /// spaces, and `...` is read as the `..` of a range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::plurals::rules::Lexer;
+    ///
+    /// assert_eq!(Lexer::new_lenient(b"I = 2...5").count(), 5);
+    /// ```
+    pub fn new_lenient(input: &'l [u8]) -> Self {
+        Self {
+            chars: input,
+            ptr: 0,
+            lenient: true,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        let c = *self.chars.get(self.ptr)?;
+        Some(if self.lenient {
+            c.to_ascii_lowercase()
+        } else {
+            c
+        })
+    }
+
+    fn bump(&mut self) -> Option<u8> {
+        let ret = self.peek();
         self.ptr += 1;
         ret
     }
 
     fn take_if(&mut self, c: u8) -> bool {
-        if self.chars.get(self.ptr) == Some(&c) {
+        if self.peek() == Some(c) {
             self.ptr += 1;
             true
         } else {
@@ -87,7 +119,7 @@ impl<'l> Lexer<'l> {
     }
 
     fn expect(&mut self, expected: u8) -> Result<(), LexerError> {
-        if self.bump() == Some(&expected) {
+        if self.bump() == Some(expected) {
             Ok(())
         } else {
             Err(LexerError::ExpectedByte(expected))
@@ -99,6 +131,7 @@ impl<'l> Lexer<'l> {
             if let Some(c) = self.bump() {
                 let token = match c {
                     b' ' => continue,
+                    b'\t' | b'\n' | b'\r' if self.lenient => continue,
                     b'n' => Token::Operand(ast::Operand::N),
                     b'i' => {
                         if self.take_if(b'n') {
@@ -120,18 +153,17 @@ impl<'l> Lexer<'l> {
                     // Zero is special, because we need to preserve it for Samples.
                     b'0' => Token::Zero,
                     b'1'..=b'9' => {
-                        let start = self.ptr - 1;
+                        let mut value = (c - b'0') as u64;
 
-                        while let Some(b'0'..=b'9') = self.chars.get(self.ptr) {
+                        // Numbers which don't fit into a `u64` are rejected, rather than
+                        // wrapped around, so that a rule can't silently match other values.
+                        while let Some(digit @ b'0'..=b'9') = self.chars.get(self.ptr) {
+                            value = value
+                                .checked_mul(10)
+                                .and_then(|value| value.checked_add((digit - b'0') as u64))
+                                .ok_or(LexerError::NumberTooLarge)?;
                             self.ptr += 1;
                         }
-                        let end = self.ptr;
-
-                        let mut value = 0;
-                        for ptr in start..end {
-                            let mul = 10_u32.pow((end - ptr - 1) as u32);
-                            value += ((self.chars[ptr] - b'0') as u32) * mul;
-                        }
                         Token::Number(value)
                     }
                     b'a' => {
@@ -149,6 +181,9 @@ impl<'l> Lexer<'l> {
                     }
                     b'.' => {
                         if self.take_if(b'.') {
+                            if self.lenient {
+                                self.take_if(b'.');
+                            }
                             Token::DotDot
                         } else {
                             Token::Dot
@@ -175,7 +210,7 @@ impl<'l> Lexer<'l> {
                     b'~' => Token::Tilde,
                     b'e' => Token::E,
                     b'c' => Token::Operand(ast::Operand::C),
-                    b => return Err(LexerError::UnknownToken(*b)),
+                    b => return Err(LexerError::UnknownToken(b)),
                 };
                 return Ok(Some(token));
             } else {
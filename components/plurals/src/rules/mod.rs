@@ -38,6 +38,7 @@
 //!     f: 0,
 //!     t: 0,
 //!     c: 0,
+//!     e: 0,
 //! };
 //! ```
 //!
@@ -144,12 +145,19 @@
 //! [`Sample`]: super::rules::ast::Samples
 //! [`AST`]: super::rules::ast
 pub mod ast;
+pub(crate) mod generator;
 pub(crate) mod lexer;
+pub(crate) mod operand_set;
 pub(crate) mod parser;
+pub(crate) mod pretty;
 pub(crate) mod resolver;
 pub(crate) mod serializer;
 
 pub use lexer::Lexer;
-pub use parser::{parse, parse_condition};
-pub use resolver::test_condition;
+pub use operand_set::OperandSet;
+pub use parser::{
+    parse, parse_condition, parse_condition_lenient, parse_lenient, parse_rules, ParserError,
+};
+pub use pretty::Pretty;
+pub use resolver::{parse_and_eval, select_first_matching, test_condition, EvalError};
 pub use serializer::serialize;
@@ -0,0 +1,98 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::ast;
+
+const ALL_OPERANDS: [ast::Operand; 8] = [
+    ast::Operand::N,
+    ast::Operand::I,
+    ast::Operand::V,
+    ast::Operand::W,
+    ast::Operand::F,
+    ast::Operand::T,
+    ast::Operand::C,
+    ast::Operand::E,
+];
+
+/// A set of [`Operand`]s, stored as a bitset.
+///
+/// # Examples
+///
+/// ```
+/// use icu::plurals::rules::ast::Operand;
+/// use icu::plurals::rules::OperandSet;
+///
+/// let mut set = OperandSet::default();
+/// set.insert(Operand::I);
+/// set.insert(Operand::V);
+///
+/// assert!(set.contains(Operand::I));
+/// assert!(!set.contains(Operand::N));
+/// assert_eq!(set.iter().collect::<Vec<_>>(), vec![Operand::I, Operand::V]);
+/// ```
+///
+/// [`Operand`]: super::ast::Operand
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct OperandSet(u8);
+
+impl OperandSet {
+    /// Adds an [`Operand`](super::ast::Operand) to the set.
+    pub fn insert(&mut self, operand: ast::Operand) {
+        self.0 |= Self::bit(operand);
+    }
+
+    /// Returns `true` if the set contains the [`Operand`](super::ast::Operand).
+    pub fn contains(&self, operand: ast::Operand) -> bool {
+        self.0 & Self::bit(operand) != 0
+    }
+
+    /// Returns `true` if the set contains no operands.
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns an iterator over the operands in the set, in the order of the
+    /// [`Operand`](super::ast::Operand) variants.
+    pub fn iter(&self) -> impl Iterator<Item = ast::Operand> + '_ {
+        ALL_OPERANDS
+            .iter()
+            .copied()
+            .filter(move |operand| self.contains(*operand))
+    }
+
+    fn bit(operand: ast::Operand) -> u8 {
+        1 << (operand as u8)
+    }
+}
+
+impl ast::Rule {
+    /// Returns the set of operands referenced by the [`Condition`] of the rule.
+    ///
+    /// This can be used to skip the computation of operands which the rule does not use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::plurals::rules::ast::Operand;
+    /// use icu::plurals::rules::parse;
+    ///
+    /// let rule = parse(b"i = 1 and v = 0").expect("Parsing failed.");
+    /// let operands = rule.referenced_operands();
+    ///
+    /// assert!(operands.contains(Operand::I));
+    /// assert!(operands.contains(Operand::V));
+    /// assert!(!operands.contains(Operand::F));
+    /// ```
+    ///
+    /// [`Condition`]: super::ast::Condition
+    pub fn referenced_operands(&self) -> OperandSet {
+        let mut set = OperandSet::default();
+        for and_condition in self.condition.0.iter() {
+            for relation in and_condition.0.iter() {
+                set.insert(relation.expression.operand);
+            }
+        }
+        set
+    }
+}
@@ -4,11 +4,14 @@
 
 use super::ast;
 use super::lexer::{Lexer, Token};
+use crate::PluralCategory;
+use alloc::boxed::Box;
 use alloc::string::String;
 use alloc::string::ToString;
 use alloc::vec;
 use alloc::vec::Vec;
 use core::iter::Peekable;
+use core::str::FromStr;
 use displaydoc::Display;
 
 #[derive(Display, Debug, PartialEq, Eq)]
@@ -26,6 +29,16 @@ pub enum ParserError {
     ExpectedValue,
     #[displaydoc("expected sample type")]
     ExpectedSampleType,
+    #[displaydoc("modulus must not be zero")]
+    ZeroModulus,
+    #[displaydoc("expected plural rule set")]
+    ExpectedRuleSet,
+    #[displaydoc("unknown plural category")]
+    UnknownCategory,
+    #[displaydoc("duplicate rule for plural category {0:?}")]
+    DuplicateCategory(PluralCategory),
+    #[displaydoc("invalid rule for plural category {0:?}: {1}")]
+    InvalidRule(PluralCategory, Box<ParserError>),
 }
 
 #[cfg(feature = "std")]
@@ -45,6 +58,13 @@ impl std::error::Error for ParserError {}
 ///
 /// At runtime, only the [`Condition`] is used and for that, consider using [`parse_condition`].
 ///
+/// # Empty input
+///
+/// An empty input, or one made only of whitespace, is not an error. It is parsed into a
+/// [`Rule`] with an empty [`Condition`] and no [`Samples`]. An empty [`Condition`] matches
+/// all operands, which is what CLDR uses for the `other` category. Any other input which is not
+/// a valid rule returns a [`ParserError`].
+///
 /// # Examples
 ///
 /// ```
@@ -52,6 +72,12 @@ impl std::error::Error for ParserError {}
 ///
 /// let input = b"i = 0 or n = 1 @integer 0, 1 @decimal 0.0~1.0, 0.00~0.04";
 /// assert_eq!(parse(input).is_ok(), true);
+///
+/// let rule = parse(b"").expect("Parsing failed");
+/// assert!(rule.condition.is_empty());
+/// assert_eq!(rule.samples, None);
+///
+/// assert!(parse(b"i =").is_err());
 /// ```
 ///
 /// [`AST`]: super::ast
@@ -73,6 +99,9 @@ pub fn parse(input: &[u8]) -> Result<ast::Rule, ParserError> {
 /// That [`AST`] can be then used by the [`test_condition`] to test
 /// against [`PluralOperands`], to find the appropriate [`PluralCategory`].
 ///
+/// As with [`parse`], an empty input is parsed into an empty [`Condition`], which matches all
+/// operands.
+///
 /// # Examples
 ///
 /// ```
@@ -86,11 +115,224 @@ pub fn parse(input: &[u8]) -> Result<ast::Rule, ParserError> {
 /// [`test_condition`]: super::test_condition
 /// [`PluralOperands`]: crate::PluralOperands
 /// [`PluralCategory`]: crate::PluralCategory
+/// [`parse`]: parse()
+/// [`Condition`]: super::ast::Condition
 pub fn parse_condition(input: &[u8]) -> Result<ast::Condition, ParserError> {
     let parser = Parser::new(input);
     parser.parse_condition()
 }
 
+/// Parses a rule like [`parse`], but tolerates minor syntax variants found in hand-written
+/// rules, and normalizes them to the same [`AST`] as the canonical syntax:
+///
+/// * the operands and keywords may be uppercase, such as `I = 1 AND V = 0`,
+/// * tabs and line breaks may separate tokens, like spaces,
+/// * a range may be written with `...`, such as `i = 2...5`.
+///
+/// [`parse`] stays strict, and should be used for validating rules.
+///
+/// # Examples
+///
+/// ```
+/// use icu::plurals::rules::{parse, parse_lenient};
+///
+/// assert_eq!(parse_lenient(b"I = 2...5"), parse(b"i = 2..5"));
+/// assert!(parse(b"I = 2...5").is_err());
+/// ```
+///
+/// [`AST`]: super::ast
+/// [`parse`]: parse()
+pub fn parse_lenient(input: &[u8]) -> Result<ast::Rule, ParserError> {
+    let parser = Parser::new_lenient(input);
+    parser.parse()
+}
+
+/// Parses a condition like [`parse_condition`], but tolerates the same minor syntax variants
+/// as [`parse_lenient`].
+///
+/// # Examples
+///
+/// ```
+/// use icu::plurals::rules::{parse_condition, parse_condition_lenient};
+///
+/// assert_eq!(
+///     parse_condition_lenient(b"N % 10 = 1\tOR n = 5"),
+///     parse_condition(b"n % 10 = 1 or n = 5")
+/// );
+/// ```
+///
+/// [`parse_condition`]: parse_condition()
+/// [`parse_lenient`]: parse_lenient()
+pub fn parse_condition_lenient(input: &[u8]) -> Result<ast::Condition, ParserError> {
+    let parser = Parser::new_lenient(input);
+    parser.parse_condition()
+}
+
+/// Parses a block of plural rules keyed by category, such as
+/// `{ "one": "i = 1 and v = 0", "other": "" }`, into a [`PluralRuleSet`].
+///
+/// Each key is the name of a [`PluralCategory`], optionally prefixed with `pluralRule-count-`
+/// as in CLDR data files, and each value is a rule parsed with [`parse`]. If a rule fails
+/// to parse, the returned [`ParserError::InvalidRule`] names its category.
+///
+/// # Examples
+///
+/// ```
+/// use icu::plurals::rules::{parse_rules, ParserError};
+/// use icu::plurals::PluralCategory;
+///
+/// let input = r#"{
+///     "pluralRule-count-one": "i = 1 and v = 0 @integer 1",
+///     "pluralRule-count-other": " @integer 0, 2~16, 100, 1000, …"
+/// }"#;
+/// let rules = parse_rules(input.as_bytes()).expect("Parsing failed");
+/// assert!(rules.get(PluralCategory::One).is_some());
+/// assert!(rules.get(PluralCategory::Other).is_some());
+///
+/// assert_eq!(
+///     parse_rules(br#"{ "one": "i = 1", "few": "i =" }"#),
+///     Err(ParserError::InvalidRule(
+///         PluralCategory::Few,
+///         Box::new(ParserError::ExpectedValue)
+///     ))
+/// );
+/// ```
+///
+/// [`PluralRuleSet`]: super::ast::PluralRuleSet
+/// [`PluralCategory`]: crate::PluralCategory
+/// [`parse`]: parse()
+pub fn parse_rules(input: &[u8]) -> Result<ast::PluralRuleSet, ParserError> {
+    let mut result = ast::PluralRuleSet::default();
+    let mut input = skip_whitespace(input)
+        .strip_prefix(b"{")
+        .ok_or(ParserError::ExpectedRuleSet)?;
+
+    input = skip_whitespace(input);
+    if let Some(rest) = input.strip_prefix(b"}") {
+        input = rest;
+    } else {
+        loop {
+            let (key, rest) = get_string(input)?;
+            let rest = skip_whitespace(rest)
+                .strip_prefix(b":")
+                .ok_or(ParserError::ExpectedRuleSet)?;
+            let (value, rest) = get_string(rest)?;
+
+            let category = get_category(key)?;
+            let rule = parse(value).map_err(|e| ParserError::InvalidRule(category, Box::new(e)))?;
+            let slot = result.get_mut(category);
+            if slot.is_some() {
+                return Err(ParserError::DuplicateCategory(category));
+            }
+            *slot = Some(rule);
+
+            let rest = skip_whitespace(rest);
+            if let Some(rest) = rest.strip_prefix(b",") {
+                input = rest;
+            } else if let Some(rest) = rest.strip_prefix(b"}") {
+                input = rest;
+                break;
+            } else {
+                return Err(ParserError::ExpectedRuleSet);
+            }
+        }
+    }
+
+    if skip_whitespace(input).is_empty() {
+        Ok(result)
+    } else {
+        Err(ParserError::ExpectedRuleSet)
+    }
+}
+
+fn skip_whitespace(input: &[u8]) -> &[u8] {
+    let start = input
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .unwrap_or(input.len());
+    &input[start..]
+}
+
+// Plural rules and category names never contain quotes or escapes, so a string ends at the
+// next quote.
+fn get_string(input: &[u8]) -> Result<(&[u8], &[u8]), ParserError> {
+    let input = skip_whitespace(input)
+        .strip_prefix(b"\"")
+        .ok_or(ParserError::ExpectedRuleSet)?;
+    let end = input
+        .iter()
+        .position(|&b| b == b'"' || b == b'\\')
+        .ok_or(ParserError::ExpectedRuleSet)?;
+    if input[end] != b'"' {
+        return Err(ParserError::ExpectedRuleSet);
+    }
+    Ok((&input[..end], &input[end + 1..]))
+}
+
+fn get_category(key: &[u8]) -> Result<PluralCategory, ParserError> {
+    match key.strip_prefix(b"pluralRule-count-").unwrap_or(key) {
+        b"zero" => Ok(PluralCategory::Zero),
+        b"one" => Ok(PluralCategory::One),
+        b"two" => Ok(PluralCategory::Two),
+        b"few" => Ok(PluralCategory::Few),
+        b"many" => Ok(PluralCategory::Many),
+        b"other" => Ok(PluralCategory::Other),
+        _ => Err(ParserError::UnknownCategory),
+    }
+}
+
+impl FromStr for ast::Operand {
+    type Err = ParserError;
+
+    /// Parses a single operand, such as `"i"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::plurals::rules::ast::Operand;
+    /// use icu::plurals::rules::ParserError;
+    ///
+    /// assert_eq!("i".parse::<Operand>(), Ok(Operand::I));
+    /// assert_eq!("x".parse::<Operand>(), Err(ParserError::ExpectedOperand));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "n" => Ok(ast::Operand::N),
+            "i" => Ok(ast::Operand::I),
+            "v" => Ok(ast::Operand::V),
+            "w" => Ok(ast::Operand::W),
+            "f" => Ok(ast::Operand::F),
+            "t" => Ok(ast::Operand::T),
+            "c" => Ok(ast::Operand::C),
+            "e" => Ok(ast::Operand::E),
+            _ => Err(ParserError::ExpectedOperand),
+        }
+    }
+}
+
+impl FromStr for ast::Operator {
+    type Err = ParserError;
+
+    /// Parses a single operator, such as `"!="`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::plurals::rules::ast::Operator;
+    /// use icu::plurals::rules::ParserError;
+    ///
+    /// assert_eq!("!=".parse::<Operator>(), Ok(Operator::NotEq));
+    /// assert_eq!("<".parse::<Operator>(), Err(ParserError::ExpectedOperator));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "=" => Ok(ast::Operator::Eq),
+            "!=" => Ok(ast::Operator::NotEq),
+            _ => Err(ParserError::ExpectedOperator),
+        }
+    }
+}
+
 struct Parser<'p> {
     lexer: Peekable<Lexer<'p>>,
 }
@@ -102,6 +344,12 @@ impl<'p> Parser<'p> {
         }
     }
 
+    fn new_lenient(input: &'p [u8]) -> Self {
+        Self {
+            lexer: Lexer::new_lenient(input).peekable(),
+        }
+    }
+
     pub fn parse(mut self) -> Result<ast::Rule, ParserError> {
         self.get_rule()
     }
@@ -180,7 +428,11 @@ impl<'p> Parser<'p> {
         };
         self.lexer.next();
         let modulus = if self.take_if(Token::Modulo) {
-            Some(self.get_value()?)
+            let modulus = self.get_value()?;
+            if modulus.0 == 0 {
+                return Err(ParserError::ZeroModulus);
+            }
+            Some(modulus)
         } else {
             None
         };
@@ -219,7 +471,7 @@ impl<'p> Parser<'p> {
 
     fn get_value(&mut self) -> Result<ast::Value, ParserError> {
         match self.lexer.next() {
-            Some(Token::Number(v)) => Ok(ast::Value(v as u64)),
+            Some(Token::Number(v)) => Ok(ast::Value(v)),
             Some(Token::Zero) => Ok(ast::Value(0)),
             _ => Err(ParserError::ExpectedValue),
         }
@@ -0,0 +1,124 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::ast;
+use super::serializer::{serialize_expression, serialize_rangelistitem, serialize_sample_list};
+use core::fmt;
+
+/// A [`Display`] wrapper which prints a [`Rule`] as an indented tree, for debugging.
+///
+/// Unlike [`serialize`], the output is not a valid plural rule and can't be parsed back.
+///
+/// # Examples
+///
+/// ```
+/// use icu::plurals::rules::parse;
+///
+/// let rule = parse(b"i = 1 and v = 0 @integer 1").expect("Parsing failed.");
+///
+/// assert_eq!(
+///     rule.pretty(2).to_string(),
+///     "\
+/// Rule
+///   Condition
+///     AndCondition
+///       Relation: i =
+///         Value: 1
+///       Relation: v =
+///         Value: 0
+///   Samples
+///     Integer: 1
+/// "
+/// );
+/// ```
+///
+/// [`Display`]: core::fmt::Display
+/// [`Rule`]: super::ast::Rule
+/// [`serialize`]: super::serialize()
+#[derive(Debug, Clone, Copy)]
+pub struct Pretty<'a> {
+    rule: &'a ast::Rule,
+    indent: usize,
+}
+
+impl ast::Rule {
+    /// Returns a [`Pretty`] wrapper which prints the rule as a tree, indenting each level of the
+    /// tree by `indent` spaces.
+    pub fn pretty(&self, indent: usize) -> Pretty<'_> {
+        Pretty { rule: self, indent }
+    }
+}
+
+impl<'a> Pretty<'a> {
+    fn write_line(
+        &self,
+        f: &mut fmt::Formatter,
+        depth: usize,
+        args: fmt::Arguments,
+    ) -> fmt::Result {
+        writeln!(f, "{:width$}{}", "", args, width = depth * self.indent)
+    }
+
+    fn write_relation(
+        &self,
+        f: &mut fmt::Formatter,
+        depth: usize,
+        relation: &ast::Relation,
+    ) -> fmt::Result {
+        write!(f, "{:width$}Relation: ", "", width = depth * self.indent)?;
+        serialize_expression(&relation.expression, f)?;
+        writeln!(f, " {}", relation.operator)?;
+        for item in relation.range_list.0.iter() {
+            let label = match item {
+                ast::RangeListItem::Range(_) => "Range",
+                ast::RangeListItem::Value(_) => "Value",
+            };
+            write!(
+                f,
+                "{:width$}{}: ",
+                "",
+                label,
+                width = (depth + 1) * self.indent
+            )?;
+            serialize_rangelistitem(item, f)?;
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+
+    fn write_sample_list(
+        &self,
+        f: &mut fmt::Formatter,
+        depth: usize,
+        label: &str,
+        samples: &ast::SampleList,
+    ) -> fmt::Result {
+        write!(f, "{:width$}{}: ", "", label, width = depth * self.indent)?;
+        serialize_sample_list(samples, f)?;
+        writeln!(f)
+    }
+}
+
+impl<'a> fmt::Display for Pretty<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write_line(f, 0, format_args!("Rule"))?;
+        self.write_line(f, 1, format_args!("Condition"))?;
+        for and_condition in self.rule.condition.0.iter() {
+            self.write_line(f, 2, format_args!("AndCondition"))?;
+            for relation in and_condition.0.iter() {
+                self.write_relation(f, 3, relation)?;
+            }
+        }
+        if let Some(samples) = &self.rule.samples {
+            self.write_line(f, 1, format_args!("Samples"))?;
+            if let Some(integer) = &samples.integer {
+                self.write_sample_list(f, 2, "Integer", integer)?;
+            }
+            if let Some(decimal) = &samples.decimal {
+                self.write_sample_list(f, 2, "Decimal", decimal)?;
+            }
+        }
+        Ok(())
+    }
+}
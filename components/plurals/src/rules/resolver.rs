@@ -3,7 +3,25 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use super::ast;
+use super::parser::{parse, ParserError};
 use crate::operands::PluralOperands;
+use crate::{PluralCategory, PLURAL_CATEGORY_ORDER};
+use core::str::FromStr;
+use displaydoc::Display;
+
+/// An error returned by [`parse_and_eval`].
+#[derive(Display, Debug, PartialEq, Eq)]
+pub enum EvalError {
+    /// The rule couldn't be parsed.
+    #[displaydoc("invalid rule: {0}")]
+    Rule(ParserError),
+    /// The operands couldn't be parsed.
+    #[displaydoc("invalid operands")]
+    Operands,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EvalError {}
 
 /// Function used to test [`Condition`] against [`PluralOperands`] to identify
 /// the appropriate [`PluralCategory`].
@@ -28,6 +46,90 @@ pub fn test_condition(condition: &ast::Condition, operands: &PluralOperands) ->
     condition.0.is_empty() || condition.0.iter().any(|c| test_and_condition(c, operands))
 }
 
+/// Selects the [`PluralCategory`] of [`PluralOperands`] from a list of rules, by testing the
+/// [`Condition`] of each category in [`PLURAL_CATEGORY_ORDER`] and returning the first one that
+/// matches.
+///
+/// The order of `rules` doesn't matter. A category missing from `rules` never matches, except
+/// for [`PluralCategory::Other`], which always matches, so it is returned when no other
+/// condition matches. A condition for [`PluralCategory::Other`] is ignored.
+///
+/// # Examples
+///
+/// ```
+/// use icu::plurals::rules::{parse_condition, select_first_matching};
+/// use icu::plurals::{PluralCategory, PluralOperands};
+///
+/// let rules = [
+///     (PluralCategory::Few, parse_condition(b"i = 2..4").expect("Failed to parse a rule.")),
+///     (PluralCategory::One, parse_condition(b"i = 1").expect("Failed to parse a rule.")),
+/// ];
+///
+/// assert_eq!(
+///     select_first_matching(&rules, &PluralOperands::from(1_usize)),
+///     PluralCategory::One
+/// );
+/// assert_eq!(
+///     select_first_matching(&rules, &PluralOperands::from(3_usize)),
+///     PluralCategory::Few
+/// );
+/// assert_eq!(
+///     select_first_matching(&rules, &PluralOperands::from(5_usize)),
+///     PluralCategory::Other
+/// );
+/// ```
+///
+/// [`PluralCategory`]: crate::PluralCategory
+/// [`PluralOperands`]: crate::PluralOperands
+/// [`PLURAL_CATEGORY_ORDER`]: crate::PLURAL_CATEGORY_ORDER
+/// [`Condition`]: super::ast::Condition
+pub fn select_first_matching(
+    rules: &[(PluralCategory, ast::Condition)],
+    operands: &PluralOperands,
+) -> PluralCategory {
+    PLURAL_CATEGORY_ORDER
+        .iter()
+        .copied()
+        .find(|&category| {
+            category == PluralCategory::Other
+                || rules.iter().any(|(rule_category, condition)| {
+                    *rule_category == category && test_condition(condition, operands)
+                })
+        })
+        .unwrap_or(PluralCategory::Other)
+}
+
+/// Parses a rule and tests its [`Condition`] against operands parsed from a number, such as
+/// `"1.50"`.
+///
+/// This is a single entry point for fuzzing the parsers and the resolver. It returns an error,
+/// rather than panicking, for any input which is not a valid rule or number.
+///
+/// # Examples
+///
+/// ```
+/// use icu::plurals::rules::{parse_and_eval, EvalError, ParserError};
+///
+/// assert_eq!(parse_and_eval(b"i = 1 and v = 0", b"1"), Ok(true));
+/// assert_eq!(parse_and_eval(b"i = 1 and v = 0", b"1.0"), Ok(false));
+///
+/// assert_eq!(
+///     parse_and_eval(b"i =", b"1"),
+///     Err(EvalError::Rule(ParserError::ExpectedValue))
+/// );
+/// assert_eq!(parse_and_eval(b"i = 1", b"1.x"), Err(EvalError::Operands));
+/// ```
+///
+/// [`Condition`]: super::ast::Condition
+pub fn parse_and_eval(rule: &[u8], operands: &[u8]) -> Result<bool, EvalError> {
+    let rule = parse(rule).map_err(EvalError::Rule)?;
+    let operands = core::str::from_utf8(operands)
+        .ok()
+        .and_then(|operands| PluralOperands::from_str(operands).ok())
+        .ok_or(EvalError::Operands)?;
+    Ok(test_condition(&rule.condition, &operands))
+}
+
 fn test_and_condition(condition: &ast::AndCondition, operands: &PluralOperands) -> bool {
     condition.0.iter().all(|r| test_relation(r, operands))
 }
@@ -64,8 +166,11 @@ fn calculate_expression(expression: &ast::Expression, operands: &PluralOperands)
         ast::Operand::V => operands.v as u64,
         ast::Operand::W => operands.w as u64,
         ast::Operand::T => operands.t,
-        ast::Operand::C | ast::Operand::E => operands.c as u64,
+        ast::Operand::C => operands.c as u64,
+        ast::Operand::E => operands.e as u64,
     };
+    // The parser rejects a modulus of zero, but an AST built by hand may still contain one,
+    // in which case the relation doesn't match.
     if let Some(modulus) = &expression.modulus {
         value.checked_rem_euclid(modulus.0)
     } else {
@@ -74,22 +179,9 @@ fn calculate_expression(expression: &ast::Expression, operands: &PluralOperands)
 }
 
 fn test_range(range: &ast::RangeList, value: u64, operator: ast::Operator) -> bool {
-    let connect = match operator {
-        ast::Operator::Eq => Iterator::any,
-        ast::Operator::NotEq => Iterator::all,
-    };
-    connect(&mut range.0.iter(), |item| {
-        test_range_item(item, value, operator)
-    })
-}
-
-fn test_range_item(item: &ast::RangeListItem, value: u64, operator: ast::Operator) -> bool {
-    let value = match item {
-        ast::RangeListItem::Value(n) => n.0 == value,
-        ast::RangeListItem::Range(range) => range.contains(&ast::Value(value)),
-    };
+    let contains = range.contains(&ast::Value(value));
     match operator {
-        ast::Operator::Eq => value,
-        ast::Operator::NotEq => !value,
+        ast::Operator::Eq => contains,
+        ast::Operator::NotEq => !contains,
     }
 }
@@ -82,7 +82,7 @@ fn serialize_relation(relation: &ast::Relation, w: &mut impl fmt::Write) -> fmt:
     serialize_rangelist(&relation.range_list, w)
 }
 
-fn serialize_expression(exp: &ast::Expression, w: &mut impl fmt::Write) -> fmt::Result {
+pub fn serialize_expression(exp: &ast::Expression, w: &mut impl fmt::Write) -> fmt::Result {
     serialize_operand(exp.operand, w)?;
     if let Some(modulus) = &exp.modulus {
         w.write_str(" % ")?;
@@ -111,6 +111,55 @@ fn serialize_operand(operand: ast::Operand, w: &mut impl fmt::Write) -> fmt::Res
     }
 }
 
+impl fmt::Display for ast::Operand {
+    /// Writes the operand as it appears in a plural rule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::plurals::rules::ast::Operand;
+    ///
+    /// assert_eq!(Operand::I.to_string(), "i");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        serialize_operand(*self, f)
+    }
+}
+
+impl fmt::Display for ast::Expression {
+    /// Writes the expression as it appears in a plural rule, with the modulus if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::plurals::rules::ast::{Expression, Operand, Value};
+    ///
+    /// let expression = Expression {
+    ///     operand: Operand::N,
+    ///     modulus: Some(Value(10)),
+    /// };
+    /// assert_eq!(expression.to_string(), "n % 10");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        serialize_expression(self, f)
+    }
+}
+
+impl fmt::Display for ast::Operator {
+    /// Writes the operator as it appears in a plural rule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::plurals::rules::ast::Operator;
+    ///
+    /// assert_eq!(Operator::NotEq.to_string(), "!=");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        serialize_operator(*self, f)
+    }
+}
+
 fn serialize_rangelist(rl: &ast::RangeList, w: &mut impl fmt::Write) -> fmt::Result {
     let mut first = true;
 
@@ -125,7 +174,7 @@ fn serialize_rangelist(rl: &ast::RangeList, w: &mut impl fmt::Write) -> fmt::Res
     Ok(())
 }
 
-fn serialize_rangelistitem(rli: &ast::RangeListItem, w: &mut impl fmt::Write) -> fmt::Result {
+pub fn serialize_rangelistitem(rli: &ast::RangeListItem, w: &mut impl fmt::Write) -> fmt::Result {
     match rli {
         ast::RangeListItem::Range(range) => serialize_range(range, w),
         ast::RangeListItem::Value(v) => serialize_value(v, w),
@@ -188,3 +237,95 @@ pub fn serialize_sample_range(
 pub fn serialize_decimal_value(val: &ast::DecimalValue, w: &mut impl fmt::Write) -> fmt::Result {
     w.write_str(&val.0)
 }
+
+impl fmt::Display for ast::Samples {
+    /// Writes the samples as they appear at the end of a plural rule, without the condition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::plurals::rules::parse;
+    ///
+    /// let rule = parse("n = 1 @integer 1 @decimal 1.0, 1.00, 1.000, …".as_bytes())
+    ///     .expect("Parsing failed");
+    /// let samples = rule.samples.expect("No samples");
+    /// assert_eq!(samples.to_string(), "@integer 1 @decimal 1.0, 1.00, 1.000, …");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(sample_list) = &self.integer {
+            f.write_str("@integer ")?;
+            serialize_sample_list(sample_list, f)?;
+        }
+        if let Some(sample_list) = &self.decimal {
+            if self.integer.is_some() {
+                f.write_str(" ")?;
+            }
+            f.write_str("@decimal ")?;
+            serialize_sample_list(sample_list, f)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for ast::SampleList {
+    /// Writes the sample ranges separated by commas, followed by an ellipsis if the list is
+    /// not complete.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::plurals::rules::ast::{DecimalValue, SampleList, SampleRange};
+    ///
+    /// let sample_list = SampleList {
+    ///     sample_ranges: Box::new([
+    ///         SampleRange {
+    ///             lower_val: DecimalValue("0".to_string()),
+    ///             upper_val: None,
+    ///         },
+    ///         SampleRange {
+    ///             lower_val: DecimalValue("2".to_string()),
+    ///             upper_val: Some(DecimalValue("16".to_string())),
+    ///         },
+    ///     ]),
+    ///     ellipsis: true,
+    /// };
+    /// assert_eq!(sample_list.to_string(), "0, 2~16, …");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        serialize_sample_list(self, f)
+    }
+}
+
+impl fmt::Display for ast::SampleRange {
+    /// Writes the sample range, with a `~` between the limits if it has an upper one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::plurals::rules::ast::{DecimalValue, SampleRange};
+    ///
+    /// let sample_range = SampleRange {
+    ///     lower_val: DecimalValue("0.0".to_string()),
+    ///     upper_val: Some(DecimalValue("1.5".to_string())),
+    /// };
+    /// assert_eq!(sample_range.to_string(), "0.0~1.5");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        serialize_sample_range(self, f)
+    }
+}
+
+impl fmt::Display for ast::DecimalValue {
+    /// Writes the decimal value as it is written in the sample.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::plurals::rules::ast::DecimalValue;
+    ///
+    /// assert_eq!(DecimalValue("1.00".to_string()).to_string(), "1.00");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        serialize_decimal_value(self, f)
+    }
+}
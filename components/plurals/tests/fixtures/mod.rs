@@ -57,6 +57,7 @@ pub enum PluralOperandsInput {
         f: Option<u64>,
         t: Option<u64>,
         c: Option<usize>,
+        e: Option<usize>,
     },
     String(String),
     Number(isize),
@@ -72,6 +73,7 @@ impl From<PluralOperandsInput> for PluralOperands {
                 f: operands.4,
                 t: operands.5,
                 c: operands.6,
+                e: operands.6,
             },
             PluralOperandsInput::Struct {
                 n,
@@ -81,6 +83,7 @@ impl From<PluralOperandsInput> for PluralOperands {
                 f,
                 t,
                 c,
+                e,
             } => Self {
                 i: i.unwrap_or_else(|| n.unwrap_or(0_f64) as u64),
                 v: v.unwrap_or(0),
@@ -88,6 +91,7 @@ impl From<PluralOperandsInput> for PluralOperands {
                 f: f.unwrap_or(0),
                 t: t.unwrap_or(0),
                 c: c.unwrap_or(0),
+                e: e.or(c).unwrap_or(0),
             },
             PluralOperandsInput::String(num) => num
                 .parse()
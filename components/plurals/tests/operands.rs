@@ -68,3 +68,26 @@ fn test_from_fixed_decimals() {
         );
     }
 }
+
+#[test]
+fn test_from_fixed_decimal_trailing_zeros() {
+    let dec: FixedDecimal = "1.50".parse().expect("Failed to parse a decimal");
+    let operands = PluralOperands::from_fixed_decimal(&dec);
+    assert_eq!(
+        operands,
+        PluralOperands {
+            i: 1,
+            v: 2,
+            w: 1,
+            f: 50,
+            t: 5,
+            c: 0,
+            e: 0,
+        }
+    );
+    assert_eq!(operands.n(), 1.5);
+
+    // The sign is ignored.
+    let dec: FixedDecimal = "-1.50".parse().expect("Failed to parse a decimal");
+    assert_eq!(PluralOperands::from_fixed_decimal(&dec), operands);
+}
@@ -68,3 +68,49 @@ fn test_plural_rules_non_static_lifetime() {
     assert_eq!(pr.select(5_usize), PluralCategory::Other);
     assert_eq!(pr.select(11_usize), PluralCategory::One);
 }
+
+#[test]
+fn test_plural_rules_select_order() {
+    // The cardinal rules of Arabic.
+    let data = PluralRuleStringsV1 {
+        zero: Some(Cow::Borrowed("n = 0")),
+        one: Some(Cow::Borrowed("n = 1")),
+        two: Some(Cow::Borrowed("n = 2")),
+        few: Some(Cow::Borrowed("n % 100 = 3..10")),
+        many: Some(Cow::Borrowed("n % 100 = 11..99")),
+    };
+    let pr = PluralRules::new_from_data(langid!("ar"), &data).unwrap();
+
+    assert_eq!(pr.select(0_usize), PluralCategory::Zero);
+    assert_eq!(pr.select(1_usize), PluralCategory::One);
+    assert_eq!(pr.select(2_usize), PluralCategory::Two);
+    assert_eq!(pr.select(5_usize), PluralCategory::Few);
+    assert_eq!(pr.select(100_usize), PluralCategory::Other);
+
+    let categories: Vec<PluralCategory> = pr.matches(5_usize).collect();
+    assert_eq!(categories, vec![PluralCategory::Few, PluralCategory::Other]);
+    let categories: Vec<PluralCategory> = pr.matches(100_usize).collect();
+    assert_eq!(categories, vec![PluralCategory::Other]);
+
+    // Overlapping conditions select the first category in CLDR order.
+    let data = PluralRuleStringsV1 {
+        zero: None,
+        one: Some(Cow::Borrowed("n = 1")),
+        two: None,
+        few: Some(Cow::Borrowed("n = 1..5")),
+        many: Some(Cow::Borrowed("n = 5..10")),
+    };
+    let pr = PluralRules::new_from_data(langid!("und"), &data).unwrap();
+
+    assert_eq!(pr.select(1_usize), PluralCategory::One);
+    assert_eq!(pr.select(5_usize), PluralCategory::Few);
+    let categories: Vec<PluralCategory> = pr.matches(5_usize).collect();
+    assert_eq!(
+        categories,
+        vec![
+            PluralCategory::Few,
+            PluralCategory::Many,
+            PluralCategory::Other
+        ]
+    );
+}
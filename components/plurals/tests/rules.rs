@@ -5,8 +5,18 @@
 mod fixtures;
 mod helpers;
 
-use icu_plurals::rules::{parse, parse_condition, serialize, test_condition, Lexer};
-use icu_plurals::PluralOperands;
+use std::collections::{BTreeSet, HashSet};
+
+use fixed_decimal::FixedDecimal;
+use icu_plurals::rules::ast::{
+    AndCondition, Condition, DecimalValue, Expression, Operand, Operator, PluralRuleSet, RangeList,
+    RangeListItem, Relation, Rule, SampleList, SampleRange, Samples, Value,
+};
+use icu_plurals::rules::{
+    parse, parse_and_eval, parse_condition, parse_condition_lenient, parse_lenient, parse_rules,
+    select_first_matching, serialize, test_condition, EvalError, Lexer, ParserError,
+};
+use icu_plurals::{PluralCategory, PluralOperands, PLURAL_CATEGORY_ORDER};
 
 #[test]
 fn test_parsing_operands() {
@@ -58,3 +68,735 @@ fn test_parsing_operands() {
         }
     }
 }
+
+#[test]
+fn test_generate_examples() {
+    let rules = [
+        "i = 1 and v = 0",
+        "n % 10 = 2..4 and n % 100 != 12..14",
+        "v = 2",
+        "i = 0 or n = 1",
+        "f = 5",
+        "n = 0",
+        "",
+    ];
+    for rule in rules.iter() {
+        let ast = parse(rule.as_bytes()).expect("Failed to parse.");
+        let examples = ast.generate_examples(20);
+        assert!(!examples.is_empty(), "No examples for {:?}", rule);
+        assert!(examples.len() <= 20);
+        for example in examples.iter() {
+            let operands: PluralOperands = example.into();
+            assert!(
+                test_condition(&ast.condition, &operands),
+                "Example {} does not match {:?}",
+                example,
+                rule
+            );
+        }
+    }
+
+    let ast = parse(b"v = 2").expect("Failed to parse.");
+    for example in ast.generate_examples(20).iter() {
+        let operands: PluralOperands = example.into();
+        assert_eq!(operands.v, 2);
+    }
+
+    let ast = parse(b"n = 0").expect("Failed to parse.");
+    let examples: Vec<String> = ast
+        .generate_examples(10)
+        .iter()
+        .map(|example| example.to_string())
+        .collect();
+    assert_eq!(examples, vec!["0", "0.0", "0.00", "0.000"]);
+}
+
+#[test]
+fn test_compact_exponent() {
+    let significand = FixedDecimal::from(12)
+        .multiplied_pow10(-1)
+        .expect("Bounds are small");
+    let operands = PluralOperands::from_compact(&significand, 6);
+
+    let condition = parse_condition(b"c = 6").expect("Failed to parse.");
+    assert!(test_condition(&condition, &operands));
+    let condition = parse_condition(b"e = 6").expect("Failed to parse.");
+    assert!(test_condition(&condition, &operands));
+    let condition = parse_condition(b"c = 3").expect("Failed to parse.");
+    assert!(!test_condition(&condition, &operands));
+
+    // Without the compact exponent, the exponent operands are 0.
+    let operands = PluralOperands::from(&significand);
+    let condition = parse_condition(b"c = 0").expect("Failed to parse.");
+    assert!(test_condition(&condition, &operands));
+}
+
+#[test]
+fn test_referenced_operands() {
+    let ast = parse(b"n = 1").expect("Failed to parse.");
+    let operands: Vec<Operand> = ast.referenced_operands().iter().collect();
+    assert_eq!(operands, vec![Operand::N]);
+
+    let ast = parse(b"i = 1 and v = 0").expect("Failed to parse.");
+    let operands: Vec<Operand> = ast.referenced_operands().iter().collect();
+    assert_eq!(operands, vec![Operand::I, Operand::V]);
+
+    let ast = parse(b"n % 10 = 3 or t = 5 and n != 13").expect("Failed to parse.");
+    let operands: Vec<Operand> = ast.referenced_operands().iter().collect();
+    assert_eq!(operands, vec![Operand::N, Operand::T]);
+
+    let ast = parse(b"").expect("Failed to parse.");
+    assert!(ast.referenced_operands().is_empty());
+}
+
+#[test]
+fn test_parsing_empty_input() {
+    for input in [&b""[..], b"   "].iter() {
+        let ast = parse(input).expect("Failed to parse.");
+        assert!(ast.condition.is_empty());
+        assert_eq!(ast.samples, None);
+
+        let condition = parse_condition(input).expect("Failed to parse.");
+        assert!(condition.is_empty());
+        assert!(test_condition(&condition, &PluralOperands::from(5_usize)));
+    }
+
+    assert_eq!(parse(b"i ="), Err(ParserError::ExpectedValue));
+    assert_eq!(parse(b"   ="), Err(ParserError::ExpectedOperand));
+    assert_eq!(parse(b"i = 1 or"), Err(ParserError::ExpectedAndCondition));
+}
+
+#[test]
+fn test_operand_from_str() {
+    let operands = [
+        ("n", Operand::N),
+        ("i", Operand::I),
+        ("v", Operand::V),
+        ("w", Operand::W),
+        ("f", Operand::F),
+        ("t", Operand::T),
+        ("c", Operand::C),
+        ("e", Operand::E),
+    ];
+    for (s, operand) in operands.iter() {
+        assert_eq!(s.parse::<Operand>(), Ok(*operand));
+        assert_eq!(operand.to_string(), *s);
+    }
+
+    assert_eq!("".parse::<Operand>(), Err(ParserError::ExpectedOperand));
+    assert_eq!("x".parse::<Operand>(), Err(ParserError::ExpectedOperand));
+    assert_eq!("in".parse::<Operand>(), Err(ParserError::ExpectedOperand));
+}
+
+#[test]
+fn test_operator_from_str() {
+    let operators = [("=", Operator::Eq), ("!=", Operator::NotEq)];
+    for (s, operator) in operators.iter() {
+        assert_eq!(s.parse::<Operator>(), Ok(*operator));
+        assert_eq!(operator.to_string(), *s);
+    }
+
+    assert_eq!("==".parse::<Operator>(), Err(ParserError::ExpectedOperator));
+    assert_eq!("!".parse::<Operator>(), Err(ParserError::ExpectedOperator));
+}
+
+#[test]
+fn test_pretty() {
+    let ast = parse(
+        "n % 10 = 3..4, 9 and n % 100 != 10..19 or n = 0 @integer 0, 3, 4, 9, … @decimal 0.0~0.5"
+            .as_bytes(),
+    )
+    .expect("Failed to parse.");
+
+    assert_eq!(
+        ast.pretty(2).to_string(),
+        "\
+Rule
+  Condition
+    AndCondition
+      Relation: n % 10 =
+        Range: 3..4
+        Value: 9
+      Relation: n % 100 !=
+        Range: 10..19
+    AndCondition
+      Relation: n =
+        Value: 0
+  Samples
+    Integer: 0, 3, 4, 9, …
+    Decimal: 0.0~0.5
+"
+    );
+
+    let ast = parse(b"").expect("Failed to parse.");
+    assert_eq!(ast.pretty(4).to_string(), "Rule\n    Condition\n");
+}
+
+#[test]
+fn test_sample_range_expand() {
+    fn expand(lower_val: &str, upper_val: Option<&str>) -> Vec<String> {
+        let range = SampleRange {
+            lower_val: DecimalValue(lower_val.to_string()),
+            upper_val: upper_val.map(|v| DecimalValue(v.to_string())),
+        };
+        range.expand().map(|v| v.0).collect()
+    }
+
+    let expected: Vec<String> = (0..=15).map(|i| format!("{}.{}", i / 10, i % 10)).collect();
+    assert_eq!(expand("0.0", Some("1.5")), expected);
+
+    assert_eq!(expand("3", Some("6")), vec!["3", "4", "5", "6"]);
+    assert_eq!(
+        expand("1.00", Some("1.03")),
+        vec!["1.00", "1.01", "1.02", "1.03"]
+    );
+    assert_eq!(expand("1e3", Some("3e3")), vec!["1e3", "2e3", "3e3"]);
+    assert_eq!(expand("7", None), vec!["7"]);
+
+    // Endpoints with a different precision.
+    assert_eq!(expand("0.8", Some("1")), vec!["0.8", "0.9", "1.0"]);
+    assert_eq!(expand("0.8", Some("1.05")), vec!["0.8", "0.9", "1.0"]);
+    assert_eq!(expand("9", Some("10.5")), vec!["9", "10"]);
+
+    // Endpoints with a different exponent.
+    assert_eq!(expand("1e3", Some("1e6")), vec!["1e3", "1e6"]);
+
+    // The compact decimal exponent.
+    assert_eq!(expand("1c3", Some("3c3")), vec!["1c3", "2c3", "3c3"]);
+    assert_eq!(expand("1.1c6", Some("1.2c6")), vec!["1.1c6", "1.2c6"]);
+    assert_eq!(expand("1c3", Some("1e3")), vec!["1c3", "1e3"]);
+
+    // More fraction digits than 10^n fits in a u64.
+    assert_eq!(
+        expand("0.00000000000000000001", Some("0.00000000000000000002")),
+        vec!["0.00000000000000000001", "0.00000000000000000002"]
+    );
+
+    // A large range is expanded lazily.
+    let range = SampleRange {
+        lower_val: DecimalValue("0".to_string()),
+        upper_val: Some(DecimalValue("4294967295".to_string())),
+    };
+    let values: Vec<String> = range.expand().take(3).map(|v| v.0).collect();
+    assert_eq!(values, vec!["0", "1", "2"]);
+}
+
+#[test]
+fn test_condition_builders() {
+    let condition = Condition::any_of(vec![
+        AndCondition::all_of(vec![Relation::eq(Operand::I, vec![1.into()])]),
+        AndCondition::all_of(vec![Relation::eq(Operand::I, vec![2.into()])]),
+    ]);
+    assert_eq!(
+        condition,
+        parse_condition(b"i = 1 or i = 2").expect("Failed to parse.")
+    );
+
+    let modulo = |operand, modulus| Expression {
+        operand,
+        modulus: Some(Value(modulus)),
+    };
+    let condition = Condition::any_of(vec![
+        AndCondition::all_of(vec![
+            Relation::eq(modulo(Operand::N, 10), vec![(3..=4).into(), 9.into()]),
+            Relation::not_eq(
+                modulo(Operand::N, 100),
+                vec![(10..=19).into(), (70..=79).into(), (90..=99).into()],
+            ),
+        ]),
+        AndCondition::all_of(vec![Relation::eq(Operand::V, vec![0.into()])]),
+    ]);
+    assert_eq!(
+        condition,
+        parse_condition(b"n % 10 = 3..4,9 and n % 100 != 10..19,70..79,90..99 or v = 0")
+            .expect("Failed to parse.")
+    );
+
+    assert_eq!(
+        Condition::any_of(vec![]),
+        parse_condition(b"").expect("Failed to parse.")
+    );
+}
+
+#[test]
+fn test_modulus() {
+    let condition = parse_condition(b"n % 10 = 3").expect("Failed to parse.");
+    for n in [3_usize, 13, 23, 103].iter() {
+        assert!(
+            test_condition(&condition, &PluralOperands::from(*n)),
+            "{}",
+            n
+        );
+    }
+    for n in [0_usize, 10, 14, 30].iter() {
+        assert!(
+            !test_condition(&condition, &PluralOperands::from(*n)),
+            "{}",
+            n
+        );
+    }
+
+    let condition = parse_condition(b"i % 7 = 2..3").expect("Failed to parse.");
+    assert!(test_condition(&condition, &PluralOperands::from(16_usize)));
+    assert!(test_condition(&condition, &PluralOperands::from(17_usize)));
+    assert!(!test_condition(&condition, &PluralOperands::from(18_usize)));
+
+    let relation = &condition.0[0].0[0];
+    assert_eq!(relation.expression.to_string(), "i % 7");
+
+    assert_eq!(parse_condition(b"n % 0 = 1"), Err(ParserError::ZeroModulus));
+    assert_eq!(parse_condition(b"n % 0"), Err(ParserError::ZeroModulus));
+}
+
+#[test]
+fn test_matches_sample() {
+    let rule = parse(b"v = 1").expect("Failed to parse.");
+    assert!(!rule.matches_sample("1"));
+    assert!(rule.matches_sample("1.0"));
+    assert!(rule.matches_sample("1.5"));
+    assert!(!rule.matches_sample("1.50"));
+
+    // Compact decimal exponents, written with `c` or `e`, shift the decimal point.
+    assert!(!rule.matches_sample("1.5c3"));
+    assert!(!rule.matches_sample("1c3"));
+    assert!(rule.matches_sample("1.25c1"));
+    let rule = parse(b"c = 6 and i = 1200000 and v = 0").expect("Failed to parse.");
+    assert!(rule.matches_sample("1.2c6"));
+    assert!(rule.matches_sample("1.2e6"));
+    assert!(!rule.matches_sample("1.2"));
+    assert!(!rule.matches_sample("1200000"));
+    let rule = parse(b"c = 3 and i = 1234 and v = 1 and f = 5").expect("Failed to parse.");
+    assert!(rule.matches_sample("1.2345c3"));
+
+    // Invalid samples don't match.
+    assert!(!rule.matches_sample(""));
+    assert!(!rule.matches_sample("1.2c"));
+    assert!(!rule.matches_sample("one"));
+}
+
+#[test]
+fn test_compact_exponent_operands() {
+    let operands = PluralOperands {
+        i: 1,
+        v: 0,
+        w: 0,
+        f: 0,
+        t: 0,
+        c: 3,
+        e: 2,
+    };
+
+    // `c` and `e` are read from their own fields.
+    let test = |rule: &str| {
+        let condition = parse_condition(rule.as_bytes()).expect("Failed to parse.");
+        test_condition(&condition, &operands)
+    };
+    assert!(test("c = 3"));
+    assert!(!test("c = 2"));
+    assert!(test("e = 2"));
+    assert!(!test("e = 3"));
+    assert!(test("c = 3 and e = 2"));
+
+    // By default, `e` is the same as `c`.
+    let operands: PluralOperands = "1.2e6".parse().expect("Failed to parse.");
+    assert_eq!((operands.c, operands.e), (6, 6));
+}
+
+#[test]
+fn test_large_values() {
+    let max = PluralOperands {
+        i: u64::MAX,
+        v: 0,
+        w: 0,
+        f: 0,
+        t: 0,
+        c: 0,
+        e: 0,
+    };
+    let below_max = PluralOperands {
+        i: u64::MAX - 1,
+        ..max
+    };
+
+    let condition = parse_condition(b"n = 18446744073709551615").expect("Failed to parse.");
+    assert_eq!(
+        condition.0[0].0[0].range_list.0[0],
+        RangeListItem::from(u64::MAX)
+    );
+    assert!(test_condition(&condition, &max));
+    assert!(!test_condition(&condition, &below_max));
+
+    let condition = parse_condition(b"i = 18446744073709551614..18446744073709551615")
+        .expect("Failed to parse.");
+    assert!(test_condition(&condition, &max));
+    assert!(test_condition(&condition, &below_max));
+    assert!(!test_condition(&condition, &PluralOperands::from(1_usize)));
+
+    let condition = parse_condition(b"i % 18446744073709551615 = 0").expect("Failed to parse.");
+    assert!(test_condition(&condition, &max));
+    assert!(!test_condition(&condition, &below_max));
+
+    // Values which don't fit into a u64 are rejected rather than wrapped around.
+    assert_eq!(
+        parse_condition(b"n = 18446744073709551616"),
+        Err(ParserError::ExpectedValue)
+    );
+    assert_eq!(
+        parse_condition(b"n = 0..99999999999999999999"),
+        Err(ParserError::ExpectedValue)
+    );
+}
+
+#[test]
+fn test_collect_values() {
+    let ast = parse_condition(b"n = 5, 1, 3..4 or n = 1, 3..4").expect("Failed to parse.");
+    let items: Vec<&RangeListItem> = ast
+        .0
+        .iter()
+        .flat_map(|and_condition| and_condition.0.iter())
+        .flat_map(|relation| relation.range_list.0.iter())
+        .collect();
+
+    let values: BTreeSet<Value> = items
+        .iter()
+        .filter_map(|item| match item {
+            RangeListItem::Value(value) => Some(value.clone()),
+            RangeListItem::Range(_) => None,
+        })
+        .collect();
+    assert_eq!(
+        values.into_iter().collect::<Vec<_>>(),
+        vec![Value(1), Value(5)]
+    );
+
+    let unique: HashSet<&RangeListItem> = items.iter().copied().collect();
+    assert_eq!(unique.len(), 3);
+    assert!(unique.contains(&RangeListItem::Range(Value(3)..=Value(4))));
+}
+
+#[test]
+fn test_range_list_contains() {
+    let range_list = RangeList(Box::new([2.into(), (5..=7).into()]));
+    assert!(range_list.contains(&Value(2)));
+    assert!(range_list.contains(&Value(5)));
+    assert!(range_list.contains(&Value(6)));
+    assert!(range_list.contains(&Value(7)));
+    assert!(!range_list.contains(&Value(3)));
+    assert!(!range_list.contains(&Value(8)));
+
+    // A range with the same lower and upper limit contains only that value.
+    let range_list = RangeList(Box::new([(4..=4).into()]));
+    assert!(range_list.contains(&Value(4)));
+    assert!(!range_list.contains(&Value(3)));
+    assert!(!range_list.contains(&Value(5)));
+
+    let range_list = RangeList(Box::new([]));
+    assert!(!range_list.contains(&Value(0)));
+
+    // The relations match the same values.
+    let condition = parse_condition(b"n = 2, 5..7").expect("Parsing failed");
+    let not_condition = parse_condition(b"n != 2, 5..7").expect("Parsing failed");
+    for n in 0..10_u64 {
+        let operands = PluralOperands::from(n);
+        let contains = RangeList(Box::new([2.into(), (5..=7).into()])).contains(&Value(n));
+        assert_eq!(test_condition(&condition, &operands), contains);
+        assert_eq!(test_condition(&not_condition, &operands), !contains);
+    }
+}
+
+#[test]
+fn test_parse_rules() {
+    // English cardinal rules, as in the CLDR plurals file.
+    let input = r#"{
+        "pluralRule-count-one": "i = 1 and v = 0 @integer 1",
+        "pluralRule-count-other": " @integer 0, 2~16, 100, 1000, 10000, 100000, 1000000, … @decimal 0.0~1.5, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …"
+    }"#;
+    let rules = parse_rules(input.as_bytes()).expect("Parsing failed");
+
+    let one = rules.get(PluralCategory::One).expect("No rule for one");
+    assert_eq!(one, &parse(b"i = 1 and v = 0 @integer 1").unwrap());
+    assert!(one.matches_sample("1"));
+    assert!(!one.matches_sample("2"));
+
+    let other = rules.get(PluralCategory::Other).expect("No rule for other");
+    assert!(other.condition.is_empty());
+    assert!(other.samples.is_some());
+
+    for category in [
+        PluralCategory::Zero,
+        PluralCategory::Two,
+        PluralCategory::Few,
+        PluralCategory::Many,
+    ]
+    .iter()
+    {
+        assert_eq!(rules.get(*category), None);
+    }
+
+    // Short category names are accepted too.
+    assert_eq!(
+        parse_rules(br#"{ "one": "i = 1 and v = 0 @integer 1" }"#)
+            .unwrap()
+            .get(PluralCategory::One),
+        Some(one)
+    );
+    assert_eq!(parse_rules(b" { } ").unwrap(), PluralRuleSet::default());
+
+    // The failing category is reported.
+    assert_eq!(
+        parse_rules(br#"{ "one": "i = 1", "many": "i = 1 and" }"#),
+        Err(ParserError::InvalidRule(
+            PluralCategory::Many,
+            Box::new(ParserError::ExpectedRelation)
+        ))
+    );
+    assert_eq!(
+        parse_rules(br#"{ "one": "i = 1", "one": "i = 2" }"#),
+        Err(ParserError::DuplicateCategory(PluralCategory::One))
+    );
+    assert_eq!(
+        parse_rules(br#"{ "single": "i = 1" }"#),
+        Err(ParserError::UnknownCategory)
+    );
+    assert_eq!(
+        parse_rules(br#"{ "one": "i = 1", }"#),
+        Err(ParserError::ExpectedRuleSet)
+    );
+    assert_eq!(
+        parse_rules(br#"{ "one": "i = 1" } x"#),
+        Err(ParserError::ExpectedRuleSet)
+    );
+    assert_eq!(
+        parse_rules(br#""one": "i = 1""#),
+        Err(ParserError::ExpectedRuleSet)
+    );
+}
+
+#[test]
+fn test_samples_display() {
+    let samples = Samples {
+        integer: Some(SampleList {
+            sample_ranges: Box::new([
+                SampleRange {
+                    lower_val: DecimalValue("0".to_string()),
+                    upper_val: None,
+                },
+                SampleRange {
+                    lower_val: DecimalValue("2".to_string()),
+                    upper_val: Some(DecimalValue("16".to_string())),
+                },
+                SampleRange {
+                    lower_val: DecimalValue("100".to_string()),
+                    upper_val: None,
+                },
+            ]),
+            ellipsis: true,
+        }),
+        decimal: Some(SampleList {
+            sample_ranges: Box::new([
+                SampleRange {
+                    lower_val: DecimalValue("0.0".to_string()),
+                    upper_val: Some(DecimalValue("1.5".to_string())),
+                },
+                SampleRange {
+                    lower_val: DecimalValue("10.0".to_string()),
+                    upper_val: None,
+                },
+            ]),
+            ellipsis: false,
+        }),
+    };
+
+    let output = samples.to_string();
+    assert_eq!(output, "@integer 0, 2~16, 100, … @decimal 0.0~1.5, 10.0");
+
+    let rule = parse(output.as_bytes()).expect("Parsing failed");
+    assert!(rule.condition.is_empty());
+    assert_eq!(rule.samples, Some(samples.clone()));
+
+    // The samples are written the same way as in the whole rule.
+    let mut rule_output = String::new();
+    serialize(
+        &Rule {
+            condition: parse_condition(b"n = 1").unwrap(),
+            samples: Some(samples),
+        },
+        &mut rule_output,
+    )
+    .unwrap();
+    assert_eq!(rule_output, format!("n = 1 {}", output));
+
+    let samples = Samples {
+        integer: None,
+        decimal: Some(SampleList {
+            sample_ranges: Box::new([SampleRange {
+                lower_val: DecimalValue("1.0".to_string()),
+                upper_val: None,
+            }]),
+            ellipsis: true,
+        }),
+    };
+    assert_eq!(samples.to_string(), "@decimal 1.0, …");
+    assert_eq!(
+        parse(samples.to_string().as_bytes()).unwrap().samples,
+        Some(samples)
+    );
+}
+
+#[test]
+fn test_parse_and_eval() {
+    assert_eq!(parse_and_eval(b"i = 1 and v = 0", b"1"), Ok(true));
+    assert_eq!(parse_and_eval(b"i = 1 and v = 0", b"1.0"), Ok(false));
+    assert_eq!(parse_and_eval(b"n % 10 = 2..4", b"-23"), Ok(true));
+    assert_eq!(parse_and_eval(b"", b"5"), Ok(true));
+
+    assert_eq!(
+        parse_and_eval(b"i % 0 = 1", b"1"),
+        Err(EvalError::Rule(ParserError::ZeroModulus))
+    );
+    assert_eq!(parse_and_eval(b"i = 1", b""), Err(EvalError::Operands));
+    assert_eq!(parse_and_eval(b"i = 1", b"\xFF"), Err(EvalError::Operands));
+}
+
+#[test]
+fn test_parse_and_eval_malformed() {
+    let mut rules: Vec<&[u8]> = vec![
+        b"",
+        b"i",
+        b"i =",
+        b"= 1",
+        b"i % = 1",
+        b"i % 0 = 1",
+        b"i = 1..",
+        b"i = ..1",
+        b"i = 1,",
+        b"i = 1 or",
+        b"i = 1 and",
+        b"i = 18446744073709551616",
+        b"i % 99999999999999999999 = 1",
+        b"n != ",
+        b"!",
+        b"...",
+        b"and",
+        b"int",
+        b"@",
+        b"@integer",
+        b"@integer 1~",
+        b"@integer 1e",
+        b"@decimal .",
+        b"@decimal 1.e",
+        b"\xE2",
+        b"\xE2\x80",
+        b"\xFF\xFE",
+        b"\x00",
+    ];
+    // Every prefix of a valid rule, including ones which end in the middle of a keyword or of
+    // the UTF-8 encoding of the ellipsis.
+    let valid = "n % 10 = 3..4,9 and n % 100 != 10..19,70..79 @integer 3, 4, 9, 23, … \
+                 @decimal 3.0, 4.0e3, 9.0, …"
+        .as_bytes();
+    rules.extend((0..=valid.len()).map(|len| &valid[..len]));
+
+    let operands: [&[u8]; 20] = [
+        b"",
+        b"-",
+        b".",
+        b"1.",
+        b".5",
+        b"1e",
+        b"1e-5",
+        b"1.5e",
+        b"e5",
+        b"--1",
+        b"1.2.3",
+        b" 1",
+        b"1 ",
+        b"0x10",
+        b"\xFF",
+        b"1\x00",
+        b"18446744073709551616",
+        b"1.99999999999999999999",
+        b"1e99999999999999999999",
+        b"1.5e3",
+    ];
+
+    for rule in rules.iter() {
+        for operand in operands.iter() {
+            // Only checks that it doesn't panic.
+            let _ = parse_and_eval(rule, operand);
+        }
+    }
+}
+
+#[test]
+fn test_select_first_matching() {
+    assert_eq!(
+        PLURAL_CATEGORY_ORDER,
+        &[
+            PluralCategory::Zero,
+            PluralCategory::One,
+            PluralCategory::Two,
+            PluralCategory::Few,
+            PluralCategory::Many,
+            PluralCategory::Other,
+        ]
+    );
+
+    let condition = |rule: &str| parse_condition(rule.as_bytes()).expect("Failed to parse.");
+    // The conditions overlap, so the order in which they are tested decides the category.
+    let rules = [
+        (PluralCategory::Many, condition("i = 0..10")),
+        (PluralCategory::Other, condition("i = 3")),
+        (PluralCategory::Few, condition("i = 2..5")),
+        (PluralCategory::Zero, condition("i = 0")),
+    ];
+    let select = |n: usize| select_first_matching(&rules, &PluralOperands::from(n));
+    assert_eq!(select(0), PluralCategory::Zero);
+    assert_eq!(select(3), PluralCategory::Few);
+    assert_eq!(select(7), PluralCategory::Many);
+
+    // Other is the fallback when no condition matches, whatever its own condition is.
+    assert_eq!(select(11), PluralCategory::Other);
+    assert_eq!(
+        select_first_matching(&[], &PluralOperands::from(1_usize)),
+        PluralCategory::Other
+    );
+}
+
+#[test]
+fn test_lenient_parsing() {
+    let lenient = |input: &str| parse_lenient(input.as_bytes()).expect("Failed to parse.");
+    let strict = |input: &str| parse(input.as_bytes());
+
+    // Uppercase operands and keywords
+    assert_eq!(lenient("I = 1"), strict("i = 1").unwrap());
+    assert_eq!(
+        lenient("N % 10 = 1 AND n % 100 != 11 @INTEGER 1, 21"),
+        strict("n % 10 = 1 and n % 100 != 11 @integer 1, 21").unwrap()
+    );
+    assert!(strict("I = 1").is_err());
+
+    // Ranges with three dots
+    assert_eq!(lenient("i = 2 ... 5"), strict("i = 2..5").unwrap());
+    assert_eq!(lenient("i = 2...5,7"), strict("i = 2..5,7").unwrap());
+    assert!(strict("i = 2...5").is_err());
+    assert!(strict("i = 2 ... 5").is_err());
+    // Spaces around `..` are allowed between tokens by UTS #35, so both modes accept them.
+    assert_eq!(lenient("i = 2 .. 5"), strict("i = 2 .. 5").unwrap());
+
+    // Tabs and line breaks
+    assert_eq!(
+        lenient("i = 1\tand\r\nv = 0"),
+        strict("i = 1 and v = 0").unwrap()
+    );
+
+    assert_eq!(
+        parse_condition_lenient(b"V = 0 OR F = 1"),
+        parse_condition(b"v = 0 or f = 1")
+    );
+
+    // Lenient mode still rejects invalid rules.
+    assert!(parse_lenient(b"I =").is_err());
+    assert!(parse_lenient(b"i = 2....5").is_err());
+    assert!(parse_lenient(b"x = 1").is_err());
+}
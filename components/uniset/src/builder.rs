@@ -148,7 +148,8 @@ impl UnicodeSetBuilder {
     /// ```
     pub fn add_range_u32(&mut self, range: &impl RangeBounds<u32>) {
         let (start, end) = deconstruct_range(range);
-        if start <= end && end <= char::MAX as u32 {
+        // `end` is exclusive, so it may be one past the last code point.
+        if start <= end && end <= char::MAX as u32 + 1 {
             self.add(start, end);
         }
     }
@@ -689,6 +690,14 @@ mod tests {
         assert_eq!(builder.intervals, expected);
     }
 
+    #[test]
+    fn test_add_range_u32_last_code_point() {
+        let mut builder = UnicodeSetBuilder::new();
+        builder.add_range_u32(&(0x10fffe..=0x10ffff));
+        let expected = vec![0x10fffe, 0x110000];
+        assert_eq!(builder.intervals, expected);
+    }
+
     #[test]
     fn test_add_invalid_range() {
         let mut builder = UnicodeSetBuilder::new();
@@ -4,6 +4,8 @@
 
 //! A collection of enums for enumerated properties.
 
+use core::convert::TryFrom;
+
 /// Selection constants for Unicode properties.
 /// These constants are used to select one of the Unicode properties.
 /// See UProperty in ICU4C.
@@ -11,11 +13,575 @@
 #[allow(missing_docs)] // TODO(#1030) - Add missing docs.
 #[non_exhaustive]
 pub enum EnumeratedProperty {
+    BidiClass = 0x1000,
+    CanonicalCombiningClass = 0x1002,
     GeneralCategory = 0x1005,
+    JoiningGroup = 0x1006,
+    JoiningType = 0x1007,
+    NumericType = 0x1009,
+    GraphemeClusterBreak = 0x1012,
+    SentenceBreak = 0x1013,
+    WordBreak = 0x1014,
+    IndicPositionalCategory = 0x1016,
+    IndicSyllabicCategory = 0x1017,
     Script = 0x100A,
     ScriptExtensions = 0x7000,
 }
 
+/// Enumerated property Bidi_Class.
+///
+/// For more information, see UAX #9: https://www.unicode.org/reports/tr9/.
+/// The discriminants correspond to the UCharDirection constants in ICU4C.
+#[derive(Clone, PartialEq, Debug)]
+#[allow(missing_docs)] // The variants should not need documenting.
+#[non_exhaustive]
+pub enum BidiClass {
+    LeftToRight = 0,
+    RightToLeft = 1,
+    EuropeanNumber = 2,
+    EuropeanSeparator = 3,
+    EuropeanTerminator = 4,
+    ArabicNumber = 5,
+    CommonSeparator = 6,
+    ParagraphSeparator = 7,
+    SegmentSeparator = 8,
+    WhiteSpace = 9,
+    OtherNeutral = 10,
+    LeftToRightEmbedding = 11,
+    LeftToRightOverride = 12,
+    ArabicLetter = 13,
+    RightToLeftEmbedding = 14,
+    RightToLeftOverride = 15,
+    PopDirectionalFormat = 16,
+    NonspacingMark = 17,
+    BoundaryNeutral = 18,
+    FirstStrongIsolate = 19,
+    LeftToRightIsolate = 20,
+    RightToLeftIsolate = 21,
+    PopDirectionalIsolate = 22,
+}
+
+/// Enumerated property Grapheme_Cluster_Break.
+///
+/// The classes of characters used by the grapheme cluster boundary rules of UAX #29. Code points
+/// not listed in GraphemeBreakProperty.txt have the value `Other`. The `EBase`, `EBaseGAZ`,
+/// `EModifier` and `GlueAfterZwj` values are no longer used by any character.
+/// See https://www.unicode.org/reports/tr29/#Grapheme_Cluster_Break_Property_Values .
+/// The discriminants correspond to the UGraphemeClusterBreak constants in ICU4C.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[allow(missing_docs)] // The variants should not need documenting.
+#[repr(u8)]
+#[non_exhaustive]
+pub enum GraphemeClusterBreak {
+    Other = 0,
+    Control = 1,
+    CR = 2,
+    Extend = 3,
+    L = 4,
+    LF = 5,
+    LV = 6,
+    LVT = 7,
+    T = 8,
+    V = 9,
+    SpacingMark = 10,
+    Prepend = 11,
+    RegionalIndicator = 12,
+    EBase = 13,
+    EBaseGAZ = 14,
+    EModifier = 15,
+    GlueAfterZwj = 16,
+    ZWJ = 17,
+}
+
+impl TryFrom<u8> for GraphemeClusterBreak {
+    type Error = u8;
+
+    /// Converts a value of a [`UnicodePropertyMapV1`](crate::provider::UnicodePropertyMapV1) for
+    /// the property, returning it back if it is out of range.
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::Other),
+            1 => Ok(Self::Control),
+            2 => Ok(Self::CR),
+            3 => Ok(Self::Extend),
+            4 => Ok(Self::L),
+            5 => Ok(Self::LF),
+            6 => Ok(Self::LV),
+            7 => Ok(Self::LVT),
+            8 => Ok(Self::T),
+            9 => Ok(Self::V),
+            10 => Ok(Self::SpacingMark),
+            11 => Ok(Self::Prepend),
+            12 => Ok(Self::RegionalIndicator),
+            13 => Ok(Self::EBase),
+            14 => Ok(Self::EBaseGAZ),
+            15 => Ok(Self::EModifier),
+            16 => Ok(Self::GlueAfterZwj),
+            17 => Ok(Self::ZWJ),
+            _ => Err(value),
+        }
+    }
+}
+
+/// Enumerated property Indic_Positional_Category.
+///
+/// The placement of dependent vowels and other marks relative to the base consonant in Indic
+/// scripts. Code points that aren't such marks have the value `NA`.
+/// See https://www.unicode.org/reports/tr44/#Indic_Positional_Category .
+/// The discriminants correspond to the UIndicPositionalCategory constants in ICU4C.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[allow(missing_docs)] // The variants should not need documenting.
+#[repr(u8)]
+#[non_exhaustive]
+pub enum IndicPositionalCategory {
+    NA = 0,
+    Bottom = 1,
+    BottomAndLeft = 2,
+    BottomAndRight = 3,
+    Left = 4,
+    LeftAndRight = 5,
+    Overstruck = 6,
+    Right = 7,
+    Top = 8,
+    TopAndBottom = 9,
+    TopAndBottomAndRight = 10,
+    TopAndLeft = 11,
+    TopAndLeftAndRight = 12,
+    TopAndRight = 13,
+    VisualOrderLeft = 14,
+    TopAndBottomAndLeft = 15,
+}
+
+impl TryFrom<u8> for IndicPositionalCategory {
+    type Error = u8;
+
+    /// Converts a value of a [`UnicodePropertyMapV1`](crate::provider::UnicodePropertyMapV1) for
+    /// the property, returning it back if it is out of range.
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::NA),
+            1 => Ok(Self::Bottom),
+            2 => Ok(Self::BottomAndLeft),
+            3 => Ok(Self::BottomAndRight),
+            4 => Ok(Self::Left),
+            5 => Ok(Self::LeftAndRight),
+            6 => Ok(Self::Overstruck),
+            7 => Ok(Self::Right),
+            8 => Ok(Self::Top),
+            9 => Ok(Self::TopAndBottom),
+            10 => Ok(Self::TopAndBottomAndRight),
+            11 => Ok(Self::TopAndLeft),
+            12 => Ok(Self::TopAndLeftAndRight),
+            13 => Ok(Self::TopAndRight),
+            14 => Ok(Self::VisualOrderLeft),
+            15 => Ok(Self::TopAndBottomAndLeft),
+            _ => Err(value),
+        }
+    }
+}
+
+/// Enumerated property Indic_Syllabic_Category.
+///
+/// The role of a character in the structure of the syllables of Indic scripts. Code points that
+/// don't play such a role have the value `Other`.
+/// See https://www.unicode.org/reports/tr44/#Indic_Syllabic_Category .
+/// The discriminants correspond to the UIndicSyllabicCategory constants in ICU4C.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[allow(missing_docs)] // The variants should not need documenting.
+#[repr(u8)]
+#[non_exhaustive]
+pub enum IndicSyllabicCategory {
+    Other = 0,
+    Avagraha = 1,
+    Bindu = 2,
+    BrahmiJoiningNumber = 3,
+    CantillationMark = 4,
+    Consonant = 5,
+    ConsonantDead = 6,
+    ConsonantFinal = 7,
+    ConsonantHeadLetter = 8,
+    ConsonantInitialPostfixed = 9,
+    ConsonantKiller = 10,
+    ConsonantMedial = 11,
+    ConsonantPlaceholder = 12,
+    ConsonantPrecedingRepha = 13,
+    ConsonantPrefixed = 14,
+    ConsonantSubjoined = 15,
+    ConsonantSucceedingRepha = 16,
+    ConsonantWithStacker = 17,
+    GeminationMark = 18,
+    InvisibleStacker = 19,
+    Joiner = 20,
+    ModifyingLetter = 21,
+    NonJoiner = 22,
+    Nukta = 23,
+    Number = 24,
+    NumberJoiner = 25,
+    PureKiller = 26,
+    RegisterShifter = 27,
+    SyllableModifier = 28,
+    ToneLetter = 29,
+    ToneMark = 30,
+    Virama = 31,
+    Visarga = 32,
+    Vowel = 33,
+    VowelDependent = 34,
+    VowelIndependent = 35,
+}
+
+impl TryFrom<u8> for IndicSyllabicCategory {
+    type Error = u8;
+
+    /// Converts a value of a [`UnicodePropertyMapV1`](crate::provider::UnicodePropertyMapV1) for
+    /// the property, returning it back if it is out of range.
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::Other),
+            1 => Ok(Self::Avagraha),
+            2 => Ok(Self::Bindu),
+            3 => Ok(Self::BrahmiJoiningNumber),
+            4 => Ok(Self::CantillationMark),
+            5 => Ok(Self::Consonant),
+            6 => Ok(Self::ConsonantDead),
+            7 => Ok(Self::ConsonantFinal),
+            8 => Ok(Self::ConsonantHeadLetter),
+            9 => Ok(Self::ConsonantInitialPostfixed),
+            10 => Ok(Self::ConsonantKiller),
+            11 => Ok(Self::ConsonantMedial),
+            12 => Ok(Self::ConsonantPlaceholder),
+            13 => Ok(Self::ConsonantPrecedingRepha),
+            14 => Ok(Self::ConsonantPrefixed),
+            15 => Ok(Self::ConsonantSubjoined),
+            16 => Ok(Self::ConsonantSucceedingRepha),
+            17 => Ok(Self::ConsonantWithStacker),
+            18 => Ok(Self::GeminationMark),
+            19 => Ok(Self::InvisibleStacker),
+            20 => Ok(Self::Joiner),
+            21 => Ok(Self::ModifyingLetter),
+            22 => Ok(Self::NonJoiner),
+            23 => Ok(Self::Nukta),
+            24 => Ok(Self::Number),
+            25 => Ok(Self::NumberJoiner),
+            26 => Ok(Self::PureKiller),
+            27 => Ok(Self::RegisterShifter),
+            28 => Ok(Self::SyllableModifier),
+            29 => Ok(Self::ToneLetter),
+            30 => Ok(Self::ToneMark),
+            31 => Ok(Self::Virama),
+            32 => Ok(Self::Visarga),
+            33 => Ok(Self::Vowel),
+            34 => Ok(Self::VowelDependent),
+            35 => Ok(Self::VowelIndependent),
+            _ => Err(value),
+        }
+    }
+}
+
+/// Enumerated property Joining_Group.
+///
+/// The groups of Arabic, Syriac and other cursive letters that share the same shapes when joined.
+/// Code points that aren't such letters have the value `NoJoiningGroup`.
+/// See https://www.unicode.org/reports/tr44/#Joining_Group .
+/// The discriminants correspond to the UJoiningGroup constants in ICU4C.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[allow(missing_docs)] // The variants should not need documenting.
+#[repr(u8)]
+#[non_exhaustive]
+pub enum JoiningGroup {
+    NoJoiningGroup = 0,
+    Ain = 1,
+    Alaph = 2,
+    Alef = 3,
+    Beh = 4,
+    Beth = 5,
+    Dal = 6,
+    DalathRish = 7,
+    E = 8,
+    Feh = 9,
+    FinalSemkath = 10,
+    Gaf = 11,
+    Gamal = 12,
+    Hah = 13,
+    TehMarbutaGoal = 14,
+    He = 15,
+    Heh = 16,
+    HehGoal = 17,
+    Heth = 18,
+    Kaf = 19,
+    Kaph = 20,
+    KnottedHeh = 21,
+    Lam = 22,
+    Lamadh = 23,
+    Meem = 24,
+    Mim = 25,
+    Noon = 26,
+    Nun = 27,
+    Pe = 28,
+    Qaf = 29,
+    Qaph = 30,
+    Reh = 31,
+    ReversedPe = 32,
+    Sad = 33,
+    Sadhe = 34,
+    Seen = 35,
+    Semkath = 36,
+    Shin = 37,
+    SwashKaf = 38,
+    SyriacWaw = 39,
+    Tah = 40,
+    Taw = 41,
+    TehMarbuta = 42,
+    Teth = 43,
+    Waw = 44,
+    Yeh = 45,
+    YehBarree = 46,
+    YehWithTail = 47,
+    Yudh = 48,
+    YudhHe = 49,
+    Zain = 50,
+    Fe = 51,
+    Khaph = 52,
+    Zhain = 53,
+    BurushaskiYehBarree = 54,
+    FarsiYeh = 55,
+    Nya = 56,
+    RohingyaYeh = 57,
+    ManichaeanAleph = 58,
+    ManichaeanAyin = 59,
+    ManichaeanBeth = 60,
+    ManichaeanDaleth = 61,
+    ManichaeanDhamedh = 62,
+    ManichaeanFive = 63,
+    ManichaeanGimel = 64,
+    ManichaeanHeth = 65,
+    ManichaeanHundred = 66,
+    ManichaeanKaph = 67,
+    ManichaeanLamedh = 68,
+    ManichaeanMem = 69,
+    ManichaeanNun = 70,
+    ManichaeanOne = 71,
+    ManichaeanPe = 72,
+    ManichaeanQoph = 73,
+    ManichaeanResh = 74,
+    ManichaeanSadhe = 75,
+    ManichaeanSamekh = 76,
+    ManichaeanTaw = 77,
+    ManichaeanTen = 78,
+    ManichaeanTeth = 79,
+    ManichaeanThamedh = 80,
+    ManichaeanTwenty = 81,
+    ManichaeanWaw = 82,
+    ManichaeanYodh = 83,
+    ManichaeanZayin = 84,
+    StraightWaw = 85,
+    AfricanFeh = 86,
+    AfricanNoon = 87,
+    AfricanQaf = 88,
+    MalayalamBha = 89,
+    MalayalamJa = 90,
+    MalayalamLla = 91,
+    MalayalamLlla = 92,
+    MalayalamNga = 93,
+    MalayalamNna = 94,
+    MalayalamNnna = 95,
+    MalayalamNya = 96,
+    MalayalamRa = 97,
+    MalayalamSsa = 98,
+    MalayalamTta = 99,
+    HanifiRohingyaKinnaYa = 100,
+    HanifiRohingyaPa = 101,
+    ThinYeh = 102,
+    VerticalTail = 103,
+}
+
+impl TryFrom<u8> for JoiningGroup {
+    type Error = u8;
+
+    /// Converts a value of a [`UnicodePropertyMapV1`](crate::provider::UnicodePropertyMapV1) for
+    /// the property, returning it back if it is out of range.
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::NoJoiningGroup),
+            1 => Ok(Self::Ain),
+            2 => Ok(Self::Alaph),
+            3 => Ok(Self::Alef),
+            4 => Ok(Self::Beh),
+            5 => Ok(Self::Beth),
+            6 => Ok(Self::Dal),
+            7 => Ok(Self::DalathRish),
+            8 => Ok(Self::E),
+            9 => Ok(Self::Feh),
+            10 => Ok(Self::FinalSemkath),
+            11 => Ok(Self::Gaf),
+            12 => Ok(Self::Gamal),
+            13 => Ok(Self::Hah),
+            14 => Ok(Self::TehMarbutaGoal),
+            15 => Ok(Self::He),
+            16 => Ok(Self::Heh),
+            17 => Ok(Self::HehGoal),
+            18 => Ok(Self::Heth),
+            19 => Ok(Self::Kaf),
+            20 => Ok(Self::Kaph),
+            21 => Ok(Self::KnottedHeh),
+            22 => Ok(Self::Lam),
+            23 => Ok(Self::Lamadh),
+            24 => Ok(Self::Meem),
+            25 => Ok(Self::Mim),
+            26 => Ok(Self::Noon),
+            27 => Ok(Self::Nun),
+            28 => Ok(Self::Pe),
+            29 => Ok(Self::Qaf),
+            30 => Ok(Self::Qaph),
+            31 => Ok(Self::Reh),
+            32 => Ok(Self::ReversedPe),
+            33 => Ok(Self::Sad),
+            34 => Ok(Self::Sadhe),
+            35 => Ok(Self::Seen),
+            36 => Ok(Self::Semkath),
+            37 => Ok(Self::Shin),
+            38 => Ok(Self::SwashKaf),
+            39 => Ok(Self::SyriacWaw),
+            40 => Ok(Self::Tah),
+            41 => Ok(Self::Taw),
+            42 => Ok(Self::TehMarbuta),
+            43 => Ok(Self::Teth),
+            44 => Ok(Self::Waw),
+            45 => Ok(Self::Yeh),
+            46 => Ok(Self::YehBarree),
+            47 => Ok(Self::YehWithTail),
+            48 => Ok(Self::Yudh),
+            49 => Ok(Self::YudhHe),
+            50 => Ok(Self::Zain),
+            51 => Ok(Self::Fe),
+            52 => Ok(Self::Khaph),
+            53 => Ok(Self::Zhain),
+            54 => Ok(Self::BurushaskiYehBarree),
+            55 => Ok(Self::FarsiYeh),
+            56 => Ok(Self::Nya),
+            57 => Ok(Self::RohingyaYeh),
+            58 => Ok(Self::ManichaeanAleph),
+            59 => Ok(Self::ManichaeanAyin),
+            60 => Ok(Self::ManichaeanBeth),
+            61 => Ok(Self::ManichaeanDaleth),
+            62 => Ok(Self::ManichaeanDhamedh),
+            63 => Ok(Self::ManichaeanFive),
+            64 => Ok(Self::ManichaeanGimel),
+            65 => Ok(Self::ManichaeanHeth),
+            66 => Ok(Self::ManichaeanHundred),
+            67 => Ok(Self::ManichaeanKaph),
+            68 => Ok(Self::ManichaeanLamedh),
+            69 => Ok(Self::ManichaeanMem),
+            70 => Ok(Self::ManichaeanNun),
+            71 => Ok(Self::ManichaeanOne),
+            72 => Ok(Self::ManichaeanPe),
+            73 => Ok(Self::ManichaeanQoph),
+            74 => Ok(Self::ManichaeanResh),
+            75 => Ok(Self::ManichaeanSadhe),
+            76 => Ok(Self::ManichaeanSamekh),
+            77 => Ok(Self::ManichaeanTaw),
+            78 => Ok(Self::ManichaeanTen),
+            79 => Ok(Self::ManichaeanTeth),
+            80 => Ok(Self::ManichaeanThamedh),
+            81 => Ok(Self::ManichaeanTwenty),
+            82 => Ok(Self::ManichaeanWaw),
+            83 => Ok(Self::ManichaeanYodh),
+            84 => Ok(Self::ManichaeanZayin),
+            85 => Ok(Self::StraightWaw),
+            86 => Ok(Self::AfricanFeh),
+            87 => Ok(Self::AfricanNoon),
+            88 => Ok(Self::AfricanQaf),
+            89 => Ok(Self::MalayalamBha),
+            90 => Ok(Self::MalayalamJa),
+            91 => Ok(Self::MalayalamLla),
+            92 => Ok(Self::MalayalamLlla),
+            93 => Ok(Self::MalayalamNga),
+            94 => Ok(Self::MalayalamNna),
+            95 => Ok(Self::MalayalamNnna),
+            96 => Ok(Self::MalayalamNya),
+            97 => Ok(Self::MalayalamRa),
+            98 => Ok(Self::MalayalamSsa),
+            99 => Ok(Self::MalayalamTta),
+            100 => Ok(Self::HanifiRohingyaKinnaYa),
+            101 => Ok(Self::HanifiRohingyaPa),
+            102 => Ok(Self::ThinYeh),
+            103 => Ok(Self::VerticalTail),
+            _ => Err(value),
+        }
+    }
+}
+
+/// Enumerated property Joining_Type.
+///
+/// The way a character joins with its neighbors in cursive scripts such as Arabic. Code points not
+/// listed in ArabicShaping.txt are `Transparent` if their General_Category is Mn, Me, or Cf, and
+/// `NonJoining` otherwise.
+/// See https://www.unicode.org/reports/tr44/#Joining_Type .
+/// The discriminants correspond to the UJoiningType constants in ICU4C.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[allow(missing_docs)] // The variants should not need documenting.
+#[repr(u8)]
+#[non_exhaustive]
+pub enum JoiningType {
+    NonJoining = 0,
+    JoinCausing = 1,
+    DualJoining = 2,
+    LeftJoining = 3,
+    RightJoining = 4,
+    Transparent = 5,
+}
+
+impl TryFrom<u8> for JoiningType {
+    type Error = u8;
+
+    /// Converts a value of a [`UnicodePropertyMapV1`](crate::provider::UnicodePropertyMapV1) for
+    /// the property, returning it back if it is out of range.
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::NonJoining),
+            1 => Ok(Self::JoinCausing),
+            2 => Ok(Self::DualJoining),
+            3 => Ok(Self::LeftJoining),
+            4 => Ok(Self::RightJoining),
+            5 => Ok(Self::Transparent),
+            _ => Err(value),
+        }
+    }
+}
+
+/// Enumerated property Numeric_Type.
+///
+/// Whether a character is a decimal digit, such as the ASCII and Arabic-Indic digits, another
+/// digit, such as a superscript, or another numeric character, such as a vulgar fraction or a Han
+/// numeral. Its value is given by the Numeric_Value property.
+/// See https://www.unicode.org/reports/tr44/#Numeric_Type .
+/// The discriminants correspond to the UNumericType constants in ICU4C.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[allow(missing_docs)] // The variants should not need documenting.
+#[repr(u8)]
+#[non_exhaustive]
+pub enum NumericType {
+    None = 0,
+    Decimal = 1,
+    Digit = 2,
+    Numeric = 3,
+}
+
+impl TryFrom<u8> for NumericType {
+    type Error = u8;
+
+    /// Converts a value of a [`UnicodePropertyMapV1`](crate::provider::UnicodePropertyMapV1) for
+    /// the property, returning it back if it is out of range.
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::None),
+            1 => Ok(Self::Decimal),
+            2 => Ok(Self::Digit),
+            3 => Ok(Self::Numeric),
+            _ => Err(value),
+        }
+    }
+}
+
 /// Enumerated Unicode general category types.
 /// The discriminants correspond to the U_GC_XX_MASK constants in ICU4C.
 /// This supports groups of general categories: for example, `Letter`
@@ -94,8 +660,10 @@ pub enum GeneralCategory {
 /// See UScriptCode in ICU4C.
 ///
 /// This enum only contains variants for scripts that are used in the Unicode
-/// Property Database.
-#[derive(Clone, PartialEq, Debug)]
+/// Property Database. Code points that are not assigned to a script have the
+/// value `Unknown`, and the ones used with several scripts have the values
+/// `Common` or `Inherited`.
+#[derive(Copy, Clone, PartialEq, Debug)]
 #[allow(missing_docs)] // The variants should not need documenting.
 #[non_exhaustive]
 pub enum Script {
@@ -262,3 +830,304 @@ pub enum Script {
     Yi = 41,
     ZanabazarSquare = 177,
 }
+
+impl TryFrom<u8> for Script {
+    type Error = u8;
+
+    /// Converts a value of a [`UnicodePropertyMapV1`](crate::provider::UnicodePropertyMapV1) for
+    /// the property, returning it back if it is out of range.
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::Common),
+            1 => Ok(Self::Inherited),
+            2 => Ok(Self::Arabic),
+            3 => Ok(Self::Armenian),
+            4 => Ok(Self::Bengali),
+            5 => Ok(Self::Bopomofo),
+            6 => Ok(Self::Cherokee),
+            7 => Ok(Self::Coptic),
+            8 => Ok(Self::Cyrillic),
+            9 => Ok(Self::Deseret),
+            10 => Ok(Self::Devanagari),
+            11 => Ok(Self::Ethiopic),
+            12 => Ok(Self::Georgian),
+            13 => Ok(Self::Gothic),
+            14 => Ok(Self::Greek),
+            15 => Ok(Self::Gujarati),
+            16 => Ok(Self::Gurmukhi),
+            17 => Ok(Self::Han),
+            18 => Ok(Self::Hangul),
+            19 => Ok(Self::Hebrew),
+            20 => Ok(Self::Hiragana),
+            21 => Ok(Self::Kannada),
+            22 => Ok(Self::Katakana),
+            23 => Ok(Self::Khmer),
+            24 => Ok(Self::Lao),
+            25 => Ok(Self::Latin),
+            26 => Ok(Self::Malayalam),
+            27 => Ok(Self::Mongolian),
+            28 => Ok(Self::Myanmar),
+            29 => Ok(Self::Ogham),
+            30 => Ok(Self::OldItalic),
+            31 => Ok(Self::Oriya),
+            32 => Ok(Self::Runic),
+            33 => Ok(Self::Sinhala),
+            34 => Ok(Self::Syriac),
+            35 => Ok(Self::Tamil),
+            36 => Ok(Self::Telugu),
+            37 => Ok(Self::Thaana),
+            38 => Ok(Self::Thai),
+            39 => Ok(Self::Tibetan),
+            40 => Ok(Self::CanadianAboriginal),
+            41 => Ok(Self::Yi),
+            42 => Ok(Self::Tagalog),
+            43 => Ok(Self::Hanunoo),
+            44 => Ok(Self::Buhid),
+            45 => Ok(Self::Tagbanwa),
+            46 => Ok(Self::Braille),
+            47 => Ok(Self::Cypriot),
+            48 => Ok(Self::Limbu),
+            49 => Ok(Self::LinearB),
+            50 => Ok(Self::Osmanya),
+            51 => Ok(Self::Shavian),
+            52 => Ok(Self::TaiLe),
+            53 => Ok(Self::Ugaritic),
+            55 => Ok(Self::Buginese),
+            56 => Ok(Self::Glagolitic),
+            57 => Ok(Self::Kharoshthi),
+            58 => Ok(Self::SylotiNagri),
+            59 => Ok(Self::NewTaiLue),
+            60 => Ok(Self::Tifinagh),
+            61 => Ok(Self::OldPersian),
+            62 => Ok(Self::Balinese),
+            63 => Ok(Self::Batak),
+            65 => Ok(Self::Brahmi),
+            66 => Ok(Self::Cham),
+            71 => Ok(Self::EgyptianHieroglyphs),
+            75 => Ok(Self::PahawhHmong),
+            76 => Ok(Self::OldHungarian),
+            78 => Ok(Self::Javanese),
+            79 => Ok(Self::KayahLi),
+            82 => Ok(Self::Lepcha),
+            83 => Ok(Self::LinearA),
+            84 => Ok(Self::Mandaic),
+            86 => Ok(Self::MeroiticHieroglyphs),
+            87 => Ok(Self::Nko),
+            88 => Ok(Self::OldTurkic),
+            89 => Ok(Self::OldPermic),
+            90 => Ok(Self::PhagsPa),
+            91 => Ok(Self::Phoenician),
+            92 => Ok(Self::Miao),
+            99 => Ok(Self::Vai),
+            101 => Ok(Self::Cuneiform),
+            103 => Ok(Self::Unknown),
+            104 => Ok(Self::Carian),
+            106 => Ok(Self::TaiTham),
+            107 => Ok(Self::Lycian),
+            108 => Ok(Self::Lydian),
+            109 => Ok(Self::OlChiki),
+            110 => Ok(Self::Rejang),
+            111 => Ok(Self::Saurashtra),
+            112 => Ok(Self::SignWriting),
+            113 => Ok(Self::Sundanese),
+            115 => Ok(Self::MeeteiMayek),
+            116 => Ok(Self::ImperialAramaic),
+            117 => Ok(Self::Avestan),
+            118 => Ok(Self::Chakma),
+            120 => Ok(Self::Kaithi),
+            121 => Ok(Self::Manichaean),
+            122 => Ok(Self::InscriptionalPahlavi),
+            123 => Ok(Self::PsalterPahlavi),
+            125 => Ok(Self::InscriptionalParthian),
+            126 => Ok(Self::Samaritan),
+            127 => Ok(Self::TaiViet),
+            130 => Ok(Self::Bamum),
+            131 => Ok(Self::Lisu),
+            133 => Ok(Self::OldSouthArabian),
+            134 => Ok(Self::BassaVah),
+            135 => Ok(Self::Duployan),
+            136 => Ok(Self::Elbasan),
+            137 => Ok(Self::Grantha),
+            140 => Ok(Self::MendeKikakui),
+            141 => Ok(Self::MeroiticCursive),
+            142 => Ok(Self::OldNorthArabian),
+            143 => Ok(Self::Nabataean),
+            144 => Ok(Self::Palmyrene),
+            145 => Ok(Self::Khudawadi),
+            146 => Ok(Self::WarangCiti),
+            149 => Ok(Self::Mro),
+            150 => Ok(Self::Nushu),
+            151 => Ok(Self::Sharada),
+            152 => Ok(Self::SoraSompeng),
+            153 => Ok(Self::Takri),
+            154 => Ok(Self::Tangut),
+            156 => Ok(Self::AnatolianHieroglyphs),
+            157 => Ok(Self::Khojki),
+            158 => Ok(Self::Tirhuta),
+            159 => Ok(Self::CaucasianAlbanian),
+            160 => Ok(Self::Mahajani),
+            161 => Ok(Self::Ahom),
+            162 => Ok(Self::Hatran),
+            163 => Ok(Self::Modi),
+            164 => Ok(Self::Multani),
+            165 => Ok(Self::PauCinHau),
+            166 => Ok(Self::Siddham),
+            167 => Ok(Self::Adlam),
+            168 => Ok(Self::Bhaiksuki),
+            169 => Ok(Self::Marchen),
+            170 => Ok(Self::Newa),
+            171 => Ok(Self::Osage),
+            175 => Ok(Self::MasaramGondi),
+            176 => Ok(Self::Soyombo),
+            177 => Ok(Self::ZanabazarSquare),
+            178 => Ok(Self::Dogra),
+            179 => Ok(Self::GunjalaGondi),
+            180 => Ok(Self::Makasar),
+            181 => Ok(Self::Medefaidrin),
+            182 => Ok(Self::HanifiRohingya),
+            183 => Ok(Self::Sogdian),
+            184 => Ok(Self::OldSogdian),
+            185 => Ok(Self::Elymaic),
+            186 => Ok(Self::NyiakengPuachueHmong),
+            187 => Ok(Self::Nandinagari),
+            188 => Ok(Self::Wancho),
+            189 => Ok(Self::Chorasmian),
+            190 => Ok(Self::DivesAkuru),
+            191 => Ok(Self::KhitanSmallScript),
+            192 => Ok(Self::Yezidi),
+            193 => Ok(Self::CyproMinoan),
+            194 => Ok(Self::OldUyghur),
+            195 => Ok(Self::Tangsa),
+            196 => Ok(Self::Toto),
+            197 => Ok(Self::Vithkuqi),
+            _ => Err(value),
+        }
+    }
+}
+
+/// Enumerated property Sentence_Break.
+///
+/// The classes of characters used by the sentence boundary rules of UAX #29. Code points not
+/// listed in SentenceBreakProperty.txt have the value `Other`.
+/// See https://www.unicode.org/reports/tr29/#Sentence_Boundaries .
+/// The discriminants correspond to the USentenceBreak constants in ICU4C.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[allow(missing_docs)] // The variants should not need documenting.
+#[repr(u8)]
+#[non_exhaustive]
+pub enum SentenceBreak {
+    Other = 0,
+    ATerm = 1,
+    Close = 2,
+    Format = 3,
+    Lower = 4,
+    Numeric = 5,
+    OLetter = 6,
+    Sep = 7,
+    Sp = 8,
+    STerm = 9,
+    Upper = 10,
+    CR = 11,
+    Extend = 12,
+    LF = 13,
+    SContinue = 14,
+}
+
+impl TryFrom<u8> for SentenceBreak {
+    type Error = u8;
+
+    /// Converts a value of a [`UnicodePropertyMapV1`](crate::provider::UnicodePropertyMapV1) for
+    /// the property, returning it back if it is out of range.
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::Other),
+            1 => Ok(Self::ATerm),
+            2 => Ok(Self::Close),
+            3 => Ok(Self::Format),
+            4 => Ok(Self::Lower),
+            5 => Ok(Self::Numeric),
+            6 => Ok(Self::OLetter),
+            7 => Ok(Self::Sep),
+            8 => Ok(Self::Sp),
+            9 => Ok(Self::STerm),
+            10 => Ok(Self::Upper),
+            11 => Ok(Self::CR),
+            12 => Ok(Self::Extend),
+            13 => Ok(Self::LF),
+            14 => Ok(Self::SContinue),
+            _ => Err(value),
+        }
+    }
+}
+
+/// Enumerated property Word_Break.
+///
+/// The classes of characters used by the word boundary rules of UAX #29. Code points not listed in
+/// WordBreakProperty.txt have the value `Other`. The `EBase`, `EBaseGAZ`, `EModifier` and
+/// `GlueAfterZwj` values are no longer used by any character.
+/// See https://www.unicode.org/reports/tr29/#Word_Boundaries .
+/// The discriminants correspond to the UWordBreakValues constants in ICU4C.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[allow(missing_docs)] // The variants should not need documenting.
+#[repr(u8)]
+#[non_exhaustive]
+pub enum WordBreak {
+    Other = 0,
+    ALetter = 1,
+    Format = 2,
+    Katakana = 3,
+    MidLetter = 4,
+    MidNum = 5,
+    Numeric = 6,
+    ExtendNumLet = 7,
+    CR = 8,
+    Extend = 9,
+    LF = 10,
+    MidNumLet = 11,
+    Newline = 12,
+    RegionalIndicator = 13,
+    HebrewLetter = 14,
+    SingleQuote = 15,
+    DoubleQuote = 16,
+    EBase = 17,
+    EBaseGAZ = 18,
+    EModifier = 19,
+    GlueAfterZwj = 20,
+    ZWJ = 21,
+    WSegSpace = 22,
+}
+
+impl TryFrom<u8> for WordBreak {
+    type Error = u8;
+
+    /// Converts a value of a [`UnicodePropertyMapV1`](crate::provider::UnicodePropertyMapV1) for
+    /// the property, returning it back if it is out of range.
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::Other),
+            1 => Ok(Self::ALetter),
+            2 => Ok(Self::Format),
+            3 => Ok(Self::Katakana),
+            4 => Ok(Self::MidLetter),
+            5 => Ok(Self::MidNum),
+            6 => Ok(Self::Numeric),
+            7 => Ok(Self::ExtendNumLet),
+            8 => Ok(Self::CR),
+            9 => Ok(Self::Extend),
+            10 => Ok(Self::LF),
+            11 => Ok(Self::MidNumLet),
+            12 => Ok(Self::Newline),
+            13 => Ok(Self::RegionalIndicator),
+            14 => Ok(Self::HebrewLetter),
+            15 => Ok(Self::SingleQuote),
+            16 => Ok(Self::DoubleQuote),
+            17 => Ok(Self::EBase),
+            18 => Ok(Self::EBaseGAZ),
+            19 => Ok(Self::EModifier),
+            20 => Ok(Self::GlueAfterZwj),
+            21 => Ok(Self::ZWJ),
+            22 => Ok(Self::WSegSpace),
+            _ => Err(value),
+        }
+    }
+}
@@ -65,6 +65,7 @@ pub mod enum_props;
 #[allow(missing_docs)] // TODO(#1030) - Add missing docs.
 pub mod props;
 pub mod provider;
+mod script;
 mod uniset;
 mod utils;
 
@@ -74,6 +75,7 @@ pub use builder::UnicodeSetBuilder;
 pub use conversions::*;
 use displaydoc::Display;
 use icu_provider::DataError;
+pub use script::ScriptRunIterator;
 pub use uniset::UnicodeSet;
 pub use utils::*;
 
@@ -493,6 +493,282 @@ where
 // Enumerated property getter fns
 //
 
+/// Return a [`UnicodePropertyMapV1`] for the Canonical_Combining_Class Unicode enumerated property
+/// Canonical_Combining_Class specifies the classes used for the canonical ordering of combining
+/// marks, from 0 to 254.
+/// See https://www.unicode.org/reports/tr44/#Canonical_Combining_Class_Values .
+pub fn get_canonical_combining_class_map<'data, D>(
+    provider: &D,
+) -> Result<DataPayload<'data, UnicodePropertyMapV1Marker>, UnicodeSetError>
+where
+    D: DataProvider<'data, UnicodePropertyMapV1Marker> + ?Sized,
+{
+    let data_req = DataRequest {
+        resource_path: ResourcePath {
+            key: key::CANONICAL_COMBINING_CLASS_V1,
+            options: ResourceOptions {
+                variant: None,
+                langid: None,
+            },
+        },
+    };
+
+    let resp: DataResponse<UnicodePropertyMapV1Marker> = provider.load_payload(&data_req)?;
+    Ok(resp.take_payload()?)
+}
+
+/// Return a [`UnicodePropertyMapV1`] for the Grapheme_Cluster_Break Unicode enumerated property
+/// Grapheme_Cluster_Break specifies the classes of characters used to find the boundaries of
+/// grapheme clusters. The values convert to [`GraphemeClusterBreak`] with `TryFrom<u8>`.
+/// See https://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries .
+pub fn get_grapheme_cluster_break_map<'data, D>(
+    provider: &D,
+) -> Result<DataPayload<'data, UnicodePropertyMapV1Marker>, UnicodeSetError>
+where
+    D: DataProvider<'data, UnicodePropertyMapV1Marker> + ?Sized,
+{
+    let data_req = DataRequest {
+        resource_path: ResourcePath {
+            key: key::GRAPHEME_CLUSTER_BREAK_V1,
+            options: ResourceOptions {
+                variant: None,
+                langid: None,
+            },
+        },
+    };
+
+    let resp: DataResponse<UnicodePropertyMapV1Marker> = provider.load_payload(&data_req)?;
+    Ok(resp.take_payload()?)
+}
+
+/// Return a [`UnicodePropertyMapV1`] for the Indic_Positional_Category Unicode enumerated property
+/// Indic_Positional_Category specifies the placement of marks relative to the base consonant in
+/// Indic scripts. The values convert to [`IndicPositionalCategory`] with `TryFrom<u8>`.
+/// See https://www.unicode.org/reports/tr44/#Indic_Positional_Category .
+pub fn get_indic_positional_category_map<'data, D>(
+    provider: &D,
+) -> Result<DataPayload<'data, UnicodePropertyMapV1Marker>, UnicodeSetError>
+where
+    D: DataProvider<'data, UnicodePropertyMapV1Marker> + ?Sized,
+{
+    let data_req = DataRequest {
+        resource_path: ResourcePath {
+            key: key::INDIC_POSITIONAL_CATEGORY_V1,
+            options: ResourceOptions {
+                variant: None,
+                langid: None,
+            },
+        },
+    };
+
+    let resp: DataResponse<UnicodePropertyMapV1Marker> = provider.load_payload(&data_req)?;
+    Ok(resp.take_payload()?)
+}
+
+/// Return a [`UnicodePropertyMapV1`] for the Indic_Syllabic_Category Unicode enumerated property
+/// Indic_Syllabic_Category specifies the role of a character in the syllables of Indic scripts.
+/// The values convert to [`IndicSyllabicCategory`] with `TryFrom<u8>`.
+/// See https://www.unicode.org/reports/tr44/#Indic_Syllabic_Category .
+pub fn get_indic_syllabic_category_map<'data, D>(
+    provider: &D,
+) -> Result<DataPayload<'data, UnicodePropertyMapV1Marker>, UnicodeSetError>
+where
+    D: DataProvider<'data, UnicodePropertyMapV1Marker> + ?Sized,
+{
+    let data_req = DataRequest {
+        resource_path: ResourcePath {
+            key: key::INDIC_SYLLABIC_CATEGORY_V1,
+            options: ResourceOptions {
+                variant: None,
+                langid: None,
+            },
+        },
+    };
+
+    let resp: DataResponse<UnicodePropertyMapV1Marker> = provider.load_payload(&data_req)?;
+    Ok(resp.take_payload()?)
+}
+
+/// Return a [`UnicodePropertyMapV1`] for the Joining_Group Unicode enumerated property
+/// Joining_Group specifies the groups of letters that share the same shapes in cursive joining.
+/// The values convert to [`JoiningGroup`] with `TryFrom<u8>`.
+/// See https://www.unicode.org/reports/tr44/#Joining_Group .
+pub fn get_joining_group_map<'data, D>(
+    provider: &D,
+) -> Result<DataPayload<'data, UnicodePropertyMapV1Marker>, UnicodeSetError>
+where
+    D: DataProvider<'data, UnicodePropertyMapV1Marker> + ?Sized,
+{
+    let data_req = DataRequest {
+        resource_path: ResourcePath {
+            key: key::JOINING_GROUP_V1,
+            options: ResourceOptions {
+                variant: None,
+                langid: None,
+            },
+        },
+    };
+
+    let resp: DataResponse<UnicodePropertyMapV1Marker> = provider.load_payload(&data_req)?;
+    Ok(resp.take_payload()?)
+}
+
+/// Return a [`UnicodePropertyMapV1`] for the Joining_Type Unicode enumerated property
+/// Joining_Type specifies how a character joins with its neighbors, for cursive shaping.
+/// The values convert to [`JoiningType`] with `TryFrom<u8>`.
+/// See https://www.unicode.org/reports/tr44/#Joining_Type .
+pub fn get_joining_type_map<'data, D>(
+    provider: &D,
+) -> Result<DataPayload<'data, UnicodePropertyMapV1Marker>, UnicodeSetError>
+where
+    D: DataProvider<'data, UnicodePropertyMapV1Marker> + ?Sized,
+{
+    let data_req = DataRequest {
+        resource_path: ResourcePath {
+            key: key::JOINING_TYPE_V1,
+            options: ResourceOptions {
+                variant: None,
+                langid: None,
+            },
+        },
+    };
+
+    let resp: DataResponse<UnicodePropertyMapV1Marker> = provider.load_payload(&data_req)?;
+    Ok(resp.take_payload()?)
+}
+
+/// Return a [`UnicodePropertyMapV1`] for the Numeric_Type Unicode enumerated property
+/// Numeric_Type specifies whether a character is a decimal digit, another digit, or another
+/// numeric character. The values convert to [`NumericType`] with `TryFrom<u8>`.
+/// See https://www.unicode.org/reports/tr44/#Numeric_Type .
+pub fn get_numeric_type_map<'data, D>(
+    provider: &D,
+) -> Result<DataPayload<'data, UnicodePropertyMapV1Marker>, UnicodeSetError>
+where
+    D: DataProvider<'data, UnicodePropertyMapV1Marker> + ?Sized,
+{
+    let data_req = DataRequest {
+        resource_path: ResourcePath {
+            key: key::NUMERIC_TYPE_V1,
+            options: ResourceOptions {
+                variant: None,
+                langid: None,
+            },
+        },
+    };
+
+    let resp: DataResponse<UnicodePropertyMapV1Marker> = provider.load_payload(&data_req)?;
+    Ok(resp.take_payload()?)
+}
+
+/// Return a [`UnicodePropertyMapV1`] for the Script Unicode enumerated property
+/// Script specifies the script of a character, or `Common` and `Inherited` for characters used
+/// with several scripts, and `Unknown` for unassigned code points. The values convert to
+/// [`Script`] with `TryFrom<u8>`.
+/// See https://www.unicode.org/reports/tr24/ .
+pub fn get_script_map<'data, D>(
+    provider: &D,
+) -> Result<DataPayload<'data, UnicodePropertyMapV1Marker>, UnicodeSetError>
+where
+    D: DataProvider<'data, UnicodePropertyMapV1Marker> + ?Sized,
+{
+    let data_req = DataRequest {
+        resource_path: ResourcePath {
+            key: key::SCRIPT_V1,
+            options: ResourceOptions {
+                variant: None,
+                langid: None,
+            },
+        },
+    };
+
+    let resp: DataResponse<UnicodePropertyMapV1Marker> = provider.load_payload(&data_req)?;
+    Ok(resp.take_payload()?)
+}
+
+/// Return a [`UnicodePropertyMapV1`] for the Sentence_Break Unicode enumerated property
+/// Sentence_Break specifies the classes of characters used to find the boundaries of sentences.
+/// The values convert to [`SentenceBreak`] with `TryFrom<u8>`.
+/// See https://www.unicode.org/reports/tr29/#Sentence_Boundaries .
+pub fn get_sentence_break_map<'data, D>(
+    provider: &D,
+) -> Result<DataPayload<'data, UnicodePropertyMapV1Marker>, UnicodeSetError>
+where
+    D: DataProvider<'data, UnicodePropertyMapV1Marker> + ?Sized,
+{
+    let data_req = DataRequest {
+        resource_path: ResourcePath {
+            key: key::SENTENCE_BREAK_V1,
+            options: ResourceOptions {
+                variant: None,
+                langid: None,
+            },
+        },
+    };
+
+    let resp: DataResponse<UnicodePropertyMapV1Marker> = provider.load_payload(&data_req)?;
+    Ok(resp.take_payload()?)
+}
+
+/// Return a [`UnicodePropertyMapV1`] for the Word_Break Unicode enumerated property
+/// Word_Break specifies the classes of characters used to find the boundaries of words.
+/// The values convert to [`WordBreak`] with `TryFrom<u8>`.
+/// See https://www.unicode.org/reports/tr29/#Word_Boundaries .
+pub fn get_word_break_map<'data, D>(
+    provider: &D,
+) -> Result<DataPayload<'data, UnicodePropertyMapV1Marker>, UnicodeSetError>
+where
+    D: DataProvider<'data, UnicodePropertyMapV1Marker> + ?Sized,
+{
+    let data_req = DataRequest {
+        resource_path: ResourcePath {
+            key: key::WORD_BREAK_V1,
+            options: ResourceOptions {
+                variant: None,
+                langid: None,
+            },
+        },
+    };
+
+    let resp: DataResponse<UnicodePropertyMapV1Marker> = provider.load_payload(&data_req)?;
+    Ok(resp.take_payload()?)
+}
+
+/// Return a [`UnicodeSet`] for a particular value of the Bidi_Class Unicode enumerated property
+/// Bidi_Class specifies the directionality of characters for the Unicode Bidirectional Algorithm.
+/// See https://www.unicode.org/reports/tr9/ .
+pub fn get_bidi_class_val_set<'data, D>(provider: &'data D, enum_val: BidiClass) -> UnisetResult
+where
+    D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized,
+{
+    let key = match enum_val {
+        BidiClass::LeftToRight => key::BIDI_CLASS_LEFT_TO_RIGHT_V1,
+        BidiClass::RightToLeft => key::BIDI_CLASS_RIGHT_TO_LEFT_V1,
+        BidiClass::EuropeanNumber => key::BIDI_CLASS_EUROPEAN_NUMBER_V1,
+        BidiClass::EuropeanSeparator => key::BIDI_CLASS_EUROPEAN_SEPARATOR_V1,
+        BidiClass::EuropeanTerminator => key::BIDI_CLASS_EUROPEAN_TERMINATOR_V1,
+        BidiClass::ArabicNumber => key::BIDI_CLASS_ARABIC_NUMBER_V1,
+        BidiClass::CommonSeparator => key::BIDI_CLASS_COMMON_SEPARATOR_V1,
+        BidiClass::ParagraphSeparator => key::BIDI_CLASS_PARAGRAPH_SEPARATOR_V1,
+        BidiClass::SegmentSeparator => key::BIDI_CLASS_SEGMENT_SEPARATOR_V1,
+        BidiClass::WhiteSpace => key::BIDI_CLASS_WHITE_SPACE_V1,
+        BidiClass::OtherNeutral => key::BIDI_CLASS_OTHER_NEUTRAL_V1,
+        BidiClass::LeftToRightEmbedding => key::BIDI_CLASS_LEFT_TO_RIGHT_EMBEDDING_V1,
+        BidiClass::LeftToRightOverride => key::BIDI_CLASS_LEFT_TO_RIGHT_OVERRIDE_V1,
+        BidiClass::ArabicLetter => key::BIDI_CLASS_ARABIC_LETTER_V1,
+        BidiClass::RightToLeftEmbedding => key::BIDI_CLASS_RIGHT_TO_LEFT_EMBEDDING_V1,
+        BidiClass::RightToLeftOverride => key::BIDI_CLASS_RIGHT_TO_LEFT_OVERRIDE_V1,
+        BidiClass::PopDirectionalFormat => key::BIDI_CLASS_POP_DIRECTIONAL_FORMAT_V1,
+        BidiClass::NonspacingMark => key::BIDI_CLASS_NONSPACING_MARK_V1,
+        BidiClass::BoundaryNeutral => key::BIDI_CLASS_BOUNDARY_NEUTRAL_V1,
+        BidiClass::FirstStrongIsolate => key::BIDI_CLASS_FIRST_STRONG_ISOLATE_V1,
+        BidiClass::LeftToRightIsolate => key::BIDI_CLASS_LEFT_TO_RIGHT_ISOLATE_V1,
+        BidiClass::RightToLeftIsolate => key::BIDI_CLASS_RIGHT_TO_LEFT_ISOLATE_V1,
+        BidiClass::PopDirectionalIsolate => key::BIDI_CLASS_POP_DIRECTIONAL_ISOLATE_V1,
+    };
+    get_prop(provider, key)
+}
+
 /// Return a [`UnicodeSet`] for a particular value of the General_Category Unicode enumerated property
 /// General_Category specifies enumerated Unicode general category types.
 /// See https://www.unicode.org/reports/tr44/ .
@@ -718,3 +994,57 @@ where
     };
     get_prop(provider, key)
 }
+
+//
+// Case mapping getter fns
+//
+
+/// Return a [`UnicodeCaseFoldingV1`] for the simple case folding of CaseFolding.txt, which
+/// maps a code point to a single code point for caseless matching.
+/// See https://www.unicode.org/reports/tr44/#Casemapping .
+pub fn get_simple_case_folding<'data, D>(
+    provider: &D,
+) -> Result<DataPayload<'data, UnicodeCaseFoldingV1Marker>, UnicodeSetError>
+where
+    D: DataProvider<'data, UnicodeCaseFoldingV1Marker> + ?Sized,
+{
+    let data_req = DataRequest {
+        resource_path: ResourcePath {
+            key: key::SIMPLE_CASE_FOLDING_V1,
+            options: ResourceOptions {
+                variant: None,
+                langid: None,
+            },
+        },
+    };
+
+    let resp: DataResponse<UnicodeCaseFoldingV1Marker> = provider.load_payload(&data_req)?;
+    Ok(resp.take_payload()?)
+}
+
+//
+// Numeric value getter fns
+//
+
+/// Return a [`UnicodeNumericValuesV1`] for the Numeric_Value Unicode property, which is the
+/// value of the characters with a Numeric_Type other than None, as a fraction.
+/// See https://www.unicode.org/reports/tr44/#Numeric_Value .
+pub fn get_numeric_values<'data, D>(
+    provider: &D,
+) -> Result<DataPayload<'data, UnicodeNumericValuesV1Marker>, UnicodeSetError>
+where
+    D: DataProvider<'data, UnicodeNumericValuesV1Marker> + ?Sized,
+{
+    let data_req = DataRequest {
+        resource_path: ResourcePath {
+            key: key::NUMERIC_VALUE_V1,
+            options: ResourceOptions {
+                variant: None,
+                langid: None,
+            },
+        },
+    };
+
+    let resp: DataResponse<UnicodeNumericValuesV1Marker> = provider.load_payload(&data_req)?;
+    Ok(resp.take_payload()?)
+}
@@ -9,7 +9,9 @@
 use crate::builder::UnicodeSetBuilder;
 use crate::uniset::UnicodeSet;
 use alloc::borrow::Cow;
+use core::ops::RangeInclusive;
 use icu_provider::yoke::{self, *};
+use zerovec::ZeroVec;
 
 //
 // resource key structs - the structs used directly by users of data provider
@@ -28,7 +30,7 @@ pub mod key {
         };
     }
 
-    define_resource_keys!(265;
+    define_resource_keys!(288;
 
         //
         // Binary properties
@@ -108,6 +110,29 @@ pub mod key {
         // short name of the enumerated property and the short name of the
         // property value.
 
+        (BIDI_CLASS_ARABIC_LETTER_V1, "bc=AL"),
+        (BIDI_CLASS_ARABIC_NUMBER_V1, "bc=AN"),
+        (BIDI_CLASS_BOUNDARY_NEUTRAL_V1, "bc=BN"),
+        (BIDI_CLASS_COMMON_SEPARATOR_V1, "bc=CS"),
+        (BIDI_CLASS_EUROPEAN_NUMBER_V1, "bc=EN"),
+        (BIDI_CLASS_EUROPEAN_SEPARATOR_V1, "bc=ES"),
+        (BIDI_CLASS_EUROPEAN_TERMINATOR_V1, "bc=ET"),
+        (BIDI_CLASS_FIRST_STRONG_ISOLATE_V1, "bc=FSI"),
+        (BIDI_CLASS_LEFT_TO_RIGHT_EMBEDDING_V1, "bc=LRE"),
+        (BIDI_CLASS_LEFT_TO_RIGHT_ISOLATE_V1, "bc=LRI"),
+        (BIDI_CLASS_LEFT_TO_RIGHT_OVERRIDE_V1, "bc=LRO"),
+        (BIDI_CLASS_LEFT_TO_RIGHT_V1, "bc=L"),
+        (BIDI_CLASS_NONSPACING_MARK_V1, "bc=NSM"),
+        (BIDI_CLASS_OTHER_NEUTRAL_V1, "bc=ON"),
+        (BIDI_CLASS_PARAGRAPH_SEPARATOR_V1, "bc=B"),
+        (BIDI_CLASS_POP_DIRECTIONAL_FORMAT_V1, "bc=PDF"),
+        (BIDI_CLASS_POP_DIRECTIONAL_ISOLATE_V1, "bc=PDI"),
+        (BIDI_CLASS_RIGHT_TO_LEFT_EMBEDDING_V1, "bc=RLE"),
+        (BIDI_CLASS_RIGHT_TO_LEFT_ISOLATE_V1, "bc=RLI"),
+        (BIDI_CLASS_RIGHT_TO_LEFT_OVERRIDE_V1, "bc=RLO"),
+        (BIDI_CLASS_RIGHT_TO_LEFT_V1, "bc=R"),
+        (BIDI_CLASS_SEGMENT_SEPARATOR_V1, "bc=S"),
+        (BIDI_CLASS_WHITE_SPACE_V1, "bc=WS"),
         (GENERAL_CATEGORY_OTHER_V1, "gc=C"),
         (GENERAL_CATEGORY_CONTROL_V1, "gc=Cc"),
         (GENERAL_CATEGORY_FORMAT_V1, "gc=Cf"),
@@ -309,6 +334,28 @@ pub mod key {
         (SCRIPT_YI_V1, "sc=Yiii"),
         (SCRIPT_ZANABAZAR_SQUARE_V1, "sc=Zanb"),
     );
+
+    // Note: Enumerated properties with numeric values are loaded as a whole, rather than one
+    // set per value.
+
+    pub const CANONICAL_COMBINING_CLASS_V1: ResourceKey = resource_key!(UnicodeSet, "ccc", 1);
+    pub const GRAPHEME_CLUSTER_BREAK_V1: ResourceKey = resource_key!(UnicodeSet, "GCB", 1);
+    pub const INDIC_POSITIONAL_CATEGORY_V1: ResourceKey = resource_key!(UnicodeSet, "InPC", 1);
+    pub const INDIC_SYLLABIC_CATEGORY_V1: ResourceKey = resource_key!(UnicodeSet, "InSC", 1);
+    pub const JOINING_GROUP_V1: ResourceKey = resource_key!(UnicodeSet, "jg", 1);
+    pub const JOINING_TYPE_V1: ResourceKey = resource_key!(UnicodeSet, "jt", 1);
+    pub const NUMERIC_TYPE_V1: ResourceKey = resource_key!(UnicodeSet, "nt", 1);
+    pub const SCRIPT_V1: ResourceKey = resource_key!(UnicodeSet, "sc", 1);
+    pub const SENTENCE_BREAK_V1: ResourceKey = resource_key!(UnicodeSet, "SB", 1);
+    pub const WORD_BREAK_V1: ResourceKey = resource_key!(UnicodeSet, "WB", 1);
+
+    // Note: Case mappings are loaded as a whole.
+
+    pub const SIMPLE_CASE_FOLDING_V1: ResourceKey = resource_key!(UnicodeSet, "scf", 1);
+
+    // Note: Numeric values are loaded as a whole.
+
+    pub const NUMERIC_VALUE_V1: ResourceKey = resource_key!(UnicodeSet, "nv", 1);
 }
 
 #[icu_provider::data_struct]
@@ -346,6 +393,29 @@ impl<'data> UnicodePropertyV1<'data> {
             inv_list: set,
         }
     }
+
+    /// Yields an [`Iterator`] over the ranges of the code points in the property, reconstructed
+    /// from the boundaries of its inversion list without copying it.
+    ///
+    /// The ranges are inclusive of their `end`, as in [`UnicodeSet::iter_ranges`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::uniset::provider::UnicodePropertyV1;
+    /// use icu::uniset::UnicodeSet;
+    /// use std::borrow::Cow;
+    ///
+    /// let set = UnicodeSet::from_inversion_list_slice(&[0x41, 0x44, 0x45, 0x46]).unwrap();
+    /// let prop = UnicodePropertyV1::from_owned_uniset(set, Cow::Borrowed("example"));
+    /// let mut ranges = prop.iter_ranges();
+    /// assert_eq!(Some(0x41..=0x43), ranges.next());
+    /// assert_eq!(Some(0x45..=0x45), ranges.next());
+    /// assert_eq!(None, ranges.next());
+    /// ```
+    pub fn iter_ranges(&self) -> impl ExactSizeIterator<Item = RangeInclusive<u32>> + '_ {
+        self.inv_list.iter_ranges()
+    }
 }
 
 impl<'data> From<UnicodePropertyV1<'data>> for UnicodeSet<'data> {
@@ -353,3 +423,197 @@ impl<'data> From<UnicodePropertyV1<'data>> for UnicodeSet<'data> {
         prop.inv_list
     }
 }
+
+/// A map from code points to the values of an enumerated property with numeric values, such as
+/// Canonical_Combining_Class. The code points are split into ranges with the same value, so that
+/// the long runs of code points with the default value `0` take up a single entry.
+#[icu_provider::data_struct]
+#[derive(Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct UnicodePropertyMapV1<'data> {
+    /// The name of the property.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub name: Cow<'data, str>,
+    /// The first code point of each range, in increasing order.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub range_starts: ZeroVec<'data, u32>,
+    /// The value of each range.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub values: ZeroVec<'data, u8>,
+}
+
+impl Default for UnicodePropertyMapV1<'static> {
+    /// Default empty nameless property, where all code points have the value `0`
+    fn default() -> UnicodePropertyMapV1<'static> {
+        UnicodePropertyMapV1 {
+            name: Cow::Borrowed(""),
+            range_starts: ZeroVec::clone_from_slice(&[]),
+            values: ZeroVec::clone_from_slice(&[]),
+        }
+    }
+}
+
+impl<'data> UnicodePropertyMapV1<'data> {
+    /// Returns the value of the property for a code point. Code points before the first range
+    /// have the value `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::uniset::provider::UnicodePropertyMapV1;
+    /// use std::borrow::Cow;
+    /// use zerovec::ZeroVec;
+    ///
+    /// let map = UnicodePropertyMapV1 {
+    ///     name: Cow::Borrowed("Canonical_Combining_Class"),
+    ///     // U+0000 - U+02FF, U+0300 - U+0314, U+0315, U+0316 - U+10FFFF
+    ///     range_starts: ZeroVec::clone_from_slice(&[0x0, 0x300, 0x315, 0x316]),
+    ///     values: ZeroVec::clone_from_slice(&[0, 230, 232, 0]),
+    /// };
+    ///
+    /// assert_eq!(map.get('a' as u32), 0);
+    /// assert_eq!(map.get(0x301), 230);
+    /// assert_eq!(map.get(0x315), 232);
+    /// assert_eq!(map.get(0x10FFFF), 0);
+    /// ```
+    pub fn get(&self, code_point: u32) -> u8 {
+        let index = match self.range_starts.binary_search(&code_point) {
+            Ok(index) => index,
+            Err(0) => return 0,
+            Err(index) => index - 1,
+        };
+        self.values.get(index).unwrap_or(0)
+    }
+}
+
+/// The simple case folding of code points, from the mappings with the status `C` and `S` of
+/// CaseFolding.txt, which map a code point to a single code point. The code points which aren't
+/// listed fold to themselves.
+///
+/// The full case folding, where a code point can fold to several code points, such as "ß" to
+/// "ss", and the Turkic mappings with the status `T` aren't included.
+/// See https://www.unicode.org/reports/tr44/#Casemapping .
+#[icu_provider::data_struct]
+#[derive(Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct UnicodeCaseFoldingV1<'data> {
+    /// The code points which don't fold to themselves, in increasing order.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub code_points: ZeroVec<'data, u32>,
+    /// The code point which each of them folds to.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub folded: ZeroVec<'data, u32>,
+}
+
+impl Default for UnicodeCaseFoldingV1<'static> {
+    /// Default empty case folding, where all code points fold to themselves
+    fn default() -> UnicodeCaseFoldingV1<'static> {
+        UnicodeCaseFoldingV1 {
+            code_points: ZeroVec::clone_from_slice(&[]),
+            folded: ZeroVec::clone_from_slice(&[]),
+        }
+    }
+}
+
+impl<'data> UnicodeCaseFoldingV1<'data> {
+    /// Returns the simple case folding of a character, as a sequence of characters. With the
+    /// simple case folding, the sequence always has a single character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::uniset::provider::UnicodeCaseFoldingV1;
+    /// use zerovec::ZeroVec;
+    ///
+    /// let case_folding = UnicodeCaseFoldingV1 {
+    ///     // A, Σ, ẞ
+    ///     code_points: ZeroVec::clone_from_slice(&[0x41, 0x3A3, 0x1E9E]),
+    ///     folded: ZeroVec::clone_from_slice(&[0x61, 0x3C3, 0xDF]),
+    /// };
+    ///
+    /// assert_eq!(case_folding.case_fold('A').collect::<String>(), "a");
+    /// assert_eq!(case_folding.case_fold('\u{1E9E}').collect::<String>(), "ß");
+    /// // "ß" folds to "ss" only with the full case folding.
+    /// assert_eq!(case_folding.case_fold('ß').collect::<String>(), "ß");
+    /// ```
+    pub fn case_fold(&self, ch: char) -> impl Iterator<Item = char> {
+        let folded = match self.code_points.binary_search(&(ch as u32)) {
+            Ok(index) => self
+                .folded
+                .get(index)
+                .and_then(char::from_u32)
+                .unwrap_or(ch),
+            Err(_) => ch,
+        };
+        core::iter::once(folded)
+    }
+}
+
+/// The Numeric_Value of code points, as fractions, since some of them aren't integers, such as
+/// the value 1/2 of "½". The code points which aren't listed have no numeric value, and have the
+/// Numeric_Type None.
+/// See https://www.unicode.org/reports/tr44/#Numeric_Value .
+#[icu_provider::data_struct]
+#[derive(Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct UnicodeNumericValuesV1<'data> {
+    /// The code points which have a numeric value, in increasing order.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub code_points: ZeroVec<'data, u32>,
+    /// The numerator of the numeric value of each of them.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub numerators: ZeroVec<'data, i64>,
+    /// The denominator of the numeric value of each of them, which is 1 for integers.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub denominators: ZeroVec<'data, u32>,
+}
+
+impl Default for UnicodeNumericValuesV1<'static> {
+    /// Default empty numeric values, where no code point has a numeric value
+    fn default() -> UnicodeNumericValuesV1<'static> {
+        UnicodeNumericValuesV1 {
+            code_points: ZeroVec::clone_from_slice(&[]),
+            numerators: ZeroVec::clone_from_slice(&[]),
+            denominators: ZeroVec::clone_from_slice(&[]),
+        }
+    }
+}
+
+impl<'data> UnicodeNumericValuesV1<'data> {
+    /// Returns the numeric value of a character as a numerator and a denominator, or [`None`] if
+    /// it has no numeric value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::uniset::provider::UnicodeNumericValuesV1;
+    /// use zerovec::ZeroVec;
+    ///
+    /// let numeric_values = UnicodeNumericValuesV1 {
+    ///     // 5, ½, ARABIC-INDIC DIGIT FIVE
+    ///     code_points: ZeroVec::clone_from_slice(&[0x35, 0xBD, 0x665]),
+    ///     numerators: ZeroVec::clone_from_slice(&[5, 1, 5]),
+    ///     denominators: ZeroVec::clone_from_slice(&[1, 2, 1]),
+    /// };
+    ///
+    /// assert_eq!(numeric_values.numeric_value('5'), Some((5, 1)));
+    /// assert_eq!(numeric_values.numeric_value('½'), Some((1, 2)));
+    /// assert_eq!(numeric_values.numeric_value('A'), None);
+    /// ```
+    pub fn numeric_value(&self, ch: char) -> Option<(i64, u32)> {
+        let index = self.code_points.binary_search(&(ch as u32)).ok()?;
+        Some((self.numerators.get(index)?, self.denominators.get(index)?))
+    }
+}
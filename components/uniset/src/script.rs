@@ -0,0 +1,75 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Grouping of text into runs of the same script.
+
+use crate::enum_props::Script;
+use crate::props::get_script_map;
+use crate::provider::UnicodePropertyMapV1Marker;
+use crate::UnicodeSetError;
+use core::convert::TryFrom;
+use core::ops::Range;
+use icu_provider::prelude::*;
+
+/// An iterator over the runs of a string that have the same value of the Script property.
+///
+/// Each item is the byte range of a run and its script. Characters with the script `Common` or
+/// `Inherited`, such as digits, punctuation and combining marks, don't start a new run, and are
+/// part of the run before them. Leading ones are part of the first run with another script. A run
+/// only has the script `Common` or `Inherited` if there is no other script in the text.
+///
+/// See https://www.unicode.org/reports/tr24/#Common .
+pub struct ScriptRunIterator<'a, 'data> {
+    text: &'a str,
+    offset: usize,
+    scripts: DataPayload<'data, UnicodePropertyMapV1Marker>,
+}
+
+impl<'a, 'data> ScriptRunIterator<'a, 'data> {
+    /// Create an iterator over the script runs of `text`, loading the Script property from
+    /// `provider`.
+    pub fn new<D>(text: &'a str, provider: &D) -> Result<Self, UnicodeSetError>
+    where
+        D: DataProvider<'data, UnicodePropertyMapV1Marker> + ?Sized,
+    {
+        Ok(Self {
+            text,
+            offset: 0,
+            scripts: get_script_map(provider)?,
+        })
+    }
+
+    fn script(&self, ch: char) -> Script {
+        Script::try_from(self.scripts.get().get(ch as u32)).unwrap_or(Script::Unknown)
+    }
+}
+
+impl<'a, 'data> Iterator for ScriptRunIterator<'a, 'data> {
+    type Item = (Range<usize>, Script);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.offset;
+        let rest = self.text.get(start..).filter(|rest| !rest.is_empty())?;
+
+        let mut run_script: Option<Script> = None;
+        let mut first_script: Option<Script> = None;
+        for (index, ch) in rest.char_indices() {
+            let script = self.script(ch);
+            first_script.get_or_insert(script);
+            match (script, run_script) {
+                (Script::Common, _) | (Script::Inherited, _) => {}
+                (_, None) => run_script = Some(script),
+                (_, Some(current)) if current == script => {}
+                (_, Some(current)) => {
+                    self.offset = start + index;
+                    return Some((start..self.offset, current));
+                }
+            }
+        }
+
+        self.offset = self.text.len();
+        let script = run_script.or(first_script).unwrap_or(Script::Common);
+        Some((start..self.offset, script))
+    }
+}
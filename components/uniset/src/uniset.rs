@@ -481,6 +481,133 @@ impl<'data> UnicodeSet<'data> {
         check_elem.is_none()
     }
 
+    /// Returns a new [`UnicodeSet`] containing the code points which are in either set.
+    ///
+    /// Performs in `O(N + M)`, where `N` and `M` are the number of endpoints in the two sets.
+    /// Overlapping and adjacent ranges are coalesced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::uniset::UnicodeSet;
+    /// let a_to_e = UnicodeSet::from_inversion_list_slice(&[0x41, 0x46]).unwrap();
+    /// let f_to_z = UnicodeSet::from_inversion_list_slice(&[0x46, 0x5B]).unwrap();
+    /// let union = a_to_e.union(&f_to_z);
+    /// assert_eq!(union.get_inversion_list(), vec![0x41, 0x5B]); // A - Z
+    /// ```
+    pub fn union(&self, set: &Self) -> UnicodeSet<'static> {
+        self.combine(set, |in_self, in_set| in_self || in_set)
+    }
+
+    /// Returns a new [`UnicodeSet`] containing the code points which are in both sets.
+    ///
+    /// Performs in `O(N + M)`, where `N` and `M` are the number of endpoints in the two sets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::uniset::UnicodeSet;
+    /// let a_to_m = UnicodeSet::from_inversion_list_slice(&[0x41, 0x4E]).unwrap();
+    /// let f_to_z = UnicodeSet::from_inversion_list_slice(&[0x46, 0x5B]).unwrap();
+    /// let intersection = a_to_m.intersection(&f_to_z);
+    /// assert_eq!(intersection.get_inversion_list(), vec![0x46, 0x4E]); // F - M
+    /// ```
+    pub fn intersection(&self, set: &Self) -> UnicodeSet<'static> {
+        self.combine(set, |in_self, in_set| in_self && in_set)
+    }
+
+    /// Returns a new [`UnicodeSet`] containing the code points which are in the calling set, but
+    /// not in the given set.
+    ///
+    /// Performs in `O(N + M)`, where `N` and `M` are the number of endpoints in the two sets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::uniset::UnicodeSet;
+    /// let a_to_z = UnicodeSet::from_inversion_list_slice(&[0x41, 0x5B]).unwrap();
+    /// let f_to_m = UnicodeSet::from_inversion_list_slice(&[0x46, 0x4E]).unwrap();
+    /// let difference = a_to_z.difference(&f_to_m);
+    /// assert_eq!(difference.get_inversion_list(), vec![0x41, 0x46, 0x4E, 0x5B]); // A - E, N - Z
+    /// ```
+    pub fn difference(&self, set: &Self) -> UnicodeSet<'static> {
+        self.combine(set, |in_self, in_set| in_self && !in_set)
+    }
+
+    /// Returns a new [`UnicodeSet`] containing the code points which are not in the calling set.
+    ///
+    /// Performs in `O(N)`, where `N` is the number of endpoints in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::uniset::UnicodeSet;
+    /// let a_to_z = UnicodeSet::from_inversion_list_slice(&[0x41, 0x5B]).unwrap();
+    /// let complement = a_to_z.complement();
+    /// assert_eq!(
+    ///     complement.get_inversion_list(),
+    ///     vec![0x0, 0x41, 0x5B, (std::char::MAX as u32) + 1]
+    /// );
+    /// ```
+    pub fn complement(&self) -> UnicodeSet<'static> {
+        UnicodeSet::all().difference(self)
+    }
+
+    /// Compares the calling set with `other`, such as the same property loaded from an older and
+    /// a newer Unicode version, and returns the code points which were added, which are only in
+    /// `other`, and the ones which were removed, which are only in the calling set.
+    ///
+    /// Performs in `O(N + M)`, where `N` and `M` are the number of endpoints in the two sets.
+    /// Adjacent ranges in each result are coalesced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::uniset::UnicodeSet;
+    /// let old = UnicodeSet::from_inversion_list_slice(&[0x41, 0x46, 0x50, 0x55]).unwrap();
+    /// let new = UnicodeSet::from_inversion_list_slice(&[0x41, 0x44, 0x46, 0x55]).unwrap();
+    /// let (added, removed) = old.diff(&new);
+    /// assert_eq!(added.get_inversion_list(), vec![0x46, 0x50]); // F - O
+    /// assert_eq!(removed.get_inversion_list(), vec![0x44, 0x46]); // D - E
+    /// ```
+    pub fn diff(&self, other: &Self) -> (UnicodeSet<'static>, UnicodeSet<'static>) {
+        (other.difference(self), self.difference(other))
+    }
+
+    /// Merges the inversion lists of the two sets, where `op` determines whether a code point
+    /// is in the resulting set from whether it is in the calling set and the given set. The
+    /// endpoints are visited in ascending order, and only the endpoints where the result of
+    /// `op` changes are kept, which coalesces overlapping and adjacent ranges.
+    ///
+    /// `op` must return `false` when the code point is in neither set.
+    fn combine(&self, set: &Self, op: impl Fn(bool, bool) -> bool) -> UnicodeSet<'static> {
+        let mut result: Vec<u32> = Vec::new();
+        let mut self_iter = self.inv_list.iter().peekable();
+        let mut set_iter = set.inv_list.iter().peekable();
+        let mut in_self = false;
+        let mut in_set = false;
+        loop {
+            let point = match (self_iter.peek(), set_iter.peek()) {
+                (Some(&a), Some(&b)) => a.min(b),
+                (Some(&a), None) => a,
+                (None, Some(&b)) => b,
+                (None, None) => break,
+            };
+            let was_included = op(in_self, in_set);
+            if self_iter.next_if_eq(&point).is_some() {
+                in_self = !in_self;
+            }
+            if set_iter.next_if_eq(&point).is_some() {
+                in_set = !in_set;
+            }
+            if op(in_self, in_set) != was_included {
+                result.push(point);
+            }
+        }
+        let inv_list: ZeroVec<u32> = ZeroVec::clone_from_slice(&result);
+        UnicodeSet::from_inversion_list(inv_list).expect("The merged inversion list is valid")
+    }
+
     /// Returns the end of the initial substring where the characters are either contained/not contained
     /// in the set.
     ///
@@ -751,6 +878,112 @@ mod tests {
         assert_eq!(check.span_back("XYZABC", false), 6);
     }
 
+    #[test]
+    fn test_unicodeset_union() {
+        let check = |a: &[u32], b: &[u32], expected: &[u32]| {
+            let a = UnicodeSet::from_inversion_list_slice(a).unwrap();
+            let b = UnicodeSet::from_inversion_list_slice(b).unwrap();
+            assert_eq!(a.union(&b).get_inversion_list(), expected);
+            assert_eq!(b.union(&a).get_inversion_list(), expected);
+        };
+        check(&[0x41, 0x46], &[0x50, 0x55], &[0x41, 0x46, 0x50, 0x55]);
+        // Overlapping ranges
+        check(&[0x41, 0x50], &[0x46, 0x55], &[0x41, 0x55]);
+        // Adjacent ranges
+        check(&[0x41, 0x46, 0x50, 0x55], &[0x46, 0x50], &[0x41, 0x55]);
+        check(&[0x41, 0x46], &[], &[0x41, 0x46]);
+    }
+
+    #[test]
+    fn test_unicodeset_intersection() {
+        let check = |a: &[u32], b: &[u32], expected: &[u32]| {
+            let a = UnicodeSet::from_inversion_list_slice(a).unwrap();
+            let b = UnicodeSet::from_inversion_list_slice(b).unwrap();
+            assert_eq!(a.intersection(&b).get_inversion_list(), expected);
+            assert_eq!(b.intersection(&a).get_inversion_list(), expected);
+        };
+        check(&[0x41, 0x50], &[0x46, 0x55], &[0x46, 0x50]);
+        check(
+            &[0x41, 0x46, 0x50, 0x55],
+            &[0x44, 0x52],
+            &[0x44, 0x46, 0x50, 0x52],
+        );
+        // Adjacent ranges have no code points in common.
+        check(&[0x41, 0x46], &[0x46, 0x50], &[]);
+        check(&[0x41, 0x46], &[], &[]);
+    }
+
+    #[test]
+    fn test_unicodeset_difference() {
+        let a = UnicodeSet::from_inversion_list_slice(&[0x41, 0x55]).unwrap();
+        let b =
+            UnicodeSet::from_inversion_list_slice(&[0x30, 0x41, 0x46, 0x50, 0x55, 0x60]).unwrap();
+        assert_eq!(
+            a.difference(&b).get_inversion_list(),
+            vec![0x41, 0x46, 0x50, 0x55]
+        );
+        assert_eq!(
+            b.difference(&a).get_inversion_list(),
+            vec![0x30, 0x41, 0x55, 0x60]
+        );
+        assert!(a.difference(&a).is_empty());
+    }
+
+    #[test]
+    fn test_unicodeset_diff() {
+        let check = |old: &[u32], new: &[u32], added: &[u32], removed: &[u32]| {
+            let old = UnicodeSet::from_inversion_list_slice(old).unwrap();
+            let new = UnicodeSet::from_inversion_list_slice(new).unwrap();
+            let (diff_added, diff_removed) = old.diff(&new);
+            assert_eq!(diff_added.get_inversion_list(), added);
+            assert_eq!(diff_removed.get_inversion_list(), removed);
+            assert_eq!(old.union(&diff_added).difference(&diff_removed), new);
+
+            let (diff_added, diff_removed) = new.diff(&old);
+            assert_eq!(diff_added.get_inversion_list(), removed);
+            assert_eq!(diff_removed.get_inversion_list(), added);
+        };
+        check(
+            &[0x41, 0x46, 0x50, 0x55],
+            &[0x41, 0x44, 0x46, 0x55],
+            &[0x46, 0x50],
+            &[0x44, 0x46],
+        );
+        // The gap between two ranges is filled, and the range is extended.
+        check(
+            &[0x41, 0x44, 0x46, 0x48],
+            &[0x41, 0x50],
+            &[0x44, 0x46, 0x48, 0x50],
+            &[],
+        );
+        check(
+            &[0x41, 0x44, 0x46, 0x48],
+            &[0x30, 0x50],
+            &[0x30, 0x41, 0x44, 0x46, 0x48, 0x50],
+            &[],
+        );
+        // A range is shrunk from both sides.
+        check(&[0x41, 0x50], &[0x44, 0x46], &[], &[0x41, 0x44, 0x46, 0x50]);
+        // Ranges which only moved are added and removed.
+        check(&[0x41, 0x46], &[0x46, 0x4B], &[0x46, 0x4B], &[0x41, 0x46]);
+        check(&[0x41, 0x46], &[0x41, 0x46], &[], &[]);
+        check(&[], &[0x41, 0x46], &[0x41, 0x46], &[]);
+    }
+
+    #[test]
+    fn test_unicodeset_complement() {
+        let max = (char::MAX as u32) + 1;
+        let check = |inv_list: &[u32], expected: &[u32]| {
+            let set = UnicodeSet::from_inversion_list_slice(inv_list).unwrap();
+            assert_eq!(set.complement().get_inversion_list(), expected);
+            assert_eq!(set.complement().complement(), set);
+        };
+        check(&[0x41, 0x46], &[0x0, 0x41, 0x46, max]);
+        check(&[0x0, 0x41, 0x46, max], &[0x41, 0x46]);
+        check(&[], &[0x0, max]);
+        check(&[0x0, max], &[]);
+    }
+
     #[test]
     fn test_uniset_to_inv_list() {
         let inv_list: Vec<u32> = vec![
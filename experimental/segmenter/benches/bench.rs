@@ -80,10 +80,38 @@ fn line_break_iter_utf16(c: &mut Criterion) {
     });
 }
 
+fn line_break_iter_reset(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Line Break/Reset");
+
+    // Many short strings, where the allocations of a new iterator for each
+    // string are significant.
+    let words: Vec<&str> = TEST_STR2.split(' ').collect();
+
+    let break_with_new = || {
+        words
+            .iter()
+            .map(|word| LineBreakIterator::new(word).count())
+            .sum::<usize>()
+    };
+    let break_with_reset = || {
+        let mut iter = LineBreakIterator::new("");
+        let mut count = 0;
+        for word in words.iter() {
+            iter = iter.reset(word);
+            count += iter.by_ref().count();
+        }
+        count
+    };
+
+    group.bench_function("Th new", |b| b.iter(break_with_new));
+    group.bench_function("Th reset", |b| b.iter(break_with_reset));
+}
+
 criterion_group!(
     benches,
     line_break_iter_latin1,
     line_break_iter_utf8,
-    line_break_iter_utf16
+    line_break_iter_utf16,
+    line_break_iter_reset
 );
 criterion_main!(benches);
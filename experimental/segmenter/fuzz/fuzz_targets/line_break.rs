@@ -0,0 +1,57 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#![no_main]
+
+use icu_segmenter::*;
+use libfuzzer_sys::fuzz_target;
+
+// Run with `cargo +nightly fuzz run line_break` in `experimental/segmenter`.
+//
+// The input is used as Latin-1 text, as UTF-16 text which may contain
+// unpaired surrogates, and as UTF-8 text after replacing invalid sequences.
+fuzz_target!(|input: &[u8]| {
+    let options = [
+        LineBreakOptions::default(),
+        LineBreakOptions::css_loose(),
+        LineBreakOptions::css_anywhere(),
+        LineBreakOptions {
+            word_break_rule: WordBreakRule::BreakAll,
+            ..LineBreakOptions::default()
+        },
+        LineBreakOptions {
+            word_break_rule: WordBreakRule::KeepAll,
+            ..LineBreakOptions::default()
+        },
+    ];
+
+    let utf16: Vec<u16> = input
+        .chunks(2)
+        .map(|pair| u16::from_le_bytes([pair[0], *pair.get(1).unwrap_or(&0)]))
+        .collect();
+    let utf8 = String::from_utf8_lossy(input);
+
+    for options in options.iter() {
+        LineBreakIteratorLatin1::new_with_options(input, *options)
+            .with_unbreakable_numbers(true)
+            .for_each(drop);
+        LineBreakIteratorUtf16::new_with_options(&utf16, *options)
+            .with_strict_joiners(true)
+            .for_each(drop);
+        LineBreakIterator::new_with_options(&utf8, *options)
+            .with_tab_rule(TabRule::BreakAfter)
+            .with_zwsp_rule(ZwspRule::NoBreak)
+            .for_each(drop);
+    }
+
+    GraphemeClusterBreakIteratorLatin1::new(input).for_each(drop);
+    GraphemeClusterBreakIteratorUtf16::new(&utf16).for_each(drop);
+    GraphemeClusterBreakIterator::new(&utf8).for_each(drop);
+    WordBreakIteratorLatin1::new(input).for_each(drop);
+    WordBreakIteratorUtf16::new(&utf16).for_each(drop);
+    WordBreakIterator::new(&utf8).for_each(drop);
+    let _ = word_segments_trimmed_latin1(input);
+    let _ = word_count(&utf8, &BuiltinWordDictionaryProvider);
+    let _ = fold_lines(&utf8, 8.0, |line| line.chars().count() as f32);
+});
@@ -0,0 +1,235 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::line_breaker::*;
+
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+/// The version of the Unicode data built into the line breaker. A
+/// [`BreakList`] computed with a different version is rejected.
+pub const UNICODE_VERSION: &str = "13.0.0";
+
+/// The type of a line break opportunity in a [`BreakList`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BreakType {
+    /// The line must be broken here, after a mandatory break character or at
+    /// the end of the text (LB3, LB4 and LB5).
+    Mandatory,
+
+    /// The line may be broken here.
+    Opportunity,
+}
+
+/// An error returned when the breaks of a [`BreakList`] don't apply to a text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BreakListError {
+    /// The list was computed with another version of the Unicode data.
+    UnicodeVersionMismatch(String),
+
+    /// The list was computed with other [`LineBreakOptions`].
+    OptionsMismatch,
+
+    /// The list was computed for another text.
+    TextMismatch,
+}
+
+impl fmt::Display for BreakListError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::UnicodeVersionMismatch(version) => write!(
+                f,
+                "The breaks were computed with Unicode {}, but the current version is {}",
+                version, UNICODE_VERSION
+            ),
+            Self::OptionsMismatch => write!(f, "The breaks were computed with other options"),
+            Self::TextMismatch => write!(f, "The breaks were computed for another text"),
+        }
+    }
+}
+
+impl std::error::Error for BreakListError {}
+
+/// The line break opportunities of a text, which can be cached and reused as
+/// long as the text doesn't change. It can be serialized with serde.
+///
+/// The list records the version of the Unicode data, the [`LineBreakOptions`]
+/// and a hash of the text it was computed for, so that a stale list is
+/// rejected by [`BreakList::iter`] instead of returning wrong breaks.
+///
+/// ```rust
+/// use icu_segmenter::{BreakList, BreakType};
+///
+/// let list = BreakList::new("hello world");
+/// let breaks: Vec<(usize, BreakType)> = list.iter("hello world").unwrap().collect();
+/// assert_eq!(
+///     breaks,
+///     vec![(6, BreakType::Opportunity), (11, BreakType::Mandatory)]
+/// );
+/// assert!(list.iter("hello there").is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BreakList {
+    unicode_version: String,
+    options: LineBreakOptions,
+    text_len: usize,
+    text_hash: u64,
+    breaks: Vec<(usize, BreakType)>,
+}
+
+impl BreakList {
+    /// Compute the line break opportunities of `text` with the
+    /// [`LineBreakIterator`].
+    pub fn new(text: &str) -> Self {
+        Self::new_with_options(text, LineBreakOptions::default())
+    }
+
+    /// Compute the line break opportunities of `text` with the
+    /// [`LineBreakIterator`] and [`LineBreakOptions`].
+    pub fn new_with_options(text: &str, options: LineBreakOptions) -> Self {
+        let breaks = LineBreakIterator::new_with_options(text, options)
+            .map(|offset| {
+                let is_mandatory = offset == text.len()
+                    || matches!(text[..offset].chars().next_back(), Some(c) if is_mandatory_break_char(c));
+                if is_mandatory {
+                    (offset, BreakType::Mandatory)
+                } else {
+                    (offset, BreakType::Opportunity)
+                }
+            })
+            .collect();
+        Self {
+            unicode_version: UNICODE_VERSION.to_string(),
+            options,
+            text_len: text.len(),
+            text_hash: hash(text),
+            breaks,
+        }
+    }
+
+    /// Returns an iterator over the line break opportunities, which are the
+    /// same as the [`LineBreakIterator`] returns for `text`. Returns an error if
+    /// the list was computed for another text, with other options or with
+    /// another version of the Unicode data.
+    pub fn iter(
+        &self,
+        text: &str,
+    ) -> Result<impl Iterator<Item = (usize, BreakType)> + '_, BreakListError> {
+        self.iter_with_options(text, LineBreakOptions::default())
+    }
+
+    /// Returns an iterator over the line break opportunities, which are the
+    /// same as the [`LineBreakIterator`] returns for `text` and `options`, see
+    /// [`BreakList::iter`].
+    pub fn iter_with_options(
+        &self,
+        text: &str,
+        options: LineBreakOptions,
+    ) -> Result<impl Iterator<Item = (usize, BreakType)> + '_, BreakListError> {
+        if self.unicode_version != UNICODE_VERSION {
+            return Err(BreakListError::UnicodeVersionMismatch(
+                self.unicode_version.clone(),
+            ));
+        }
+        if self.options != options {
+            return Err(BreakListError::OptionsMismatch);
+        }
+        if self.text_len != text.len() || self.text_hash != hash(text) {
+            return Err(BreakListError::TextMismatch);
+        }
+        Ok(self.breaks.iter().copied())
+    }
+}
+
+/// The 64-bit FNV-1a hash of `text`, which is stable across platforms and
+/// compiler versions.
+fn hash(text: &str) -> u64 {
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BreakList, BreakListError, BreakType, LineBreakIterator, LineBreakOptions};
+
+    #[test]
+    fn break_types() {
+        let text = "The quick\nbrown fox\r\n";
+        let list = BreakList::new(text);
+        let breaks: Vec<(usize, BreakType)> = list.iter(text).unwrap().collect();
+        assert_eq!(
+            breaks,
+            vec![
+                (4, BreakType::Opportunity),
+                (10, BreakType::Mandatory),
+                (16, BreakType::Opportunity),
+                (21, BreakType::Mandatory),
+            ]
+        );
+        let offsets: Vec<usize> = breaks.iter().map(|(offset, _)| *offset).collect();
+        assert_eq!(
+            offsets,
+            LineBreakIterator::new(text).collect::<Vec<usize>>()
+        );
+
+        assert_eq!(
+            list.iter("The quick\nbrown cat\r\n").err(),
+            Some(BreakListError::TextMismatch)
+        );
+        assert_eq!(list.iter("").err(), Some(BreakListError::TextMismatch));
+    }
+
+    #[test]
+    fn options() {
+        // Loose allows a break before the iteration mark, and Strict doesn't.
+        let text = "\u{30B5}\u{3005}\u{30B5}";
+        let options = LineBreakOptions {
+            ja_zh: true,
+            ..LineBreakOptions::css_loose()
+        };
+        let list = BreakList::new_with_options(text, options);
+        let offsets: Vec<usize> = list
+            .iter_with_options(text, options)
+            .unwrap()
+            .map(|(offset, _)| offset)
+            .collect();
+        assert_eq!(
+            offsets,
+            LineBreakIterator::new_with_options(text, options).collect::<Vec<usize>>()
+        );
+        assert_ne!(
+            offsets,
+            LineBreakIterator::new(text).collect::<Vec<usize>>()
+        );
+
+        assert_eq!(list.iter(text).err(), Some(BreakListError::OptionsMismatch));
+        assert_eq!(
+            BreakList::new(text).iter_with_options(text, options).err(),
+            Some(BreakListError::OptionsMismatch)
+        );
+    }
+
+    #[test]
+    fn serde_round_trip() {
+        let text = "Hello, world!\nThis is a test.";
+        let list = BreakList::new(text);
+
+        let json = serde_json::to_string(&list).unwrap();
+        let cached: BreakList = serde_json::from_str(&json).unwrap();
+        assert_eq!(cached, list);
+        assert_eq!(
+            cached.iter(text).unwrap().collect::<Vec<_>>(),
+            list.iter(text).unwrap().collect::<Vec<_>>()
+        );
+
+        // A cache from another version of the Unicode data is rejected.
+        let stale = json.replace("\"13.0.0\"", "\"12.1.0\"");
+        let stale: BreakList = serde_json::from_str(&stale).unwrap();
+        assert_eq!(
+            stale.iter(text).err(),
+            Some(BreakListError::UnicodeVersionMismatch("12.1.0".to_string()))
+        );
+    }
+}
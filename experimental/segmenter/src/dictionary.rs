@@ -0,0 +1,59 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::provider::WordDictionaryV1;
+
+/// Returns the number of words of a string, as segmented by the dictionary.
+///
+/// The text is matched against the longest word of the dictionary at each
+/// position. A character which doesn't start any word of the dictionary is a
+/// word of its own.
+pub fn get_word_count(dictionary: &WordDictionaryV1, input: &str) -> usize {
+    let max_len = dictionary
+        .words
+        .iter()
+        .map(|word| word.len())
+        .max()
+        .unwrap_or(0);
+    let mut count = 0;
+    let mut start = 0;
+    while let Some(first) = input[start..].chars().next() {
+        let rest = &input[start..];
+        let len = rest
+            .char_indices()
+            .skip(1)
+            .map(|(index, _)| index)
+            .chain(core::iter::once(rest.len()))
+            .take_while(|len| *len <= max_len)
+            .filter(|len| dictionary.contains(&rest[..*len]))
+            .last()
+            .unwrap_or_else(|| first.len_utf8());
+        count += 1;
+        start += len;
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dictionary::get_word_count;
+    use crate::WordDictionaryV1;
+
+    #[test]
+    fn longest_match() {
+        let dictionary = WordDictionaryV1::new(
+            ["東京", "東京都", "京都", "に", "住む"]
+                .iter()
+                .map(|word| word.to_string())
+                .collect(),
+        );
+        assert_eq!(get_word_count(&dictionary, ""), 0);
+        // "東京都" is preferred to "東京" and "京都".
+        assert_eq!(get_word_count(&dictionary, "東京都に住む"), 3);
+        assert_eq!(get_word_count(&dictionary, "京都に住む"), 3);
+        // The characters which don't start a word are words of their own.
+        assert_eq!(get_word_count(&dictionary, "私は東京"), 3);
+        assert_eq!(get_word_count(&WordDictionaryV1::default(), "東京"), 2);
+    }
+}
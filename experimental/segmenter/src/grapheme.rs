@@ -0,0 +1,489 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::grapheme_property_table::*;
+use crate::line_breaker::{Latin1Indices, Utf16Indices};
+
+use core::str::CharIndices;
+
+/// The values of the `Grapheme_Cluster_Break` property used by the rules.
+/// `ExtendedPictographic` is the value of the characters with the
+/// `Extended_Pictographic` property, which are all `Other`.
+///
+/// The consonants, linkers and extending characters of the
+/// `Indic_Conjunct_Break` property are values of their own, which otherwise
+/// behave like `Other` and `Extend`. `IndicExtend` is the value of the
+/// `Extend` characters with a non-zero `Canonical_Combining_Class`, which
+/// continue a conjunct, unlike the other `Extend` characters.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[allow(clippy::upper_case_acronyms)]
+pub(crate) enum GraphemeClusterBreakProperty {
+    CR,
+    LF,
+    Control,
+    Extend,
+    ZWJ,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,
+    V,
+    T,
+    LV,
+    LVT,
+    ExtendedPictographic,
+    IndicConsonant,
+    IndicExtend,
+    IndicLinker,
+    Other,
+}
+
+use GraphemeClusterBreakProperty::*;
+
+/// Returns the grapheme cluster break property of a code point, from the
+/// table generated by `generate_uax29_properties.py`.
+fn get_grapheme_cluster_break_property_utf32(codepoint: u32) -> GraphemeClusterBreakProperty {
+    // Unpaired surrogates are control characters.
+    if (0xD800..=0xDFFF).contains(&codepoint) {
+        return Control;
+    }
+    let codepoint = codepoint as usize;
+    match GCB_PROPERTY_INDEX.get(codepoint / GCB_PROPERTY_BLOCK_SIZE) {
+        Some(block) => {
+            let value = GCB_PROPERTY_BLOCKS[*block as usize][codepoint % GCB_PROPERTY_BLOCK_SIZE];
+            GCB_PROPERTY_VALUES[value as usize]
+        }
+        None => Other,
+    }
+}
+
+/// The grapheme cluster break rules of [Unicode Standard Annex #29][UAX29],
+/// shared by the iterators over each encoding.
+///
+/// [UAX29]: https://www.unicode.org/reports/tr29/
+struct GraphemeClusterBreaker<I> {
+    iter: I,
+    len: usize,
+    /// The property of the character after the last boundary.
+    current: Option<GraphemeClusterBreakProperty>,
+}
+
+impl<I, C> GraphemeClusterBreaker<I>
+where
+    I: Iterator<Item = (usize, C)>,
+    C: Into<u32>,
+{
+    fn new(mut iter: I, len: usize) -> Self {
+        let current = iter
+            .next()
+            .map(|(_, c)| get_grapheme_cluster_break_property_utf32(c.into()));
+        Self { iter, len, current }
+    }
+
+    fn next(&mut self) -> Option<usize> {
+        let mut left = self.current.take()?;
+        // Whether the cluster so far ends with ExtPict Extend* (GB11).
+        let mut pictographic = left == ExtendedPictographic;
+        // Whether the cluster so far ends with ExtPict Extend* ZWJ (GB11).
+        let mut pictographic_zwj = false;
+        // Whether the cluster so far ends with an odd number of regional
+        // indicators (GB12, GB13).
+        let mut odd_regional_indicators = left == RegionalIndicator;
+        // Whether the cluster so far ends with an Indic consonant followed by
+        // Indic extending characters and linkers (GB9c).
+        let mut indic_consonant = left == IndicConsonant;
+        // Whether these extending characters include a linker (GB9c).
+        let mut indic_linker = false;
+
+        for (offset, c) in &mut self.iter {
+            let right = get_grapheme_cluster_break_property_utf32(c.into());
+            let is_break = match (left, right) {
+                // GB3
+                (CR, LF) => false,
+                // GB4, GB5
+                (CR, _) | (LF, _) | (Control, _) | (_, CR) | (_, LF) | (_, Control) => true,
+                // GB6, GB7, GB8
+                (L, L) | (L, V) | (L, LV) | (L, LVT) => false,
+                (LV, V) | (LV, T) | (V, V) | (V, T) => false,
+                (LVT, T) | (T, T) => false,
+                // GB9
+                (_, Extend) | (_, ZWJ) | (_, IndicExtend) | (_, IndicLinker) => false,
+                // GB9a
+                (_, SpacingMark) => false,
+                // GB9b
+                (Prepend, _) => false,
+                // GB9c
+                (_, IndicConsonant) if indic_linker => false,
+                // GB11
+                (ZWJ, ExtendedPictographic) => !pictographic_zwj,
+                // GB12, GB13
+                (RegionalIndicator, RegionalIndicator) => !odd_regional_indicators,
+                // GB999
+                _ => true,
+            };
+            if is_break {
+                self.current = Some(right);
+                return Some(offset);
+            }
+
+            // The Indic extending characters and linkers are extending
+            // characters as well.
+            let extend = matches!(right, Extend | IndicExtend | IndicLinker);
+            pictographic_zwj = pictographic && right == ZWJ;
+            pictographic = right == ExtendedPictographic || (pictographic && extend);
+            odd_regional_indicators = right == RegionalIndicator && !odd_regional_indicators;
+            // Only the Indic extending characters, the linkers and ZWJ
+            // continue an Indic conjunct.
+            let indic_extend = matches!(right, IndicExtend | IndicLinker | ZWJ);
+            indic_linker =
+                indic_consonant && (right == IndicLinker || (indic_linker && indic_extend));
+            indic_consonant = right == IndicConsonant || (indic_consonant && indic_extend);
+            left = right;
+        }
+        Some(self.len)
+    }
+}
+
+macro_rules! grapheme_cluster_break_iterator_impl {
+    ($(#[$attr:meta])* $name:ident, $iter_attr:ty) => {
+        $(#[$attr])*
+        ///
+        /// The consonants and linkers of Indic conjuncts are the ones of the
+        /// Devanagari, Bengali, Gujarati, Oriya, Telugu and Malayalam
+        /// scripts.
+        ///
+        /// [`Iterator`]: core::iter::Iterator
+        /// [UAX29]: https://www.unicode.org/reports/tr29/
+        pub struct $name<'a> {
+            breaker: GraphemeClusterBreaker<$iter_attr>,
+        }
+
+        impl<'a> Iterator for $name<'a> {
+            type Item = usize;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.breaker.next()
+            }
+        }
+    };
+}
+
+grapheme_cluster_break_iterator_impl!(
+    /// The struct implementing the [`Iterator`] trait over the grapheme
+    /// cluster boundaries of an `str` (a UTF-8 string), as defined in [Unicode
+    /// Standard Annex #29][UAX29]. It returns the byte offsets of the
+    /// boundaries, excluding the start of the string and including its end.
+    GraphemeClusterBreakIterator,
+    CharIndices<'a>
+);
+
+impl<'a> GraphemeClusterBreakIterator<'a> {
+    /// Create a grapheme cluster break iterator for an `str` (a UTF-8
+    /// string).
+    ///
+    /// ```rust
+    /// use icu_segmenter::GraphemeClusterBreakIterator;
+    ///
+    /// // A thumbs up sign with a skin tone modifier is a single cluster.
+    /// let breaks: Vec<usize> =
+    ///     GraphemeClusterBreakIterator::new("a\u{1F44D}\u{1F3FD}").collect();
+    /// assert_eq!(breaks, vec![1, 9]);
+    /// ```
+    pub fn new(input: &str) -> GraphemeClusterBreakIterator<'_> {
+        GraphemeClusterBreakIterator {
+            breaker: GraphemeClusterBreaker::new(input.char_indices(), input.len()),
+        }
+    }
+}
+
+grapheme_cluster_break_iterator_impl!(
+    /// The struct implementing the [`Iterator`] trait over the grapheme
+    /// cluster boundaries of a Latin-1 (8-bit) string, as defined in [Unicode
+    /// Standard Annex #29][UAX29]. It returns the byte offsets of the
+    /// boundaries, excluding the start of the string and including its end.
+    GraphemeClusterBreakIteratorLatin1,
+    Latin1Indices<'a>
+);
+
+impl<'a> GraphemeClusterBreakIteratorLatin1<'a> {
+    /// Create a grapheme cluster break iterator for a Latin-1 (8-bit) string.
+    ///
+    /// Latin-1 has no characters with the `Extend`, `SpacingMark` or
+    /// `Prepend` property, so every character is a cluster of its own, except
+    /// for CR LF. The soft hyphen (U+00AD) has the `Control` property, so it
+    /// is a cluster of its own as well.
+    ///
+    /// ```rust
+    /// use icu_segmenter::GraphemeClusterBreakIteratorLatin1;
+    ///
+    /// let breaks: Vec<usize> = GraphemeClusterBreakIteratorLatin1::new(b"a\r\nb").collect();
+    /// assert_eq!(breaks, vec![1, 3, 4]);
+    /// ```
+    pub fn new(input: &[u8]) -> GraphemeClusterBreakIteratorLatin1<'_> {
+        GraphemeClusterBreakIteratorLatin1 {
+            breaker: GraphemeClusterBreaker::new(Latin1Indices::new(input), input.len()),
+        }
+    }
+}
+
+grapheme_cluster_break_iterator_impl!(
+    /// The struct implementing the [`Iterator`] trait over the grapheme
+    /// cluster boundaries of a UTF-16 string, as defined in [Unicode Standard
+    /// Annex #29][UAX29]. It returns the offsets of the boundaries in code
+    /// units, excluding the start of the string and including its end.
+    GraphemeClusterBreakIteratorUtf16,
+    Utf16Indices<'a>
+);
+
+impl<'a> GraphemeClusterBreakIteratorUtf16<'a> {
+    /// Create a grapheme cluster break iterator for a UTF-16 string. A
+    /// surrogate pair is never split.
+    ///
+    /// ```rust
+    /// use icu_segmenter::GraphemeClusterBreakIteratorUtf16;
+    ///
+    /// let input: Vec<u16> = "a\u{1F44D}\u{1F3FD}".encode_utf16().collect();
+    /// let breaks: Vec<usize> = GraphemeClusterBreakIteratorUtf16::new(&input).collect();
+    /// assert_eq!(breaks, vec![1, 5]);
+    /// ```
+    pub fn new(input: &[u16]) -> GraphemeClusterBreakIteratorUtf16<'_> {
+        GraphemeClusterBreakIteratorUtf16 {
+            breaker: GraphemeClusterBreaker::new(Utf16Indices::new(input), input.len()),
+        }
+    }
+}
+
+/// Returns the number of grapheme clusters in an `str` (a UTF-8 string),
+/// which is its length as perceived by users, such as for a limit on the
+/// length of a text field.
+///
+/// This is the same as counting the items of a
+/// [`GraphemeClusterBreakIterator`].
+///
+/// ```rust
+/// use icu_segmenter::grapheme_count;
+///
+/// // The flag of Japan is a single cluster of two regional indicators.
+/// assert_eq!(grapheme_count("caf\u{E9} \u{1F1EF}\u{1F1F5}"), 6);
+/// ```
+pub fn grapheme_count(input: &str) -> usize {
+    GraphemeClusterBreakIterator::new(input).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        grapheme_count, GraphemeClusterBreakIterator, GraphemeClusterBreakIteratorLatin1,
+        GraphemeClusterBreakIteratorUtf16,
+    };
+
+    fn breaks(input: &[u8]) -> Vec<usize> {
+        GraphemeClusterBreakIteratorLatin1::new(input).collect()
+    }
+
+    #[test]
+    fn grapheme_cluster_break() {
+        assert_eq!(breaks(b""), Vec::<usize>::new());
+        assert_eq!(breaks(b"abc"), vec![1, 2, 3]);
+        assert_eq!(breaks(b"a\r\nb"), vec![1, 3, 4]);
+        assert_eq!(breaks(b"\n\r"), vec![1, 2]);
+        // "Café" and "ñ" are precomposed in Latin-1.
+        assert_eq!(breaks(b"Caf\xE9 \xF1"), vec![1, 2, 3, 4, 5, 6]);
+        // The soft hyphen is a control character, not a combining mark.
+        assert_eq!(breaks(b"a\xADb"), vec![1, 2, 3]);
+        assert_eq!(breaks(b"\x85\xA0"), vec![1, 2]);
+    }
+
+    /// Returns the breaks of the UTF-8 iterator, converted to UTF-16 offsets.
+    fn utf8_breaks_as_utf16(input: &str) -> Vec<usize> {
+        GraphemeClusterBreakIterator::new(input)
+            .map(|offset| input[..offset].encode_utf16().count())
+            .collect()
+    }
+
+    #[test]
+    fn utf16() {
+        for (input, expected) in [
+            ("", vec![]),
+            ("a\r\nb", vec![1, 3, 4]),
+            // e + combining acute accent
+            ("e\u{301}x", vec![2, 3]),
+            // Thai KO KAI + SARA I, NO NU
+            ("\u{E01}\u{E34}\u{E19}", vec![2, 3]),
+            // Devanagari KA + SIGN I (a spacing mark)
+            ("\u{915}\u{93F}\u{915}", vec![2, 3]),
+            // Thumbs up sign + skin tone modifier
+            ("\u{1F44D}\u{1F3FD}\u{1F44D}", vec![4, 6]),
+            // Woman + ZWJ + laptop
+            ("\u{1F469}\u{200D}\u{1F4BB}!", vec![5, 6]),
+            // Woman + skin tone modifier + ZWJ + laptop
+            ("\u{1F469}\u{1F3FD}\u{200D}\u{1F4BB}", vec![7]),
+            // A ZWJ after a letter doesn't join an emoji.
+            ("a\u{200D}\u{1F4BB}", vec![2, 4]),
+            // Flags of the US and Japan, and a lone regional indicator
+            (
+                "\u{1F1FA}\u{1F1F8}\u{1F1EF}\u{1F1F5}\u{1F1FA}",
+                vec![4, 8, 10],
+            ),
+            // Hangul syllables and conjoining jamo
+            ("\u{D55C}\u{AD6D}\u{1100}\u{1161}\u{11A8}", vec![1, 2, 5]),
+            ("\u{20000}\u{20001}", vec![2, 4]),
+        ]
+        .iter()
+        {
+            let utf16: Vec<u16> = input.encode_utf16().collect();
+            let result: Vec<usize> = GraphemeClusterBreakIteratorUtf16::new(&utf16).collect();
+            assert_eq!(&result, expected, "{}", input);
+            assert_eq!(utf8_breaks_as_utf16(input), result, "{}", input);
+        }
+
+        // An unpaired surrogate is a cluster of its own.
+        let result: Vec<usize> =
+            GraphemeClusterBreakIteratorUtf16::new(&[0x61, 0xD83D, 0x301, 0x62]).collect();
+        assert_eq!(result, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn utf8_matches_latin1() {
+        let latin1 = b"Hello\r\nW\xF6rld\xAD \x85\xA0!";
+        let utf8: String = latin1.iter().map(|c| *c as char).collect();
+        let expected: Vec<usize> = GraphemeClusterBreakIteratorLatin1::new(latin1).collect();
+        let result: Vec<usize> = GraphemeClusterBreakIterator::new(&utf8)
+            .map(|offset| utf8[..offset].chars().count())
+            .collect();
+        assert_eq!(result, expected);
+    }
+
+    /// Checks a test case written like those of `GraphemeBreakTest.txt`,
+    /// such as `÷ 0915 × 094D × 0924 ÷`.
+    fn check_break_test(line: &str) {
+        let mut input = String::new();
+        let mut expected = Vec::new();
+        for token in line.split_whitespace() {
+            match token {
+                "÷" if !input.is_empty() => expected.push(input.len()),
+                "÷" | "×" => (),
+                _ => input.push(char::from_u32(u32::from_str_radix(token, 16).unwrap()).unwrap()),
+            }
+        }
+        let result: Vec<usize> = GraphemeClusterBreakIterator::new(&input).collect();
+        assert_eq!(result, expected, "{}", line);
+    }
+
+    #[test]
+    fn indic_conjunct() {
+        for line in [
+            // Devanagari KA + virama + TA
+            "÷ 0915 × 094D × 0924 ÷",
+            "÷ 0915 × 094D × 094D × 0924 ÷",
+            "÷ 0915 × 0300 × 094D × 0924 ÷",
+            "÷ 0915 × 094D × 0300 × 0924 ÷",
+            "÷ 0915 × 094D × 200D × 0924 ÷",
+            "÷ 0915 × 093C × 200D × 094D × 0924 ÷",
+            "÷ 0915 × 093C × 094D × 200D × 0924 ÷",
+            "÷ 0915 × 094D × 0924 × 094D × 092F ÷",
+            // Without a linker, or without a consonant before it
+            "÷ 0915 × 0300 ÷ 0924 ÷",
+            "÷ 0915 × 094D ÷ 0061 ÷",
+            "÷ 0061 × 094D ÷ 0924 ÷",
+            "÷ 003F × 094D ÷ 0924 ÷",
+            // Spacing marks, and extending characters without a combining
+            // class, such as vowel signs and ZWNJ, end the conjunct.
+            "÷ 0915 × 093E × 094D ÷ 0924 ÷",
+            "÷ 0915 × 094D × 093E ÷ 0924 ÷",
+            "÷ 0915 × 0941 × 094D ÷ 0924 ÷",
+            "÷ 0915 × 094D × 0941 ÷ 0924 ÷",
+            "÷ 0915 × 094D × 200C ÷ 0924 ÷",
+            // Bengali, Gujarati, Oriya, Telugu and Malayalam
+            "÷ 0995 × 09CD × 09B7 ÷",
+            "÷ 0A95 × 0ACD × 0AB7 ÷",
+            "÷ 0B15 × 0B4D × 0B37 ÷",
+            "÷ 0C15 × 0C4D × 0C37 ÷",
+            "÷ 0D15 × 0D4D × 0D37 ÷",
+            // The rule doesn't depend on the script of the consonants.
+            "÷ 0915 × 094D × 0995 ÷",
+        ]
+        .iter()
+        {
+            check_break_test(line);
+        }
+        assert_eq!(grapheme_count("\u{0915}\u{094D}\u{0937}\u{093F}"), 1);
+    }
+
+    #[test]
+    fn properties() {
+        for line in [
+            // Thai SARA I and MAI EK are extending characters, and SARA AM is
+            // a spacing mark.
+            "÷ 0E01 × 0E34 ÷ 0E19 ÷",
+            "÷ 0E01 × 0E48 × 0E33 ÷",
+            // Spacing marks of Devanagari, Tamil and Myanmar
+            "÷ 0915 × 093F ÷ 0924 × 093E ÷",
+            "÷ 0B95 × 0BBF ÷",
+            "÷ 1000 × 103B ÷",
+            // Variation selectors, tags and emoji modifiers are extending
+            // characters.
+            "÷ 0061 × FE0F ÷",
+            "÷ 1F3F4 × E0067 × E0062 × E007F ÷",
+            "÷ 1F44D × 1F3FD ÷",
+            // Copyright sign + ZWJ + heart
+            "÷ 00A9 × 200D × 2764 ÷",
+            // Zero width space and line separator are control characters.
+            "÷ 0061 ÷ 200B ÷ 0301 ÷",
+            "÷ 0061 ÷ 2028 ÷ 0301 ÷",
+            // Other letters and ideographs
+            "÷ 05D0 ÷ 05D1 ÷",
+            "÷ 30AB ÷ 30BF ÷",
+            "÷ 4E00 ÷ 20000 ÷",
+        ]
+        .iter()
+        {
+            check_break_test(line);
+        }
+    }
+
+    #[test]
+    fn prepend() {
+        for line in [
+            "÷ 0600 × 0020 ÷",
+            "÷ 0600 × 0600 × 0061 ÷ 0062 ÷",
+            "÷ 0600 × 0308 ÷",
+            "÷ 0020 ÷ 0600 × 0020 ÷",
+            "÷ 0600 ÷ 000D ÷",
+            "÷ 0600 ÷ 000A ÷",
+            "÷ 0600 ÷ 0001 ÷",
+            "÷ 0600 × 1F1E6 ÷",
+            "÷ 0600 × 1100 × 1161 ÷",
+            "÷ 0600 × 231A ÷",
+            "÷ 0D4E × 0D15 ÷",
+            "÷ 111C2 × 1118F ÷",
+            "÷ 0600 ÷",
+        ]
+        .iter()
+        {
+            check_break_test(line);
+        }
+    }
+
+    #[test]
+    fn count() {
+        assert_eq!(grapheme_count(""), 0);
+        assert_eq!(grapheme_count("abc"), 3);
+        assert_eq!(grapheme_count("a\r\nb"), 3);
+        // a + combining acute accent
+        assert_eq!(grapheme_count("a\u{0301}"), 1);
+        // Man + ZWJ + woman + ZWJ + girl + ZWJ + boy
+        assert_eq!(
+            grapheme_count("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}"),
+            1
+        );
+        // Flags of the US and Japan, and a lone regional indicator
+        assert_eq!(
+            grapheme_count("\u{1F1FA}\u{1F1F8}\u{1F1EF}\u{1F1F5}\u{1F1FA}"),
+            3
+        );
+        // Hangul syllable made of conjoining jamo
+        assert_eq!(grapheme_count("\u{1100}\u{1161}\u{11A8}"), 1);
+    }
+}
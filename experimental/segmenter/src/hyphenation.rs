@@ -0,0 +1,205 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::line_breaker::*;
+
+use std::collections::VecDeque;
+
+const SOFT_HYPHEN: char = '\u{00AD}';
+
+/// A trait for proposing hyphenation opportunities within a word.
+///
+/// It is implemented for any `Fn(&str) -> Vec<usize>`, so a closure can be
+/// used as a hyphenator.
+pub trait Hyphenator {
+    /// Returns the byte offsets within `word` where the word may be hyphenated.
+    ///
+    /// `word` never contains trailing white spaces. Offsets that are not
+    /// strictly inside `word` or not on a character boundary are ignored.
+    fn hyphenate(&self, word: &str) -> Vec<usize>;
+}
+
+impl<F> Hyphenator for F
+where
+    F: Fn(&str) -> Vec<usize>,
+{
+    fn hyphenate(&self, word: &str) -> Vec<usize> {
+        self(word)
+    }
+}
+
+/// A break opportunity returned by [`HyphenatingLineBreakIterator`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BreakOpportunity {
+    /// A line break opportunity defined by UAX #14 and the CSS rules.
+    Line(usize),
+
+    /// A hyphenation opportunity inside a word, either after a soft hyphen
+    /// (U+00AD) or proposed by the [`Hyphenator`]. A hyphen should be
+    /// displayed if the line is broken here.
+    Hyphenation(usize),
+}
+
+impl BreakOpportunity {
+    /// The byte offset of this break opportunity.
+    pub fn offset(&self) -> usize {
+        match *self {
+            BreakOpportunity::Line(offset) => offset,
+            BreakOpportunity::Hyphenation(offset) => offset,
+        }
+    }
+}
+
+/// A line break iterator which also returns hyphenation opportunities between
+/// the line break opportunities.
+///
+/// If a word contains a soft hyphen (U+00AD), the soft hyphens are used as the
+/// hyphenation opportunities of the word, and the [`Hyphenator`] isn't asked
+/// for it. See <https://drafts.csswg.org/css-text-3/#hyphenation>.
+///
+/// ```rust
+/// use icu_segmenter::{BreakOpportunity, HyphenatingLineBreakIterator};
+///
+/// let hyphenator = |word: &str| match word {
+///     "hyphenation" => vec![2, 6],
+///     _ => vec![],
+/// };
+/// let iter = HyphenatingLineBreakIterator::new("hyphenation test", hyphenator);
+/// let result: Vec<BreakOpportunity> = iter.collect();
+/// assert_eq!(
+///     result,
+///     vec![
+///         BreakOpportunity::Hyphenation(2),
+///         BreakOpportunity::Hyphenation(6),
+///         BreakOpportunity::Line(12),
+///         BreakOpportunity::Line(16),
+///     ]
+/// );
+/// ```
+pub struct HyphenatingLineBreakIterator<'a, H: Hyphenator> {
+    input: &'a str,
+    iter: LineBreakIterator<'a>,
+    hyphenator: H,
+    last: usize,
+    after_soft_hyphen: bool,
+    pending: VecDeque<BreakOpportunity>,
+}
+
+impl<'a, H: Hyphenator> HyphenatingLineBreakIterator<'a, H> {
+    /// Create a hyphenating line break iterator for an `str` (a UTF-8 string).
+    pub fn new(input: &'a str, hyphenator: H) -> Self {
+        Self::from_iter(input, LineBreakIterator::new(input), hyphenator)
+    }
+
+    /// Create a hyphenating line break iterator with CSS rules for an `str`
+    /// (a UTF-8 string). See [`LineBreakIterator::new_with_break_rule`].
+    pub fn new_with_break_rule(
+        input: &'a str,
+        line_break_rule: LineBreakRule,
+        word_break_rule: WordBreakRule,
+        ja_zh: bool,
+        hyphenator: H,
+    ) -> Self {
+        let iter =
+            LineBreakIterator::new_with_break_rule(input, line_break_rule, word_break_rule, ja_zh);
+        Self::from_iter(input, iter, hyphenator)
+    }
+
+    fn from_iter(input: &'a str, iter: LineBreakIterator<'a>, hyphenator: H) -> Self {
+        Self {
+            input,
+            iter,
+            hyphenator,
+            last: 0,
+            after_soft_hyphen: false,
+            pending: VecDeque::new(),
+        }
+    }
+}
+
+impl<'a, H: Hyphenator> Iterator for HyphenatingLineBreakIterator<'a, H> {
+    type Item = BreakOpportunity;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(pending) = self.pending.pop_front() {
+            return Some(pending);
+        }
+
+        let start = self.last;
+        let end = self.iter.next()?;
+        self.last = end;
+        let segment = &self.input[start..end];
+
+        // UAX #14 already allows a break after a soft hyphen.
+        if segment.ends_with(SOFT_HYPHEN) {
+            self.after_soft_hyphen = true;
+            return Some(BreakOpportunity::Hyphenation(end));
+        }
+
+        // The rest of a word containing soft hyphens isn't hyphenated automatically.
+        if !core::mem::replace(&mut self.after_soft_hyphen, false) {
+            let word = segment.trim_end();
+            let mut offsets: Vec<usize> = self
+                .hyphenator
+                .hyphenate(word)
+                .into_iter()
+                .filter(|&offset| offset > 0 && offset < word.len())
+                .filter(|&offset| word.is_char_boundary(offset))
+                .collect();
+            offsets.sort_unstable();
+            offsets.dedup();
+            self.pending.extend(
+                offsets
+                    .into_iter()
+                    .map(|offset| BreakOpportunity::Hyphenation(start + offset)),
+            );
+        }
+        self.pending.push_back(BreakOpportunity::Line(end));
+        self.pending.pop_front()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::BreakOpportunity::{Hyphenation, Line};
+    use crate::{BreakOpportunity, HyphenatingLineBreakIterator};
+
+    fn stub_hyphenator(word: &str) -> Vec<usize> {
+        match word {
+            "hyphenation" => vec![6, 2],
+            "phenation" => vec![4],
+            _ => vec![],
+        }
+    }
+
+    fn hyphenate(s: &str) -> Vec<BreakOpportunity> {
+        HyphenatingLineBreakIterator::new(s, stub_hyphenator).collect()
+    }
+
+    #[test]
+    fn hyphenator() {
+        assert_eq!(
+            hyphenate("hyphenation is hard"),
+            vec![Hyphenation(2), Hyphenation(6), Line(12), Line(15), Line(19)]
+        );
+        assert_eq!(
+            hyphenate("the hyphenation"),
+            vec![Line(4), Hyphenation(6), Hyphenation(10), Line(15)]
+        );
+        assert_eq!(hyphenate(""), vec![]);
+    }
+
+    #[test]
+    fn soft_hyphen() {
+        // The soft hyphen is reported once, and the hyphenator isn't used for the word.
+        assert_eq!(
+            hyphenate("hy\u{00AD}phenation"),
+            vec![Hyphenation(4), Line(13)]
+        );
+        assert_eq!(
+            hyphenate("a hy\u{00AD}phenation"),
+            vec![Line(2), Hyphenation(6), Line(15)]
+        );
+    }
+}
@@ -1,6 +1,7 @@
 #[derive(PartialEq)]
 pub enum Language {
     Burmese,
+    ChineseOrJapanese,
     Thai,
     Unknown,
 }
@@ -11,6 +12,19 @@ pub fn get_language(codepoint: u32) -> Language {
         0x1000..=0x109f => Language::Burmese,
         0xa9e0..=0xa9ff => Language::Burmese,
         0xaa60..=0xaa7f => Language::Burmese,
+        // Ideographs, hiragana and katakana, without the katakana middle dot
+        0x3005
+        | 0x3007
+        | 0x3041..=0x3096
+        | 0x309d..=0x309f
+        | 0x30a1..=0x30fa
+        | 0x30fc..=0x30ff
+        | 0x31f0..=0x31ff
+        | 0x3400..=0x4dbf
+        | 0x4e00..=0x9fff
+        | 0xf900..=0xfaff
+        | 0xff66..=0xff9f
+        | 0x20000..=0x3ffff => Language::ChineseOrJapanese,
 
         _ => Language::Unknown,
     }
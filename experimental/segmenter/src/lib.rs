@@ -46,17 +46,47 @@
 //! Copy the following files to `tools` directory. Then run `./generate_properties.py` in `tools` directory (requires Python 3.8+). Machine generated files are moved to `src` directory.
 //! - <https://www.unicode.org/Public/UCD/latest/ucd/LineBreak.txt>
 //! - <https://www.unicode.org/Public/UCD/latest/ucd/EastAsianWidth.txt>
+//!
+//! The grapheme cluster break and word break property tables are generated the same way by
+//! `./generate_uax29_properties.py`, from the following files.
+//! - <https://www.unicode.org/Public/UCD/latest/ucd/auxiliary/GraphemeBreakProperty.txt>
+//! - <https://www.unicode.org/Public/UCD/latest/ucd/auxiliary/WordBreakProperty.txt>
+//! - <https://www.unicode.org/Public/UCD/latest/ucd/emoji/emoji-data.txt>
+//! - <https://www.unicode.org/Public/UCD/latest/ucd/extracted/DerivedCombiningClass.txt>
+//! - <https://www.unicode.org/Public/UCD/latest/ucd/IndicSyllabicCategory.txt>
+//! - <https://www.unicode.org/Public/UCD/latest/ucd/Scripts.txt>
 
+mod break_list;
+mod dictionary;
+mod grapheme;
+mod grapheme_property_table;
+mod hyphenation;
 mod language;
 mod lb_define;
+mod line_break_class;
 mod line_breaker;
+mod line_folding;
 mod lstm;
-mod properties_defines;
-mod properties_other;
+mod owned;
 mod property_table;
+mod provider;
 mod rule_table;
+mod segment_index;
+mod streaming;
+mod word;
+mod word_property_table;
 
 #[macro_use]
 extern crate lazy_static;
 
+pub use crate::break_list::*;
+pub use crate::grapheme::*;
+pub use crate::hyphenation::*;
+pub use crate::line_break_class::*;
 pub use crate::line_breaker::*;
+pub use crate::line_folding::*;
+pub use crate::owned::*;
+pub use crate::provider::*;
+pub use crate::segment_index::*;
+pub use crate::streaming::*;
+pub use crate::word::*;
@@ -0,0 +1,324 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::lb_define::*;
+use crate::line_breaker::*;
+
+/// The line break class of a character defined in [Unicode Standard Annex
+/// #14][UAX14], as used by the line breaker.
+///
+/// See <https://www.unicode.org/reports/tr14/#Table1> for the description of
+/// each class.
+///
+/// [UAX14]: http://www.unicode.org/reports/tr14/
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LineBreakClass {
+    /// AI
+    Ambiguous,
+    /// AL
+    Alphabetic,
+    /// B2
+    BreakBoth,
+    /// BA
+    BreakAfter,
+    /// BB
+    BreakBefore,
+    /// BK
+    MandatoryBreak,
+    /// CB
+    ContingentBreak,
+    /// CJ
+    ConditionalJapaneseStarter,
+    /// CL
+    ClosePunctuation,
+    /// CM
+    CombiningMark,
+    /// CP
+    CloseParenthesis,
+    /// CR
+    CarriageReturn,
+    /// EB
+    EBase,
+    /// EM
+    EModifier,
+    /// EX
+    Exclamation,
+    /// GL
+    Glue,
+    /// H2
+    H2,
+    /// H3
+    H3,
+    /// HL
+    HebrewLetter,
+    /// HY
+    Hyphen,
+    /// ID
+    Ideographic,
+    /// IN
+    Inseparable,
+    /// IS
+    InfixNumeric,
+    /// JL
+    JL,
+    /// JT
+    JT,
+    /// JV
+    JV,
+    /// LF
+    LineFeed,
+    /// NL
+    NextLine,
+    /// NS
+    Nonstarter,
+    /// NU
+    Numeric,
+    /// OP
+    OpenPunctuation,
+    /// PO
+    PostfixNumeric,
+    /// PR
+    PrefixNumeric,
+    /// QU
+    Quotation,
+    /// RI
+    RegionalIndicator,
+    /// SA
+    ComplexContext,
+    /// SG
+    Surrogate,
+    /// SP
+    Space,
+    /// SY
+    BreakSymbols,
+    /// WJ
+    WordJoiner,
+    /// XX
+    Unknown,
+    /// ZW
+    ZWSpace,
+    /// ZWJ
+    ZWJ,
+}
+
+impl LineBreakClass {
+    // The property table splits some classes for the rule table. Map them back.
+    fn from_property(prop: u8) -> Self {
+        match prop {
+            AI => LineBreakClass::Ambiguous,
+            AL => LineBreakClass::Alphabetic,
+            B2 => LineBreakClass::BreakBoth,
+            BA => LineBreakClass::BreakAfter,
+            BB => LineBreakClass::BreakBefore,
+            BK => LineBreakClass::MandatoryBreak,
+            CB => LineBreakClass::ContingentBreak,
+            CJ => LineBreakClass::ConditionalJapaneseStarter,
+            CL => LineBreakClass::ClosePunctuation,
+            CM => LineBreakClass::CombiningMark,
+            CP => LineBreakClass::CloseParenthesis,
+            CR => LineBreakClass::CarriageReturn,
+            EB => LineBreakClass::EBase,
+            EM => LineBreakClass::EModifier,
+            EX => LineBreakClass::Exclamation,
+            GL => LineBreakClass::Glue,
+            H2 => LineBreakClass::H2,
+            H3 => LineBreakClass::H3,
+            HL => LineBreakClass::HebrewLetter,
+            HY => LineBreakClass::Hyphen,
+            ID => LineBreakClass::Ideographic,
+            IN => LineBreakClass::Inseparable,
+            IS => LineBreakClass::InfixNumeric,
+            JL => LineBreakClass::JL,
+            JT => LineBreakClass::JT,
+            JV => LineBreakClass::JV,
+            LF => LineBreakClass::LineFeed,
+            NL => LineBreakClass::NextLine,
+            NS => LineBreakClass::Nonstarter,
+            NU => LineBreakClass::Numeric,
+            OP_EA | OP_OP30 => LineBreakClass::OpenPunctuation,
+            PO => LineBreakClass::PostfixNumeric,
+            PR => LineBreakClass::PrefixNumeric,
+            QU => LineBreakClass::Quotation,
+            RI => LineBreakClass::RegionalIndicator,
+            SA => LineBreakClass::ComplexContext,
+            SG => LineBreakClass::Surrogate,
+            SP => LineBreakClass::Space,
+            SY => LineBreakClass::BreakSymbols,
+            WJ => LineBreakClass::WordJoiner,
+            ZW => LineBreakClass::ZWSpace,
+            ZWJ => LineBreakClass::ZWJ,
+            _ => LineBreakClass::Unknown,
+        }
+    }
+
+    // Opening punctuation is split by East Asian Width in the property table.
+    // The generic one is used for the rule table.
+    fn to_property(self) -> u8 {
+        match self {
+            LineBreakClass::Ambiguous => AI,
+            LineBreakClass::Alphabetic => AL,
+            LineBreakClass::BreakBoth => B2,
+            LineBreakClass::BreakAfter => BA,
+            LineBreakClass::BreakBefore => BB,
+            LineBreakClass::MandatoryBreak => BK,
+            LineBreakClass::ContingentBreak => CB,
+            LineBreakClass::ConditionalJapaneseStarter => CJ,
+            LineBreakClass::ClosePunctuation => CL,
+            LineBreakClass::CombiningMark => CM,
+            LineBreakClass::CloseParenthesis => CP,
+            LineBreakClass::CarriageReturn => CR,
+            LineBreakClass::EBase => EB,
+            LineBreakClass::EModifier => EM,
+            LineBreakClass::Exclamation => EX,
+            LineBreakClass::Glue => GL,
+            LineBreakClass::H2 => H2,
+            LineBreakClass::H3 => H3,
+            LineBreakClass::HebrewLetter => HL,
+            LineBreakClass::Hyphen => HY,
+            LineBreakClass::Ideographic => ID,
+            LineBreakClass::Inseparable => IN,
+            LineBreakClass::InfixNumeric => IS,
+            LineBreakClass::JL => JL,
+            LineBreakClass::JT => JT,
+            LineBreakClass::JV => JV,
+            LineBreakClass::LineFeed => LF,
+            LineBreakClass::NextLine => NL,
+            LineBreakClass::Nonstarter => NS,
+            LineBreakClass::Numeric => NU,
+            LineBreakClass::OpenPunctuation => OP_OP30,
+            LineBreakClass::PostfixNumeric => PO,
+            LineBreakClass::PrefixNumeric => PR,
+            LineBreakClass::Quotation => QU,
+            LineBreakClass::RegionalIndicator => RI,
+            LineBreakClass::ComplexContext => SA,
+            LineBreakClass::Surrogate => SG,
+            LineBreakClass::Space => SP,
+            LineBreakClass::BreakSymbols => SY,
+            LineBreakClass::WordJoiner => WJ,
+            LineBreakClass::Unknown => XX,
+            LineBreakClass::ZWSpace => ZW,
+            LineBreakClass::ZWJ => ZWJ,
+        }
+    }
+}
+
+/// The action of the rule table of the line breaker between two adjacent
+/// characters, returned by [`break_action`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BreakAction {
+    /// There is a break opportunity between the characters.
+    Direct,
+
+    /// There is no break opportunity between the characters.
+    Prohibited,
+
+    /// Whether there is a break opportunity depends on the following
+    /// characters, such as for spaces (LB14 to LB17) or combining marks (LB9).
+    Indirect,
+}
+
+/// Returns the line break class of the character, as resolved by the property
+/// table of the line breaker.
+///
+/// ```rust
+/// use icu_segmenter::{line_break_class, LineBreakClass};
+///
+/// assert_eq!(line_break_class(' '), LineBreakClass::Space);
+/// assert_eq!(line_break_class('\u{4E00}'), LineBreakClass::Ideographic);
+/// ```
+pub fn line_break_class(c: char) -> LineBreakClass {
+    LineBreakClass::from_property(get_linebreak_property_with_rule(
+        c,
+        LineBreakRule::Strict,
+        WordBreakRule::Normal,
+    ))
+}
+
+/// Returns the action of the rule table of the line breaker between a
+/// character of the class `before` and a following character of the class
+/// `after`, to find out which rule applies to them.
+///
+/// This doesn't include the CSS `line-break` and `word-break` rules, nor the
+/// breaking of complex languages such as Thai.
+///
+/// ```rust
+/// use icu_segmenter::{break_action, BreakAction, LineBreakClass};
+///
+/// // LB18: Break after spaces.
+/// assert_eq!(
+///     break_action(LineBreakClass::Space, LineBreakClass::Alphabetic),
+///     BreakAction::Direct
+/// );
+/// ```
+pub fn break_action(before: LineBreakClass, after: LineBreakClass) -> BreakAction {
+    match get_break_state(before.to_property(), after.to_property()) {
+        KEEP_RULE => BreakAction::Prohibited,
+        state if state >= 0 => BreakAction::Indirect,
+        _ => BreakAction::Direct,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{break_action, line_break_class, BreakAction, LineBreakClass};
+
+    #[test]
+    fn class() {
+        assert_eq!(line_break_class(' '), LineBreakClass::Space);
+        assert_eq!(line_break_class('\u{4E00}'), LineBreakClass::Ideographic);
+        assert_eq!(line_break_class('\u{20000}'), LineBreakClass::Ideographic);
+        assert_eq!(line_break_class('a'), LineBreakClass::Alphabetic);
+        assert_eq!(line_break_class('('), LineBreakClass::OpenPunctuation);
+        assert_eq!(
+            line_break_class('\u{FF08}'),
+            LineBreakClass::OpenPunctuation
+        );
+        assert_eq!(
+            line_break_class('\u{3041}'),
+            LineBreakClass::ConditionalJapaneseStarter
+        );
+        assert_eq!(line_break_class('\n'), LineBreakClass::LineFeed);
+        assert_eq!(line_break_class('\u{0E01}'), LineBreakClass::ComplexContext);
+        assert_eq!(line_break_class('\u{50005}'), LineBreakClass::Unknown);
+    }
+
+    #[test]
+    fn action() {
+        use BreakAction::*;
+        use LineBreakClass::*;
+
+        for (before, after, expected) in [
+            // LB4
+            (MandatoryBreak, Alphabetic, Direct),
+            // LB5
+            (CarriageReturn, LineFeed, Prohibited),
+            // LB7
+            (Alphabetic, ZWSpace, Prohibited),
+            // LB9
+            (Alphabetic, CombiningMark, Indirect),
+            // LB13
+            (Alphabetic, Exclamation, Prohibited),
+            // LB14
+            (OpenPunctuation, Alphabetic, Prohibited),
+            // LB15
+            (Quotation, Space, Indirect),
+            // LB18
+            (Space, Alphabetic, Direct),
+            // LB28
+            (Alphabetic, Alphabetic, Prohibited),
+            // LB31
+            (Ideographic, Ideographic, Direct),
+        ]
+        .iter()
+        {
+            assert_eq!(
+                break_action(*before, *after),
+                *expected,
+                "{:?} {:?}",
+                before,
+                after
+            );
+        }
+    }
+}
@@ -8,10 +8,12 @@ use crate::language::*;
 use crate::lb_define::*;
 use crate::lstm::*;
 use crate::property_table::*;
+use crate::provider::*;
 use crate::rule_table::*;
 
 use core::char;
 use core::str::CharIndices;
+use serde::{Deserialize, Serialize};
 use unicode_width::UnicodeWidthChar;
 
 /// An enum specifies the strictness of line-breaking rules. It can be passed as
@@ -20,7 +22,7 @@ use unicode_width::UnicodeWidthChar;
 /// Each enum value has the same meaning with respect to the `line-break`
 /// property values in the CSS Text spec. See the details in
 /// <https://drafts.csswg.org/css-text-3/#line-break-property>.
-#[derive(Copy, Clone, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LineBreakRule {
     /// Breaks text using the most common set of line-breaking rules.
     /// <https://drafts.csswg.org/css-text-3/#valdef-line-break-normal>
@@ -48,7 +50,7 @@ pub enum LineBreakRule {
 /// Each enum value has the same meaning with respect to the `word-break`
 /// property values in the CSS Text spec. See the details in
 /// <https://drafts.csswg.org/css-text-3/#word-break-property>
-#[derive(Copy, Clone, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum WordBreakRule {
     /// Words break according to their customary rules. See the details in
     /// <https://drafts.csswg.org/css-text-3/#valdef-word-break-normal>.
@@ -63,14 +65,161 @@ pub enum WordBreakRule {
     KeepAll,
 }
 
+/// An enum specifies how tab characters (U+0009) are treated. It can be set
+/// with [`LineBreakIterator::with_tab_rule`] when creating a line breaker.
+///
+/// Mandatory breaks, such as a line feed following a tab, are not affected.
+#[derive(Copy, Clone, PartialEq)]
+pub enum TabRule {
+    /// Tabs are treated according to their `BA` (break after) line break
+    /// class in UAX #14.
+    Normal,
+
+    /// There is always a break opportunity after a tab, except before a
+    /// mandatory break.
+    BreakAfter,
+
+    /// There are no break opportunities before or after a tab, as if it was a
+    /// word joiner.
+    NoBreak,
+}
+
+/// An enum specifies how zero width spaces (U+200B) are treated. It can be
+/// set with [`LineBreakIterator::with_zwsp_rule`] when creating a line
+/// breaker.
+///
+/// Mandatory breaks, such as a line feed following a zero width space, are
+/// not affected.
+#[derive(Copy, Clone, PartialEq)]
+pub enum ZwspRule {
+    /// Zero width spaces are treated according to their `ZW` line break class
+    /// in UAX #14, which allows a break after them and the following spaces,
+    /// unless the line break rule or the word break rule prevents it.
+    Normal,
+
+    /// There is always a break opportunity after a zero width space, except
+    /// before a mandatory break.
+    BreakAfter,
+
+    /// There are no break opportunities before or after a zero width space, as
+    /// if it was a word joiner.
+    NoBreak,
+}
+
+/// A bundle of the options for creating a line breaker, which can be passed
+/// to [`LineBreakIterator::new_with_options`].
+///
+/// The presets match the values of the `line-break` property in the CSS Text
+/// spec, with `word-break: normal`. Other combinations can be built with the
+/// struct update syntax.
+///
+/// ```rust
+/// use icu_segmenter::{LineBreakIterator, LineBreakOptions};
+///
+/// let options = LineBreakOptions {
+///     ja_zh: true,
+///     ..LineBreakOptions::css_loose()
+/// };
+/// let breaks: Vec<usize> =
+///     LineBreakIterator::new_with_options("\u{30B5}\u{3005}\u{30B5}", options).collect();
+/// assert_eq!(breaks, vec![3, 6, 9]);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LineBreakOptions {
+    /// The strictness of line-breaking rules. See [`LineBreakRule`].
+    pub line_break_rule: LineBreakRule,
+
+    /// The line break opportunities between letters. See [`WordBreakRule`].
+    pub word_break_rule: WordBreakRule,
+
+    /// Use `true` as a hint to the line breaker that the writing system is
+    /// Chinese or Japanese. This allows more break opportunities when
+    /// [`LineBreakRule`] is `Normal` or `Loose`. See
+    /// <https://drafts.csswg.org/css-text-3/#line-break-property> for details.
+    pub ja_zh: bool,
+}
+
+impl Default for LineBreakOptions {
+    /// The options used by [`LineBreakIterator::new`], which follow the
+    /// default rules of UAX #14.
+    fn default() -> Self {
+        Self {
+            line_break_rule: LineBreakRule::Strict,
+            word_break_rule: WordBreakRule::Normal,
+            ja_zh: false,
+        }
+    }
+}
+
+impl LineBreakOptions {
+    /// The options for `line-break: normal`.
+    pub fn css_normal() -> Self {
+        Self {
+            line_break_rule: LineBreakRule::Normal,
+            ..Self::default()
+        }
+    }
+
+    /// The options for `line-break: strict`.
+    pub fn css_strict() -> Self {
+        Self {
+            line_break_rule: LineBreakRule::Strict,
+            ..Self::default()
+        }
+    }
+
+    /// The options for `line-break: loose`.
+    pub fn css_loose() -> Self {
+        Self {
+            line_break_rule: LineBreakRule::Loose,
+            ..Self::default()
+        }
+    }
+
+    /// The options for `line-break: anywhere`.
+    pub fn css_anywhere() -> Self {
+        Self {
+            line_break_rule: LineBreakRule::Anywhere,
+            ..Self::default()
+        }
+    }
+}
+
+const TAB: u32 = 0x09;
+const SPACE: u32 = 0x20;
+const NBSP: u32 = 0xA0;
+const ZWSP: u32 = 0x200B;
+const WORD_JOINER: u32 = 0x2060;
+
 fn get_linebreak_property_utf32_with_rule(
     codepoint: u32,
     line_break_rule: LineBreakRule,
     word_break_rule: WordBreakRule,
 ) -> u8 {
-    if codepoint < 0x20000 {
-        let codepoint = codepoint as usize;
-        let prop = UAX14_PROPERTY_TABLE[codepoint / 1024][(codepoint & 0x3ff)];
+    get_linebreak_property_utf32_with_data(codepoint, line_break_rule, word_break_rule, None)
+}
+
+/// Returns the line break property from the property table of `data`, or
+/// from the built-in one if `data` is `None`.
+#[inline]
+fn get_linebreak_property_from_table(data: Option<&LineBreakDataV1>, codepoint: usize) -> u8 {
+    match data {
+        Some(data) => data.property_table[codepoint],
+        None => {
+            let block = UAX14_PROPERTY_INDEX[codepoint / UAX14_PROPERTY_BLOCK_SIZE];
+            UAX14_PROPERTY_BLOCKS[block as usize][codepoint % UAX14_PROPERTY_BLOCK_SIZE]
+        }
+    }
+}
+
+fn get_linebreak_property_utf32_with_data(
+    codepoint: u32,
+    line_break_rule: LineBreakRule,
+    word_break_rule: WordBreakRule,
+    data: Option<&LineBreakDataV1>,
+) -> u8 {
+    if codepoint < PROPERTY_TABLE_LEN as u32 {
+        let prop = get_linebreak_property_from_table(data, codepoint as usize);
 
         if word_break_rule == WordBreakRule::BreakAll
             || line_break_rule == LineBreakRule::Loose
@@ -98,13 +247,12 @@ fn get_linebreak_property_utf32_with_rule(
 }
 
 #[inline]
-fn get_linebreak_property_latin1(codepoint: u8) -> u8 {
-    let codepoint = codepoint as usize;
-    UAX14_PROPERTY_TABLE[codepoint / 1024][(codepoint & 0x3ff)]
+fn get_linebreak_property_latin1(codepoint: u8, data: Option<&LineBreakDataV1>) -> u8 {
+    get_linebreak_property_from_table(data, codepoint as usize)
 }
 
 #[inline]
-fn get_linebreak_property_with_rule(
+pub(crate) fn get_linebreak_property_with_rule(
     codepoint: char,
     linebreak_rule: LineBreakRule,
     wordbreak_rule: WordBreakRule,
@@ -112,6 +260,15 @@ fn get_linebreak_property_with_rule(
     get_linebreak_property_utf32_with_rule(codepoint as u32, linebreak_rule, wordbreak_rule)
 }
 
+/// Returns `true` if a line must be broken after the character (LB4 and LB5).
+#[inline]
+pub(crate) fn is_mandatory_break_char(c: char) -> bool {
+    matches!(
+        get_linebreak_property_with_rule(c, LineBreakRule::Strict, WordBreakRule::Normal),
+        BK | CR | LF | NL
+    )
+}
+
 #[inline]
 fn is_break_utf32_by_normal(codepoint: u32, ja_zh: bool) -> bool {
     match codepoint as u32 {
@@ -239,10 +396,15 @@ fn get_break_state_from_table(rule_table: &[i8], property_count: usize, left: u8
 }
 
 #[inline]
-fn get_break_state(left: u8, right: u8) -> i8 {
+pub(crate) fn get_break_state(left: u8, right: u8) -> i8 {
     get_break_state_from_table(&UAX14_RULE_TABLE, PROP_COUNT, left, right)
 }
 
+#[inline]
+fn is_joiner(codepoint: u32) -> bool {
+    codepoint == WORD_JOINER || codepoint == NBSP
+}
+
 #[inline]
 fn use_complex_breaking_utf32(codepoint: u32) -> bool {
     let line_break_property = get_linebreak_property_utf32_with_rule(
@@ -264,15 +426,21 @@ fn use_complex_breaking_utf32(codepoint: u32) -> bool {
 */
 
 macro_rules! break_iterator_impl {
-    ($name:ident, $iter_attr:ty, $char_type:ty) => {
+    ($name:ident, $input_type:ty, $iter_attr:ty, $char_type:ty) => {
         #[allow(dead_code)]
         /// The struct implementing the [`Iterator`] trait over the line break
         /// opportunities of the given string. Please see the [module-level
         /// documentation] for its usages.
         ///
+        /// The iterator is [`Send`] and [`Sync`]. The property tables and the
+        /// LSTM models used by it are immutable, and the models are lazily
+        /// initialized in a thread-safe way, so iterators can be created and
+        /// used on multiple threads at the same time.
+        ///
         /// [`Iterator`]: core::iter::Iterator
         /// [module-level documentation]: ../index.html
         pub struct $name<'a> {
+            input: $input_type,
             iter: $iter_attr,
             len: usize,
             current_pos_data: Option<(usize, $char_type)>,
@@ -280,12 +448,52 @@ macro_rules! break_iterator_impl {
             line_break_rule: LineBreakRule,
             word_break_rule: WordBreakRule,
             ja_zh: bool,
+            tab_rule: TabRule,
+            zwsp_rule: ZwspRule,
+            strict_joiners: bool,
+            unbreakable_numbers: bool,
+            no_break_before_closing: bool,
+            trailing_whitespace_break: bool,
+            trailing_whitespace_start: usize,
+            pending_break: Option<usize>,
+            complex_breaking: bool,
+            data: Option<&'a LineBreakDataV1>,
+            reached_eof: bool,
+            last_break: usize,
+            restart_offset: usize,
         }
 
         impl<'a> Iterator for $name<'a> {
             type Item = usize;
 
             fn next(&mut self) -> Option<Self::Item> {
+                let offset = self.next_unrecorded()?;
+                self.last_break = offset;
+                Some(offset)
+            }
+        }
+
+        impl<'a> $name<'a> {
+            fn next_unrecorded(&mut self) -> Option<usize> {
+                if let Some(offset) = self.pending_break.take() {
+                    return Some(offset);
+                }
+                // The iterator is at the last returned break opportunity.
+                let previous = self.current_pos_data.map_or(0, |(offset, _)| offset);
+                let offset = self.next_break()?;
+                let start = self.trailing_whitespace_start;
+                if self.trailing_whitespace_break
+                    && offset == self.len
+                    && previous < start
+                    && start < offset
+                {
+                    self.pending_break = Some(offset);
+                    return Some(start);
+                }
+                Some(offset)
+            }
+
+            fn next_break(&mut self) -> Option<usize> {
                 if self.is_eof() {
                     return None;
                 }
@@ -295,13 +503,13 @@ macro_rules! break_iterator_impl {
                     let mut i = 0;
                     loop {
                         if i == *self.result_cache.first().unwrap() {
-                            self.result_cache.remove(0);
-                            self.result_cache = self.result_cache.iter().map(|r| r - i).collect();
+                            self.take_cached_break(i);
                             return Some(self.current_pos_data.unwrap().0);
                         }
                         self.current_pos_data = self.iter.next();
                         if self.current_pos_data.is_none() {
                             // Reach EOF
+                            self.reached_eof = true;
                             self.result_cache.clear();
                             return Some(self.len);
                         }
@@ -310,14 +518,62 @@ macro_rules! break_iterator_impl {
                 }
 
                 loop {
-                    let mut left_prop = self.get_linebreak_property();
+                    // The following break opportunities only depend on the
+                    // text from the current character.
+                    self.restart_offset = self.current_pos_data.unwrap().0;
+                    let mut left_prop = self.get_linebreak_property_with_char_rules();
                     let left_codepoint = self.current_pos_data;
                     self.current_pos_data = self.iter.next();
                     if self.current_pos_data.is_none() {
                         // EOF
+                        self.reached_eof = true;
                         return Some(self.len);
                     }
-                    let right_prop = self.get_linebreak_property();
+                    let right_prop = self.get_linebreak_property_with_char_rules();
+
+                    if self.strict_joiners
+                        && (is_joiner(u32::from(left_codepoint.unwrap().1))
+                            || (is_joiner(u32::from(self.current_pos_data.unwrap().1))
+                                && !matches!(left_prop, BK | CR | LF | NL)))
+                    {
+                        continue;
+                    }
+
+                    if self.unbreakable_numbers && left_prop == NU {
+                        if right_prop == NU {
+                            continue;
+                        }
+                        // A separator is only part of the number if a digit
+                        // follows it, so that a full stop ending a sentence
+                        // after a number is not.
+                        if right_prop == IS {
+                            let mut next_iter = self.iter.clone();
+                            if let Some(next) = next_iter.next() {
+                                if self.get_linebreak_property_with_rule(next.1) == NU {
+                                    self.iter = next_iter;
+                                    self.current_pos_data = Some(next);
+                                    continue;
+                                }
+                            }
+                        }
+                    }
+
+                    // Every character of a run of closing punctuation is kept
+                    // with the one before it, so that none of them starts a
+                    // line.
+                    if self.no_break_before_closing
+                        && matches!(right_prop, CL | CP)
+                        && !matches!(left_prop, BK | CR | LF | NL)
+                    {
+                        continue;
+                    }
+
+                    let left = u32::from(left_codepoint.unwrap().1);
+                    let break_after = (self.tab_rule == TabRule::BreakAfter && left == TAB)
+                        || (self.zwsp_rule == ZwspRule::BreakAfter && left == ZWSP);
+                    if break_after && !matches!(right_prop, BK | CR | LF | NL) {
+                        return Some(self.current_pos_data.unwrap().0);
+                    }
 
                     // CSS word-break property handling
                     match self.word_break_rule {
@@ -365,7 +621,7 @@ macro_rules! break_iterator_impl {
                     };
 
                     // UAX14 doesn't have Thai etc, so use another way.
-                    if self.word_break_rule != WordBreakRule::BreakAll
+                    if self.complex_breaking
                         && $name::use_complex_breaking(left_codepoint.unwrap().1)
                         && $name::use_complex_breaking(self.current_pos_data.unwrap().1)
                     {
@@ -377,7 +633,7 @@ macro_rules! break_iterator_impl {
                     }
 
                     // If break_state is equals or grater than 0, it is alias of property.
-                    let mut break_state = get_break_state(left_prop, right_prop);
+                    let mut break_state = self.get_break_state_with_data(left_prop, right_prop);
                     if break_state >= 0 as i8 {
                         let mut previous_iter = self.iter.clone();
                         let mut previous_pos_data = self.current_pos_data;
@@ -386,7 +642,9 @@ macro_rules! break_iterator_impl {
                             self.current_pos_data = self.iter.next();
                             if self.current_pos_data.is_none() {
                                 // Reached EOF. But we are analyzing multiple characters now, so next break may be previous point.
-                                let break_state = get_break_state(break_state as u8, EOT);
+                                self.reached_eof = true;
+                                let break_state =
+                                    self.get_break_state_with_data(break_state as u8, EOT);
                                 if break_state == PREVIOUS_BREAK_RULE {
                                     self.iter = previous_iter;
                                     self.current_pos_data = previous_pos_data;
@@ -396,8 +654,8 @@ macro_rules! break_iterator_impl {
                                 return Some(self.len);
                             }
 
-                            let prop = self.get_linebreak_property();
-                            break_state = get_break_state(break_state as u8, prop);
+                            let prop = self.get_linebreak_property_with_char_rules();
+                            break_state = self.get_break_state_with_data(break_state as u8, prop);
                             if break_state < 0 {
                                 break;
                             }
@@ -416,19 +674,184 @@ macro_rules! break_iterator_impl {
                         return Some(self.current_pos_data.unwrap().0);
                     }
 
-                    if is_break(left_prop, right_prop) {
+                    if self.is_break_with_data(left_prop, right_prop) {
                         return Some(self.current_pos_data.unwrap().0);
                     }
                 }
             }
-        }
 
-        impl<'a> $name<'a> {
+            /// Set how tab characters are treated. See [`TabRule`].
+            pub fn with_tab_rule(mut self, tab_rule: TabRule) -> Self {
+                self.tab_rule = tab_rule;
+                self
+            }
+
+            /// Set how zero width spaces are treated. See [`ZwspRule`].
+            pub fn with_zwsp_rule(mut self, zwsp_rule: ZwspRule) -> Self {
+                self.zwsp_rule = zwsp_rule;
+                self
+            }
+
+            /// Set whether there are never break opportunities before or
+            /// after word joiners (U+2060) and no-break spaces (U+00A0), even
+            /// if the line break rule or the word break rule allows them, such
+            /// as with [`LineBreakRule::Anywhere`]. A break after a mandatory
+            /// break is kept.
+            pub fn with_strict_joiners(mut self, strict_joiners: bool) -> Self {
+                self.strict_joiners = strict_joiners;
+                self
+            }
+
+            /// Set whether numbers, which are runs of digits with infix
+            /// separators such as commas and full stops between them (for
+            /// example "1,234.56"), are never broken, even if the line break
+            /// rule or the word break rule allows it. A separator that isn't
+            /// followed by a digit, such as a full stop ending a sentence, is
+            /// not part of the number.
+            pub fn with_unbreakable_numbers(mut self, unbreakable_numbers: bool) -> Self {
+                self.unbreakable_numbers = unbreakable_numbers;
+                self
+            }
+
+            /// Set whether there are never break opportunities before closing
+            /// punctuation, such as `)`, `]` and `」`, so that a line never
+            /// starts with it, even if the line break rule or the word break
+            /// rule allows it. A run of several closing marks is kept together
+            /// with the character before it. A break after a mandatory break
+            /// is kept.
+            pub fn with_no_break_before_closing(mut self, no_break_before_closing: bool) -> Self {
+                self.no_break_before_closing = no_break_before_closing;
+                self
+            }
+
+            /// Set whether there is a break opportunity before the spaces and
+            /// tabs at the end of the text, so that they can hang at the end
+            /// of the last line. There is none if the text is made only of
+            /// spaces and tabs, because there is never a break opportunity at
+            /// the start of the text.
+            pub fn with_trailing_whitespace_break(
+                mut self,
+                trailing_whitespace_break: bool,
+            ) -> Self {
+                self.trailing_whitespace_break = trailing_whitespace_break;
+                self.trailing_whitespace_start = self.find_trailing_whitespace();
+                self
+            }
+
+            /// Set whether runs of complex context scripts, such as Thai and
+            /// Burmese, are broken at the word boundaries found by the LSTM
+            /// word segmenter. UAX #14 doesn't define breaks within these
+            /// scripts, so without it they are only broken as the word break
+            /// rule allows.
+            ///
+            /// This is enabled by default, except with
+            /// [`WordBreakRule::BreakAll`], which allows a break between any
+            /// two letters of these scripts. Enabling it with `BreakAll`
+            /// breaks the other scripts anywhere, and the complex context
+            /// runs at their word boundaries. A run mixing several of these
+            /// scripts is broken between the scripts, and each part with the
+            /// model of its script.
+            pub fn with_complex_breaking(mut self, complex_breaking: bool) -> Self {
+                self.complex_breaking = complex_breaking;
+                self
+            }
+
+            /// Use the line break property data and rule table of `data`
+            /// instead of the ones built into the crate. See
+            /// [`load_line_break_data`].
+            pub fn with_data(mut self, data: &'a LineBreakDataV1) -> Self {
+                self.data = Some(data);
+                self
+            }
+
+            // Restart with a new input, keeping the options and the allocation
+            // of the result cache.
+            // Removes the first cached break opportunity, `offset` characters
+            // after the previous one, and makes the others relative to it.
+            fn take_cached_break(&mut self, offset: usize) {
+                self.result_cache.remove(0);
+                for r in self.result_cache.iter_mut() {
+                    *r -= offset;
+                }
+            }
+
+            fn reset_with_iter(&mut self, input: $input_type, iter: $iter_attr, len: usize) {
+                self.input = input;
+                self.iter = iter;
+                self.len = len;
+                self.current_pos_data = None;
+                self.result_cache.clear();
+                self.pending_break = None;
+                self.reached_eof = false;
+                self.last_break = 0;
+                self.restart_offset = 0;
+                if self.trailing_whitespace_break {
+                    self.trailing_whitespace_start = self.find_trailing_whitespace();
+                }
+            }
+
+            // Resumes breaking a text at a break opportunity, where `cache` is
+            // the result cache of the iterator that returned it. The break
+            // opportunities cached by the complex breaking are counted from
+            // the last returned one, so they don't depend on where the input
+            // starts. The UTF-16 iterator has no owned variant.
+            #[allow(dead_code)]
+            pub(crate) fn with_result_cache(mut self, cache: Vec<usize>) -> Self {
+                self.result_cache = cache;
+                self
+            }
+
+            #[allow(dead_code)]
+            pub(crate) fn into_result_cache(self) -> Vec<usize> {
+                self.result_cache
+            }
+
+            // Returns the offset of the spaces and tabs at the end of the rest
+            // of the text, or its length if it doesn't end with any.
+            fn find_trailing_whitespace(&self) -> usize {
+                let mut start = None;
+                for (offset, c) in self.iter.clone() {
+                    if matches!(u32::from(c), TAB | SPACE) {
+                        start.get_or_insert(offset);
+                    } else {
+                        start = None;
+                    }
+                }
+                start.unwrap_or(self.len)
+            }
+
+            fn get_break_state_with_data(&self, left: u8, right: u8) -> i8 {
+                match self.data {
+                    Some(data) => {
+                        get_break_state_from_table(&data.rule_table, PROP_COUNT, left, right)
+                    }
+                    None => get_break_state(left, right),
+                }
+            }
+
+            fn is_break_with_data(&self, left: u8, right: u8) -> bool {
+                match self.data {
+                    Some(data) => is_break_from_table(&data.rule_table, PROP_COUNT, left, right),
+                    None => is_break(left, right),
+                }
+            }
+
+            fn get_linebreak_property_with_char_rules(&mut self) -> u8 {
+                let codepoint = u32::from(self.current_pos_data.unwrap().1);
+                if (self.tab_rule == TabRule::NoBreak && codepoint == TAB)
+                    || (self.zwsp_rule == ZwspRule::NoBreak && codepoint == ZWSP)
+                {
+                    return WJ;
+                }
+                self.get_linebreak_property()
+            }
+
             #[inline]
             fn is_eof(&mut self) -> bool {
                 if self.current_pos_data.is_none() {
                     self.current_pos_data = self.iter.next();
                     if self.current_pos_data.is_none() {
+                        self.reached_eof = true;
                         return true;
                     }
                 }
@@ -445,6 +868,7 @@ macro_rules! break_iterator_impl {
                     s.push(self.current_pos_data.unwrap().1 as u16);
                     self.current_pos_data = self.iter.next();
                     if self.current_pos_data.is_none() {
+                        self.reached_eof = true;
                         break;
                     }
                     if !$name::use_complex_breaking(self.current_pos_data.unwrap().1) {
@@ -456,16 +880,18 @@ macro_rules! break_iterator_impl {
                 self.current_pos_data = start_point;
                 let breaks = self.get_line_break_by_platform_fallback(&s);
                 let mut i = 1;
-                self.result_cache = breaks;
+                // Keep the buffer of the cache, which is reused after a reset.
+                self.result_cache.clear();
+                self.result_cache.extend_from_slice(&breaks);
                 // result_cache vector is utf-16 index that is in BMP.
                 loop {
                     if i == *self.result_cache.first().unwrap() {
-                        self.result_cache.remove(0);
-                        self.result_cache = self.result_cache.iter().map(|r| r - i).collect();
+                        self.take_cached_break(i);
                         return Some(self.current_pos_data.unwrap().0);
                     }
                     self.current_pos_data = self.iter.next();
                     if self.current_pos_data.is_none() {
+                        self.reached_eof = true;
                         self.result_cache.clear();
                         return Some(self.len);
                     }
@@ -476,20 +902,12 @@ macro_rules! break_iterator_impl {
     };
 }
 
-break_iterator_impl!(LineBreakIterator, CharIndices<'a>, char);
+break_iterator_impl!(LineBreakIterator, &'a str, CharIndices<'a>, char);
 
 impl<'a> LineBreakIterator<'a> {
     /// Create a line break iterator for an `str` (a UTF-8 string).
     pub fn new(input: &str) -> LineBreakIterator {
-        LineBreakIterator {
-            iter: input.char_indices(),
-            len: input.len(),
-            current_pos_data: None,
-            result_cache: Vec::new(),
-            line_break_rule: LineBreakRule::Strict,
-            word_break_rule: WordBreakRule::Normal,
-            ja_zh: false,
-        }
+        Self::new_with_options(input, LineBreakOptions::default())
     }
 
     /// Create line break iterator with CSS rules for an `str` (a UTF-8 string).
@@ -504,23 +922,165 @@ impl<'a> LineBreakIterator<'a> {
         word_break_rule: WordBreakRule,
         ja_zh: bool,
     ) -> LineBreakIterator {
+        Self::new_with_options(
+            input,
+            LineBreakOptions {
+                line_break_rule,
+                word_break_rule,
+                ja_zh,
+            },
+        )
+    }
+
+    /// Create a line break iterator with [`LineBreakOptions`] for an `str` (a
+    /// UTF-8 string).
+    pub fn new_with_options(input: &str, options: LineBreakOptions) -> LineBreakIterator<'_> {
         LineBreakIterator {
+            input,
             iter: input.char_indices(),
             len: input.len(),
             current_pos_data: None,
             result_cache: Vec::new(),
-            line_break_rule,
-            word_break_rule,
-            ja_zh,
+            line_break_rule: options.line_break_rule,
+            word_break_rule: options.word_break_rule,
+            ja_zh: options.ja_zh,
+            tab_rule: TabRule::Normal,
+            zwsp_rule: ZwspRule::Normal,
+            strict_joiners: false,
+            unbreakable_numbers: false,
+            no_break_before_closing: false,
+            trailing_whitespace_break: false,
+            trailing_whitespace_start: 0,
+            pending_break: None,
+            complex_breaking: options.word_break_rule != WordBreakRule::BreakAll,
+            data: None,
+            reached_eof: false,
+            last_break: 0,
+            restart_offset: 0,
         }
     }
 
+    /// Create an iterator over the line break opportunities of an `str` (a
+    /// UTF-8 string) in the byte range `start..end` only, such as for breaking
+    /// again a changed region of a document.
+    ///
+    /// The line break rules depend on the preceding characters, so the text is
+    /// broken from the beginning of the paragraph containing `start`, that is
+    /// after the last mandatory break before it, and the break opportunities
+    /// before `start` are skipped. The returned offsets are the same as
+    /// [`LineBreakIterator::new`] returns for the whole text.
+    ///
+    /// There are no break opportunities inside a grapheme cluster or a
+    /// character, so `start` and `end` may point into one.
+    ///
+    /// ```rust
+    /// use icu_segmenter::LineBreakIterator;
+    ///
+    /// let text = "The quick\nbrown fox";
+    /// let result: Vec<usize> = LineBreakIterator::new_in_range(text, 5, 19).collect();
+    /// assert_eq!(result, vec![10, 16]);
+    /// ```
+    pub fn new_in_range(input: &'a str, start: usize, end: usize) -> LineBreakRangeIterator<'a> {
+        let mut char_start = start.min(input.len());
+        while !input.is_char_boundary(char_start) {
+            char_start -= 1;
+        }
+        let offset = match input[..char_start]
+            .char_indices()
+            .rev()
+            .find(|&(_, c)| is_mandatory_break_char(c))
+        {
+            // There is no break opportunity between CR and LF.
+            Some((index, '\r')) if input[index..].starts_with("\r\n") => index + 2,
+            Some((index, c)) => index + c.len_utf8(),
+            None => 0,
+        };
+        LineBreakRangeIterator {
+            iter: LineBreakIterator::new(&input[offset..]),
+            // The mandatory break itself isn't returned by the iterator.
+            pending_break: Some(offset).filter(|&offset| offset > 0 && offset >= start),
+            offset,
+            start,
+            end,
+        }
+    }
+
+    /// Reuse the iterator for another `str` (a UTF-8 string), with the same
+    /// rules, options and data.
+    ///
+    /// The new string may be borrowed for a shorter lifetime than the
+    /// previous one. The buffer caching the break opportunities found by the
+    /// complex breaking of Thai and Burmese is kept, so it isn't reallocated
+    /// for each string, although the complex breaking itself still allocates.
+    ///
+    /// ```rust
+    /// use icu_segmenter::LineBreakIterator;
+    ///
+    /// let mut iter = LineBreakIterator::new("hello world");
+    /// assert_eq!(iter.by_ref().collect::<Vec<usize>>(), vec![6, 11]);
+    /// let text = String::from("foo bar");
+    /// let iter = iter.reset(&text);
+    /// assert_eq!(iter.collect::<Vec<usize>>(), vec![4, 7]);
+    /// ```
+    pub fn reset<'b>(self, input: &'b str) -> LineBreakIterator<'b>
+    where
+        'a: 'b,
+    {
+        let mut iter: LineBreakIterator<'b> = self;
+        iter.reset_with_iter(input, input.char_indices(), input.len());
+        iter
+    }
+
+    /// Returns an iterator over the line break opportunities that also yields
+    /// the segment of the text ending at each of them, so the segments don't
+    /// need to be sliced from the text afterwards. The last segment ends at
+    /// the end of the text.
+    ///
+    /// The first segment starts at the last break opportunity returned by
+    /// this iterator, or at the beginning of the text if none was returned
+    /// since it was created or [reset].
+    ///
+    /// ```rust
+    /// use icu_segmenter::LineBreakIterator;
+    ///
+    /// let segments: Vec<(usize, &str)> =
+    ///     LineBreakIterator::new("hello world").with_segments().collect();
+    /// assert_eq!(segments, vec![(6, "hello "), (11, "world")]);
+    /// ```
+    ///
+    /// [reset]: LineBreakIterator::reset
+    pub fn with_segments(self) -> LineBreakSegments<'a> {
+        LineBreakSegments {
+            text: self.input,
+            start: self.last_break,
+            breaks: self,
+        }
+    }
+
+    /// Returns `true` if the break opportunities returned so far don't depend
+    /// on where the input ends.
+    pub(crate) fn is_independent_of_eof(&self) -> bool {
+        !self.reached_eof
+    }
+
+    /// Returns the offset from which a new iterator yields the same break
+    /// opportunities as this iterator after it, which is the furthest one
+    /// reached so far.
+    pub(crate) fn restart_offset(&self) -> usize {
+        self.restart_offset
+    }
+
     fn get_linebreak_property(&mut self) -> u8 {
         self.get_linebreak_property_with_rule(self.current_pos_data.unwrap().1)
     }
 
     fn get_linebreak_property_with_rule(&mut self, c: char) -> u8 {
-        get_linebreak_property_with_rule(c, self.line_break_rule, self.word_break_rule)
+        get_linebreak_property_utf32_with_data(
+            c as u32,
+            self.line_break_rule,
+            self.word_break_rule,
+            self.data,
+        )
     }
 
     fn is_break_by_normal(&mut self) -> bool {
@@ -562,13 +1122,120 @@ impl<'a> LineBreakIterator<'a> {
     */
 }
 
+/// An iterator over the line break opportunities of an `str` (a UTF-8
+/// string) and the segments ending at them, returned by
+/// [`LineBreakIterator::with_segments`].
+///
+/// Each item is the byte offset of a break opportunity and the text from the
+/// previous break opportunity, or from the beginning of the text, to it.
+pub struct LineBreakSegments<'a> {
+    text: &'a str,
+    start: usize,
+    breaks: LineBreakIterator<'a>,
+}
+
+impl<'a> Iterator for LineBreakSegments<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.breaks.next()?;
+        let segment = &self.text[self.start..offset];
+        self.start = offset;
+        Some((offset, segment))
+    }
+}
+
+/// An iterator over the line break opportunities of an `str` (a UTF-8
+/// string) in a byte range, returned by [`LineBreakIterator::new_in_range`].
+pub struct LineBreakRangeIterator<'a> {
+    iter: LineBreakIterator<'a>,
+    pending_break: Option<usize>,
+    offset: usize,
+    start: usize,
+    end: usize,
+}
+
+impl<'a> Iterator for LineBreakRangeIterator<'a> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        loop {
+            let offset = match self.pending_break.take() {
+                Some(offset) => offset,
+                None => self.offset + self.iter.next()?,
+            };
+            if offset >= self.end {
+                return None;
+            }
+            if offset >= self.start {
+                return Some(offset);
+            }
+        }
+    }
+}
+
+/// Returns the number of line break opportunities in an `str` (a UTF-8
+/// string), including the mandatory breaks and the end of the string.
+///
+/// This is the same as counting the items of a [`LineBreakIterator`].
+pub fn count_breaks(input: &str) -> usize {
+    LineBreakIterator::new(input).count()
+}
+
+/// Clears `breaks` and fills it with the line break opportunities in an `str`
+/// (a UTF-8 string). The allocation of `breaks` is reused, so a buffer can be
+/// kept across calls to avoid allocating for every string.
+///
+/// This is the same as collecting the items of a [`LineBreakIterator`].
+///
+/// ```rust
+/// use icu_segmenter::collect_breaks_into;
+///
+/// let mut breaks = Vec::new();
+/// collect_breaks_into("hello world", &mut breaks);
+/// assert_eq!(breaks, vec![6, 11]);
+/// collect_breaks_into("foo", &mut breaks);
+/// assert_eq!(breaks, vec![3]);
+/// ```
+pub fn collect_breaks_into(input: &str, breaks: &mut Vec<usize>) {
+    breaks.clear();
+    breaks.extend(LineBreakIterator::new(input));
+}
+
+/// Returns `true` if an `str` (a UTF-8 string) has a mandatory line break,
+/// such as after a line feed, a carriage return, a next line (U+0085), a line
+/// separator (U+2028) or a paragraph separator (U+2029), as in LB4 and LB5.
+///
+/// A trailing newline counts, so this can check that a string is a single
+/// line. It stops at the first mandatory break, without running the line break
+/// rules over the rest of the string.
+///
+/// ```rust
+/// use icu_segmenter::contains_mandatory_break;
+///
+/// assert!(!contains_mandatory_break("a title"));
+/// assert!(contains_mandatory_break("a title\u{2029}"));
+/// ```
+pub fn contains_mandatory_break(input: &str) -> bool {
+    input.chars().any(is_mandatory_break_char)
+}
+
 /// Latin-1 version of line break iterator.
 #[derive(Clone)]
-struct Latin1Indices<'a> {
+pub(crate) struct Latin1Indices<'a> {
     front_offset: usize,
     iter: &'a [u8],
 }
 
+impl<'a> Latin1Indices<'a> {
+    pub(crate) fn new(input: &'a [u8]) -> Self {
+        Self {
+            front_offset: 0,
+            iter: input,
+        }
+    }
+}
+
 impl<'a> Iterator for Latin1Indices<'a> {
     type Item = (usize, u8);
 
@@ -584,23 +1251,12 @@ impl<'a> Iterator for Latin1Indices<'a> {
     }
 }
 
-break_iterator_impl!(LineBreakIteratorLatin1, Latin1Indices<'a>, u8);
+break_iterator_impl!(LineBreakIteratorLatin1, &'a [u8], Latin1Indices<'a>, u8);
 
 impl<'a> LineBreakIteratorLatin1<'a> {
     /// Create a line break iterator for a Latin-1 (8-bit) string.
     pub fn new(input: &[u8]) -> LineBreakIteratorLatin1 {
-        LineBreakIteratorLatin1 {
-            iter: Latin1Indices {
-                front_offset: 0,
-                iter: input,
-            },
-            len: input.len(),
-            current_pos_data: None,
-            result_cache: Vec::new(),
-            line_break_rule: LineBreakRule::Strict,
-            word_break_rule: WordBreakRule::Normal,
-            ja_zh: false,
-        }
+        Self::new_with_options(input, LineBreakOptions::default())
     }
 
     /// Create a line break iterator with CSS rules for a Latin-1 (8-bit)
@@ -610,20 +1266,58 @@ impl<'a> LineBreakIteratorLatin1<'a> {
         line_break_rule: LineBreakRule,
         word_break_rule: WordBreakRule,
     ) -> LineBreakIteratorLatin1 {
-        LineBreakIteratorLatin1 {
-            iter: Latin1Indices {
-                front_offset: 0,
-                iter: input,
+        Self::new_with_options(
+            input,
+            LineBreakOptions {
+                line_break_rule,
+                word_break_rule,
+                ja_zh: false,
             },
+        )
+    }
+
+    /// Create a line break iterator with [`LineBreakOptions`] for a Latin-1
+    /// (8-bit) string.
+    pub fn new_with_options(
+        input: &[u8],
+        options: LineBreakOptions,
+    ) -> LineBreakIteratorLatin1<'_> {
+        LineBreakIteratorLatin1 {
+            input,
+            iter: Latin1Indices::new(input),
             len: input.len(),
             current_pos_data: None,
             result_cache: Vec::new(),
-            line_break_rule,
-            word_break_rule,
-            ja_zh: false,
+            line_break_rule: options.line_break_rule,
+            word_break_rule: options.word_break_rule,
+            ja_zh: options.ja_zh,
+            tab_rule: TabRule::Normal,
+            zwsp_rule: ZwspRule::Normal,
+            strict_joiners: false,
+            unbreakable_numbers: false,
+            no_break_before_closing: false,
+            trailing_whitespace_break: false,
+            trailing_whitespace_start: 0,
+            pending_break: None,
+            complex_breaking: options.word_break_rule != WordBreakRule::BreakAll,
+            data: None,
+            reached_eof: false,
+            last_break: 0,
+            restart_offset: 0,
         }
     }
 
+    /// Reuse the iterator for another Latin-1 (8-bit) string, with the same
+    /// rules and options. See [`LineBreakIterator::reset`].
+    pub fn reset<'b>(self, input: &'b [u8]) -> LineBreakIteratorLatin1<'b>
+    where
+        'a: 'b,
+    {
+        let mut iter: LineBreakIteratorLatin1<'b> = self;
+        iter.reset_with_iter(input, Latin1Indices::new(input), input.len());
+        iter
+    }
+
     fn get_linebreak_property(&mut self) -> u8 {
         // No CJ on Latin1
         self.get_linebreak_property_with_rule(self.current_pos_data.unwrap().1)
@@ -631,7 +1325,7 @@ impl<'a> LineBreakIteratorLatin1<'a> {
 
     fn get_linebreak_property_with_rule(&mut self, c: u8) -> u8 {
         // No CJ on Latin1
-        get_linebreak_property_latin1(c)
+        get_linebreak_property_latin1(c, self.data)
     }
 
     fn is_break_by_normal(&mut self) -> bool {
@@ -650,11 +1344,20 @@ impl<'a> LineBreakIteratorLatin1<'a> {
 
 /// UTF-16 version of line break iterator.
 #[derive(Clone)]
-struct Utf16Indices<'a> {
+pub(crate) struct Utf16Indices<'a> {
     front_offset: usize,
     iter: &'a [u16],
 }
 
+impl<'a> Utf16Indices<'a> {
+    pub(crate) fn new(input: &'a [u16]) -> Self {
+        Self {
+            front_offset: 0,
+            iter: input,
+        }
+    }
+}
+
 impl<'a> Iterator for Utf16Indices<'a> {
     type Item = (usize, u32);
 
@@ -684,23 +1387,12 @@ impl<'a> Iterator for Utf16Indices<'a> {
     }
 }
 
-break_iterator_impl!(LineBreakIteratorUtf16, Utf16Indices<'a>, u32);
+break_iterator_impl!(LineBreakIteratorUtf16, &'a [u16], Utf16Indices<'a>, u32);
 
 impl<'a> LineBreakIteratorUtf16<'a> {
     /// Create a line break iterator for a UTF-16 string.
     pub fn new(input: &[u16]) -> LineBreakIteratorUtf16 {
-        LineBreakIteratorUtf16 {
-            iter: Utf16Indices {
-                front_offset: 0,
-                iter: input,
-            },
-            len: input.len(),
-            current_pos_data: None,
-            result_cache: Vec::new(),
-            line_break_rule: LineBreakRule::Strict,
-            word_break_rule: WordBreakRule::Normal,
-            ja_zh: false,
-        }
+        Self::new_with_options(input, LineBreakOptions::default())
     }
 
     /// Create a line break iterator with CSS rules for a UTF-16 string.
@@ -715,26 +1407,69 @@ impl<'a> LineBreakIteratorUtf16<'a> {
         word_break_rule: WordBreakRule,
         ja_zh: bool,
     ) -> LineBreakIteratorUtf16 {
-        LineBreakIteratorUtf16 {
-            iter: Utf16Indices {
-                front_offset: 0,
-                iter: input,
+        Self::new_with_options(
+            input,
+            LineBreakOptions {
+                line_break_rule,
+                word_break_rule,
+                ja_zh,
             },
+        )
+    }
+
+    /// Create a line break iterator with [`LineBreakOptions`] for a UTF-16
+    /// string.
+    pub fn new_with_options(
+        input: &[u16],
+        options: LineBreakOptions,
+    ) -> LineBreakIteratorUtf16<'_> {
+        LineBreakIteratorUtf16 {
+            input,
+            iter: Utf16Indices::new(input),
             len: input.len(),
             current_pos_data: None,
             result_cache: Vec::new(),
-            line_break_rule,
-            word_break_rule,
-            ja_zh,
+            line_break_rule: options.line_break_rule,
+            word_break_rule: options.word_break_rule,
+            ja_zh: options.ja_zh,
+            tab_rule: TabRule::Normal,
+            zwsp_rule: ZwspRule::Normal,
+            strict_joiners: false,
+            unbreakable_numbers: false,
+            no_break_before_closing: false,
+            trailing_whitespace_break: false,
+            trailing_whitespace_start: 0,
+            pending_break: None,
+            complex_breaking: options.word_break_rule != WordBreakRule::BreakAll,
+            data: None,
+            reached_eof: false,
+            last_break: 0,
+            restart_offset: 0,
         }
     }
 
+    /// Reuse the iterator for another UTF-16 string, with the same rules and
+    /// options. See [`LineBreakIterator::reset`].
+    pub fn reset<'b>(self, input: &'b [u16]) -> LineBreakIteratorUtf16<'b>
+    where
+        'a: 'b,
+    {
+        let mut iter: LineBreakIteratorUtf16<'b> = self;
+        iter.reset_with_iter(input, Utf16Indices::new(input), input.len());
+        iter
+    }
+
     fn get_linebreak_property(&mut self) -> u8 {
         self.get_linebreak_property_with_rule(self.current_pos_data.unwrap().1)
     }
 
     fn get_linebreak_property_with_rule(&mut self, c: u32) -> u8 {
-        get_linebreak_property_utf32_with_rule(c, self.line_break_rule, self.word_break_rule)
+        get_linebreak_property_utf32_with_data(
+            c,
+            self.line_break_rule,
+            self.word_break_rule,
+            self.data,
+        )
     }
 
     fn is_break_by_normal(&mut self) -> bool {
@@ -757,14 +1492,24 @@ impl<'a> LineBreakIteratorUtf16<'a> {
 
 #[cfg(test)]
 mod tests {
+    use crate::collect_breaks_into;
+    use crate::contains_mandatory_break;
+    use crate::count_breaks;
     use crate::lb_define::*;
+    use crate::line_breaker::get_linebreak_property_from_table;
     use crate::line_breaker::get_linebreak_property_with_rule;
     use crate::line_breaker::is_break;
+    use crate::line_breaker::is_mandatory_break_char;
+    use crate::LineBreakDataV1;
     use crate::LineBreakIterator;
     use crate::LineBreakIteratorLatin1;
     use crate::LineBreakIteratorUtf16;
+    use crate::LineBreakOptions;
     use crate::LineBreakRule;
+    use crate::TabRule;
     use crate::WordBreakRule;
+    use crate::ZwspRule;
+    use crate::PROPERTY_TABLE_LEN;
 
     fn get_linebreak_property(codepoint: char) -> u8 {
         get_linebreak_property_with_rule(codepoint, LineBreakRule::Strict, WordBreakRule::Normal)
@@ -788,6 +1533,69 @@ mod tests {
         assert_eq!(get_linebreak_property('\u{2014}'), B2);
     }
 
+    #[test]
+    fn packed_property_table() {
+        // The properties of every 257th code point of the BMP in the unpacked
+        // table generated from Unicode 14.
+        let bmp = [
+            CM, AL, AL, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, SA, EX, SA, JL, AL, AL, AL, AL,
+            AL, XX, NU, AL, CM, AL, AL, AL, AL, XX, AI, AI, AL, AL, AL, AI, AL, AL, AL, AL, AL, AL,
+            AL, AL, EX, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, AL, AL, AL, AL, AL, SA, AL, H3, H3, H3, H3,
+            H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H2, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+            H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, JT, SG, SG, SG, SG,
+            SG, SG, SG, SG, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, ID, ID, AL, AL, AL, XX, XX,
+        ];
+        for (i, expected) in bmp.iter().enumerate() {
+            let codepoint = i * 257;
+            assert_eq!(
+                get_linebreak_property_from_table(None, codepoint),
+                *expected,
+                "U+{:04X}",
+                codepoint
+            );
+        }
+
+        let astral = [
+            (0x10000, AL),
+            (0x1034A, AL),
+            (0x10A3F, CM),
+            (0x11047, BA),
+            (0x16FE4, GL),
+            (0x1B000, ID),
+            (0x1D7CE, NU),
+            (0x1F1E6, RI),
+            (0x1F3FB, EM),
+            (0x1F466, EB),
+            (0x1F600, ID),
+            (0x1FFFD, ID),
+        ];
+        for (codepoint, expected) in astral.iter() {
+            assert_eq!(
+                get_linebreak_property_from_table(None, *codepoint),
+                *expected,
+                "U+{:04X}",
+                codepoint
+            );
+        }
+
+        // The unpacked table of the data is the same as the packed one.
+        let data = LineBreakDataV1::builtin();
+        for codepoint in 0..PROPERTY_TABLE_LEN {
+            assert_eq!(
+                get_linebreak_property_from_table(Some(&data), codepoint),
+                get_linebreak_property_from_table(None, codepoint),
+                "U+{:04X}",
+                codepoint
+            );
+        }
+    }
+
     #[test]
     #[allow(clippy::bool_assert_comparison)] // clearer when we're testing bools directly
     fn break_rule() {
@@ -876,12 +1684,605 @@ mod tests {
         // LB29
         assert_eq!(is_break(IS, AL), false);
         assert_eq!(is_break(IS, HL), false);
+        // LB30a
+        assert_eq!(is_break(RI, RI), false);
         // LB30b
         assert_eq!(is_break(EB, EM), false);
         // LB31
         assert_eq!(is_break(ID, ID), true);
     }
 
+    #[test]
+    fn count() {
+        for s in [
+            "",
+            "hello world",
+            "a\nb\r\nc\n",
+            "[  abc def",
+            "\u{2014}\u{2014}  \u{2014}\u{2014}123 abc",
+            "ภาษาไทยภาษาไทย",
+        ]
+        .iter()
+        {
+            let breaks: Vec<usize> = LineBreakIterator::new(s).collect();
+            assert_eq!(count_breaks(s), breaks.len(), "{}", s);
+        }
+        assert_eq!(count_breaks("a\nb\r\nc\n"), 3);
+    }
+
+    #[test]
+    fn mandatory_break() {
+        assert!(contains_mandatory_break("a\nb"));
+        assert!(contains_mandatory_break("a\r\nb"));
+        assert!(contains_mandatory_break("a\rb"));
+        assert!(contains_mandatory_break("a\n"));
+        assert!(contains_mandatory_break("\u{000B}\u{000C}"));
+        assert!(contains_mandatory_break("a\u{0085}b"));
+        assert!(contains_mandatory_break("a\u{2028}b"));
+        assert!(contains_mandatory_break("a\u{2029}b"));
+        assert!(!contains_mandatory_break(""));
+        assert!(!contains_mandatory_break("a b"));
+        assert!(!contains_mandatory_break("a\tb\u{200B}c\u{00A0}d"));
+        assert!(!contains_mandatory_break(
+            "\u{0E20}\u{0E32}\u{0E29}\u{0E32}"
+        ));
+
+        // The same as looking for a break after a mandatory break character.
+        for s in ["a b", "a\nb", "a\r\nb", "ab\u{2029}", "\u{2028}"].iter() {
+            let expected = LineBreakIterator::new(s)
+                .any(|offset| s[..offset].ends_with(is_mandatory_break_char));
+            assert_eq!(contains_mandatory_break(s), expected, "{:?}", s);
+        }
+    }
+
+    #[test]
+    fn collect_into() {
+        let mut breaks = Vec::with_capacity(16);
+        let capacity = breaks.capacity();
+        for s in [
+            "hello world",
+            "",
+            "a\nb\r\nc\n",
+            "[  abc def",
+            "\u{2014}\u{2014}  \u{2014}\u{2014}123 abc",
+            "\u{0E20}\u{0E32}\u{0E29}\u{0E32}\u{0E44}\u{0E17}\u{0E22}",
+        ]
+        .iter()
+        {
+            collect_breaks_into(s, &mut breaks);
+            let expected: Vec<usize> = LineBreakIterator::new(s).collect();
+            assert_eq!(breaks, expected, "{}", s);
+            // The buffer is reused rather than reallocated.
+            assert_eq!(breaks.capacity(), capacity, "{}", s);
+        }
+    }
+
+    #[test]
+    fn with_segments() {
+        let segments: Vec<(usize, &str)> =
+            LineBreakIterator::new("a b c").with_segments().collect();
+        assert_eq!(segments, vec![(2, "a "), (4, "b "), (5, "c")]);
+
+        // The segments cover the text.
+        let text = "The quick\nbrown fox";
+        let segments: Vec<&str> = LineBreakIterator::new(text)
+            .with_segments()
+            .map(|(_, segment)| segment)
+            .collect();
+        assert_eq!(segments, vec!["The ", "quick\n", "brown ", "fox"]);
+        assert_eq!(segments.concat(), text);
+
+        assert_eq!(LineBreakIterator::new("").with_segments().next(), None);
+
+        // The segments start at the last returned break opportunity.
+        let mut iter = LineBreakIterator::new("a b c");
+        assert_eq!(iter.next(), Some(2));
+        let segments: Vec<(usize, &str)> = iter.with_segments().collect();
+        assert_eq!(segments, vec![(4, "b "), (5, "c")]);
+
+        let mut iter = LineBreakIterator::new("hello world");
+        assert_eq!(iter.by_ref().count(), 2);
+        let iter = iter.reset("foo bar");
+        let segments: Vec<(usize, &str)> = iter.with_segments().collect();
+        assert_eq!(segments, vec![(4, "foo "), (7, "bar")]);
+    }
+
+    #[test]
+    fn new_in_range() {
+        let inputs = [
+            "The quick\nbrown fox",
+            "ab\r\ncd\r\n\r\nef gh",
+            "abc\u{2028}def\u{0085}ghi \u{000B}jkl",
+            "$10 $10 (abc) \"def\"",
+            "e\u{301}\u{301} x\u{301} \u{1F469}\u{200D}\u{1F469} z",
+            "\u{1F1EF}\u{1F1F5}\u{1F1EF}\u{1F1F5} a",
+            "ภาษาไทยภาษาไทย ภาษาไทย\nabc ภาษาไทย",
+            "\u{30B5}\u{3041}\u{30B5}\u{3002}\u{300C}\u{30B5}",
+            "",
+        ];
+        for text in inputs.iter() {
+            let breaks: Vec<usize> = LineBreakIterator::new(text).collect();
+            for start in 0..=text.len() + 1 {
+                // Breaking the text again for every range is slow with the
+                // complex breaking of Thai, so only some ends are tested.
+                let mut ends = vec![start, start + 1, start + 7, text.len(), text.len() + 1];
+                ends.dedup();
+                for end in ends {
+                    let expected: Vec<usize> = breaks
+                        .iter()
+                        .copied()
+                        .filter(|&offset| offset >= start && offset < end)
+                        .collect();
+                    let result: Vec<usize> =
+                        LineBreakIterator::new_in_range(text, start, end).collect();
+                    assert_eq!(result, expected, "{:?} {}..{}", text, start, end);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn reset() {
+        let inputs = [
+            "hello world",
+            "ภาษาไทยภาษาไทย ภาษาไทย abc",
+            "",
+            "a\tb",
+            "\u{1F3FB} \u{1F3FB}",
+            "ภาษาไทย",
+        ];
+
+        let mut iter = LineBreakIterator::new("");
+        for s in inputs.iter() {
+            iter = iter.reset(s);
+            let expected: Vec<usize> = LineBreakIterator::new(s).collect();
+            assert_eq!(iter.by_ref().collect::<Vec<usize>>(), expected, "{}", s);
+        }
+
+        // The options are kept, and the cache of an unfinished input is dropped.
+        let mut iter = LineBreakIterator::new("ภาษาไทยภาษาไทย").with_tab_rule(TabRule::NoBreak);
+        assert_eq!(iter.next(), Some(12));
+        let iter = iter.reset("a\tb c");
+        assert_eq!(iter.collect::<Vec<usize>>(), vec![4, 5]);
+
+        let mut iter = LineBreakIteratorLatin1::new(b"hello world");
+        assert_eq!(iter.next(), Some(6));
+        let iter = iter.reset(b"foo bar");
+        assert_eq!(iter.collect::<Vec<usize>>(), vec![4, 7]);
+
+        let iter = LineBreakIteratorUtf16::new(&[0x61]);
+        // The new input doesn't need to live as long as the previous one.
+        let utf16: Vec<u16> = "foo bar".encode_utf16().collect();
+        let iter = iter.reset(&utf16);
+        assert_eq!(iter.collect::<Vec<usize>>(), vec![4, 7]);
+    }
+
+    #[test]
+    fn zwsp_rule() {
+        let breaks = |s: &str, zwsp_rule| -> Vec<usize> {
+            LineBreakIterator::new(s)
+                .with_zwsp_rule(zwsp_rule)
+                .collect()
+        };
+
+        // The default rules also break after a zero width space.
+        assert_eq!(breaks("a\u{200B}b", ZwspRule::Normal), vec![4, 5]);
+        assert_eq!(breaks("a\u{200B}b", ZwspRule::BreakAfter), vec![4, 5]);
+        assert_eq!(breaks("a\u{200B}b", ZwspRule::NoBreak), vec![5]);
+
+        // The default rules break after the following spaces.
+        assert_eq!(breaks("a\u{200B} b", ZwspRule::Normal), vec![5, 6]);
+        assert_eq!(breaks("a\u{200B} b", ZwspRule::BreakAfter), vec![4, 5, 6]);
+        assert_eq!(breaks("a\u{200B} b", ZwspRule::NoBreak), vec![5, 6]);
+
+        // Mandatory breaks are not affected.
+        for zwsp_rule in [ZwspRule::Normal, ZwspRule::BreakAfter, ZwspRule::NoBreak].iter() {
+            assert_eq!(breaks("a\u{200B}\nb", *zwsp_rule), vec![5, 6]);
+        }
+
+        let s: Vec<u16> = "a\u{200B}b".encode_utf16().collect();
+        let iter = LineBreakIteratorUtf16::new(&s).with_zwsp_rule(ZwspRule::NoBreak);
+        assert_eq!(iter.collect::<Vec<usize>>(), vec![3]);
+    }
+
+    #[test]
+    fn strict_joiners() {
+        let breaks = |s: &str, strict_joiners| -> Vec<usize> {
+            LineBreakIterator::new_with_break_rule(
+                s,
+                LineBreakRule::Anywhere,
+                WordBreakRule::Normal,
+                false,
+            )
+            .with_strict_joiners(strict_joiners)
+            .collect()
+        };
+
+        assert_eq!(breaks("ab\u{00A0}cd", false), vec![1, 2, 4, 5, 6]);
+        assert_eq!(breaks("ab\u{00A0}cd", true), vec![1, 5, 6]);
+        assert_eq!(breaks("ab\u{2060}cd", false), vec![1, 2, 5, 6, 7]);
+        assert_eq!(breaks("ab\u{2060}cd", true), vec![1, 6, 7]);
+
+        // Mandatory breaks are not affected.
+        assert_eq!(breaks("a\n\u{00A0}b", true), vec![1, 2, 5]);
+
+        // The standard rules already keep them joined.
+        let iter = LineBreakIterator::new("ab\u{00A0}cd \u{2060}ef").with_strict_joiners(true);
+        assert_eq!(
+            iter.collect::<Vec<usize>>(),
+            LineBreakIterator::new("ab\u{00A0}cd \u{2060}ef").collect::<Vec<usize>>()
+        );
+
+        let iter = LineBreakIteratorLatin1::new_with_break_rule(
+            b"ab\xA0cd",
+            LineBreakRule::Anywhere,
+            WordBreakRule::Normal,
+        )
+        .with_strict_joiners(true);
+        assert_eq!(iter.collect::<Vec<usize>>(), vec![1, 4, 5]);
+    }
+
+    #[test]
+    fn unbreakable_numbers() {
+        let breaks = |s: &str, options, unbreakable_numbers| -> Vec<usize> {
+            LineBreakIterator::new_with_options(s, options)
+                .with_unbreakable_numbers(unbreakable_numbers)
+                .collect()
+        };
+        let break_all = LineBreakOptions {
+            word_break_rule: WordBreakRule::BreakAll,
+            ..LineBreakOptions::default()
+        };
+
+        assert_eq!(breaks("1,234.56", break_all, false), vec![2, 3, 4, 6, 7, 8]);
+        assert_eq!(breaks("1,234.56", break_all, true), vec![8]);
+        assert_eq!(
+            breaks("1,234.56", LineBreakOptions::css_anywhere(), true),
+            vec![8]
+        );
+        assert_eq!(
+            breaks("1,234.56", LineBreakOptions::default(), true),
+            vec![8]
+        );
+
+        // A full stop ending a sentence is not part of the number.
+        assert_eq!(breaks("12. 34", break_all, true), vec![4, 6]);
+        assert_eq!(
+            breaks("12.", LineBreakOptions::css_anywhere(), true),
+            vec![2, 3]
+        );
+        assert_eq!(
+            breaks("a1.b", LineBreakOptions::css_anywhere(), true),
+            vec![1, 2, 3, 4]
+        );
+
+        let iter = LineBreakIteratorLatin1::new_with_break_rule(
+            b"1,234.56",
+            LineBreakRule::Anywhere,
+            WordBreakRule::Normal,
+        )
+        .with_unbreakable_numbers(true);
+        assert_eq!(iter.collect::<Vec<usize>>(), vec![8]);
+
+        let s: Vec<u16> = "1,234.56".encode_utf16().collect();
+        let iter =
+            LineBreakIteratorUtf16::new_with_options(&s, break_all).with_unbreakable_numbers(true);
+        assert_eq!(iter.collect::<Vec<usize>>(), vec![8]);
+    }
+
+    #[test]
+    fn no_break_before_closing() {
+        let breaks = |s: &str, options, no_break_before_closing| -> Vec<usize> {
+            LineBreakIterator::new_with_options(s, options)
+                .with_no_break_before_closing(no_break_before_closing)
+                .collect()
+        };
+        let anywhere = LineBreakOptions::css_anywhere();
+
+        // The closing bracket would start a line.
+        assert_eq!(breaks("ab)", anywhere, false), vec![1, 2, 3]);
+        assert_eq!(breaks("ab)", anywhere, true), vec![1, 3]);
+
+        // Stacked closing marks are kept with the character before them.
+        assert_eq!(breaks("a)]\u{300D}b", anywhere, false), vec![1, 2, 3, 6, 7]);
+        assert_eq!(breaks("a)]\u{300D}b", anywhere, true), vec![6, 7]);
+
+        // A break after a zero width space is suppressed too.
+        let iter = LineBreakIterator::new("a\u{200B})")
+            .with_zwsp_rule(ZwspRule::BreakAfter)
+            .with_no_break_before_closing(true);
+        assert_eq!(iter.collect::<Vec<usize>>(), vec![5]);
+
+        // A mandatory break is kept.
+        assert_eq!(
+            breaks("a\n)", LineBreakOptions::default(), true),
+            vec![2, 3]
+        );
+
+        // The default rules never break before closing punctuation.
+        let s = "(abc) [def]";
+        assert_eq!(
+            breaks(s, LineBreakOptions::default(), true),
+            breaks(s, LineBreakOptions::default(), false)
+        );
+
+        let s: Vec<u16> = "ab)".encode_utf16().collect();
+        let iter = LineBreakIteratorUtf16::new_with_options(&s, anywhere)
+            .with_no_break_before_closing(true);
+        assert_eq!(iter.collect::<Vec<usize>>(), vec![1, 3]);
+    }
+
+    #[test]
+    fn trailing_whitespace_break() {
+        let breaks = |s: &str, trailing_whitespace_break| -> Vec<usize> {
+            LineBreakIterator::new(s)
+                .with_trailing_whitespace_break(trailing_whitespace_break)
+                .collect()
+        };
+
+        assert_eq!(breaks("ab cd  ", false), vec![3, 7]);
+        assert_eq!(breaks("ab cd  ", true), vec![3, 5, 7]);
+        assert_eq!(breaks("ab cd\t ", true), vec![3, 5, 7]);
+        assert_eq!(breaks("ab cd", true), vec![3, 5]);
+        assert_eq!(breaks("ab cd  \n", true), vec![3, 8]);
+
+        // There is no break opportunity at the start of the text.
+        assert_eq!(breaks("   ", false), vec![3]);
+        assert_eq!(breaks("   ", true), vec![3]);
+        assert_eq!(breaks("", true), Vec::<usize>::new());
+
+        // There already is a break opportunity after the trailing whitespace.
+        let iter = LineBreakIterator::new_with_options("ab  ", LineBreakOptions::css_anywhere())
+            .with_trailing_whitespace_break(true);
+        assert_eq!(iter.collect::<Vec<usize>>(), vec![1, 2, 3, 4]);
+
+        let mut iter = LineBreakIterator::new("a b ").with_trailing_whitespace_break(true);
+        assert_eq!(iter.by_ref().collect::<Vec<usize>>(), vec![2, 3, 4]);
+        let mut iter = iter.reset("c d");
+        assert_eq!(iter.by_ref().collect::<Vec<usize>>(), vec![2, 3]);
+        let iter = iter.reset("\u{0E20}\u{0E32}\u{0E29}\u{0E32}\u{0E44}\u{0E17}\u{0E22} ");
+        assert_eq!(iter.collect::<Vec<usize>>(), vec![12, 21, 22]);
+
+        let iter = LineBreakIteratorLatin1::new(b"a\xA0b  ").with_trailing_whitespace_break(true);
+        assert_eq!(iter.collect::<Vec<usize>>(), vec![3, 5]);
+
+        let s: Vec<u16> = "ab cd  ".encode_utf16().collect();
+        let iter = LineBreakIteratorUtf16::new(&s).with_trailing_whitespace_break(true);
+        assert_eq!(iter.collect::<Vec<usize>>(), vec![3, 5, 7]);
+    }
+
+    #[test]
+    fn options_presets() {
+        let breaks = |s: &str, options| -> Vec<usize> {
+            LineBreakIterator::new_with_options(s, options).collect()
+        };
+        let ja_zh = |options| LineBreakOptions {
+            ja_zh: true,
+            ..options
+        };
+
+        // A small hiragana letter (CJ) is only breakable before with the
+        // normal and loose rules.
+        let s = "\u{30B5}\u{3041}\u{30B5}";
+        assert_eq!(breaks(s, LineBreakOptions::css_strict()), vec![6, 9]);
+        assert_eq!(breaks(s, LineBreakOptions::css_normal()), vec![3, 6, 9]);
+        assert_eq!(breaks(s, LineBreakOptions::css_loose()), vec![3, 6, 9]);
+        assert_eq!(breaks(s, LineBreakOptions::css_anywhere()), vec![3, 6, 9]);
+
+        // An iteration mark is only breakable before with the loose rule for
+        // Chinese or Japanese.
+        let s = "\u{30B5}\u{3005}\u{30B5}";
+        assert_eq!(breaks(s, ja_zh(LineBreakOptions::css_strict())), vec![6, 9]);
+        assert_eq!(breaks(s, ja_zh(LineBreakOptions::css_normal())), vec![6, 9]);
+        assert_eq!(
+            breaks(s, ja_zh(LineBreakOptions::css_loose())),
+            vec![3, 6, 9]
+        );
+
+        // A wave dash is only breakable before with the normal and loose rules
+        // for Chinese or Japanese.
+        let s = "\u{30B5}\u{301C}\u{30B5}";
+        assert_eq!(breaks(s, LineBreakOptions::css_normal()), vec![6, 9]);
+        assert_eq!(
+            breaks(s, ja_zh(LineBreakOptions::css_normal())),
+            vec![3, 6, 9]
+        );
+
+        // Only the anywhere rule breaks within a word.
+        let s = "a(b) c";
+        assert_eq!(breaks(s, LineBreakOptions::css_strict()), vec![5, 6]);
+        assert_eq!(breaks(s, LineBreakOptions::css_normal()), vec![5, 6]);
+        assert_eq!(breaks(s, LineBreakOptions::css_loose()), vec![5, 6]);
+        assert_eq!(
+            breaks(s, LineBreakOptions::css_anywhere()),
+            vec![1, 2, 3, 4, 5, 6]
+        );
+
+        // The default options are the same as the default iterator.
+        let s = "\u{30B5}\u{3041}\u{30B5} hello, world";
+        assert_eq!(
+            breaks(s, LineBreakOptions::default()),
+            LineBreakIterator::new(s).collect::<Vec<usize>>()
+        );
+        assert_eq!(
+            LineBreakIteratorUtf16::new_with_options(
+                &s.encode_utf16().collect::<Vec<u16>>(),
+                LineBreakOptions::css_anywhere()
+            )
+            .count(),
+            s.chars().count()
+        );
+    }
+
+    #[test]
+    fn complex_breaking() {
+        let breaks = |s: &str, word_break_rule, complex_breaking| -> Vec<usize> {
+            LineBreakIterator::new_with_break_rule(s, LineBreakRule::Strict, word_break_rule, false)
+                .with_complex_breaking(complex_breaking)
+                .collect()
+        };
+
+        // The Thai run gains the word boundaries found by the LSTM model.
+        let s = "hello \u{0E20}\u{0E32}\u{0E29}\u{0E32}\u{0E44}\u{0E17}\u{0E22}\u{0E20}\u{0E32}\u{0E29}\u{0E32}\u{0E44}\u{0E17}\u{0E22} world";
+        assert_eq!(breaks(s, WordBreakRule::Normal, false), vec![6, 49, 54]);
+        assert_eq!(
+            breaks(s, WordBreakRule::Normal, true),
+            vec![6, 18, 27, 39, 48, 49, 54]
+        );
+        assert_eq!(
+            breaks(s, WordBreakRule::KeepAll, true),
+            vec![6, 18, 27, 39, 48, 49, 54]
+        );
+        assert_eq!(
+            LineBreakIterator::new(s).collect::<Vec<usize>>(),
+            vec![6, 18, 27, 39, 48, 49, 54]
+        );
+
+        // With BreakAll, the Latin letters are still broken anywhere.
+        assert_eq!(
+            breaks(s, WordBreakRule::BreakAll, true),
+            vec![1, 2, 3, 4, 6, 18, 27, 39, 48, 49, 50, 51, 52, 53, 54]
+        );
+        assert_eq!(breaks(s, WordBreakRule::BreakAll, false).len(), 24);
+
+        // A run of Thai and Burmese is broken between the scripts.
+        let s = "\u{0E20}\u{0E32}\u{0E29}\u{0E32}\u{0E44}\u{0E17}\u{0E22}\u{1019}\u{103C}\u{1014}\u{103A}\u{1019}\u{102C}";
+        assert_eq!(breaks(s, WordBreakRule::Normal, false), vec![39]);
+        assert_eq!(breaks(s, WordBreakRule::Normal, true), vec![12, 21, 39]);
+
+        let s: Vec<u16> = s.encode_utf16().collect();
+        let iter = LineBreakIteratorUtf16::new_with_break_rule(
+            &s,
+            LineBreakRule::Strict,
+            WordBreakRule::BreakAll,
+            false,
+        )
+        .with_complex_breaking(true);
+        assert_eq!(iter.collect::<Vec<usize>>(), vec![4, 7, 13]);
+    }
+
+    #[test]
+    fn tab_rule() {
+        let breaks = |s: &str, tab_rule| -> Vec<usize> {
+            LineBreakIterator::new(s).with_tab_rule(tab_rule).collect()
+        };
+
+        assert_eq!(breaks("a\t\tb", TabRule::Normal), vec![3, 4]);
+        assert_eq!(breaks("a\t\tb", TabRule::BreakAfter), vec![2, 3, 4]);
+        assert_eq!(breaks("a\t\tb", TabRule::NoBreak), vec![4]);
+
+        assert_eq!(breaks("a\t)", TabRule::Normal), vec![3]);
+        assert_eq!(breaks("a\t)", TabRule::BreakAfter), vec![2, 3]);
+
+        assert_eq!(breaks("a \tb", TabRule::Normal), vec![2, 3, 4]);
+        assert_eq!(breaks("a \tb", TabRule::NoBreak), vec![4]);
+
+        // Mandatory breaks are not affected.
+        for tab_rule in [TabRule::Normal, TabRule::BreakAfter, TabRule::NoBreak].iter() {
+            assert_eq!(breaks("a\t\nb", *tab_rule), vec![3, 4]);
+            assert_eq!(breaks("a\n\tb", *tab_rule)[0], 2);
+        }
+        assert_eq!(breaks("a\n\tb", TabRule::NoBreak), vec![2, 4]);
+
+        let iter = LineBreakIteratorLatin1::new(b"a\t)").with_tab_rule(TabRule::BreakAfter);
+        assert_eq!(iter.collect::<Vec<usize>>(), vec![2, 3]);
+        let s: Vec<u16> = "a\t\tb".encode_utf16().collect();
+        let iter = LineBreakIteratorUtf16::new(&s).with_tab_rule(TabRule::NoBreak);
+        assert_eq!(iter.collect::<Vec<usize>>(), vec![4]);
+    }
+
+    fn assert_send<T: Send>() {}
+    fn assert_sync<T: Sync>() {}
+
+    #[test]
+    fn thread_safety() {
+        assert_send::<LineBreakIterator>();
+        assert_sync::<LineBreakIterator>();
+        assert_send::<LineBreakIteratorLatin1>();
+        assert_sync::<LineBreakIteratorLatin1>();
+        assert_send::<LineBreakIteratorUtf16>();
+        assert_sync::<LineBreakIteratorUtf16>();
+
+        // Initialize the LSTM models from multiple threads at once.
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                std::thread::spawn(|| {
+                    LineBreakIterator::new("ภาษาไทยภาษาไทย").collect::<Vec<usize>>()
+                })
+            })
+            .collect();
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), vec![12, 21, 33, 42]);
+        }
+
+        let iter = LineBreakIterator::new("hello world");
+        let result = std::thread::spawn(move || iter.collect::<Vec<usize>>());
+        assert_eq!(result.join().unwrap(), vec![6, 11]);
+    }
+
+    #[test]
+    fn newlines() {
+        // LB5: CR LF is a single mandatory break after the LF, and a lone CR
+        // or LF is a mandatory break on its own.
+        for (input, expected) in [
+            ("a\nb", vec![2, 3]),
+            ("a\r\nb", vec![3, 4]),
+            ("a\rb", vec![2, 3]),
+            ("a\r\rb", vec![2, 3, 4]),
+            ("a\n\nb", vec![2, 3, 4]),
+            ("a\n\rb", vec![2, 3, 4]),
+            ("a\r\n\r\nb", vec![3, 5, 6]),
+            ("a\r\n", vec![3]),
+            ("\r\n\r", vec![2, 3]),
+        ]
+        .iter()
+        {
+            let result: Vec<usize> = LineBreakIterator::new(input).collect();
+            assert_eq!(&result, expected, "{:?}", input);
+
+            let result: Vec<usize> = LineBreakIteratorLatin1::new(input.as_bytes()).collect();
+            assert_eq!(&result, expected, "{:?}", input);
+
+            let utf16: Vec<u16> = input.encode_utf16().collect();
+            let result: Vec<usize> = LineBreakIteratorUtf16::new(&utf16).collect();
+            assert_eq!(&result, expected, "{:?}", input);
+
+            // Each break but the one at the end of the text is mandatory.
+            for &offset in expected.iter().filter(|&&offset| offset < input.len()) {
+                assert!(input[..offset].ends_with(&['\r', '\n'][..]), "{:?}", input);
+            }
+        }
+    }
+
+    #[test]
+    fn regional_indicator_pairs() {
+        // LB30a: the regional indicators are paired from the start of a run,
+        // so a flag is never broken.
+        let us = "\u{1F1FA}\u{1F1F8}";
+        let jp = "\u{1F1EF}\u{1F1F5}";
+        let flags = [us, jp].concat();
+        let result: Vec<usize> = LineBreakIterator::new(&flags).collect();
+        assert_eq!(result, vec![8, 16]);
+
+        let utf16: Vec<u16> = flags.encode_utf16().collect();
+        let result: Vec<usize> = LineBreakIteratorUtf16::new(&utf16).collect();
+        assert_eq!(result, vec![4, 8]);
+
+        // An unpaired regional indicator is a unit on its own.
+        let result: Vec<usize> = LineBreakIterator::new(&[us, jp, "\u{1F1FA}"].concat()).collect();
+        assert_eq!(result, vec![8, 16, 20]);
+
+        // The pairing restarts after another character.
+        let input = ["\u{1F1FA}", " ", &flags].concat();
+        let result: Vec<usize> = LineBreakIterator::new(&input).collect();
+        assert_eq!(result, vec![5, 13, 21]);
+
+        // A combining mark is part of the preceding regional indicator.
+        let input = [us, "\u{0301}", jp].concat();
+        let result: Vec<usize> = LineBreakIterator::new(&input).collect();
+        assert_eq!(result, vec![10, 18]);
+    }
+
     #[test]
     fn linebreak() {
         let mut iter = LineBreakIterator::new("hello world");
@@ -0,0 +1,178 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::grapheme::GraphemeClusterBreakIterator;
+use crate::line_breaker::*;
+
+/// Fold `text` into lines no wider than `max_width` using the greedy algorithm.
+///
+/// The width of a line candidate is computed by `measure`, not counting its
+/// trailing white spaces. Lines are wrapped at the line break opportunities of
+/// [`LineBreakIterator`], and always at mandatory breaks. If a single segment
+/// is wider than `max_width`, it is returned as an overflowing line of its own.
+///
+/// Each line keeps its trailing white spaces and line feeds, so joining the
+/// lines gives back `text`.
+///
+/// ```rust
+/// use icu_segmenter::fold_lines;
+///
+/// let lines = fold_lines("The quick brown fox", 10.0, |s| s.chars().count() as f32);
+/// assert_eq!(lines, vec!["The quick ", "brown fox"]);
+/// ```
+pub fn fold_lines<F>(text: &str, max_width: f32, measure: F) -> Vec<&str>
+where
+    F: Fn(&str) -> f32,
+{
+    let mut lines = Vec::new();
+    let mut line_start = 0;
+    let mut last_break: Option<usize> = None;
+
+    for offset in LineBreakIterator::new(text) {
+        if let Some(last_break) = last_break {
+            if measure(text[line_start..offset].trim_end()) > max_width {
+                lines.push(&text[line_start..last_break]);
+                line_start = last_break;
+            }
+        }
+
+        if matches!(text[..offset].chars().next_back(), Some(c) if is_mandatory_break_char(c)) {
+            lines.push(&text[line_start..offset]);
+            line_start = offset;
+            last_break = None;
+        } else {
+            last_break = Some(offset);
+        }
+    }
+
+    if line_start < text.len() {
+        lines.push(&text[line_start..]);
+    }
+    lines
+}
+
+/// Returns the byte length of the longest prefix of `text` that ends on a line
+/// break opportunity and is no wider than `max_width`, such as for truncating
+/// `text` to a single line followed by an ellipsis.
+///
+/// The width of a prefix is computed by `measure`, not counting its trailing
+/// white spaces, which are still included in the prefix. The prefix never
+/// extends past a mandatory break.
+///
+/// If even the first segment is wider than `max_width`, this returns 0, or the
+/// length of the first grapheme cluster of `text` if `keep_first_grapheme` is
+/// `true`.
+///
+/// ```rust
+/// use icu_segmenter::longest_prefix;
+///
+/// let text = "The quick brown fox";
+/// let len = longest_prefix(text, 12.0, |s| s.chars().count() as f32, false);
+/// assert_eq!(&text[..len], "The quick ");
+/// ```
+pub fn longest_prefix<F>(text: &str, max_width: f32, measure: F, keep_first_grapheme: bool) -> usize
+where
+    F: Fn(&str) -> f32,
+{
+    let mut prefix_len = 0;
+
+    for offset in LineBreakIterator::new(text) {
+        if measure(text[..offset].trim_end()) > max_width {
+            break;
+        }
+        prefix_len = offset;
+        if matches!(text[..offset].chars().next_back(), Some(c) if is_mandatory_break_char(c)) {
+            break;
+        }
+    }
+
+    if prefix_len == 0 && keep_first_grapheme {
+        prefix_len = GraphemeClusterBreakIterator::new(text).next().unwrap_or(0);
+    }
+    prefix_len
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{fold_lines, longest_prefix};
+
+    fn fold(text: &str, max_width: f32) -> Vec<&str> {
+        fold_lines(text, max_width, |s| s.chars().count() as f32)
+    }
+
+    #[test]
+    fn greedy() {
+        assert_eq!(
+            fold("The quick brown fox jumps over the lazy dog", 10.0),
+            vec![
+                "The quick ",
+                "brown fox ",
+                "jumps over ",
+                "the lazy ",
+                "dog"
+            ]
+        );
+        assert_eq!(fold("", 10.0), Vec::<&str>::new());
+        assert_eq!(fold("short", 10.0), vec!["short"]);
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(
+            fold("a verylongwordhere b", 5.0),
+            vec!["a ", "verylongwordhere ", "b"]
+        );
+        assert_eq!(fold("verylongwordhere", 5.0), vec!["verylongwordhere"]);
+    }
+
+    #[test]
+    fn mandatory_break() {
+        assert_eq!(fold("ab\ncd ef", 10.0), vec!["ab\n", "cd ef"]);
+        assert_eq!(fold("ab\r\n\ncd", 10.0), vec!["ab\r\n", "\n", "cd"]);
+        assert_eq!(
+            fold("The quick brown\nfox", 10.0),
+            vec!["The quick ", "brown\n", "fox"]
+        );
+    }
+
+    #[test]
+    fn prefix() {
+        let prefix = |text: &'static str, max_width: f32, keep_first_grapheme: bool| {
+            let len = longest_prefix(
+                text,
+                max_width,
+                |s| s.chars().count() as f32,
+                keep_first_grapheme,
+            );
+            &text[..len]
+        };
+
+        assert_eq!(prefix("The quick brown fox", 8.0, false), "The ");
+        assert_eq!(prefix("The quick brown fox", 9.0, false), "The quick ");
+        assert_eq!(
+            prefix("The quick brown fox", 100.0, false),
+            "The quick brown fox"
+        );
+        assert_eq!(prefix("The quick\nbrown fox", 100.0, false), "The quick\n");
+        assert_eq!(prefix("", 8.0, true), "");
+
+        // The first segment is wider than the maximum width.
+        assert_eq!(prefix("Supercalifragilistic word", 8.0, false), "");
+        assert_eq!(prefix("Supercalifragilistic word", 8.0, true), "S");
+        assert_eq!(
+            prefix("e\u{301}\u{302}xpos\u{e9}", 2.0, true),
+            "e\u{301}\u{302}"
+        );
+        // Grapheme clusters that aren't combining sequences: a Hangul syllable
+        // of conjoining jamos and an emoji with a skin tone modifier.
+        assert_eq!(
+            prefix("\u{1100}\u{1161}\u{11A8}\u{1100}\u{1161}", 1.0, true),
+            "\u{1100}\u{1161}\u{11A8}"
+        );
+        assert_eq!(
+            prefix("\u{1F44D}\u{1F3FD}\u{1F44D}", 1.0, true),
+            "\u{1F44D}\u{1F3FD}"
+        );
+    }
+}
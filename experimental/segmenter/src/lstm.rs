@@ -175,6 +175,17 @@ pub fn get_line_break_utf8(input: &str) -> Option<Vec<usize>> {
     Some(result)
 }
 
+/// Returns the number of words of a string of Thai or Burmese, as segmented by
+/// the LSTM models.
+pub fn get_word_count(input: &str) -> usize {
+    LanguageIterator::new(input)
+        .map(|str_per_lang| {
+            let lstm = get_best_lstm_model(str_per_lang.chars().next().unwrap() as u32);
+            LstmSegmenterIteratorUtf16::new(lstm, &str_per_lang).count() + 1
+        })
+        .sum()
+}
+
 pub fn get_line_break_utf16(input: &[u16]) -> Option<Vec<usize>> {
     let s: String = decode_utf16(input.iter().cloned())
         .map(|r| r.unwrap())
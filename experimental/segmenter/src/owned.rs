@@ -0,0 +1,190 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::line_breaker::*;
+
+macro_rules! owned_break_iterator_impl {
+    ($name:ident, $iter_name:ident, $owned_type:ty, $input_type:ty) => {
+        /// A line break iterator that owns its text.
+        ///
+        /// Unlike the borrowing iterator, it doesn't borrow the text, so it can
+        /// be stored in a struct or moved around freely. It returns the same
+        /// break opportunities for the same text and options.
+        pub struct $name {
+            text: $owned_type,
+            options: LineBreakOptions,
+            offset: usize,
+            result_cache: Vec<usize>,
+        }
+
+        impl $name {
+            /// Create a line break iterator that owns `text`.
+            pub fn new(text: $owned_type) -> Self {
+                Self::new_with_options(text, LineBreakOptions::default())
+            }
+
+            /// Create a line break iterator with [`LineBreakOptions`] that owns
+            /// `text`.
+            pub fn new_with_options(text: $owned_type, options: LineBreakOptions) -> Self {
+                Self {
+                    text,
+                    options,
+                    offset: 0,
+                    result_cache: Vec::new(),
+                }
+            }
+        }
+
+        impl Iterator for $name {
+            type Item = usize;
+
+            // The rest of the text is broken by a borrowing iterator starting at
+            // the last returned break opportunity, which gets the break
+            // opportunities cached by the previous one, so each character is
+            // only visited once.
+            fn next(&mut self) -> Option<usize> {
+                let input: $input_type = &self.text[self.offset..];
+                let mut iter = $iter_name::new_with_options(input, self.options)
+                    .with_result_cache(core::mem::take(&mut self.result_cache));
+                let next = iter.next();
+                self.result_cache = iter.into_result_cache();
+                self.offset += next?;
+                Some(self.offset)
+            }
+        }
+    };
+}
+
+owned_break_iterator_impl!(OwnedLineBreakIterator, LineBreakIterator, String, &str);
+
+impl OwnedLineBreakIterator {
+    /// Return the text.
+    ///
+    /// ```rust
+    /// use icu_segmenter::OwnedLineBreakIterator;
+    ///
+    /// let iter = OwnedLineBreakIterator::new(String::from("hello world"));
+    /// assert_eq!(iter.as_str(), "hello world");
+    /// let result: Vec<usize> = iter.collect();
+    /// assert_eq!(result, vec![6, 11]);
+    /// ```
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Return the text, dropping the iterator.
+    pub fn into_string(self) -> String {
+        self.text
+    }
+}
+
+owned_break_iterator_impl!(
+    OwnedLineBreakIteratorLatin1,
+    LineBreakIteratorLatin1,
+    Vec<u8>,
+    &[u8]
+);
+
+impl OwnedLineBreakIteratorLatin1 {
+    /// Return the Latin-1 (8-bit) text.
+    ///
+    /// ```rust
+    /// use icu_segmenter::OwnedLineBreakIteratorLatin1;
+    ///
+    /// let iter = OwnedLineBreakIteratorLatin1::new(b"hello world".to_vec());
+    /// assert_eq!(iter.as_bytes(), b"hello world");
+    /// let result: Vec<usize> = iter.collect();
+    /// assert_eq!(result, vec![6, 11]);
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.text
+    }
+
+    /// Return the Latin-1 (8-bit) text, dropping the iterator.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        LineBreakIterator, LineBreakIteratorLatin1, LineBreakOptions, OwnedLineBreakIterator,
+        OwnedLineBreakIteratorLatin1,
+    };
+
+    struct Paragraph {
+        breaks: OwnedLineBreakIterator,
+    }
+
+    #[test]
+    fn same_as_iterator() {
+        let inputs = [
+            "hello world",
+            "$10 $10",
+            "abc\u{0022}  (def",
+            "ab\r\ncd\n\nef",
+            "ภาษาไทยภาษาไทย ภาษาไทย abc",
+            "\u{30B5}\u{3041}\u{30B5}",
+            "",
+        ];
+        for s in inputs.iter() {
+            let expected: Vec<usize> = LineBreakIterator::new(s).collect();
+            let result: Vec<usize> = OwnedLineBreakIterator::new(s.to_string()).collect();
+            assert_eq!(result, expected, "{}", s);
+
+            let options = LineBreakOptions::css_normal();
+            let expected: Vec<usize> = LineBreakIterator::new_with_options(s, options).collect();
+            let result: Vec<usize> =
+                OwnedLineBreakIterator::new_with_options(s.to_string(), options).collect();
+            assert_eq!(result, expected, "{}", s);
+        }
+    }
+
+    #[test]
+    fn same_as_iterator_latin1() {
+        let inputs: [&[u8]; 4] = [b"hello world", b"$10 $10", b"ab\r\ncd\n\nef", b""];
+        for s in inputs.iter() {
+            let expected: Vec<usize> = LineBreakIteratorLatin1::new(s).collect();
+            let result: Vec<usize> = OwnedLineBreakIteratorLatin1::new(s.to_vec()).collect();
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn moved_into_struct() {
+        let text = String::from("hello world, ");
+        let text = text + "and more";
+        let mut paragraph = Paragraph {
+            breaks: OwnedLineBreakIterator::new(text),
+        };
+        assert_eq!(paragraph.breaks.next(), Some(6));
+
+        // The iterator continues after it is moved.
+        let mut moved = paragraph;
+        assert_eq!(
+            moved.breaks.by_ref().collect::<Vec<usize>>(),
+            vec![13, 17, 21]
+        );
+        assert_eq!(moved.breaks.next(), None);
+        assert_eq!(moved.breaks.into_string(), "hello world, and more");
+    }
+
+    #[test]
+    fn keeps_complex_breaks() {
+        // The break opportunities of a Thai run are found at once and cached,
+        // so they are returned one by one across calls.
+        let text = "ภาษาไทยภาษาไทย";
+        let mut iter = OwnedLineBreakIterator::new(text.to_string());
+        let first = iter.next().expect("There is a break in the Thai text");
+        assert!(first < text.len());
+        assert!(!iter.result_cache.is_empty());
+        let mut expected = LineBreakIterator::new(text);
+        assert_eq!(Some(first), expected.next());
+        assert_eq!(
+            iter.collect::<Vec<usize>>(),
+            expected.collect::<Vec<usize>>()
+        );
+    }
+}
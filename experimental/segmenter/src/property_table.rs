@@ -4,136 +4,1927 @@
 
 // This file is generated by generate_properties.py. DO NOT EDIT MANUALLY!
 
-use crate::properties_defines::*;
-use crate::properties_other::*;
+use crate::lb_define::*;
 
-pub const UAX14_PROPERTY_TABLE: [&[u8; 1024]; 128] = [
-    &UAX14_PROPERTIES_0,
-    &UAX14_PROPERTIES_1,
-    &UAX14_PROPERTIES_2,
-    &UAX14_PROPERTIES_3,
-    &UAX14_PROPERTIES_4,
-    &UAX14_PROPERTIES_5,
-    &UAX14_PROPERTIES_6,
-    &UAX14_PROPERTIES_7,
-    &UAX14_PROPERTIES_8,
-    &UAX14_PROPERTIES_9,
-    &UAX14_PROPERTIES_10,
-    &UAX14_PROPERTIES_11,
-    &UAX14_PROPERTIES_12,
-    &UAX14_PROPERTIES_ID,
-    &UAX14_PROPERTIES_ID,
-    &UAX14_PROPERTIES_ID,
-    &UAX14_PROPERTIES_ID,
-    &UAX14_PROPERTIES_ID,
-    &UAX14_PROPERTIES_ID,
-    &UAX14_PROPERTIES_19,
-    &UAX14_PROPERTIES_ID,
-    &UAX14_PROPERTIES_ID,
-    &UAX14_PROPERTIES_ID,
-    &UAX14_PROPERTIES_ID,
-    &UAX14_PROPERTIES_ID,
-    &UAX14_PROPERTIES_ID,
-    &UAX14_PROPERTIES_ID,
-    &UAX14_PROPERTIES_ID,
-    &UAX14_PROPERTIES_ID,
-    &UAX14_PROPERTIES_ID,
-    &UAX14_PROPERTIES_ID,
-    &UAX14_PROPERTIES_ID,
-    &UAX14_PROPERTIES_ID,
-    &UAX14_PROPERTIES_ID,
-    &UAX14_PROPERTIES_ID,
-    &UAX14_PROPERTIES_ID,
-    &UAX14_PROPERTIES_ID,
-    &UAX14_PROPERTIES_ID,
-    &UAX14_PROPERTIES_ID,
-    &UAX14_PROPERTIES_ID,
-    &UAX14_PROPERTIES_40,
-    &UAX14_PROPERTIES_41,
-    &UAX14_PROPERTIES_42,
-    &UAX14_PROPERTIES_43,
-    &UAX14_PROPERTIES_44,
-    &UAX14_PROPERTIES_45,
-    &UAX14_PROPERTIES_46,
-    &UAX14_PROPERTIES_47,
-    &UAX14_PROPERTIES_48,
-    &UAX14_PROPERTIES_49,
-    &UAX14_PROPERTIES_50,
-    &UAX14_PROPERTIES_51,
-    &UAX14_PROPERTIES_52,
-    &UAX14_PROPERTIES_53,
-    &UAX14_PROPERTIES_SG,
-    &UAX14_PROPERTIES_SG,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_62,
-    &UAX14_PROPERTIES_63,
-    &UAX14_PROPERTIES_64,
-    &UAX14_PROPERTIES_65,
-    &UAX14_PROPERTIES_66,
-    &UAX14_PROPERTIES_67,
-    &UAX14_PROPERTIES_68,
-    &UAX14_PROPERTIES_69,
-    &UAX14_PROPERTIES_70,
-    &UAX14_PROPERTIES_71,
-    &UAX14_PROPERTIES_72,
-    &UAX14_PROPERTIES_73,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_76,
-    &UAX14_PROPERTIES_77,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_81,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_90,
-    &UAX14_PROPERTIES_91,
-    &UAX14_PROPERTIES_ID,
-    &UAX14_PROPERTIES_ID,
-    &UAX14_PROPERTIES_ID,
-    &UAX14_PROPERTIES_ID,
-    &UAX14_PROPERTIES_ID,
-    &UAX14_PROPERTIES_97,
-    &UAX14_PROPERTIES_98,
-    &UAX14_PROPERTIES_99,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_108,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_111,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_116,
-    &UAX14_PROPERTIES_117,
-    &UAX14_PROPERTIES_118,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_120,
-    &UAX14_PROPERTIES_XX,
-    &UAX14_PROPERTIES_122,
-    &UAX14_PROPERTIES_123,
-    &UAX14_PROPERTIES_124,
-    &UAX14_PROPERTIES_125,
-    &UAX14_PROPERTIES_126,
-    &UAX14_PROPERTIES_127,
+pub const UAX14_PROPERTY_BLOCK_SIZE: usize = 128;
+
+pub static UAX14_PROPERTY_INDEX: [u8; 1024] = [
+    0, 1, 2, 2, 2, 3, 4, 5, 2, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
+    24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 2, 2, 2, 2, 38, 39, 40, 41, 42, 43, 44,
+    45, 46, 47, 48, 49, 50, 2, 51, 2, 2, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64, 65,
+    66, 67, 68, 69, 2, 2, 2, 70, 2, 2, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85,
+    79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+    79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+    79, 79, 79, 79, 79, 79, 86, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+    79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+    79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+    79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+    79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+    79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+    79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+    79, 79, 79, 87, 79, 79, 79, 79, 79, 79, 79, 79, 88, 2, 2, 89, 90, 2, 91, 92, 93, 94, 95, 96,
+    97, 98, 99, 100, 101, 102, 103, 104, 105, 106, 100, 101, 102, 103, 104, 105, 106, 100, 101,
+    102, 103, 104, 105, 106, 100, 101, 102, 103, 104, 105, 106, 100, 101, 102, 103, 104, 105, 106,
+    100, 101, 102, 103, 104, 105, 106, 100, 101, 102, 103, 104, 105, 106, 100, 101, 102, 103, 104,
+    105, 106, 100, 101, 102, 103, 104, 105, 106, 100, 101, 102, 103, 104, 105, 106, 100, 101, 102,
+    103, 104, 105, 106, 100, 101, 102, 103, 104, 105, 106, 100, 101, 102, 107, 108, 108, 108, 108,
+    108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 108, 109, 109, 109, 109, 109, 109, 109,
+    109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109,
+    109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109,
+    109, 109, 109, 109, 109, 79, 79, 79, 79, 110, 111, 2, 2, 112, 113, 114, 115, 116, 117, 118,
+    119, 120, 121, 109, 122, 123, 124, 2, 125, 126, 109, 2, 2, 127, 109, 128, 129, 130, 131, 132,
+    133, 134, 135, 136, 137, 138, 109, 139, 140, 141, 142, 143, 144, 145, 146, 147, 148, 149, 109,
+    150, 151, 109, 152, 153, 154, 155, 109, 156, 157, 158, 159, 160, 161, 109, 109, 162, 163, 164,
+    165, 109, 166, 109, 167, 2, 2, 2, 2, 2, 2, 2, 168, 169, 2, 170, 109, 109, 109, 109, 109, 109,
+    109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 2, 2, 2, 2, 171,
+    172, 173, 2, 174, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109,
+    109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 2, 2, 2, 175,
+    176, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109,
+    109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109,
+    109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109,
+    109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 2, 2, 2, 2, 177, 178, 179, 180, 109,
+    109, 109, 109, 181, 182, 183, 184, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+    79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+    79, 79, 79, 79, 79, 79, 79, 79, 185, 79, 79, 79, 79, 79, 79, 2, 2, 2, 186, 187, 109, 109, 109,
+    109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109,
+    109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109,
+    109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109,
+    109, 109, 109, 109, 109, 109, 109, 109, 109, 79, 79, 188, 79, 79, 189, 109, 109, 109, 109, 109,
+    109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 190, 191, 109, 109, 109, 109,
+    109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109,
+    109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 109, 2, 192, 193, 194,
+    195, 196, 197, 109, 198, 199, 200, 2, 2, 201, 2, 202, 2, 2, 2, 2, 203, 204, 109, 109, 109, 109,
+    109, 109, 109, 109, 109, 109, 205, 109, 206, 109, 109, 207, 109, 109, 109, 109, 109, 109, 109,
+    109, 109, 109, 2, 208, 209, 109, 109, 109, 109, 109, 210, 211, 212, 109, 213, 214, 109, 109,
+    79, 79, 215, 216, 79, 79, 79, 217, 218, 219, 220, 221, 222, 223, 224, 225, 226, 227, 228, 229,
+    230, 79, 2, 231, 79, 79, 79, 79, 79, 79, 79, 232,
+];
+
+pub static UAX14_PROPERTY_BLOCKS: [[u8; 128]; 233] = [
+    [
+        CM, CM, CM, CM, CM, CM, CM, CM, CM, BA, LF, BK, BK, CR, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, CM, CM, CM, CM, CM, CM, CM, CM, SP, EX, QU, AL, PR, PO, AL, QU, OP_OP30, CP, AL, PR,
+        IS, HY, IS, SY, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, IS, IS, AL, AL, AL, EX, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, OP_OP30, PR, CP, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, OP_OP30, BA, CL, AL, CM,
+    ],
+    [
+        CM, CM, CM, CM, CM, NL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, CM, CM, CM, CM, CM, CM, CM, CM, GL, OP_OP30, PO, PR, PR, PR, AL, AI, AI, AL, AI, QU,
+        AL, BA, AL, AL, PO, PR, AI, AI, BB, AL, AI, AI, AI, AI, AI, QU, AI, AI, AI, OP_OP30, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AI,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AI, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AI, BB, AI, AI, AI, BB, AI, AL, AL, AI, AL, AL, AL, AL, AL, AL, AL, AI, AI, AI, AI,
+        AL, AI, AL, BB, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, GL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        GL, GL, GL, GL, GL, GL, GL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, AL, AL, AL,
+        AL, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL, IS, AL,
+    ],
+    [
+        XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, XX, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, IS, BA, XX, XX, AL, AL, PR, XX, CM, CM, CM, CM, CM, CM,
+        CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, BA, CM, AL, CM, CM, AL, CM,
+        CM, EX, CM, XX, XX, XX, XX, XX, XX, XX, XX, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL,
+        HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, XX, XX, XX, XX, HL, HL, HL, HL,
+        AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, PO, PO, PO, IS, IS, AL, AL, CM, CM, CM, CM, CM, CM, CM,
+        CM, CM, CM, CM, EX, CM, XX, EX, EX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, CM, CM, CM, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, PO, NU, NU, AL, AL, AL, CM, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, EX, AL, CM, CM, CM, CM, CM, CM,
+        CM, AL, AL, CM, CM, CM, CM, CM, CM, AL, AL, CM, CM, AL, CM, CM, CM, CM, AL, AL, NU, NU, NU,
+        NU, NU, NU, NU, NU, NU, NU, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, CM, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, CM, CM, CM, CM, CM, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, CM, CM, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU,
+        NU, NU, NU, NU, NU, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, AL, AL, AL, AL, IS, EX, AL, XX, XX, CM, PR, PR,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM,
+        CM, CM, CM, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, AL, CM, CM, CM, AL, CM, CM, CM, CM, CM,
+        XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM,
+        XX, XX, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, CM, CM, CM, CM, CM, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+    ],
+    [
+        CM, CM, CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, AL, CM, CM, CM, CM, CM, CM, CM,
+        CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, AL, CM, CM, CM, CM, CM, CM, CM, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, CM, CM, BA, BA, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, CM, CM, CM, XX, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, AL, AL, XX, XX, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL,
+        AL, AL, AL, XX, AL, XX, XX, XX, AL, AL, AL, AL, XX, XX, CM, AL, CM, CM, CM, CM, CM, CM, CM,
+        XX, XX, CM, CM, XX, XX, CM, CM, CM, AL, XX, XX, XX, XX, XX, XX, XX, XX, CM, XX, XX, XX, XX,
+        AL, AL, XX, AL, AL, AL, CM, CM, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, AL, AL, PO,
+        PO, AL, AL, AL, AL, AL, PO, AL, PR, AL, AL, CM, XX,
+    ],
+    [
+        XX, CM, CM, CM, XX, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, AL, AL, XX, XX, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL,
+        AL, AL, AL, XX, AL, AL, XX, AL, AL, XX, AL, AL, XX, XX, CM, XX, CM, CM, CM, CM, CM, XX, XX,
+        XX, XX, CM, CM, XX, XX, CM, CM, CM, XX, XX, XX, CM, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL,
+        AL, XX, AL, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, CM, CM, AL,
+        AL, AL, CM, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        XX, CM, CM, CM, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, XX, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL,
+        AL, AL, AL, XX, AL, AL, XX, AL, AL, AL, AL, AL, XX, XX, CM, AL, CM, CM, CM, CM, CM, CM, CM,
+        CM, XX, CM, CM, CM, XX, CM, CM, CM, XX, XX, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, AL, AL, CM, CM, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, AL, PR, XX,
+        XX, XX, XX, XX, XX, XX, AL, CM, CM, CM, CM, CM, CM,
+    ],
+    [
+        XX, CM, CM, CM, XX, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, AL, AL, XX, XX, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL,
+        AL, AL, AL, XX, AL, AL, XX, AL, AL, AL, AL, AL, XX, XX, CM, AL, CM, CM, CM, CM, CM, CM, CM,
+        XX, XX, CM, CM, XX, XX, CM, CM, CM, XX, XX, XX, XX, XX, XX, XX, CM, CM, CM, XX, XX, XX, XX,
+        AL, AL, XX, AL, AL, AL, CM, CM, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, AL, AL, AL,
+        AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        XX, XX, CM, AL, XX, AL, AL, AL, AL, AL, AL, XX, XX, XX, AL, AL, AL, XX, AL, AL, AL, AL, XX,
+        XX, XX, AL, AL, XX, AL, XX, AL, AL, XX, XX, XX, AL, AL, XX, XX, XX, AL, AL, AL, XX, XX, XX,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, CM, CM, CM, CM, CM, XX, XX,
+        XX, CM, CM, CM, XX, CM, CM, CM, CM, XX, XX, AL, XX, XX, XX, XX, XX, XX, CM, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, PR, AL, XX, XX, XX, XX, XX,
+    ],
+    [
+        CM, CM, CM, CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, XX, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, AL, CM, CM, CM, CM, CM, CM, CM,
+        XX, CM, CM, CM, XX, CM, CM, CM, CM, XX, XX, XX, XX, XX, XX, XX, CM, CM, XX, AL, AL, AL, XX,
+        XX, XX, XX, XX, AL, AL, CM, CM, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX,
+        XX, XX, XX, XX, BB, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, CM, CM, CM, BB, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, XX, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, XX, XX, CM, AL, CM, CM, CM, CM, CM, CM, CM,
+        XX, CM, CM, CM, XX, CM, CM, CM, CM, XX, XX, XX, XX, XX, XX, XX, CM, CM, XX, XX, XX, XX, XX,
+        XX, XX, AL, XX, AL, AL, CM, CM, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, AL, AL,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        CM, CM, CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, XX, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, AL, CM, CM, CM, CM, CM, CM, CM,
+        XX, CM, CM, CM, XX, CM, CM, CM, CM, AL, AL, XX, XX, XX, XX, AL, AL, AL, CM, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, CM, CM, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, PO, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        XX, CM, CM, CM, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, XX, XX, AL, AL, AL, AL, AL,
+        AL, AL, XX, XX, XX, CM, XX, XX, XX, XX, CM, CM, CM, CM, CM, CM, XX, CM, XX, CM, CM, CM, CM,
+        CM, CM, CM, CM, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, CM,
+        CM, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        XX, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+        SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+        SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, XX, XX, XX, XX, PR, SA, SA, SA, SA, SA,
+        SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, AL, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, BA, BA,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        XX, SA, SA, XX, SA, XX, SA, SA, SA, SA, SA, XX, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+        SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, XX, SA, XX, SA, SA, SA, SA, SA, SA, SA,
+        SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, XX, XX, SA, SA, SA, SA, SA,
+        XX, SA, XX, SA, SA, SA, SA, SA, SA, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX,
+        SA, SA, SA, SA, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, BB, BB, BB, BB, AL, BB, BB, GL, BB, BB, BA, GL, EX, EX, EX, EX, EX, GL, AL, EX, AL, AL,
+        AL, CM, CM, AL, AL, AL, AL, AL, AL, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, BA, CM, AL, CM, AL, CM, OP_OP30, CL, OP_OP30, CL, CM, CM, AL, AL,
+        AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX,
+        XX, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, BA,
+    ],
+    [
+        CM, CM, CM, CM, CM, BA, CM, CM, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, XX, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, XX, BA, BA, AL, AL, AL, AL, AL,
+        AL, CM, AL, AL, AL, AL, AL, AL, XX, AL, AL, BB, BB, BA, BB, AL, AL, AL, AL, AL, GL, GL, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+        SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+        SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, NU, NU, NU, NU, NU,
+        NU, NU, NU, NU, NU, BA, BA, AL, AL, AL, AL, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+        SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+        SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+    ],
+    [
+        SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, NU, NU, NU, NU, NU, NU, NU,
+        NU, NU, NU, SA, SA, SA, SA, SA, SA, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, XX, AL, XX, XX, XX, XX, XX, AL, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL,
+        JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL,
+        JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL,
+        JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL,
+        JL, JL, JL, JL, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV,
+        JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV,
+    ],
+    [
+        JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV,
+        JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JT, JT, JT, JT, JT, JT,
+        JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT,
+        JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT,
+        JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT,
+        JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, XX, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL, AL, AL, AL, XX, AL, XX, AL, AL,
+        AL, AL, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, XX, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL, AL, AL, AL, XX, AL, XX, AL, AL, AL,
+        AL, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, XX,
+        XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX,
+        XX, CM, CM, CM, AL, BA, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, XX, XX, AL, AL, AL, AL, AL, AL, XX, XX,
+    ],
+    [
+        BA, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        BA, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, OP_OP30, CL, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, BA, BA, BA, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, CM, CM, CM, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, CM, CM, CM, BA, BA, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, XX, CM,
+        CM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+        SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+        SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+        SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, BA, BA, NS, SA, BA, AL, BA, PR,
+        SA, SA, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, EX, EX, BA, BA, BB, AL, EX, EX, AL, CM, CM, CM, GL, XX, NU, NU, NU, NU, NU, NU, NU,
+        NU, NU, NU, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, AL, XX, XX, XX,
+        XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, XX, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, XX, XX,
+        XX, XX, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, XX, XX, XX, XX, AL, XX, XX, XX, EX,
+        EX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+        SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, XX, XX, SA, SA, SA,
+        SA, SA, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+        SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, XX, XX,
+        XX, XX, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+        SA, SA, SA, SA, SA, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, SA, XX,
+        XX, XX, SA, SA, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        CM, CM, CM, CM, CM, XX, XX, AL, AL, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+        SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+        SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+        SA, SA, SA, XX, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+        SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, XX, XX, CM,
+    ],
+    [
+        NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU,
+        NU, NU, NU, XX, XX, XX, XX, XX, XX, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+        XX, XX, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        CM, CM, CM, CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, BA, BA,
+        AL, BA, BA, BA, BA, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX,
+    ],
+    [
+        CM, CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        AL, AL, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, XX, XX, XX, BA, BA, BA, BA, BA, NU, NU, NU, NU, NU,
+        NU, NU, NU, NU, NU, XX, XX, XX, AL, AL, AL, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, BA, BA,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, CM, CM, CM, AL, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, AL, AL, AL, AL, CM, AL, AL, AL, AL, AL,
+        AL, CM, AL, AL, CM, CM, CM, AL, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM,
+        CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, CM, CM, CM, CM, CM, CM, XX, CM, CM, CM, CM, CM,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX,
+        XX, AL, AL, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, XX, XX, AL, AL, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, XX, AL,
+        XX, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL, AL, AL,
+        XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, AL,
+        AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, BB, AL, XX,
+    ],
+    [
+        BA, BA, BA, BA, BA, BA, BA, GL, BA, BA, BA, ZW, CM, ZWJ, CM, CM, BA, GL, BA, BA, B2, AI,
+        AI, AL, QU, QU, OP_OP30, QU, QU, QU, OP_OP30, QU, AI, AI, AL, AL, IN, IN, IN, BA, BK, BK,
+        CM, CM, CM, CM, CM, GL, PO, PO, PO, PO, PO, PO, PO, PO, AL, QU, QU, AI, NS, NS, AL, AL, AL,
+        AL, AL, AL, IS, OP_OP30, CL, NS, NS, NS, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        BA, AL, BA, BA, BA, BA, AL, BA, BA, BA, WJ, AL, AL, AL, AL, XX, CM, CM, CM, CM, CM, CM, CM,
+        CM, CM, CM, AL, AL, XX, XX, AI, AL, AL, AL, AL, AL, AL, AL, AL, OP_OP30, CL, AI,
+    ],
+    [
+        AL, AI, AI, AI, AI, AL, AL, AL, AL, AL, AL, AL, AL, OP_OP30, CL, XX, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, PR, PR, PR, PR, PR, PR, PR, PO, PR, PR, PR, PR,
+        PR, PR, PR, PR, PR, PR, PR, PR, PR, PR, PO, PR, PR, PR, PR, PO, PR, PR, PO, PR, PR, PR, PR,
+        PR, PR, PR, PR, PR, PR, PR, PR, PR, PR, PR, PR, PR, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, PO, AL, AI, AL, AL, AL, PO, AL, AL, AL, AL, AL, AL, AL, AL, AL, AI, AL, AL, PR,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AI, AI, AL, AL, AL, AL, AL, AL, AL, AL, AI, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AI, AI, AL, AL, AL, AL, AL, AI,
+        AL, AL, AI, AL, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AL, AL, AL, AL, AI, AI, AI,
+        AI, AI, AI, AI, AI, AI, AI, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AI, AL, AL, XX, XX, XX, XX, AI, AI, AI, AI, AI, AI, AI,
+        AI, AI, AI, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AI, AL, AI, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AI, AL, AI, AI, AL, AL, AL, AI, AI, AL, AL, AI, AL, AL, AL, AI, AL, AI, PR, PR, AL, AI, AL,
+        AL, AL, AL, AI, AL, AL, AI, AI, AI, AI, AL, AL, AI, AL, AI, AL, AI, AI, AI, AI, AI, AI, AL,
+        AI, AL, AL, AL, AL, AL, AI, AI, AI, AI, AL, AL, AL, AL, AI, AI, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AI, AL, AL, AL, AI, AL, AL, AL, AL, AL, AI, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AI, AI, AL, AL, AI, AI, AI, AI, AL, AL, AI, AI, AL, AL, AI, AI, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AI, AI, AL, AL, AI, AI, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AI, AL,
+        AL, AL, AI, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AI, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AI, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, IN, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, OP_OP30, CL, OP_OP30, CL, AL, AL, AL, AL, AL, AL, AI, AL,
+        AL, AL, AL, AL, AL, AL, ID, ID, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, OP_EA,
+        CL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, ID, ID, ID,
+        ID, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI,
+        AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI,
+    ],
+    [
+        AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI,
+        AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI,
+        AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI,
+        AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI,
+        AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI,
+        AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AL,
+    ],
+    [
+        AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI,
+        AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI,
+        AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI,
+        AI, AI, AI, AI, AI, AI, AI, AL, AL, AL, AL, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI,
+        AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI,
+        AI, AI, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AL, AL, AI, AI, AI, AI, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AI, AI, AL, AI, AI, AI, AI, AI, AI, AI, AL, AL, AL, AL,
+        AL, AL, AL, AL, AI, AI, AL, AL, AI, AI, AL, AL, AL, AL, AI, AI, AL, AL, AI, AI, AL, AL, AL,
+        AL, AI, AI, AI, AL, AL, AI, AL, AL, AI, AI, AI, AI, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AI, AI, AI, AI, AL, AL, AL, AL, AL, AL, AL, AL, AL, AI, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        ID, ID, ID, ID, AL, AI, AI, AL, AL, AI, AL, AL, AL, AL, AI, AI, AL, AL, AL, AL, ID, ID, AI,
+        AI, ID, AL, ID, ID, ID, EB, ID, ID, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, ID, ID, ID, AL, AL, AL, AL, AI, AL, AI, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AI, AI, AL, AI, AI, AI, AL, AI, ID, AI, AI, AL, AI, AI, AL, AI, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, ID,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AI, AI, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, AI, AI, AI, AI, ID, AL, ID, ID, ID, AI, ID, ID, AI, AI, AI, ID, ID, AI, AI,
+        ID, AI, AI, ID, ID, ID, AL, AI, AL, AL, AL, AL, AI, AI, ID, AI, AI, AI, AI, AI, AI, ID, ID,
+        ID, ID, ID, AI, ID, ID, EB, ID, AI, AI, ID, ID, ID,
+    ],
+    [
+        ID, ID, ID, ID, ID, AL, AL, AL, ID, ID, EB, EB, EB, EB, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AI, AL, AL, AL, QU,
+        QU, QU, QU, QU, QU, AL, EX, EX, ID, AL, AL, AL, OP_OP30, CL, OP_OP30, CL, OP_OP30, CL,
+        OP_OP30, CL, OP_OP30, CL, OP_OP30, CL, OP_OP30, CL, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI,
+    ],
+    [
+        AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        OP_OP30, CL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, OP_OP30, CL, OP_OP30, CL, OP_OP30, CL,
+        OP_OP30, CL, OP_OP30, CL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, OP_OP30, CL, OP_OP30, CL, OP_OP30, CL, OP_OP30, CL, OP_OP30, CL, OP_OP30, CL,
+        OP_OP30, CL, OP_OP30, CL, OP_OP30, CL, OP_OP30, CL, OP_OP30, CL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, OP_OP30, CL, OP_OP30, CL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, OP_OP30, CL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AI, AI, AI, AI, AI, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, AL,
+        AL, XX, XX, XX, XX, XX, EX, BA, BA, BA, AL, EX, BA,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, XX, XX, XX, XX, XX, AL,
+        XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, AL, BA, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, CM,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL,
+        AL, XX, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL,
+        AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL,
+        AL, AL, AL, XX, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+    ],
+    [
+        QU, QU, QU, QU, QU, QU, QU, QU, QU, QU, QU, QU, QU, QU, BA, BA, BA, BA, BA, BA, BA, BA, AL,
+        BA, OP_OP30, BA, AL, AL, QU, QU, AL, AL, QU, QU, OP_OP30, CL, OP_OP30, CL, OP_OP30, CL,
+        OP_OP30, CL, BA, BA, BA, BA, EX, AL, BA, BA, AL, BA, BA, AL, AL, AL, AL, AL, B2, B2, BA,
+        BA, BA, AL, BA, BA, OP_OP30, BA, BA, BA, BA, BA, BA, BA, BA, AL, BA, AL, BA, BA, AL, AL,
+        AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, XX, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+    ],
+    [
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, XX, XX, XX, XX,
+    ],
+    [
+        BA, CL, CL, ID, ID, NS, ID, ID, OP_EA, CL, OP_EA, CL, OP_EA, CL, OP_EA, CL, OP_EA, CL, ID,
+        ID, OP_EA, CL, OP_EA, CL, OP_EA, CL, OP_EA, CL, NS, OP_EA, CL, CL, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, CM, CM, CM, CM, CM, CM, ID, ID, ID, ID, ID, CM, ID, ID, ID, ID, ID, NS, NS,
+        ID, ID, ID, XX, CJ, ID, CJ, ID, CJ, ID, CJ, ID, CJ, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, CJ, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+    ],
+    [
+        ID, ID, ID, CJ, ID, CJ, ID, CJ, ID, ID, ID, ID, ID, ID, CJ, ID, ID, ID, ID, ID, ID, CJ, CJ,
+        XX, XX, CM, CM, NS, NS, NS, NS, ID, NS, CJ, ID, CJ, ID, CJ, ID, CJ, ID, CJ, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, CJ, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, CJ, ID, CJ, ID, CJ, ID, ID, ID, ID, ID, ID, CJ, ID, ID, ID, ID,
+        ID, ID, CJ, CJ, ID, ID, ID, ID, NS, CJ, NS, NS, ID,
+    ],
+    [
+        XX, XX, XX, XX, XX, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, XX, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+    ],
+    [
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, XX, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, CJ, CJ, CJ,
+        CJ, CJ, CJ, CJ, CJ, CJ, CJ, CJ, CJ, CJ, CJ, CJ, CJ,
+    ],
+    [
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, XX, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, AI, AI, AI, AI, AI, AI, AI, AI, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+    ],
+    [
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, NS, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+    ],
+    [
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, XX, XX, XX, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, BA, BA,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, BA, EX, BA, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, AL, AL, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM,
+        AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, AL,
+        BA, BA, BA, BA, BA, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, CM, AL, AL, AL, CM, AL, AL, AL, AL, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, AL, AL, AL, AL, CM, XX,
+        XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, PO, AL, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, BB, BB, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, XX, XX, XX, XX, XX, XX, XX, XX, BA, BA, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX,
+        XX, XX, XX, XX, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, BB, AL, AL, CM,
+    ],
+    [
+        NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM,
+        BA, BA, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, AL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL,
+        JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, XX, XX, XX,
+    ],
+    [
+        CM, CM, CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, AL, AL, AL, AL,
+        AL, AL, BA, BA, BA, AL, AL, AL, AL, XX, AL, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX,
+        XX, XX, AL, AL, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, NU, NU, NU,
+        NU, NU, NU, NU, NU, NU, NU, SA, SA, SA, SA, SA, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM,
+        CM, CM, CM, CM, CM, CM, CM, CM, CM, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, CM, AL,
+        AL, AL, AL, AL, AL, AL, AL, CM, CM, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX,
+        AL, BA, BA, BA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+        SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+    ],
+    [
+        SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+        SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+        SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, SA,
+        SA, SA, SA, SA, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, BA, BA, AL,
+        AL, AL, CM, CM, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        XX, AL, AL, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL, AL, AL,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL,
+        AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, BA, CM, CM, XX, XX, NU, NU, NU,
+        NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        H2, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+        H3, H3, H3, H3, H3, H2, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+        H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H2, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+        H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H2, H3, H3, H3, H3, H3, H3, H3,
+        H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H2, H3, H3,
+        H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+    ],
+    [
+        H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H2, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+        H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H2, H3, H3, H3, H3, H3,
+        H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H2,
+        H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+        H3, H3, H3, H3, H2, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+        H3, H3, H3, H3, H3, H3, H3, H3, H3, H2, H3, H3, H3,
+    ],
+    [
+        H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+        H3, H2, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+        H3, H3, H3, H3, H3, H3, H2, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+        H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H2, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+        H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H2, H3, H3, H3, H3, H3, H3,
+        H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+    ],
+    [
+        H3, H3, H3, H3, H3, H3, H3, H3, H2, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+        H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H2, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+        H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H2, H3, H3, H3, H3,
+        H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+        H2, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+        H3, H3, H3, H3, H3, H2, H3, H3, H3, H3, H3, H3, H3,
+    ],
+    [
+        H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H2, H3, H3,
+        H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+        H3, H3, H2, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+        H3, H3, H3, H3, H3, H3, H3, H2, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+        H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H2, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+        H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+    ],
+    [
+        H3, H3, H3, H3, H2, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+        H3, H3, H3, H3, H3, H3, H3, H3, H3, H2, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+        H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H2, H3, H3, H3, H3, H3, H3, H3, H3,
+        H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H2, H3, H3, H3,
+        H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+        H3, H2, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+    ],
+    [
+        H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H2, H3, H3, H3, H3, H3, H3,
+        H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H2, H3,
+        H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+        H3, H3, H3, H2, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+        H3, H3, H3, H3, H3, H3, H3, H3, H2, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+        H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+    ],
+    [
+        H3, H3, H3, H3, H3, H3, H3, H3, H2, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+        H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV,
+        JV, JV, XX, XX, XX, XX, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT,
+        JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT,
+        JT, JT, JT, JT, JT, JT, JT, JT, JT, XX, XX, XX, XX,
+    ],
+    [
+        SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG,
+        SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG,
+        SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG,
+        SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG,
+        SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG,
+        SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG,
+    ],
+    [
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL,
+        AL, XX, XX, XX, XX, XX, HL, CM, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, AL, HL, HL, HL, HL,
+        HL, HL, HL, HL, HL, HL, HL, HL, HL, XX, HL, HL, HL, HL, HL, XX, HL, XX, HL, HL, XX, HL, HL,
+        XX, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CL, OP_OP30, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, PO, AL, XX, XX,
+    ],
+    [
+        CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, IS, CL, CL, IS, IS, EX, EX,
+        OP_EA, CL, IN, XX, XX, XX, XX, XX, XX, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, CM, CM, ID, ID, ID, ID, ID, OP_EA, CL, OP_EA, CL, OP_EA, CL, OP_EA, CL, OP_EA, CL,
+        OP_EA, CL, OP_EA, CL, OP_EA, CL, ID, ID, OP_EA, CL, ID, ID, ID, ID, ID, ID, ID, CL, ID, CL,
+        XX, NS, NS, EX, EX, ID, OP_EA, CL, OP_EA, CL, OP_EA, CL, ID, ID, ID, ID, ID, ID, ID, ID,
+        XX, ID, PR, PO, ID, XX, XX, XX, XX, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, WJ,
+    ],
+    [
+        XX, EX, ID, ID, PR, PO, ID, ID, OP_EA, CL, ID, ID, CL, ID, CL, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, NS, NS, ID, ID, ID, EX, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, OP_EA, ID, CL, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, OP_EA, ID, CL, ID, OP_EA, CL, CL, OP_EA, CL, CL, NS, ID, CJ, CJ, CJ, CJ, CJ, CJ, CJ,
+        CJ, CJ, CJ, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+    ],
+    [
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, NS, NS, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, XX, XX, XX, ID, ID, ID,
+        ID, ID, ID, XX, XX, ID, ID, ID, ID, ID, ID, XX, XX, ID, ID, ID, ID, ID, ID, XX, XX, ID, ID,
+        ID, XX, XX, XX, PO, PR, ID, ID, ID, PR, PR, XX, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, CM, CM, CM, CB, AI, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, XX, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX,
+    ],
+    [
+        BA, BA, BA, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, XX, XX, XX, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, CM, CM, CM, CM, CM, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, XX, BA, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX,
+        XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, BA, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX,
+        XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX,
+        XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, XX, XX, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, XX, XX, XX, AL, XX, XX, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, BA, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        XX, AL, AL, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, XX, XX, XX, BA, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, AL, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, CM, CM, CM, XX, CM, CM, XX, XX, XX, XX, XX, CM, CM, CM, CM, AL, AL, AL, AL, XX, AL, AL,
+        AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, CM, CM, CM, XX, XX, XX, XX, CM, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, BA, BA, BA, BA, BA, BA, BA, BA, AL, XX, XX, XX,
+        XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, XX, XX, XX, XX, AL, AL, AL, AL, AL, BA, BA, BA,
+        BA, BA, BA, IN, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, BA, BA, BA, BA, BA, BA, BA, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX,
+        XX, XX, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL,
+        AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, XX, XX, XX, XX, XX, XX,
+        XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, CM, CM, BA,
+        XX, XX, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX,
+        XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        CM, CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, CM, BA, BA, AL, AL, AL, AL, AL, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, CM,
+    ],
+    [
+        CM, CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, AL, AL, AL, BA, BA, BA, BA, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, AL, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU,
+        NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        CM, CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM,
+        CM, CM, CM, CM, CM, CM, CM, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, BA, BA, BA, BA, AL,
+        CM, CM, AL, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        CM, AL, BB, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        CM, CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, AL, AL, AL, AL,
+        BA, BA, AL, BA, CM, CM, CM, CM, AL, CM, CM, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, AL, BB,
+        AL, BA, BA, BA, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM,
+        CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, BA, BA, AL, BA, BA, AL, CM, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, XX, AL, XX, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, BA, XX, XX, XX, XX,
+        XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, XX, XX, XX, XX, XX, NU, NU, NU,
+        NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        CM, CM, CM, CM, XX, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, AL, AL, XX, XX, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL,
+        AL, AL, AL, XX, AL, AL, XX, AL, AL, AL, AL, AL, XX, CM, CM, AL, CM, CM, CM, CM, CM, CM, CM,
+        XX, XX, CM, CM, XX, XX, CM, CM, CM, XX, XX, AL, XX, XX, XX, XX, XX, XX, CM, XX, XX, XX, XX,
+        XX, AL, AL, AL, AL, AL, CM, CM, XX, XX, CM, CM, CM, CM, CM, CM, CM, XX, XX, XX, CM, CM, CM,
+        CM, CM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, CM, AL, AL, AL, AL, BA, BA, BA, BA, AL, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, BA, BA,
+        XX, AL, CM, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, AL,
+        AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, CM, CM, CM, CM, CM, CM, CM, XX, XX, CM, CM, CM, CM, CM, CM, CM, CM, CM, BB, BA, BA, EX,
+        EX, AL, AL, AL, BA, BA, BA, BA, BA, BA, BA, BA, BA, BA, BA, BA, BA, BA, BA, AL, AL, AL, AL,
+        CM, CM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, BA, BA, AL, AL,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX,
+        XX, XX, XX, XX, BB, BB, BB, BB, BB, BB, BB, BB, BB, BB, BB, BB, BB, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM,
+        CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, AL, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU,
+        NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+        SA, SA, SA, SA, XX, XX, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, XX, XX,
+        XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, SA, SA, BA, BA, BA, SA, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM,
+        CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, XX, XX, AL, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL,
+        XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, CM, CM, CM, CM, CM, CM, XX, CM, CM, XX, XX, CM, CM, CM, CM, AL, CM, AL, CM, CM, BA,
+        BA, BA, XX, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, XX, XX, CM, CM,
+        CM, CM, CM, CM, CM, AL, BB, AL, CM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, AL, CM, CM, CM, CM, BB, AL, BA, BA, BA, BA,
+        BB, AL, CM, XX, XX, XX, XX, XX, XX, XX, XX, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, CM, CM, BA, BA, BA, AL, BB, BB, BB, BA, BA, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, CM, CM, CM, CM, CM, CM, CM, CM, XX, CM, CM, CM, CM, CM, CM, CM, CM, AL, BA, BA, BA, BA,
+        BA, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, BB, EX, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, CM, CM, CM, CM, CM,
+        CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, XX, CM, CM, CM, CM, CM,
+        CM, CM, CM, CM, CM, CM, CM, CM, CM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, CM, CM, CM, CM, CM, CM, XX, XX, XX, CM, XX, CM, CM, XX, CM, CM, CM, CM, CM, CM,
+        CM, AL, CM, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX,
+        XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, XX, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, XX, CM, CM, XX, CM, CM, CM, CM,
+        CM, AL, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        CM, CM, CM, CM, AL, AL, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, PO, PO, PO, PO, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, BA,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, BA, BA, BA,
+        BA, BA, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, OP_OP30,
+        OP_OP30, OP_OP30, CL, CL, CL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, CL, AL, AL, AL, OP_OP30, CL, OP_OP30, CL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, OP_OP30, CL, CL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, XX, GL, GL, GL, GL, GL, GL, GL, OP_OP30, CL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, OP_OP30, CL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, BA, BA, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, CM, CM, CM,
+        CM, CM, BA, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, CM, CM, CM, CM, CM, CM, CM, BA, BA, BA, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, BA,
+        AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, AL,
+        AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        BA, BA, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, CM, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+    ],
+    [
+        CM, CM, CM, CM, CM, CM, CM, CM, XX, XX, XX, XX, XX, XX, XX, CM, CM, CM, CM, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, NS, NS, NS, NS, GL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, CM, CM, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, CJ, CJ, CJ, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, CJ, CJ, CJ, CJ, XX, XX, XX, XX, XX, XX, XX, XX, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+    ],
+    [
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, XX, XX, AL, CM, CM, BA, CM, CM, CM, CM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, AL, AL, AL, CM, CM, CM, CM, CM, CM,
+        CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+    ],
+    [
+        CM, CM, CM, AL, AL, CM, CM, CM, CM, CM, CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM,
+        AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, XX, AL, AL, XX, XX, AL, XX, XX, AL, AL, XX, XX, AL, AL, AL, AL, XX,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, XX, AL, AL, AL, AL, AL, AL, AL, XX,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL,
+        AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL,
+        XX, AL, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU,
+        NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU,
+        NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU,
+    ],
+    [
+        CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, CM, CM, CM, CM, CM, CM, CM, CM, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, AL, AL, AL, AL, AL, AL,
+        AL, AL, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, CM, AL, AL, BA, BA, BA, BA, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, CM, CM, CM, CM, CM, XX, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, CM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        CM, CM, CM, CM, CM, CM, CM, XX, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        CM, CM, XX, XX, CM, CM, CM, CM, CM, CM, CM, XX, CM, CM, XX, CM, CM, CM, CM, CM, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX,
+        XX, XX, CM, CM, CM, CM, CM, CM, CM, AL, AL, AL, AL, AL, AL, AL, XX, XX, NU, NU, NU, NU, NU,
+        NU, NU, NU, NU, NU, XX, XX, XX, XX, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, NU, NU, NU,
+        NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, PR,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM,
+        CM, CM, CM, CM, CM, CM, AL, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX,
+        XX, XX, OP_OP30, OP_OP30, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, PO, AL,
+        AL, AL, PO, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, XX, AL, XX, XX, AL, XX, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, XX, AL, XX, AL, XX, XX, XX, XX, XX, XX, AL, XX, XX,
+        XX, XX, AL, XX, AL, XX, AL, XX, AL, AL, AL, XX, AL, AL, XX, AL, XX, XX, AL, XX, AL, XX, AL,
+        XX, AL, XX, AL, XX, AL, AL, XX, AL, XX, XX, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL,
+        XX, AL, AL, AL, AL, XX, AL, AL, AL, AL, XX, AL, XX,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, AL, AL, AL, XX, AL, AL, AL, AL, AL, XX, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, ID, ID, ID, AI, AI, AI, AI, AI, AI, AI,
+        AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI,
+        AL, AL, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI,
+        AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI,
+        AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AL, AL, AL, ID, ID, ID, AI, AI, AI,
+        AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI,
+    ],
+    [
+        AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI,
+        AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI,
+        RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI,
+    ],
+    [
+        ID, ID, ID, ID, ID, EB, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, AL, AL, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, AL, AL, ID, ID, ID, ID, ID, AL, ID, ID, ID, ID, ID, EB, EB, EB,
+        ID, ID, EB, ID, ID, EB, EB, EB, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, EM, EM, EM, EM, EM,
+    ],
+    [
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, EB, EB, ID,
+        ID, EB, EB, EB, EB, EB, EB, EB, EB, EB, EB, EB, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, EB, EB, EB, EB, EB, EB, EB, EB, EB, EB, EB, EB, EB,
+        EB, EB, EB, EB, EB, EB, ID, ID, ID, EB, ID, ID, ID,
+    ],
+    [
+        ID, EB, EB, EB, ID, EB, EB, EB, ID, ID, ID, ID, ID, ID, ID, EB, ID, EB, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, AL, ID, AL, ID, AL, ID, ID, ID, ID, ID, EB, ID, ID, ID,
+        ID, AL, ID, AL, AL, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, EB, EB, ID, ID, ID, ID, EB, ID, ID, ID, ID, ID,
+    ],
+    [
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, EB, ID, ID, ID, ID, EB, EB,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, AL, AL, AL, AL, AL, AL, AL, AL,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, AL, AL, AL, AL, AL, AL, ID, ID, ID, ID, ID, ID,
+    ],
+    [
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        EB, EB, EB, ID, ID, ID, EB, EB, EB, EB, EB, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, QU, QU, QU, NS, NS, NS, AL, AL, AL, AL,
+    ],
+    [
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, EB, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, EB, EB, EB, ID, ID, ID, ID, ID, ID, ID, ID, ID, EB, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, EB, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, ID, ID, ID, ID, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, ID, ID, ID, ID, ID, ID, ID, ID, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, ID, ID,
+        ID, ID, ID, ID, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, ID, ID, ID, ID, ID, ID, ID, ID, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, EB, ID, ID, EB, ID, ID, ID, ID, ID, ID, ID,
+        ID, EB, EB, EB, EB, EB, EB, EB, EB, ID, ID, ID, ID, ID, ID, EB, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, EB, EB, EB, EB, EB, EB, EB, EB, EB, EB, ID, ID, EB, EB, EB, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, EB, ID, ID, ID, ID, ID, ID, ID, ID,
+    ],
+    [
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, EB, EB, ID, EB, EB, ID, EB, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, EB, EB, EB, ID, EB, EB, EB, EB, EB, EB, EB, EB, EB, EB, EB,
+        EB, EB, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+    ],
+    [
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU,
+        NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX,
+    ],
+    [
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, XX, XX,
+    ],
 ];
@@ -0,0 +1,314 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::lb_define::*;
+use crate::property_table::*;
+use crate::rule_table::*;
+
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+/// The number of code points whose line break property is looked up in
+/// [`LineBreakDataV1::property_table`]. The properties of the code points
+/// above it are fixed.
+pub const PROPERTY_TABLE_LEN: usize = 0x20000;
+
+/// The data used by the line breaker to implement [Unicode Standard Annex
+/// #14][UAX14], so that it can be updated without recompiling the crate.
+///
+/// The values of the properties and the break states are the ones used by the
+/// tables built into the crate, which are generated by
+/// `generate_properties.py` and `generate_rule_table.py`.
+///
+/// [UAX14]: http://www.unicode.org/reports/tr14/
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LineBreakDataV1 {
+    /// The line break property of each code point below
+    /// [`PROPERTY_TABLE_LEN`].
+    pub property_table: Vec<u8>,
+
+    /// The break state for each pair of the line break properties of two
+    /// adjacent characters, in row-major order.
+    pub rule_table: Vec<i8>,
+}
+
+impl LineBreakDataV1 {
+    /// Returns a copy of the data built into the crate, which is used by the
+    /// line breaker by default.
+    pub fn builtin() -> Self {
+        Self {
+            property_table: UAX14_PROPERTY_INDEX
+                .iter()
+                .flat_map(|block| UAX14_PROPERTY_BLOCKS[*block as usize].iter().copied())
+                .collect(),
+            rule_table: UAX14_RULE_TABLE.to_vec(),
+        }
+    }
+
+    /// Checks that the tables have the expected lengths, and that they only
+    /// contain known properties and break states.
+    pub fn validate(&self) -> Result<(), LineBreakDataError> {
+        if self.property_table.len() != PROPERTY_TABLE_LEN {
+            return Err(LineBreakDataError::InvalidPropertyTableLength(
+                self.property_table.len(),
+            ));
+        }
+        if self.rule_table.len() != PROP_COUNT * PROP_COUNT {
+            return Err(LineBreakDataError::InvalidRuleTableLength(
+                self.rule_table.len(),
+            ));
+        }
+        if let Some(codepoint) = self
+            .property_table
+            .iter()
+            .position(|prop| *prop == 0 || *prop >= EOT)
+        {
+            return Err(LineBreakDataError::InvalidProperty(codepoint as u32));
+        }
+        if let Some(index) = self
+            .rule_table
+            .iter()
+            .position(|state| *state == 0 || *state > EOT as i8)
+        {
+            return Err(LineBreakDataError::InvalidBreakState(index));
+        }
+        Ok(())
+    }
+}
+
+/// An error returned when loading a [`LineBreakDataV1`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum LineBreakDataError {
+    /// The property table doesn't have [`PROPERTY_TABLE_LEN`] entries.
+    InvalidPropertyTableLength(usize),
+
+    /// The rule table doesn't have an entry for each pair of properties.
+    InvalidRuleTableLength(usize),
+
+    /// The property of the code point is unknown.
+    InvalidProperty(u32),
+
+    /// The break state at the index of the rule table is unknown.
+    InvalidBreakState(usize),
+
+    /// The provider failed to load the data.
+    Load(String),
+}
+
+impl fmt::Display for LineBreakDataError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidPropertyTableLength(len) => {
+                write!(f, "Invalid length of the property table: {}", len)
+            }
+            Self::InvalidRuleTableLength(len) => {
+                write!(f, "Invalid length of the rule table: {}", len)
+            }
+            Self::InvalidProperty(codepoint) => {
+                write!(f, "Invalid property of U+{:04X}", codepoint)
+            }
+            Self::InvalidBreakState(index) => {
+                write!(f, "Invalid break state at index {}", index)
+            }
+            Self::Load(message) => write!(f, "Failed to load the data: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for LineBreakDataError {}
+
+/// A source of the [`LineBreakDataV1`] used by the line breaker.
+pub trait LineBreakDataProvider {
+    /// Loads the data. It is validated by [`load_line_break_data`] before use.
+    fn load(&self) -> Result<LineBreakDataV1, LineBreakDataError>;
+}
+
+/// A [`LineBreakDataProvider`] returning the data built into the crate.
+pub struct BuiltinLineBreakDataProvider;
+
+impl LineBreakDataProvider for BuiltinLineBreakDataProvider {
+    fn load(&self) -> Result<LineBreakDataV1, LineBreakDataError> {
+        Ok(LineBreakDataV1::builtin())
+    }
+}
+
+/// A dictionary of the words of a language written without spaces between
+/// words, used by [`word_count`](crate::word_count) to segment its text.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct WordDictionaryV1 {
+    /// The words of the dictionary, sorted and without duplicates.
+    pub words: Vec<String>,
+}
+
+impl WordDictionaryV1 {
+    /// Creates a dictionary of `words`, in any order.
+    pub fn new(mut words: Vec<String>) -> Self {
+        words.sort();
+        words.dedup();
+        Self { words }
+    }
+
+    /// Returns `true` if `word` is a word of the dictionary.
+    pub fn contains(&self, word: &str) -> bool {
+        self.words
+            .binary_search_by(|entry| entry.as_str().cmp(word))
+            .is_ok()
+    }
+}
+
+/// A source of the [`WordDictionaryV1`] used by [`word_count`](crate::word_count).
+pub trait WordDictionaryProvider {
+    /// Returns the dictionary of the words of Chinese and Japanese, if any.
+    fn chinese_japanese_dictionary(&self) -> Option<&WordDictionaryV1>;
+}
+
+/// A [`WordDictionaryProvider`] returning the dictionaries built into the
+/// crate. There are none yet.
+pub struct BuiltinWordDictionaryProvider;
+
+impl WordDictionaryProvider for BuiltinWordDictionaryProvider {
+    fn chinese_japanese_dictionary(&self) -> Option<&WordDictionaryV1> {
+        None
+    }
+}
+
+impl WordDictionaryProvider for WordDictionaryV1 {
+    fn chinese_japanese_dictionary(&self) -> Option<&WordDictionaryV1> {
+        Some(self)
+    }
+}
+
+/// Loads and validates the data of `provider`, to be used by a line breaker
+/// with `with_data`.
+///
+/// ```rust
+/// use icu_segmenter::{load_line_break_data, BuiltinLineBreakDataProvider, LineBreakIterator};
+///
+/// let data = load_line_break_data(&BuiltinLineBreakDataProvider).expect("Data should be valid");
+/// let iter = LineBreakIterator::new("Hello World").with_data(&data);
+/// let result: Vec<usize> = iter.collect();
+/// assert_eq!(result, vec![6, 11]);
+/// ```
+pub fn load_line_break_data<P: LineBreakDataProvider + ?Sized>(
+    provider: &P,
+) -> Result<LineBreakDataV1, LineBreakDataError> {
+    let data = provider.load()?;
+    data.validate()?;
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        load_line_break_data, LineBreakDataError, LineBreakDataProvider, LineBreakDataV1,
+        LineBreakIterator, LineBreakIteratorLatin1, LineBreakIteratorUtf16, LineBreakRule,
+        WordBreakRule,
+    };
+
+    /// A provider deserializing the data from JSON, like a provider reading
+    /// data files would.
+    struct MockProvider {
+        json: String,
+    }
+
+    impl LineBreakDataProvider for MockProvider {
+        fn load(&self) -> Result<LineBreakDataV1, LineBreakDataError> {
+            serde_json::from_str(&self.json).map_err(|e| LineBreakDataError::Load(e.to_string()))
+        }
+    }
+
+    #[test]
+    fn mock_provider() {
+        let provider = MockProvider {
+            json: serde_json::to_string(&LineBreakDataV1::builtin()).unwrap(),
+        };
+        let data = load_line_break_data(&provider).expect("Data should be valid");
+
+        for s in [
+            "",
+            "hello world",
+            "a\nb\r\nc\n",
+            "[  abc def",
+            "\u{2014}\u{2014}  \u{2014}\u{2014}123 abc",
+            "（はいそうです）",
+            "\u{20000}\u{20001} \u{1F3FB}a",
+        ]
+        .iter()
+        {
+            let expected: Vec<usize> = LineBreakIterator::new(s).collect();
+            let result: Vec<usize> = LineBreakIterator::new(s).with_data(&data).collect();
+            assert_eq!(result, expected, "{}", s);
+
+            let expected: Vec<usize> = LineBreakIterator::new_with_break_rule(
+                s,
+                LineBreakRule::Loose,
+                WordBreakRule::BreakAll,
+                true,
+            )
+            .collect();
+            let result: Vec<usize> = LineBreakIterator::new_with_break_rule(
+                s,
+                LineBreakRule::Loose,
+                WordBreakRule::BreakAll,
+                true,
+            )
+            .with_data(&data)
+            .collect();
+            assert_eq!(result, expected, "{}", s);
+
+            let utf16: Vec<u16> = s.encode_utf16().collect();
+            let expected: Vec<usize> = LineBreakIteratorUtf16::new(&utf16).collect();
+            let result: Vec<usize> = LineBreakIteratorUtf16::new(&utf16)
+                .with_data(&data)
+                .collect();
+            assert_eq!(result, expected, "{}", s);
+        }
+
+        let latin1 = b"Hello, World! 3.14 \xE9t\xE9";
+        let expected: Vec<usize> = LineBreakIteratorLatin1::new(latin1).collect();
+        let result: Vec<usize> = LineBreakIteratorLatin1::new(latin1)
+            .with_data(&data)
+            .collect();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn tailored_data() {
+        // Treat the space as a word joiner, so that there is no break around it.
+        let mut data = LineBreakDataV1::builtin();
+        data.property_table[' ' as usize] = crate::lb_define::WJ;
+        let data = load_line_break_data(&MockProvider {
+            json: serde_json::to_string(&data).unwrap(),
+        })
+        .expect("Data should be valid");
+
+        let result: Vec<usize> = LineBreakIterator::new("a b").with_data(&data).collect();
+        assert_eq!(result, vec![3]);
+    }
+
+    #[test]
+    fn invalid_data() {
+        let mut data = LineBreakDataV1::builtin();
+        data.rule_table.pop();
+        assert_eq!(
+            data.validate(),
+            Err(LineBreakDataError::InvalidRuleTableLength(57 * 57 - 1))
+        );
+
+        let mut data = LineBreakDataV1::builtin();
+        data.property_table['a' as usize] = 0;
+        assert_eq!(
+            data.validate(),
+            Err(LineBreakDataError::InvalidProperty('a' as u32))
+        );
+
+        let provider = MockProvider {
+            json: "{}".to_string(),
+        };
+        assert!(matches!(
+            load_line_break_data(&provider),
+            Err(LineBreakDataError::Load(_))
+        ));
+    }
+}
@@ -0,0 +1,74 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use core::iter::FromIterator;
+
+/// A lookup from byte offsets to the segments between line break
+/// opportunities, such as the ones returned by [`LineBreakIterator`].
+///
+/// The segments are numbered from 0. An offset exactly on a break opportunity
+/// belongs to the segment that starts there, so the offset of the end of the
+/// text maps to the number of segments.
+///
+/// ```rust
+/// use icu_segmenter::{LineBreakIterator, SegmentIndex};
+///
+/// let index: SegmentIndex = LineBreakIterator::new("hello world").collect();
+/// assert_eq!(index.segment_index_of(0), 0);
+/// assert_eq!(index.segment_index_of(5), 0);
+/// assert_eq!(index.segment_index_of(6), 1);
+/// ```
+///
+/// [`LineBreakIterator`]: crate::LineBreakIterator
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SegmentIndex {
+    breaks: Vec<usize>,
+}
+
+impl SegmentIndex {
+    /// Create a lookup from the byte offsets of the line break opportunities,
+    /// in ascending order. An offset of 0 is ignored, since the first segment
+    /// always starts there.
+    pub fn new(mut breaks: Vec<usize>) -> Self {
+        if breaks.first() == Some(&0) {
+            breaks.remove(0);
+        }
+        Self { breaks }
+    }
+
+    /// Returns the index of the segment that contains the byte at `offset`.
+    pub fn segment_index_of(&self, offset: usize) -> usize {
+        match self.breaks.binary_search(&offset) {
+            Ok(index) => index + 1,
+            Err(index) => index,
+        }
+    }
+}
+
+impl FromIterator<usize> for SegmentIndex {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{LineBreakIterator, SegmentIndex};
+
+    #[test]
+    fn segment_index_of() {
+        let text = "The quick\nbrown fox";
+        let index: SegmentIndex = LineBreakIterator::new(text).collect();
+        assert_eq!(index, SegmentIndex::new(vec![4, 10, 16, 19]));
+
+        let expected = [0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 3, 3, 3, 4];
+        for (offset, segment) in expected.iter().enumerate() {
+            assert_eq!(index.segment_index_of(offset), *segment, "{}", offset);
+        }
+
+        // A leading break at 0 doesn't start another segment.
+        assert_eq!(SegmentIndex::new(vec![0, 4]).segment_index_of(0), 0);
+        assert_eq!(SegmentIndex::default().segment_index_of(0), 0);
+    }
+}
@@ -0,0 +1,304 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::line_breaker::*;
+
+/// A push-based line breaker for text that arrives in chunks.
+///
+/// Each call to [`push_str`] returns the line break opportunities that are
+/// determined by the text pushed so far. Some line breaking rules need to look
+/// at the following characters, so the opportunities near the end of the
+/// pushed text are withheld until enough text arrives, or until [`finish`] is
+/// called. The returned offsets are byte offsets from the beginning of the
+/// whole text, and are the same as [`LineBreakIterator`] returns for the whole
+/// text.
+///
+/// Only the text that the following break opportunities depend on is
+/// buffered, and breaking resumes from there, so each character is broken
+/// once. A long run of text without break opportunities can still be bounded
+/// with [`with_max_buffer`].
+///
+/// ```rust
+/// use icu_segmenter::LineBreakSegmenter;
+///
+/// let mut segmenter = LineBreakSegmenter::new();
+/// let mut result = segmenter.push_str("hello wo");
+/// result.extend(segmenter.push_str("rld"));
+/// result.extend(segmenter.finish());
+/// assert_eq!(result, vec![6, 11]);
+/// ```
+///
+/// [`push_str`]: LineBreakSegmenter::push_str
+/// [`finish`]: LineBreakSegmenter::finish
+/// [`with_max_buffer`]: LineBreakSegmenter::with_max_buffer
+pub struct LineBreakSegmenter {
+    buffer: String,
+    buffer_offset: usize,
+    last_break: usize,
+    options: LineBreakOptions,
+    max_buffer: Option<usize>,
+}
+
+impl Default for LineBreakSegmenter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LineBreakSegmenter {
+    /// Create a streaming line breaker.
+    pub fn new() -> Self {
+        Self::new_with_options(LineBreakOptions::default())
+    }
+
+    /// Create a streaming line breaker with CSS rules. See
+    /// [`LineBreakIterator::new_with_break_rule`].
+    pub fn new_with_break_rule(
+        line_break_rule: LineBreakRule,
+        word_break_rule: WordBreakRule,
+        ja_zh: bool,
+    ) -> Self {
+        Self::new_with_options(LineBreakOptions {
+            line_break_rule,
+            word_break_rule,
+            ja_zh,
+        })
+    }
+
+    /// Create a streaming line breaker with [`LineBreakOptions`].
+    pub fn new_with_options(options: LineBreakOptions) -> Self {
+        Self {
+            buffer: String::new(),
+            buffer_offset: 0,
+            last_break: 0,
+            options,
+            max_buffer: None,
+        }
+    }
+
+    /// Set the maximum number of bytes of text after the last line break
+    /// opportunity. When the text exceeds it, a line break is forced at the
+    /// last character boundary within the bound, and the following text is
+    /// broken as if it started there. This bounds the length of the lines of
+    /// input without break opportunities.
+    ///
+    /// ```rust
+    /// use icu_segmenter::LineBreakSegmenter;
+    ///
+    /// let mut segmenter = LineBreakSegmenter::new().with_max_buffer(4);
+    /// assert_eq!(segmenter.push_str("abcdefghij"), vec![4, 8]);
+    /// assert_eq!(segmenter.finish(), vec![10]);
+    /// ```
+    pub fn with_max_buffer(mut self, max_buffer: usize) -> Self {
+        self.max_buffer = Some(max_buffer);
+        self
+    }
+
+    /// Append `input` to the text, and return the line break opportunities
+    /// that are resolved by it.
+    pub fn push_str(&mut self, input: &str) -> Vec<usize> {
+        self.buffer.push_str(input);
+
+        let mut result = Vec::new();
+        loop {
+            self.resolve(&mut result);
+            match self.max_buffer {
+                Some(max_buffer) if self.len() - self.last_break > max_buffer => {
+                    self.force_break(max_buffer, &mut result)
+                }
+                _ => break,
+            }
+        }
+        result
+    }
+
+    /// The length of the text pushed so far.
+    fn len(&self) -> usize {
+        self.buffer_offset + self.buffer.len()
+    }
+
+    /// Appends the line break opportunities that don't depend on the text
+    /// after the buffer to `result`, and drops the buffered text that the
+    /// following ones don't depend on.
+    fn resolve(&mut self, result: &mut Vec<usize>) {
+        let mut last_break = self.last_break;
+        let mut iter = self.iter();
+        while let Some(offset) = iter.next() {
+            if !iter.is_independent_of_eof() {
+                break;
+            }
+            Self::push_break(result, &mut last_break, self.buffer_offset + offset);
+        }
+        let restart = iter.restart_offset();
+
+        self.last_break = last_break;
+        // The text from the bound is kept, so that a break can be forced
+        // there.
+        if let Some(max_buffer) = self.max_buffer {
+            if self.buffer_offset + restart > last_break + max_buffer {
+                return;
+            }
+        }
+        self.buffer.drain(..restart);
+        self.buffer_offset += restart;
+    }
+
+    /// Forces a break `max_buffer` bytes after the last returned break, and
+    /// restarts the buffer there.
+    fn force_break(&mut self, max_buffer: usize, result: &mut Vec<usize>) {
+        let mut restart = self.last_break + max_buffer - self.buffer_offset;
+        while !self.buffer.is_char_boundary(restart) {
+            restart -= 1;
+        }
+        if self.buffer_offset + restart <= self.last_break {
+            // Always make progress, even if the bound is within the first
+            // character.
+            restart = self.last_break - self.buffer_offset;
+            restart += self.buffer[restart..]
+                .chars()
+                .next()
+                .map_or(0, char::len_utf8);
+        }
+        Self::push_break(result, &mut self.last_break, self.buffer_offset + restart);
+        self.buffer.drain(..restart);
+        self.buffer_offset += restart;
+    }
+
+    /// Return the remaining line break opportunities, including the one at
+    /// the end of the text.
+    pub fn finish(self) -> Vec<usize> {
+        let mut result = Vec::new();
+        let mut last_break = self.last_break;
+        for offset in self.iter() {
+            Self::push_break(&mut result, &mut last_break, self.buffer_offset + offset);
+        }
+        result
+    }
+
+    fn iter(&self) -> LineBreakIterator<'_> {
+        LineBreakIterator::new_with_options(&self.buffer, self.options)
+    }
+
+    // The buffer is restarted at a returned break, so skip what is already returned.
+    fn push_break(result: &mut Vec<usize>, last_break: &mut usize, offset: usize) {
+        if offset > *last_break {
+            result.push(offset);
+            *last_break = offset;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{LineBreakIterator, LineBreakSegmenter};
+
+    fn push_chunks(chunks: &[&str]) -> Vec<usize> {
+        let mut segmenter = LineBreakSegmenter::new();
+        let mut result = Vec::new();
+        for chunk in chunks {
+            result.extend(segmenter.push_str(chunk));
+        }
+        result.extend(segmenter.finish());
+        result
+    }
+
+    #[test]
+    fn same_as_iterator() {
+        let inputs = [
+            "hello world",
+            "$10 $10",
+            "[  abc def",
+            "abc\u{0022}  (def",
+            "\u{2014}\u{2014}  \u{2014}\u{2014}123 abc",
+            "(0,1)+(2,3)",
+            "\u{1F3FB} \u{1F3FB}",
+            "ab\r\ncd\n\nef",
+            "a\rb\r\rc\r\n\r\nd",
+            "ภาษาไทยภาษาไทย ภาษาไทย abc",
+            "",
+        ];
+        for s in inputs.iter() {
+            let expected: Vec<usize> = LineBreakIterator::new(s).collect();
+
+            // One chunk, and one chunk per character.
+            assert_eq!(push_chunks(&[s]), expected, "{}", s);
+            let chars: Vec<String> = s.chars().map(|c| c.to_string()).collect();
+            let chars: Vec<&str> = chars.iter().map(|c| c.as_str()).collect();
+            assert_eq!(push_chunks(&chars), expected, "{}", s);
+
+            // Two chunks split at every position.
+            for (i, _) in s.char_indices() {
+                assert_eq!(push_chunks(&[&s[..i], &s[i..]]), expected, "{} at {}", s, i);
+            }
+        }
+    }
+
+    #[test]
+    fn resolved_before_finish() {
+        let mut segmenter = LineBreakSegmenter::new();
+        assert_eq!(segmenter.push_str("hello "), Vec::<usize>::new());
+        assert_eq!(segmenter.push_str("world and"), vec![6, 12]);
+        assert_eq!(segmenter.push_str(" more"), vec![16]);
+        assert_eq!(segmenter.finish(), vec![20]);
+    }
+
+    #[test]
+    fn unresolved_tail() {
+        // Text without break opportunities isn't broken again when more of it
+        // arrives, and only its last character is buffered.
+        let mut segmenter = LineBreakSegmenter::new();
+        for _ in 0..1000 {
+            assert_eq!(segmenter.push_str("a"), Vec::<usize>::new());
+            assert_eq!(segmenter.buffer.len(), 1);
+        }
+        assert_eq!(segmenter.push_str(" b"), vec![1001]);
+        assert_eq!(segmenter.finish(), vec![1002]);
+
+        // Within a number, the buffer restarts at the last digit.
+        let mut segmenter = LineBreakSegmenter::new();
+        assert_eq!(segmenter.push_str("$1"), Vec::<usize>::new());
+        assert_eq!(segmenter.push_str("23"), Vec::<usize>::new());
+        assert!(segmenter.buffer.len() < 4);
+        assert_eq!(segmenter.push_str(" $"), vec![5]);
+        assert_eq!(segmenter.finish(), vec![6]);
+    }
+
+    #[test]
+    fn max_buffer() {
+        // An unbreakable run is broken at the bound.
+        let input = "a".repeat(100);
+        let expected: Vec<usize> = (1..=10).map(|i| i * 10).collect();
+        let mut segmenter = LineBreakSegmenter::new().with_max_buffer(10);
+        let mut result = segmenter.push_str(&input);
+        result.extend(segmenter.finish());
+        assert_eq!(result, expected);
+
+        let mut segmenter = LineBreakSegmenter::new().with_max_buffer(10);
+        let mut result = Vec::new();
+        for chunk in input.as_bytes().chunks(3) {
+            result.extend(segmenter.push_str(core::str::from_utf8(chunk).unwrap()));
+        }
+        result.extend(segmenter.finish());
+        assert_eq!(result, expected);
+
+        // Only the unresolved text counts toward the bound.
+        let mut segmenter = LineBreakSegmenter::new().with_max_buffer(4);
+        assert_eq!(segmenter.push_str("hello world"), vec![6, 10]);
+        assert_eq!(segmenter.finish(), vec![11]);
+
+        // A forced break doesn't split a character.
+        let mut segmenter = LineBreakSegmenter::new().with_max_buffer(4);
+        assert_eq!(segmenter.push_str("\u{E9}\u{E9}\u{E9}"), vec![4]);
+        assert_eq!(segmenter.finish(), vec![6]);
+        let mut segmenter = LineBreakSegmenter::new().with_max_buffer(1);
+        assert_eq!(segmenter.push_str("\u{E9}\u{E9}"), vec![2, 4]);
+        assert_eq!(segmenter.finish(), Vec::<usize>::new());
+
+        // Text within the bound is broken as usual.
+        let mut segmenter = LineBreakSegmenter::new().with_max_buffer(100);
+        let mut result = segmenter.push_str("hello world");
+        result.extend(segmenter.finish());
+        assert_eq!(result, vec![6, 11]);
+    }
+}
@@ -0,0 +1,675 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::dictionary;
+use crate::language::*;
+use crate::line_breaker::{Latin1Indices, Utf16Indices};
+use crate::lstm;
+use crate::provider::WordDictionaryProvider;
+use crate::word_property_table::*;
+
+use core::ops::Range;
+
+/// The values of the `Word_Break` property used by the rules.
+/// `ExtendedPictographic` is the value of the characters with the
+/// `Extended_Pictographic` property which are `Other`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[allow(clippy::upper_case_acronyms)]
+pub(crate) enum WordBreakProperty {
+    CR,
+    LF,
+    Newline,
+    Extend,
+    ZWJ,
+    RegionalIndicator,
+    Format,
+    Katakana,
+    HebrewLetter,
+    ALetter,
+    SingleQuote,
+    DoubleQuote,
+    MidNumLet,
+    MidLetter,
+    MidNum,
+    Numeric,
+    ExtendNumLet,
+    WSegSpace,
+    ExtendedPictographic,
+    Other,
+}
+
+use WordBreakProperty::*;
+
+/// Returns the word break property of a code point, from the table generated
+/// by `generate_uax29_properties.py`.
+fn get_word_break_property_utf32(codepoint: u32) -> WordBreakProperty {
+    let codepoint = codepoint as usize;
+    match WB_PROPERTY_INDEX.get(codepoint / WB_PROPERTY_BLOCK_SIZE) {
+        Some(block) => {
+            let value = WB_PROPERTY_BLOCKS[*block as usize][codepoint % WB_PROPERTY_BLOCK_SIZE];
+            WB_PROPERTY_VALUES[value as usize]
+        }
+        None => Other,
+    }
+}
+
+fn is_newline(prop: WordBreakProperty) -> bool {
+    matches!(prop, CR | LF | Newline)
+}
+
+fn is_ahletter(prop: WordBreakProperty) -> bool {
+    matches!(prop, ALetter | HebrewLetter)
+}
+
+fn is_mid_letter(prop: WordBreakProperty) -> bool {
+    matches!(prop, MidLetter | MidNumLet | SingleQuote)
+}
+
+fn is_mid_num(prop: WordBreakProperty) -> bool {
+    matches!(prop, MidNum | MidNumLet | SingleQuote)
+}
+
+/// The word break rules of [Unicode Standard Annex #29][UAX29], shared by the
+/// iterators over each encoding.
+///
+/// [UAX29]: https://www.unicode.org/reports/tr29/
+struct WordBreaker {
+    /// The offsets and the properties of the characters, where the characters
+    /// which never start a word are merged into the preceding one (WB3c, WB4,
+    /// WB15 and WB16).
+    chars: Vec<(usize, WordBreakProperty)>,
+    len: usize,
+    pos: usize,
+}
+
+impl WordBreaker {
+    fn new<I, C>(iter: I, len: usize) -> Self
+    where
+        I: Iterator<Item = (usize, C)>,
+        C: Into<u32>,
+    {
+        let mut chars: Vec<(usize, WordBreakProperty)> = Vec::new();
+        // The property of the previous character, even if it was merged.
+        let mut previous: Option<WordBreakProperty> = None;
+        // Whether the last regional indicator isn't part of a pair yet.
+        let mut unpaired_regional_indicator = false;
+        for (offset, c) in iter {
+            let prop = get_word_break_property_utf32(c.into());
+            let merged = match chars.last_mut() {
+                // WB3c: ZWJ × \p{Extended_Pictographic}
+                Some(last) if previous == Some(ZWJ) && prop == ExtendedPictographic => {
+                    last.1 = ExtendedPictographic;
+                    true
+                }
+                // WB4: X (Extend | Format | ZWJ)* → X, except after sot, CR,
+                // LF and Newline.
+                Some(last) if matches!(prop, Extend | Format | ZWJ) => !is_newline(last.1),
+                // WB15, WB16
+                Some(last)
+                    if last.1 == RegionalIndicator
+                        && prop == RegionalIndicator
+                        && unpaired_regional_indicator =>
+                {
+                    unpaired_regional_indicator = false;
+                    true
+                }
+                _ => false,
+            };
+            if !merged {
+                chars.push((offset, prop));
+                unpaired_regional_indicator = prop == RegionalIndicator;
+            }
+            previous = Some(prop);
+        }
+        WordBreaker { chars, len, pos: 0 }
+    }
+
+    fn prop(&self, index: usize) -> Option<WordBreakProperty> {
+        self.chars.get(index).map(|(_, prop)| *prop)
+    }
+
+    /// Returns `true` if there is a word boundary before the character at
+    /// `index`.
+    fn is_break(&self, index: usize) -> bool {
+        let left = self.chars[index - 1].1;
+        let right = self.chars[index].1;
+        // The properties of the characters after `right` and before `left`.
+        let after = self.prop(index + 1);
+        let before = index.checked_sub(2).and_then(|index| self.prop(index));
+        match (left, right) {
+            // WB3
+            (CR, LF) => false,
+            // WB3a, WB3b
+            _ if is_newline(left) || is_newline(right) => true,
+            // WB3d
+            (WSegSpace, WSegSpace) => false,
+            // WB5
+            _ if is_ahletter(left) && is_ahletter(right) => false,
+            // WB6
+            _ if is_ahletter(left)
+                && is_mid_letter(right)
+                && matches!(after, Some(prop) if is_ahletter(prop)) =>
+            {
+                false
+            }
+            // WB7
+            _ if matches!(before, Some(prop) if is_ahletter(prop))
+                && is_mid_letter(left)
+                && is_ahletter(right) =>
+            {
+                false
+            }
+            // WB7a
+            (HebrewLetter, SingleQuote) => false,
+            // WB7b
+            (HebrewLetter, DoubleQuote) if after == Some(HebrewLetter) => false,
+            // WB7c
+            (DoubleQuote, HebrewLetter) if before == Some(HebrewLetter) => false,
+            // WB8
+            (Numeric, Numeric) => false,
+            // WB9
+            (_, Numeric) if is_ahletter(left) => false,
+            // WB10
+            (Numeric, _) if is_ahletter(right) => false,
+            // WB11
+            (_, Numeric) if before == Some(Numeric) && is_mid_num(left) => false,
+            // WB12
+            (Numeric, _) if is_mid_num(right) && after == Some(Numeric) => false,
+            // WB13
+            (Katakana, Katakana) => false,
+            // WB13a
+            (_, ExtendNumLet) if is_ahletter(left) => false,
+            (Numeric, ExtendNumLet) | (Katakana, ExtendNumLet) | (ExtendNumLet, ExtendNumLet) => {
+                false
+            }
+            // WB13b
+            (ExtendNumLet, _) if is_ahletter(right) => false,
+            (ExtendNumLet, Numeric) | (ExtendNumLet, Katakana) => false,
+            // WB999
+            _ => true,
+        }
+    }
+
+    fn next(&mut self) -> Option<usize> {
+        if self.pos >= self.chars.len() {
+            return None;
+        }
+        self.pos += 1;
+        while self.pos < self.chars.len() {
+            if self.is_break(self.pos) {
+                return Some(self.chars[self.pos].0);
+            }
+            self.pos += 1;
+        }
+        Some(self.len)
+    }
+}
+
+macro_rules! word_break_iterator_impl {
+    ($(#[$attr:meta])* $name:ident) => {
+        $(#[$attr])*
+        ///
+        /// [`Iterator`]: core::iter::Iterator
+        /// [UAX29]: https://www.unicode.org/reports/tr29/
+        pub struct $name {
+            breaker: WordBreaker,
+        }
+
+        impl Iterator for $name {
+            type Item = usize;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.breaker.next()
+            }
+        }
+    };
+}
+
+word_break_iterator_impl!(
+    /// The struct implementing the [`Iterator`] trait over the word boundaries
+    /// of an `str` (a UTF-8 string), as defined in [Unicode Standard Annex
+    /// #29][UAX29]. It returns the byte offsets of the boundaries, excluding
+    /// the start of the string and including its end.
+    WordBreakIterator
+);
+
+impl WordBreakIterator {
+    /// Create a word break iterator for an `str` (a UTF-8 string).
+    ///
+    /// ```rust
+    /// use icu_segmenter::WordBreakIterator;
+    ///
+    /// let breaks: Vec<usize> = WordBreakIterator::new("caf\u{E9} \u{1F44D}\u{1F3FD}").collect();
+    /// assert_eq!(breaks, vec![5, 6, 14]);
+    /// ```
+    pub fn new(input: &str) -> WordBreakIterator {
+        WordBreakIterator {
+            breaker: WordBreaker::new(input.char_indices(), input.len()),
+        }
+    }
+}
+
+word_break_iterator_impl!(
+    /// The struct implementing the [`Iterator`] trait over the word boundaries
+    /// of a Latin-1 (8-bit) string, as defined in [Unicode Standard Annex
+    /// #29][UAX29]. It returns the byte offsets of the boundaries, excluding
+    /// the start of the string and including its end.
+    WordBreakIteratorLatin1
+);
+
+impl WordBreakIteratorLatin1 {
+    /// Create a word break iterator for a Latin-1 (8-bit) string.
+    ///
+    /// The soft hyphen (U+00AD) is the only character of Latin-1 with the
+    /// `Format` property. It is ignored by the rules, so it never breaks a
+    /// word.
+    ///
+    /// ```rust
+    /// use icu_segmenter::WordBreakIteratorLatin1;
+    ///
+    /// let breaks: Vec<usize> = WordBreakIteratorLatin1::new(b"can't stop").collect();
+    /// assert_eq!(breaks, vec![5, 6, 10]);
+    /// ```
+    pub fn new(input: &[u8]) -> WordBreakIteratorLatin1 {
+        WordBreakIteratorLatin1 {
+            breaker: WordBreaker::new(Latin1Indices::new(input), input.len()),
+        }
+    }
+}
+
+word_break_iterator_impl!(
+    /// The struct implementing the [`Iterator`] trait over the word boundaries
+    /// of a UTF-16 string, as defined in [Unicode Standard Annex #29][UAX29].
+    /// It returns the offsets of the boundaries in code units, excluding the
+    /// start of the string and including its end.
+    WordBreakIteratorUtf16
+);
+
+impl WordBreakIteratorUtf16 {
+    /// Create a word break iterator for a UTF-16 string. A surrogate pair is
+    /// never split.
+    ///
+    /// ```rust
+    /// use icu_segmenter::WordBreakIteratorUtf16;
+    ///
+    /// let input: Vec<u16> = "caf\u{E9} \u{1F44D}\u{1F3FD}".encode_utf16().collect();
+    /// let breaks: Vec<usize> = WordBreakIteratorUtf16::new(&input).collect();
+    /// assert_eq!(breaks, vec![4, 5, 9]);
+    /// ```
+    pub fn new(input: &[u16]) -> WordBreakIteratorUtf16 {
+        WordBreakIteratorUtf16 {
+            breaker: WordBreaker::new(Utf16Indices::new(input), input.len()),
+        }
+    }
+}
+
+/// Returns whether a character is a letter or a digit for the word break
+/// rules, which makes a segment a word.
+fn is_word_char(c: u32) -> bool {
+    let prop = get_word_break_property_utf32(c);
+    is_ahletter(prop) || prop == Numeric
+}
+
+/// Returns the ranges of the segments between `breaks` which are words, see
+/// `is_word`, joining the words separated by a single hyphen, see
+/// `is_hyphen`.
+fn trimmed_segments(
+    breaks: impl Iterator<Item = usize>,
+    is_word: impl Fn(Range<usize>) -> bool,
+    is_hyphen: impl Fn(Range<usize>) -> bool,
+) -> Vec<Range<usize>> {
+    let mut words: Vec<Range<usize>> = Vec::new();
+    let mut start = 0;
+    // Whether the previous segment is a hyphen directly after a word.
+    let mut after_hyphen = false;
+
+    for end in breaks {
+        if is_word(start..end) {
+            match words.last_mut() {
+                Some(last) if after_hyphen => last.end = end,
+                _ => words.push(start..end),
+            }
+            after_hyphen = false;
+        } else {
+            after_hyphen =
+                is_hyphen(start..end) && matches!(words.last(), Some(last) if last.end == start);
+        }
+        start = end;
+    }
+    words
+}
+
+/// Returns the byte ranges of the words of an `str` (a UTF-8 string), as
+/// segmented by [`WordBreakIterator`], such as for search indexing.
+///
+/// The segments without letters or digits, such as white spaces and
+/// punctuation, are skipped. Apostrophes within words are kept by the word
+/// break rules, and a single hyphen (U+002D) directly between two words joins
+/// them, so "don't" and "well-known" are both single words.
+///
+/// ```rust
+/// use icu_segmenter::word_segments_trimmed;
+///
+/// let input = "Don't stop, well-known caf\u{E9}!";
+/// let words: Vec<&str> = word_segments_trimmed(input)
+///     .into_iter()
+///     .map(|range| &input[range])
+///     .collect();
+/// assert_eq!(words, vec!["Don't", "stop", "well-known", "caf\u{E9}"]);
+/// ```
+pub fn word_segments_trimmed(input: &str) -> Vec<Range<usize>> {
+    trimmed_segments(
+        WordBreakIterator::new(input),
+        |range| input[range].chars().any(|c| is_word_char(c as u32)),
+        |range| &input[range] == "-",
+    )
+}
+
+/// Returns the byte ranges of the words of a Latin-1 (8-bit) string, as
+/// segmented by [`WordBreakIteratorLatin1`], such as for search indexing.
+///
+/// The words are the same as with [`word_segments_trimmed`].
+///
+/// ```rust
+/// use icu_segmenter::word_segments_trimmed_latin1;
+///
+/// let input = b"Don't stop, well-known fox!";
+/// let words: Vec<&[u8]> = word_segments_trimmed_latin1(input)
+///     .into_iter()
+///     .map(|range| &input[range])
+///     .collect();
+/// assert_eq!(words, vec![&b"Don't"[..], b"stop", b"well-known", b"fox"]);
+/// ```
+pub fn word_segments_trimmed_latin1(input: &[u8]) -> Vec<Range<usize>> {
+    trimmed_segments(
+        WordBreakIteratorLatin1::new(input),
+        |range| input[range].iter().any(|c| is_word_char(u32::from(*c))),
+        |range| &input[range] == b"-",
+    )
+}
+
+/// Counts the words of a string without Thai, Burmese, Chinese or Japanese.
+fn count_words(input: &str) -> usize {
+    let mut count = 0;
+    let mut start = 0;
+    for end in WordBreakIterator::new(input) {
+        let is_word = input[start..end].chars().any(|c| is_word_char(c as u32));
+        if is_word {
+            count += 1;
+        }
+        start = end;
+    }
+    count
+}
+
+/// Returns the number of words of a string, such as for the statistics of a
+/// document mixing several languages.
+///
+/// The string is segmented by [`WordBreakIterator`], and the segments without
+/// letters or digits, such as white spaces and punctuation, are not counted.
+/// The runs of the languages which are written without spaces between words
+/// are segmented differently: Thai and Burmese by the LSTM models, and
+/// Chinese and Japanese by the dictionary of `provider`. Without a
+/// dictionary, each ideograph and hiragana is counted as a word, and a run of
+/// katakana as one word.
+///
+/// ```rust
+/// use icu_segmenter::{word_count, BuiltinWordDictionaryProvider, WordDictionaryV1};
+///
+/// let provider = BuiltinWordDictionaryProvider;
+/// assert_eq!(word_count("The quick (\"brown\") fox can't jump.", &provider), 6);
+/// assert_eq!(word_count("コーヒーを飲む", &provider), 4);
+///
+/// let dictionary = WordDictionaryV1::new(vec!["コーヒー".to_string(), "飲む".to_string()]);
+/// assert_eq!(word_count("コーヒーを飲む", &dictionary), 3);
+/// ```
+pub fn word_count<P: WordDictionaryProvider + ?Sized>(input: &str, provider: &P) -> usize {
+    let mut count = 0;
+    let mut start = 0;
+    while let Some(first) = input[start..].chars().next() {
+        let language = get_language(first as u32);
+        let end = input[start..]
+            .char_indices()
+            .find(|(_, c)| get_language(*c as u32) != language)
+            .map_or(input.len(), |(index, _)| start + index);
+        let run = &input[start..end];
+        count += match language {
+            Language::Thai | Language::Burmese => lstm::get_word_count(run),
+            Language::ChineseOrJapanese => match provider.chinese_japanese_dictionary() {
+                Some(dictionary) => dictionary::get_word_count(dictionary, run),
+                // The run only has ideographs and kana, so each segment is a
+                // word.
+                None => WordBreakIterator::new(run).count(),
+            },
+            Language::Unknown => count_words(run),
+        };
+        start = end;
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        word_count, word_segments_trimmed, word_segments_trimmed_latin1,
+        BuiltinWordDictionaryProvider, WordBreakIterator, WordBreakIteratorLatin1,
+        WordBreakIteratorUtf16, WordDictionaryV1,
+    };
+
+    fn breaks(input: &[u8]) -> Vec<usize> {
+        WordBreakIteratorLatin1::new(input).collect()
+    }
+
+    #[test]
+    fn word_break() {
+        assert_eq!(breaks(b""), Vec::<usize>::new());
+        assert_eq!(
+            breaks(b"The quick (\"brown\") fox"),
+            vec![3, 4, 9, 10, 11, 12, 17, 18, 19, 20, 23]
+        );
+        assert_eq!(breaks(b"can't stop"), vec![5, 6, 10]);
+        assert_eq!(breaks(b"e.g. 3.14"), vec![3, 4, 5, 9]);
+        assert_eq!(breaks(b"1,000;5"), vec![7]);
+        assert_eq!(breaks(b"a_1 __"), vec![3, 4, 6]);
+        assert_eq!(breaks(b"a  b"), vec![1, 3, 4]);
+        assert_eq!(breaks(b"a\r\nb\x0Bc"), vec![1, 3, 4, 5, 6]);
+        // "Café" and "señor" are words in Latin-1.
+        assert_eq!(breaks(b"Caf\xE9 se\xF1or"), vec![4, 5, 10]);
+        // A trailing mid-letter character is not part of the word.
+        assert_eq!(breaks(b"end."), vec![3, 4]);
+    }
+
+    #[test]
+    fn properties() {
+        for (input, expected) in [
+            // A run of katakana is a word (WB13), and so are Japanese words
+            // joined by an underscore (WB13a, WB13b).
+            ("\u{30AB}\u{30BF}\u{30AB}\u{30CA}", vec![12]),
+            ("\u{30B3}\u{30FC}\u{30D2}\u{30FC}", vec![12]),
+            ("\u{30C6}\u{30EC}\u{30FB}\u{30D3}", vec![6, 9, 12]),
+            ("\u{30AB}_\u{30BF}", vec![7]),
+            ("\u{30AB}\u{3072}", vec![3, 6]),
+            // Hebrew letters, with a geresh (WB7a) and a gershayim (WB7b,
+            // WB7c) written as quotation marks
+            ("\u{5E6}\u{5D4}\"\u{5DC}", vec![7]),
+            ("\u{5D2}' a", vec![3, 4, 5]),
+            ("\u{5D2}\" a", vec![2, 3, 4, 5]),
+            // Thai letters are not letters for the rules, since the words of
+            // Thai are found by the LSTM models instead.
+            ("\u{E01}\u{E34}\u{E19} a", vec![6, 9, 10, 11]),
+            // Symbols are not letters.
+            ("a\u{2192}b", vec![1, 4, 5]),
+            ("\u{24C2}\u{24C2}", vec![6]),
+            // Ideographic space is a white space.
+            ("a\u{3000}\u{3000}b", vec![1, 7, 8]),
+        ]
+        .iter()
+        {
+            let result: Vec<usize> = WordBreakIterator::new(input).collect();
+            assert_eq!(&result, expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn soft_hyphen() {
+        // The soft hyphen is ignored within words and numbers.
+        assert_eq!(breaks(b"hy\xADphen"), vec![7]);
+        assert_eq!(breaks(b"can\xAD't"), vec![6]);
+        assert_eq!(breaks(b"1\xAD2"), vec![3]);
+        // It is attached to the preceding character, but not to line feeds
+        // or the start of the string.
+        assert_eq!(breaks(b"a \xADb"), vec![1, 3, 4]);
+        assert_eq!(breaks(b"\xAD\xADa"), vec![2, 3]);
+        assert_eq!(breaks(b"\n\xADa"), vec![1, 2, 3]);
+    }
+
+    fn words(input: &[u8]) -> Vec<&[u8]> {
+        word_segments_trimmed_latin1(input)
+            .into_iter()
+            .map(|range| &input[range])
+            .collect()
+    }
+
+    #[test]
+    fn trimmed() {
+        assert_eq!(
+            words(b"The quick, brown fox"),
+            vec![&b"The"[..], b"quick", b"brown", b"fox"]
+        );
+        assert_eq!(
+            word_segments_trimmed_latin1(b"The quick, brown fox"),
+            vec![0..3, 4..9, 11..16, 17..20]
+        );
+        assert_eq!(words(b"  don't  "), vec![&b"don't"[..]]);
+        assert_eq!(words(b"well-known"), vec![&b"well-known"[..]]);
+        assert_eq!(
+            words(b"3.14 and 1,000"),
+            vec![&b"3.14"[..], b"and", b"1,000"]
+        );
+        assert_eq!(words(b"hy\xADphen"), vec![&b"hy\xADphen"[..]]);
+        // Hyphens which are not directly between two words don't join them.
+        assert_eq!(words(b"a - b"), vec![&b"a"[..], b"b"]);
+        assert_eq!(words(b"-a--b-"), vec![&b"a"[..], b"b"]);
+        assert_eq!(words(b""), Vec::<&[u8]>::new());
+        assert_eq!(words(b" ,.!? "), Vec::<&[u8]>::new());
+    }
+
+    fn words_utf8(input: &str) -> Vec<&str> {
+        word_segments_trimmed(input)
+            .into_iter()
+            .map(|range| &input[range])
+            .collect()
+    }
+
+    #[test]
+    fn trimmed_utf8() {
+        assert_eq!(
+            words_utf8("The quick, brown fox"),
+            vec!["The", "quick", "brown", "fox"]
+        );
+        assert_eq!(words_utf8("  don\u{2019}t  "), vec!["don\u{2019}t"]);
+        assert_eq!(words_utf8("well-known"), vec!["well-known"]);
+        assert_eq!(
+            words_utf8("\u{5E9}\u{5DC}\u{5D5}\u{5DD} \u{1F44D}"),
+            vec!["\u{5E9}\u{5DC}\u{5D5}\u{5DD}"]
+        );
+        assert_eq!(words_utf8("a \u{2014} b"), vec!["a", "b"]);
+        assert_eq!(words_utf8(""), Vec::<&str>::new());
+    }
+
+    /// Returns the breaks of the UTF-8 iterator, converted to UTF-16 offsets.
+    fn utf8_breaks_as_utf16(input: &str) -> Vec<usize> {
+        WordBreakIterator::new(input)
+            .map(|offset| input[..offset].encode_utf16().count())
+            .collect()
+    }
+
+    #[test]
+    fn utf16() {
+        for (input, expected) in [
+            ("", vec![]),
+            ("The quick brown fox", vec![3, 4, 9, 10, 15, 16, 19]),
+            // e + combining acute accent
+            ("cafe\u{301} au lait", vec![5, 6, 8, 9, 13]),
+            // Thumbs up sign + skin tone modifier
+            ("\u{1F44D}\u{1F3FD}\u{1F44D}", vec![4, 6]),
+            // Woman + ZWJ + laptop
+            ("\u{1F469}\u{200D}\u{1F4BB}!", vec![5, 6]),
+            // A ZWJ followed by an emoji doesn't break a word.
+            ("a\u{200D}\u{1F4BB}b", vec![4, 5]),
+            // Flags of the US and Japan, and a lone regional indicator
+            (
+                "\u{1F1FA}\u{1F1F8}\u{1F1EF}\u{1F1F5}\u{1F1FA}",
+                vec![4, 8, 10],
+            ),
+            ("\u{D55C}\u{AD6D}\u{C5B4} \u{1100}\u{1161}", vec![3, 4, 6]),
+            ("\u{4E00}\u{4E8C}", vec![1, 2]),
+            (
+                "\u{3C0}=3.14\u{2003}\u{2003}\u{10400}",
+                vec![1, 2, 6, 8, 10],
+            ),
+            ("a\u{2028}b", vec![1, 2, 3]),
+        ]
+        .iter()
+        {
+            let utf16: Vec<u16> = input.encode_utf16().collect();
+            let result: Vec<usize> = WordBreakIteratorUtf16::new(&utf16).collect();
+            assert_eq!(&result, expected, "{}", input);
+            assert_eq!(utf8_breaks_as_utf16(input), result, "{}", input);
+        }
+    }
+
+    #[test]
+    fn utf8_matches_latin1() {
+        let latin1 = b"The (\"quick\") br\xF6wn fox can't jump 3.14 \xADft\r\n";
+        let utf8: String = latin1.iter().map(|c| *c as char).collect();
+        let expected: Vec<usize> = WordBreakIteratorLatin1::new(latin1).collect();
+        let result: Vec<usize> = WordBreakIterator::new(&utf8)
+            .map(|offset| utf8[..offset].chars().count())
+            .collect();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn count() {
+        let provider = BuiltinWordDictionaryProvider;
+        let count = |input: &str| word_count(input, &provider);
+        assert_eq!(count(""), 0);
+        assert_eq!(count(" ,.!? "), 0);
+        assert_eq!(count("The quick (\"brown\") fox can't jump 3.14 feet."), 8);
+
+        // Without a dictionary, each ideograph and hiragana is a word, and a
+        // run of katakana is one word: "コーヒー", "を", "飲" and "む".
+        let japanese = "コーヒーを飲む。";
+        assert_eq!(count(japanese), 4);
+        // Katakana separated by a middle dot are two words.
+        assert_eq!(count("テレ・ビ"), 2);
+
+        // The Thai text is segmented by the LSTM model.
+        let thai = "ภาษาไทยภาษาไทย";
+        assert_eq!(count(thai), 4);
+
+        let mixed = format!("I said {} and {}.", japanese, thai);
+        assert_eq!(count(&mixed), 11);
+    }
+
+    #[test]
+    fn count_with_dictionary() {
+        let dictionary = WordDictionaryV1::new(
+            ["私", "は", "コーヒー", "を", "飲む", "毎朝"]
+                .iter()
+                .map(|word| word.to_string())
+                .collect(),
+        );
+        // "I drink coffee every morning", in English and in Japanese
+        let english = "I drink coffee every morning.";
+        let japanese = "私は毎朝コーヒーを飲む。";
+        assert_eq!(word_count(english, &dictionary), 5);
+        assert_eq!(word_count(japanese, &dictionary), 6);
+        assert_eq!(word_count(japanese, &BuiltinWordDictionaryProvider), 8);
+
+        let mixed = format!("{} {}", english, japanese);
+        assert_eq!(word_count(&mixed, &dictionary), 11);
+    }
+}
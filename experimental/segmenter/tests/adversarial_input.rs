@@ -0,0 +1,309 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Unusual inputs, which every entry point must handle without panicking.
+
+use icu_segmenter::*;
+
+fn str_corpus() -> Vec<String> {
+    let mut corpus: Vec<String> = [
+        "",
+        " ",
+        "\t",
+        "\r",
+        "\n",
+        "\r\n",
+        "\n\r",
+        "\r\r\n\n",
+        "\u{0085}",
+        "\u{2028}\u{2029}",
+        "\u{000B}\u{000C}",
+        // Combining marks and joiners at the start of the string.
+        "\u{0301}",
+        "\u{0301}abc",
+        "\u{0301}\u{0301}\u{0301}",
+        "\u{200D}",
+        "\u{200D}\u{1F600}",
+        "\u{FE0F}\u{2764}",
+        "\u{1F3FB}",
+        "\u{0E31}\u{0E01}",
+        "\u{0E48}",
+        "\u{1039}\u{1000}",
+        "\u{102C}",
+        "\u{11A8}\u{1161}",
+        "\u{30FC}\u{3005}",
+        "\u{05BE}\u{05D0}",
+        // Odd numbers of regional indicators.
+        "\u{1F1E6}",
+        "\u{1F1E6}\u{1F1E8}\u{1F1E6}",
+        // Joiners and spaces.
+        "\u{200B}",
+        "\u{200B}\u{200B} \u{200B}",
+        "\u{2060}",
+        "\u{00A0}\u{00A0}",
+        "\u{00AD}",
+        "a\u{00AD}\u{00AD}b",
+        "\u{FEFF}",
+        // The edges of the property table and of Unicode.
+        "\u{FFFD}",
+        "\u{FFFF}",
+        "\u{1FFFF}",
+        "\u{20000}",
+        "\u{E0001}\u{E0020}\u{E007F}",
+        "\u{E0100}",
+        "\u{10FFFF}",
+        // Complex context scripts mixed with other characters.
+        "\u{0E01}",
+        "\u{0E01}\u{1F600}\u{0E01}",
+        "\u{0E01}\u{0301}\u{0E01}",
+        "\u{0E01}\u{1000}\u{0E81}\u{1780}\u{1A20}",
+        "\u{0E01}\n\u{0E01}\r\n",
+        "\u{0E2F}\u{0E46}",
+        // Numbers and punctuation.
+        "(",
+        ")",
+        "\"",
+        "1.",
+        ".1",
+        "-1",
+        "$(1,234.56)%",
+        "\u{0660}\u{066B}\u{0661}",
+        "\u{FF08}\u{3000}\u{FF09}",
+        "a\u{0301}\u{200D}\u{0301}",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect();
+    corpus.push("\u{0E01}".repeat(100));
+    corpus.push("\u{1000}\u{102C}".repeat(100));
+    corpus.push("\u{0301}".repeat(100));
+    corpus.push("\u{1F1E6}".repeat(51));
+    corpus.push("1,".repeat(100));
+    corpus.push(" ".repeat(100) + "a");
+    corpus
+}
+
+fn byte_corpus() -> Vec<Vec<u8>> {
+    let mut corpus: Vec<Vec<u8>> = [
+        &b""[..],
+        b"\x00",
+        b"\xFF",
+        b"\x80\x80\x80",
+        b"\xA0\xAD\x85",
+        b"\r\n\r",
+        b"a\x00b",
+        b"\xAD\xAD",
+        b"1,\xA0234.5\x85",
+        // UTF-8 encodings, which are not valid as UTF-8 when cut.
+        b"\xC3\xA9",
+        b"\xC3",
+        b"\xE2\x80\xA6",
+        b"\xE2\x80",
+        b"\xF0\x9F\x98\x80",
+        b"\xF0\x9F",
+        b"\xED\xA0\x80",
+        b"\xE0\xB8\x81\xE0\xB8",
+    ]
+    .iter()
+    .map(|b| b.to_vec())
+    .collect();
+    corpus.push((0..=255).collect());
+    corpus.push((0..=255).rev().collect());
+    corpus
+}
+
+fn utf16_corpus() -> Vec<Vec<u16>> {
+    let mut corpus: Vec<Vec<u16>> = [
+        &[][..],
+        // Unpaired surrogates.
+        &[0xD800],
+        &[0xDC00],
+        &[0xDC00, 0xD800],
+        &[0xD800, 0x0061],
+        &[0x0061, 0xDFFF],
+        &[0xD800, 0xD800, 0xDC00],
+        &[0x0E01, 0xD800, 0x0E01],
+        &[0x0E01, 0xDC00],
+        &[0x0301, 0xD83D, 0xDE00],
+        &[0xDBFF, 0xDFFF],
+        &[0xFFFF, 0xFFFE],
+        &[0x000D, 0xD800, 0x000A],
+    ]
+    .iter()
+    .map(|u| u.to_vec())
+    .collect();
+    corpus.push([0x0E01, 0xD800].repeat(100));
+    corpus
+}
+
+fn line_break_options() -> Vec<LineBreakOptions> {
+    let mut options = vec![
+        LineBreakOptions::default(),
+        LineBreakOptions::css_normal(),
+        LineBreakOptions::css_loose(),
+        LineBreakOptions::css_anywhere(),
+    ];
+    for word_break_rule in [WordBreakRule::BreakAll, WordBreakRule::KeepAll].iter() {
+        options.push(LineBreakOptions {
+            word_break_rule: *word_break_rule,
+            ..LineBreakOptions::default()
+        });
+    }
+    for ja_zh in [
+        LineBreakOptions::css_normal(),
+        LineBreakOptions::css_loose(),
+    ]
+    .iter()
+    {
+        options.push(LineBreakOptions {
+            ja_zh: true,
+            ..*ja_zh
+        });
+    }
+    options
+}
+
+// The break opportunities are in increasing order, within the input and on
+// character boundaries.
+fn check_breaks(breaks: &[usize], len: usize, is_boundary: impl Fn(usize) -> bool, input: &str) {
+    assert!(
+        breaks.windows(2).all(|w| w[0] < w[1]),
+        "{:?}: {:?}",
+        input,
+        breaks
+    );
+    assert!(
+        breaks.iter().all(|b| *b <= len && is_boundary(*b)),
+        "{:?}: {:?}",
+        input,
+        breaks
+    );
+}
+
+#[test]
+fn str_input() {
+    for s in str_corpus().iter() {
+        let check = |breaks: &[usize]| check_breaks(breaks, s.len(), |b| s.is_char_boundary(b), s);
+
+        for options in line_break_options() {
+            let breaks: Vec<usize> = LineBreakIterator::new_with_options(s, options).collect();
+            check(&breaks);
+
+            let breaks: Vec<usize> = LineBreakIterator::new_with_options(s, options)
+                .with_tab_rule(TabRule::BreakAfter)
+                .with_zwsp_rule(ZwspRule::NoBreak)
+                .with_strict_joiners(true)
+                .with_unbreakable_numbers(true)
+                .with_trailing_whitespace_break(true)
+                .with_complex_breaking(true)
+                .collect();
+            check(&breaks);
+
+            let breaks: Vec<usize> =
+                OwnedLineBreakIterator::new_with_options(s.clone(), options).collect();
+            check(&breaks);
+
+            // Feed the text one character at a time.
+            let mut segmenter = LineBreakSegmenter::new_with_options(options).with_max_buffer(4);
+            let mut breaks = Vec::new();
+            let mut buf = [0; 4];
+            for c in s.chars() {
+                breaks.extend(segmenter.push_str(c.encode_utf8(&mut buf)));
+            }
+            breaks.extend(segmenter.finish());
+            check(&breaks);
+        }
+
+        let breaks: Vec<usize> = GraphemeClusterBreakIterator::new(s).collect();
+        check(&breaks);
+        let breaks: Vec<usize> = WordBreakIterator::new(s).collect();
+        check(&breaks);
+        let breaks: Vec<usize> = HyphenatingLineBreakIterator::new(s, |word: &str| {
+            (0..word.len() + 2).collect::<Vec<usize>>()
+        })
+        .map(|b| b.offset())
+        .collect();
+        check(&breaks);
+
+        let mut breaks = Vec::new();
+        collect_breaks_into(s, &mut breaks);
+        check(&breaks);
+        assert_eq!(count_breaks(s), breaks.len());
+        let _ = contains_mandatory_break(s);
+        let _ = grapheme_count(s);
+        let _ = word_count(s, &BuiltinWordDictionaryProvider);
+
+        let width = |text: &str| text.chars().count() as f32;
+        assert_eq!(fold_lines(s, 2.0, width).concat(), *s);
+        assert_eq!(fold_lines(s, 0.0, width).concat(), *s);
+        assert!(longest_prefix(s, 0.0, width, true) <= s.len());
+        assert!(longest_prefix(s, 0.0, width, false) <= s.len());
+    }
+}
+
+#[test]
+fn latin1_input() {
+    for bytes in byte_corpus().iter() {
+        let lossy = String::from_utf8_lossy(bytes);
+        let check = |breaks: &[usize]| check_breaks(breaks, bytes.len(), |_| true, &lossy);
+
+        for options in line_break_options() {
+            let breaks: Vec<usize> =
+                LineBreakIteratorLatin1::new_with_options(bytes, options).collect();
+            check(&breaks);
+
+            let breaks: Vec<usize> = LineBreakIteratorLatin1::new_with_options(bytes, options)
+                .with_tab_rule(TabRule::NoBreak)
+                .with_zwsp_rule(ZwspRule::BreakAfter)
+                .with_strict_joiners(true)
+                .with_unbreakable_numbers(true)
+                .with_trailing_whitespace_break(true)
+                .collect();
+            check(&breaks);
+        }
+
+        let breaks: Vec<usize> = GraphemeClusterBreakIteratorLatin1::new(bytes).collect();
+        check(&breaks);
+        let breaks: Vec<usize> = WordBreakIteratorLatin1::new(bytes).collect();
+        check(&breaks);
+        for range in word_segments_trimmed_latin1(bytes) {
+            assert!(range.start <= range.end && range.end <= bytes.len());
+        }
+
+        // The replacement characters of invalid UTF-8 are valid input too.
+        let breaks: Vec<usize> = LineBreakIterator::new(&lossy).collect();
+        check_breaks(&breaks, lossy.len(), |b| lossy.is_char_boundary(b), &lossy);
+    }
+}
+
+#[test]
+fn utf16_input() {
+    for units in utf16_corpus().iter() {
+        let lossy = String::from_utf16_lossy(units);
+        let is_boundary = |b: usize| {
+            b == 0
+                || b == units.len()
+                || !(0xDC00..=0xDFFF).contains(&units[b])
+                || !(0xD800..=0xDBFF).contains(&units[b - 1])
+        };
+        let check = |breaks: &[usize]| check_breaks(breaks, units.len(), is_boundary, &lossy);
+
+        for options in line_break_options() {
+            let breaks: Vec<usize> =
+                LineBreakIteratorUtf16::new_with_options(units, options).collect();
+            check(&breaks);
+
+            let breaks: Vec<usize> = LineBreakIteratorUtf16::new_with_options(units, options)
+                .with_strict_joiners(true)
+                .with_unbreakable_numbers(true)
+                .collect();
+            check(&breaks);
+        }
+
+        let breaks: Vec<usize> = GraphemeClusterBreakIteratorUtf16::new(units).collect();
+        check(&breaks);
+        let breaks: Vec<usize> = WordBreakIteratorUtf16::new(units).collect();
+        check(&breaks);
+    }
+}
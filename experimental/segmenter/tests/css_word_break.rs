@@ -174,6 +174,15 @@ fn wordbreak_keepall() {
     // failed test. JL, JV and JT
     let s = "\u{110B}\u{1162}\u{1100}\u{1175}\u{1111}\u{1161}\u{11AB}\u{1103}\u{1161}";
     keep_all(s, vec![27], vec![9]);
+
+    // Korean breaks between syllables by default, but not with keep-all.
+    let s = "한국어 문장";
+    normal(s, vec![3, 6, 10, 13, 16], vec![1, 2, 4, 5, 6]);
+    keep_all(s, vec![10, 16], vec![4, 6]);
+
+    // Mixed Korean and Latin still breaks at spaces.
+    let s = "한국어 latin text 한국어";
+    keep_all(s, vec![10, 16, 21, 30], vec![4, 10, 15, 18]);
 }
 
 #[test]
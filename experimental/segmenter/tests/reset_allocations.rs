@@ -0,0 +1,76 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// This test is in its own target, as it replaces the global allocator.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use icu_segmenter::LineBreakIterator;
+
+/// The system allocator, counting the allocations.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Returns the number of allocations made by `f`, and its result.
+fn count_allocations<T>(f: impl FnOnce() -> T) -> (usize, T) {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    let result = f();
+    (ALLOCATIONS.load(Ordering::Relaxed) - before, result)
+}
+
+#[test]
+fn reset_allocates_less_than_new() {
+    let words = [
+        "ภาษาไทยภาษาไทย",
+        "ภาษาไทยภาษาไทย",
+        "ภาษาไทยภาษาไทย",
+        "ภาษาไทยภาษาไทย",
+    ];
+
+    let (with_new, breaks_with_new) = count_allocations(|| {
+        words
+            .iter()
+            .map(|word| LineBreakIterator::new(word).count())
+            .sum::<usize>()
+    });
+    let (with_reset, breaks_with_reset) = count_allocations(|| {
+        let mut iter = LineBreakIterator::new("");
+        let mut count = 0;
+        for word in words.iter() {
+            iter = iter.reset(word);
+            count += iter.by_ref().count();
+        }
+        count
+    });
+
+    assert_eq!(breaks_with_new, breaks_with_reset);
+    // Resetting keeps the buffers of the iterator, which a new iterator allocates for each word.
+    assert!(
+        with_reset + (words.len() - 1) <= with_new,
+        "{} allocations with reset, {} with new iterators",
+        with_reset,
+        with_new
+    );
+}
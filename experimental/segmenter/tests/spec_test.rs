@@ -2,6 +2,9 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use icu_segmenter::GraphemeClusterBreakIterator;
+use icu_segmenter::GraphemeClusterBreakIteratorLatin1;
+use icu_segmenter::GraphemeClusterBreakIteratorUtf16;
 use icu_segmenter::LineBreakIterator;
 use icu_segmenter::LineBreakIteratorLatin1;
 use icu_segmenter::LineBreakIteratorUtf16;
@@ -99,3 +102,101 @@ fn run_line_break_test() {
         }
     }
 }
+
+#[test]
+fn run_grapheme_break_test() {
+    // The characters whose properties changed after the Unicode version of
+    // the property tables: Khmer, Myanmar and Balinese conjuncts, GUJARATI
+    // SIGN SHADDA and LOWER BLADE SCISSORS.
+    let failed = [
+        "\u{2701}\u{200D}\u{2701}",
+        "\u{0AB8}\u{0AFB}\u{0ACD}\u{0AB8}\u{0AFB}",
+        "\u{1019}\u{1039}\u{1018}\u{102C}\u{1037}",
+        "\u{1004}\u{103A}\u{1039}\u{1011}\u{1039}\u{1011}",
+        "\u{1B12}\u{1B01}\u{1B32}\u{1B44}\u{1B2F}\u{1B32}\u{1B44}\u{1B22}\u{1B44}\u{1B2C}\u{1B32}\u{1B44}\u{1B22}\u{1B38}",
+        "\u{179F}\u{17D2}\u{178F}\u{17D2}\u{179A}\u{17B8}",
+        "\u{1B26}\u{1B17}\u{1B44}\u{1B13}",
+        "\u{1B27}\u{1B13}\u{1B44}\u{1B0B}\u{1B0B}\u{1B04}",
+        "\u{1795}\u{17D2}\u{17AF}\u{1798}",
+        "\u{17A0}\u{17D2}\u{17AB}\u{1791}\u{17D0}\u{1799}",
+    ];
+
+    let f = File::open("./tests/testdata/GraphemeBreakTest.txt");
+    let f = BufReader::new(f.unwrap());
+    for line in f.lines() {
+        let line = line.unwrap();
+        if line.starts_with('#') {
+            continue;
+        }
+        let mut r = line.split('#');
+        let r = r.next();
+        let v: Vec<_> = r.unwrap().split_ascii_whitespace().collect();
+        let mut char_break: Vec<_> = Vec::new();
+        let mut u8_break: Vec<_> = Vec::new();
+        let mut u16_break: Vec<_> = Vec::new();
+        let mut char_vec: Vec<_> = Vec::new();
+        let mut u8_vec: Vec<_> = Vec::new();
+        let mut u16_vec: Vec<_> = Vec::new();
+
+        let mut char_len = 0;
+        let mut u8_len = 0;
+        let mut u16_len = 0;
+
+        let mut ascii_only = true;
+        for (count, token) in v.iter().enumerate() {
+            if count % 2 == 1 {
+                let ch = char::from_u32(u32::from_str_radix(token, 16).unwrap()).unwrap();
+                char_vec.push(ch);
+                char_len += ch.len_utf8();
+
+                if ch as u32 >= 0x100 {
+                    ascii_only = false;
+                } else {
+                    u8_vec.push(ch as u8);
+                    u8_len += 1;
+                }
+
+                let mut buf = [0; 2];
+                let encoded = ch.encode_utf16(&mut buf);
+                u16_vec.extend_from_slice(encoded);
+                u16_len += encoded.len();
+            } else if *token != "\u{00d7}" {
+                assert_eq!(*token, "\u{00f7}");
+                // Unlike the line break iterators, the grapheme cluster break
+                // iterators don't return the break at the start of the text.
+                if count > 0 {
+                    char_break.push(char_len);
+                    u8_break.push(u8_len);
+                    u16_break.push(u16_len);
+                }
+            }
+        }
+        let s: String = char_vec.into_iter().collect();
+        let iter = GraphemeClusterBreakIterator::new(&s);
+        if failed.contains(&s.as_str()) {
+            let result: Vec<usize> = iter.collect();
+            assert_ne!(result, char_break, "{}", line);
+            continue;
+        }
+
+        {
+            println!("UTF8: {}", line);
+            let result: Vec<usize> = iter.collect();
+            assert_eq!(result, char_break, "{}", line);
+        }
+
+        {
+            println!("UTF16: {}", line);
+            let iter = GraphemeClusterBreakIteratorUtf16::new(&u16_vec);
+            let result: Vec<usize> = iter.collect();
+            assert_eq!(result, u16_break, "UTF16: {}", line);
+        }
+
+        if ascii_only {
+            println!("Latin1: {}", line);
+            let iter = GraphemeClusterBreakIteratorLatin1::new(&u8_vec);
+            let result: Vec<usize> = iter.collect();
+            assert_eq!(result, u8_break, "Latin1: {}", line);
+        }
+    }
+}
@@ -452,53 +452,39 @@ with open('lb_define.rs', 'w') as prop_file:
     prop_file.write("pub const KEEP_RULE: i8 = -1;\n")
     prop_file.write("\n")
 
-# For Line break property
-with open('properties_defines.rs', 'w') as properties_file:
-    properties_file.write(header)
-    properties_file.write("use crate::lb_define::*;\n\n")
-
-    for a in range(128):
-        first_value = lb_props[a * 1024]
-        generate_table = False
-        for i in range(1024):
-            if lb_props[a * 1024 + i] != first_value:
-                generate_table = True
-                break
-
-        if not generate_table:
-            table.append("UAX14_PROPERTIES_%s" % first_value)
-            continue
-
-        properties_file.write("pub const UAX14_PROPERTIES_%s: [u8; 1024] = [\n" % str(a))
-        for i in range(int(1024)):
-            properties_file.write(" %s," % lb_props[a * 1024 + i])
-        properties_file.write("];\n\n")
-
-        table.append("UAX14_PROPERTIES_%s" % str(a))
-
-# For Line break property
-with open('properties_other.rs', 'w') as properties_file:
-    properties_file.write(header)
-    properties_file.write("use crate::lb_define::*;\n\n")
-
-    for t in ["ID", "SG", "XX"]:
-        properties_file.write("pub const UAX14_PROPERTIES_%s: [u8; 1024] = [\n" % t)
-        for i in range(int(1024 / 16)):
-            properties_file.write(" ")
-            for j in range(16):
-                properties_file.write(" %s," % t)
-            properties_file.write("\n");
-        properties_file.write("];\n\n");
-
-# For Line break property
+# For Line break property. The code points are split into blocks of
+# PROPERTY_BLOCK_SIZE, and blocks with the same properties are only stored
+# once. The index maps each block of code points to its properties.
+PROPERTY_BLOCK_SIZE = 128
+
+blocks = []
+index = []
+for a in range(0, begin_plane2, PROPERTY_BLOCK_SIZE):
+    block = lb_props[a:a + PROPERTY_BLOCK_SIZE]
+    if block not in blocks:
+        blocks.append(block)
+    index.append(blocks.index(block))
+
+# The index is a [u8].
+assert len(blocks) <= 256
+
 with open('property_table.rs', 'w') as table_file:
     table_file.write(header)
-    table_file.write("use crate::properties_defines::*;\n");
-    table_file.write("use crate::properties_other::*;\n\n");
+    table_file.write("use crate::lb_define::*;\n\n")
+
+    table_file.write("pub const UAX14_PROPERTY_BLOCK_SIZE: usize = %d;\n\n" % PROPERTY_BLOCK_SIZE)
+
+    table_file.write("pub static UAX14_PROPERTY_INDEX: [u8; %d] = [\n" % len(index))
+    for i in index:
+        table_file.write(" %d," % i)
+    table_file.write("];\n\n")
 
-    table_file.write("pub const UAX14_PROPERTY_TABLE: [&[u8; 1024]; 128] = [\n")
-    for i in table:
-        table_file.write("  &%s,\n" % i)
+    table_file.write("pub static UAX14_PROPERTY_BLOCKS: [[u8; %d]; %d] = [\n" % (PROPERTY_BLOCK_SIZE, len(blocks)))
+    for block in blocks:
+        table_file.write("  [")
+        for prop in block:
+            table_file.write(" %s," % prop)
+        table_file.write("],\n")
     table_file.write("];\n")
 
 with open('rule_table.rs', 'w') as table_file:
@@ -535,11 +521,7 @@ with open('rule_table.rs', 'w') as table_file:
 
 # Format and copy generated rs files to src directory.
 
-# properties_defines.rs, properties_other.rs and property_table.rs are Line Break property table map for UAX14
-subprocess.call(["rustfmt", "properties_defines.rs"])
-os.rename("properties_defines.rs", "../src/properties_defines.rs")
-subprocess.call(["rustfmt", "properties_other.rs"])
-os.rename("properties_other.rs", "../src/properties_other.rs")
+# property_table.rs is Line Break property table map for UAX14
 subprocess.call(["rustfmt", "property_table.rs"])
 os.rename("property_table.rs", "../src/property_table.rs")
 # lb_defines.rs is custom state machine defines.
@@ -0,0 +1,156 @@
+#!/usr/bin/env python3
+
+# This file is part of ICU4X. For terms of use, please see the file
+# called LICENSE at the top level of the ICU4X source tree
+# (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+# Generates the Grapheme_Cluster_Break and Word_Break property tables used by
+# the UAX #29 iterators from GraphemeBreakProperty.txt, WordBreakProperty.txt,
+# emoji-data.txt, DerivedCombiningClass.txt, IndicSyllabicCategory.txt and
+# Scripts.txt.
+
+import os
+import re
+import subprocess
+
+end_of_unicode = 0x110000
+
+range_codepoint_pattern = r"([0-9A-F]{4,6})\.\.([0-9A-F]{4,6})\s*;\s*([A-Za-z_]+)"
+single_codepoint_pattern = r"([0-9A-F]{4,6})\s*;\s*([A-Za-z_]+)"
+
+
+def read_property_file(file_name, props, wanted=None):
+    with open(file_name, 'r') as prop_file:
+        for line in prop_file.readlines():
+            start = end = prop = None
+            if m := re.match(range_codepoint_pattern, line):
+                start = int(m[1], 16)
+                end = int(m[2], 16)
+                prop = m[3]
+            elif m := re.match(single_codepoint_pattern, line):
+                start = end = int(m[1], 16)
+                prop = m[2]
+
+            if prop and (wanted is None or prop == wanted):
+                for i in range(start, end + 1):
+                    props[i] = prop
+
+
+# Extended_Pictographic is a property of its own, which is used by both the
+# grapheme cluster break rules (GB11) and the word break rules (WB3c). It is
+# stored as a value of the break properties for the characters which have no
+# other value.
+ext_pict = [None for x in range(end_of_unicode)]
+read_property_file('emoji-data.txt', ext_pict, 'Extended_Pictographic')
+
+
+def get_props(file_name):
+    # Set default value to 'Other' (XX) for all the codepoints
+    props = ["Other" for x in range(end_of_unicode)]
+    read_property_file(file_name, props)
+    for i in range(end_of_unicode):
+        if props[i] == "Other" and ext_pict[i]:
+            props[i] = ext_pict[i]
+    # The Rust enum variants are the property values without underscores.
+    return [prop.replace("_", "") for prop in props]
+
+
+gcb_props = get_props('GraphemeBreakProperty.txt')
+
+# The extending characters which continue an Indic conjunct (GB9c), the
+# Indic_Conjunct_Break value Extend, are the ones with a non-zero
+# Canonical_Combining_Class. The others, such as ZWNJ and the vowel signs
+# which are not spacing marks, end a conjunct.
+ccc_pattern = r"([0-9A-F]{4,6})(?:\.\.([0-9A-F]{4,6}))?\s*;\s*([0-9]+)"
+with open('DerivedCombiningClass.txt', 'r') as ccc_file:
+    for line in ccc_file.readlines():
+        if m := re.match(ccc_pattern, line):
+            start = int(m[1], 16)
+            end = int(m[2], 16) if m[2] else start
+            if int(m[3]) != 0:
+                for i in range(start, end + 1):
+                    if gcb_props[i] == "Extend":
+                        gcb_props[i] = "IndicExtend"
+
+# The consonants and linkers of the Indic_Conjunct_Break property (GB9c) are
+# derived as in UAX #44, from the Indic_Syllabic_Category of the characters of
+# the scripts below. The linkers are Extend characters of their own.
+incb_scripts = {"Bengali", "Devanagari", "Gujarati", "Malayalam", "Oriya", "Telugu"}
+scripts = [None for x in range(end_of_unicode)]
+read_property_file('Scripts.txt', scripts)
+insc = [None for x in range(end_of_unicode)]
+read_property_file('IndicSyllabicCategory.txt', insc)
+for i in range(end_of_unicode):
+    if scripts[i] not in incb_scripts:
+        continue
+    if insc[i] == "Virama":
+        assert gcb_props[i] in ("Extend", "IndicExtend")
+        gcb_props[i] = "IndicLinker"
+    elif insc[i] == "Consonant":
+        assert gcb_props[i] == "Other"
+        gcb_props[i] = "IndicConsonant"
+
+wb_props = get_props('WordBreakProperty.txt')
+
+header = """// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// This file is generated by generate_uax29_properties.py. DO NOT EDIT MANUALLY!
+
+"""
+
+# The code points are split into blocks of PROPERTY_BLOCK_SIZE, and blocks
+# with the same properties are only stored once. The index maps each block of
+# code points to its properties, which are stored as indices of the values.
+PROPERTY_BLOCK_SIZE = 128
+
+
+def write_table(file_name, enum_path, enum_name, prefix, props):
+    values = sorted(set(props))
+    blocks = []
+    index = []
+    for a in range(0, end_of_unicode, PROPERTY_BLOCK_SIZE):
+        block = [values.index(prop) for prop in props[a:a + PROPERTY_BLOCK_SIZE]]
+        if block not in blocks:
+            blocks.append(block)
+        index.append(blocks.index(block))
+
+    # The index is a [u8].
+    assert len(blocks) <= 256
+
+    with open(file_name, 'w') as table_file:
+        table_file.write(header)
+        table_file.write("use %s::{self, *};\n\n" % enum_path)
+
+        table_file.write("pub const %s_PROPERTY_BLOCK_SIZE: usize = %d;\n\n" % (prefix, PROPERTY_BLOCK_SIZE))
+
+        table_file.write("pub static %s_PROPERTY_INDEX: [u8; %d] = [\n" % (prefix, len(index)))
+        for i in index:
+            table_file.write(" %d," % i)
+        table_file.write("];\n\n")
+
+        table_file.write("pub static %s_PROPERTY_VALUES: [%s; %d] = [\n" % (prefix, enum_name, len(values)))
+        for value in values:
+            table_file.write(" %s," % value)
+        table_file.write("];\n\n")
+
+        table_file.write("pub static %s_PROPERTY_BLOCKS: [[u8; %d]; %d] = [\n" % (prefix, PROPERTY_BLOCK_SIZE, len(blocks)))
+        for block in blocks:
+            table_file.write("  [")
+            for prop in block:
+                table_file.write(" %d," % prop)
+            table_file.write("],\n")
+        table_file.write("];\n")
+
+    # Format and copy the generated rs file to src directory.
+    subprocess.call(["rustfmt", file_name])
+    os.rename(file_name, "../src/" + file_name)
+
+
+# grapheme_property_table.rs is Grapheme_Cluster_Break property table map for UAX29
+write_table('grapheme_property_table.rs', 'crate::grapheme::GraphemeClusterBreakProperty',
+            'GraphemeClusterBreakProperty', 'GCB', gcb_props)
+# word_property_table.rs is Word_Break property table map for UAX29
+write_table('word_property_table.rs', 'crate::word::WordBreakProperty',
+            'WordBreakProperty', 'WB', wb_props)
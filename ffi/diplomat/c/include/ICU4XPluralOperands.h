@@ -17,6 +17,7 @@ typedef struct ICU4XPluralOperands {
     uint64_t f;
     uint64_t t;
     size_t c;
+    size_t e;
 } ICU4XPluralOperands;
 #include "ICU4XCreatePluralOperandsResult.h"
 
@@ -37,6 +37,7 @@ struct ICU4XPluralOperands {
   uint64_t f;
   uint64_t t;
   size_t c;
+  size_t e;
 
   /**
    * FFI version of `PluralOperands::from_str()`.
@@ -50,6 +51,6 @@ struct ICU4XPluralOperands {
 inline ICU4XCreatePluralOperandsResult ICU4XPluralOperands::create(const std::string_view s) {
   capi::ICU4XCreatePluralOperandsResult diplomat_raw_struct_out_value = capi::ICU4XPluralOperands_create(s.data(), s.length());
   capi::ICU4XPluralOperands diplomat_raw_struct_out_value_operands = diplomat_raw_struct_out_value.operands;
-  return ICU4XCreatePluralOperandsResult{ .operands = std::move(ICU4XPluralOperands{ .i = std::move(diplomat_raw_struct_out_value_operands.i), .v = std::move(diplomat_raw_struct_out_value_operands.v), .w = std::move(diplomat_raw_struct_out_value_operands.w), .f = std::move(diplomat_raw_struct_out_value_operands.f), .t = std::move(diplomat_raw_struct_out_value_operands.t), .c = std::move(diplomat_raw_struct_out_value_operands.c) }), .success = std::move(diplomat_raw_struct_out_value.success) };
+  return ICU4XCreatePluralOperandsResult{ .operands = std::move(ICU4XPluralOperands{ .i = std::move(diplomat_raw_struct_out_value_operands.i), .v = std::move(diplomat_raw_struct_out_value_operands.v), .w = std::move(diplomat_raw_struct_out_value_operands.w), .f = std::move(diplomat_raw_struct_out_value_operands.f), .t = std::move(diplomat_raw_struct_out_value_operands.t), .c = std::move(diplomat_raw_struct_out_value_operands.c), .e = std::move(diplomat_raw_struct_out_value_operands.e) }), .success = std::move(diplomat_raw_struct_out_value.success) };
 }
 #endif
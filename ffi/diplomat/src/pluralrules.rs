@@ -81,6 +81,7 @@ pub mod ffi {
                 f: op.f,
                 t: op.t,
                 c: op.c,
+                e: op.e,
             });
 
             match res {
@@ -136,6 +137,7 @@ pub mod ffi {
         pub f: u64,
         pub t: u64,
         pub c: usize,
+        pub e: usize,
     }
 
     impl ICU4XPluralOperands {
@@ -152,6 +154,7 @@ pub mod ffi {
                         f: ops.f,
                         t: ops.t,
                         c: ops.c,
+                        e: ops.e,
                     },
                     success: true,
                 })
@@ -163,6 +166,7 @@ pub mod ffi {
                         f: 0,
                         t: 0,
                         c: 0,
+                        e: 0,
                     },
                     success: false,
                 })
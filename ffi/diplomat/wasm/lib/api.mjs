@@ -75,7 +75,7 @@ export class ICU4XCreatePluralOperandsResult {
   }
 
   get success() {
-    return (new Uint8Array(wasm.memory.buffer, this.underlying + 36, 1))[0] == 1;
+    return (new Uint8Array(wasm.memory.buffer, this.underlying + 40, 1))[0] == 1;
   }
 }
 
@@ -804,12 +804,12 @@ export class ICU4XPluralOperands {
     let s_diplomat_buf = new Uint8Array(wasm.memory.buffer, s_diplomat_ptr, s_diplomat_bytes.length);
     s_diplomat_buf.set(s_diplomat_bytes, 0);
     const diplomat_out = (() => {
-      const diplomat_receive_buffer = wasm.diplomat_alloc(37, 8);
+      const diplomat_receive_buffer = wasm.diplomat_alloc(41, 8);
       wasm.ICU4XPluralOperands_create(diplomat_receive_buffer, s_diplomat_ptr, s_diplomat_bytes.length);
       const out = new ICU4XCreatePluralOperandsResult(diplomat_receive_buffer);
       diplomat_alloc_destroy_registry.register(out, {
         ptr: out.underlying,
-        size: 37,
+        size: 41,
         align: 8,
       });
       return out;
@@ -841,6 +841,10 @@ export class ICU4XPluralOperands {
   get c() {
     return (new Uint32Array(wasm.memory.buffer, this.underlying + 32, 1))[0];
   }
+
+  get e() {
+    return (new Uint32Array(wasm.memory.buffer, this.underlying + 36, 1))[0];
+  }
 }
 
 const ICU4XPluralRuleType_js_to_rust = {
@@ -215,6 +215,7 @@ pub(crate) mod internal {
                     f: 50,
                     t: 5,
                     c: 0,
+                    e: 0,
                 },
             }];
             for test in tests {
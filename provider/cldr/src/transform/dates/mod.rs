@@ -4,6 +4,7 @@
 
 pub mod patterns;
 pub mod symbols;
+pub mod week_data;
 
 /// Serde structs for the CLDR JSON dates files.
 pub(self) mod cldr_json {
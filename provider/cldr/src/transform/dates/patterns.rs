@@ -51,6 +51,23 @@ impl TryFrom<&dyn CldrPaths> for DatePatternsProvider<'_> {
     }
 }
 
+/// Reads the CLDR JSON of a ca-gregorian.json file directly, such as
+/// `{"main": {"en": {"dates": {"calendars": {"gregorian": {...}}}}}}`.
+///
+/// The `dateFormats`, `timeFormats` and `dateTimeFormats` of each locale, including the
+/// `availableFormats` skeletons, are converted to a [`gregory::DatePatternsV1`] when it is loaded.
+impl TryFrom<&str> for DatePatternsProvider<'_> {
+    type Error = Error;
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let resource: cldr_json::Resource =
+            serde_json::from_str(input).map_err(|e| Error::Json(e, None))?;
+        Ok(Self {
+            data: resource.main.0,
+            _phantom: PhantomData,
+        })
+    }
+}
+
 impl<'data> KeyedDataProvider for DatePatternsProvider<'data> {
     fn supports_key(resc_key: &ResourceKey) -> Result<(), DataError> {
         key::GREGORY_DATE_PATTERNS_V1.match_key(*resc_key)
@@ -71,13 +88,13 @@ impl<'data> DataProvider<'data, gregory::DatePatternsV1Marker> for DatePatternsP
             Ok(idx) => &self.data[idx].1.dates,
             Err(_) => return Err(DataError::MissingResourceOptions(req.clone())),
         };
+        let patterns =
+            gregory::DatePatternsV1::try_from(dates).map_err(DataError::new_resc_error)?;
         Ok(DataResponse {
             metadata: DataResponseMetadata {
                 data_langid: req.resource_path.options.langid.clone(),
             },
-            payload: Some(DataPayload::from_owned(gregory::DatePatternsV1::from(
-                dates,
-            ))),
+            payload: Some(DataPayload::from_owned(patterns)),
         })
     }
 }
@@ -117,72 +134,130 @@ impl From<&cldr_json::LengthPatterns> for gregory::patterns::LengthPatternsV1 {
     }
 }
 
-impl From<&cldr_json::DateTimeFormats> for gregory::patterns::DateTimeFormatsV1 {
-    fn from(other: &cldr_json::DateTimeFormats) -> Self {
-        use gregory::patterns::{PatternV1, SkeletonV1, SkeletonsV1};
+impl TryFrom<&cldr_json::DateTimeFormats> for gregory::patterns::DateTimeFormatsV1 {
+    type Error = Error;
+    fn try_from(other: &cldr_json::DateTimeFormats) -> Result<Self, Self::Error> {
+        use gregory::patterns::{
+            PatternV1, PluralPatternsV1, PluralSkeletonsV1, SkeletonV1, SkeletonsV1,
+        };
+        use icu_plurals::PluralCategory;
         use litemap::LiteMap;
 
-        // TODO(#308): Support numbering system variations. We currently throw them away.
-        Self {
-            length_patterns: gregory::patterns::LengthPatternsV1 {
-                full: Cow::Owned(other.full.get_pattern().clone()),
-                long: Cow::Owned(other.long.get_pattern().clone()),
-                medium: Cow::Owned(other.medium.get_pattern().clone()),
-                short: Cow::Owned(other.short.get_pattern().clone()),
-            },
-            skeletons: {
-                let mut skeletons = SkeletonsV1(LiteMap::new());
-
-                // The CLDR keys for available_formats can have duplicate skeletons with either
-                // an additional variant, or with multiple variants for different plurals.
-                for (skeleton_str, pattern_str) in other.available_formats.0.iter() {
-                    let mut unique_skeleton = None;
-                    let mut variant_parts = Vec::new();
-
-                    for part in skeleton_str.split('-') {
-                        match unique_skeleton {
-                            None => {
-                                unique_skeleton = Some(part);
-                            }
-                            Some(_) => variant_parts.push(part),
-                        }
+        let mut skeletons = SkeletonsV1(LiteMap::new());
+        // The variants of each plural skeleton, which are only complete once all the skeletons
+        // have been read.
+        let mut plural_variants: LiteMap<SkeletonV1, Vec<(PluralCategory, PatternV1)>> =
+            LiteMap::new();
+
+        // The CLDR keys for available_formats can have duplicate skeletons with either
+        // an additional variant, or with multiple variants for different plurals.
+        for (skeleton_str, pattern_str) in other.available_formats.0.iter() {
+            let mut unique_skeleton = None;
+            let mut variant_parts = Vec::new();
+
+            for part in skeleton_str.split('-') {
+                match unique_skeleton {
+                    None => {
+                        unique_skeleton = Some(part);
                     }
+                    Some(_) => variant_parts.push(part),
+                }
+            }
+
+            let unique_skeleton = unique_skeleton.expect("Expected to find a skeleton.");
+
+            let skeleton_fields_v1 = match SkeletonV1::try_from(unique_skeleton) {
+                Ok(s) => s,
+                Err(err) => match err {
+                    // Ignore unimplemented fields for now.
+                    SkeletonError::SymbolUnimplemented(_) => continue,
+                    _ => panic!("{:?} {}", unique_skeleton, err),
+                },
+            };
 
-                    let unique_skeleton = unique_skeleton.expect("Expected to find a skeleton.");
-
-                    let skeleton_fields_v1 = match SkeletonV1::try_from(unique_skeleton) {
-                        Ok(s) => s,
-                        Err(err) => match err {
-                            // Ignore unimplemented fields for now.
-                            SkeletonError::SymbolUnimplemented(_) => continue,
-                            _ => panic!("{:?} {}", unique_skeleton, err),
-                        },
-                    };
-
-                    if !variant_parts.is_empty() {
-                        eprintln!(
-                            "This skeleton string is not yet supported: {:?}",
-                            skeleton_str
-                        );
-                        continue;
+            let category = match variant_parts.as_slice() {
+                [] => None,
+                ["count", count] => Some(match *count {
+                    "zero" => PluralCategory::Zero,
+                    "one" => PluralCategory::One,
+                    "two" => PluralCategory::Two,
+                    "few" => PluralCategory::Few,
+                    "many" => PluralCategory::Many,
+                    "other" => PluralCategory::Other,
+                    _ => {
+                        return Err(Error::Custom(
+                            format!("Unknown plural category: {:?}", skeleton_str),
+                            None,
+                        ))
                     }
+                }),
+                _ => {
+                    eprintln!(
+                        "This skeleton string is not yet supported: {:?}",
+                        skeleton_str
+                    );
+                    continue;
+                }
+            };
 
-                    let pattern_v1 = PatternV1::try_from(pattern_str as &str)
-                        .expect("Unable to parse a pattern");
+            let pattern_v1 =
+                PatternV1::try_from(pattern_str as &str).expect("Unable to parse a pattern");
 
+            match category {
+                None => {
                     skeletons.0.insert(skeleton_fields_v1, pattern_v1);
                 }
+                Some(category) => match plural_variants.get_mut(&skeleton_fields_v1) {
+                    Some(variants) => variants.push((category, pattern_v1)),
+                    None => {
+                        plural_variants.insert(skeleton_fields_v1, vec![(category, pattern_v1)]);
+                    }
+                },
+            }
+        }
 
-                skeletons
-            },
+        // The `other` variant is the fallback of all the categories, so it must be present.
+        let mut plural_skeletons = PluralSkeletonsV1(LiteMap::new());
+        for (skeleton, variants) in plural_variants.iter() {
+            let other = variants
+                .iter()
+                .find(|(category, _)| *category == PluralCategory::Other)
+                .ok_or_else(|| {
+                    Error::Custom(
+                        format!(
+                            "Missing the other plural variant of skeleton: {:?}",
+                            skeleton
+                        ),
+                        None,
+                    )
+                })?;
+            let mut patterns = PluralPatternsV1::new(other.1.clone());
+            for (category, pattern) in variants.iter() {
+                patterns.set(*category, pattern.clone());
+            }
+            plural_skeletons.0.insert(skeleton.clone(), patterns);
         }
+
+        // TODO(#308): Support numbering system variations. We currently throw them away.
+        Ok(Self {
+            length_patterns: gregory::patterns::LengthPatternsV1 {
+                full: Cow::Owned(other.full.get_pattern().clone()),
+                long: Cow::Owned(other.long.get_pattern().clone()),
+                medium: Cow::Owned(other.medium.get_pattern().clone()),
+                short: Cow::Owned(other.short.get_pattern().clone()),
+            },
+            skeletons,
+            plural_skeletons,
+        })
     }
 }
 
-impl From<&cldr_json::Dates> for gregory::DatePatternsV1 {
-    fn from(other: &cldr_json::Dates) -> Self {
-        let date_time_formats_v1 =
-            gregory::patterns::DateTimeFormatsV1::from(&other.calendars.gregorian.datetime_formats);
+impl TryFrom<&cldr_json::Dates> for gregory::DatePatternsV1 {
+    type Error = Error;
+    fn try_from(other: &cldr_json::Dates) -> Result<Self, Self::Error> {
+        let date_time_formats_v1 = gregory::patterns::DateTimeFormatsV1::try_from(
+            &other.calendars.gregorian.datetime_formats,
+        )?;
 
         let pattern_str_full = other.calendars.gregorian.time_formats.full.get_pattern();
         let pattern_str_long = other.calendars.gregorian.time_formats.long.get_pattern();
@@ -254,13 +329,13 @@ impl From<&cldr_json::Dates> for gregory::DatePatternsV1 {
             }
         };
 
-        Self {
+        Ok(Self {
             date: (&other.calendars.gregorian.date_formats).into(),
             time_h11_h12,
             time_h23_h24,
             preferred_hour_cycle,
             datetime: date_time_formats_v1,
-        }
+        })
     }
 }
 
@@ -342,3 +417,137 @@ fn test_datetime_skeletons() {
         skeletons.get(&SkeletonV1::try_from("M").expect("Failed to create Skeleton"))
     );
 }
+
+#[test]
+fn test_from_json() {
+    use gregory::patterns::{PatternV1, SkeletonV1};
+    use icu_locid_macros::langid;
+
+    let json = r#"{
+        "main": {
+            "en": {
+                "dates": {
+                    "calendars": {
+                        "gregorian": {
+                            "months": {
+                                "format": {
+                                    "abbreviated": {"1": "Jan", "2": "Feb", "3": "Mar", "4": "Apr", "5": "May", "6": "Jun", "7": "Jul", "8": "Aug", "9": "Sep", "10": "Oct", "11": "Nov", "12": "Dec"},
+                                    "narrow": {"1": "J", "2": "F", "3": "M", "4": "A", "5": "M", "6": "J", "7": "J", "8": "A", "9": "S", "10": "O", "11": "N", "12": "D"},
+                                    "wide": {"1": "January", "2": "February", "3": "March", "4": "April", "5": "May", "6": "June", "7": "July", "8": "August", "9": "September", "10": "October", "11": "November", "12": "December"}
+                                }
+                            },
+                            "days": {
+                                "format": {
+                                    "abbreviated": {"sun": "Sun", "mon": "Mon", "tue": "Tue", "wed": "Wed", "thu": "Thu", "fri": "Fri", "sat": "Sat"},
+                                    "narrow": {"sun": "S", "mon": "M", "tue": "T", "wed": "W", "thu": "T", "fri": "F", "sat": "S"},
+                                    "wide": {"sun": "Sunday", "mon": "Monday", "tue": "Tuesday", "wed": "Wednesday", "thu": "Thursday", "fri": "Friday", "sat": "Saturday"}
+                                }
+                            },
+                            "dayPeriods": {
+                                "format": {
+                                    "abbreviated": {"am": "AM", "pm": "PM"},
+                                    "narrow": {"am": "a", "pm": "p"},
+                                    "wide": {"am": "AM", "pm": "PM"}
+                                }
+                            },
+                            "dateFormats": {
+                                "full": "EEEE, MMMM d, y",
+                                "long": "MMMM d, y",
+                                "medium": "MMM d, y",
+                                "short": "M/d/yy"
+                            },
+                            "timeFormats": {
+                                "full": "h:mm:ss a",
+                                "long": "h:mm:ss a",
+                                "medium": "h:mm:ss a",
+                                "short": "h:mm a"
+                            },
+                            "dateTimeFormats": {
+                                "full": "{1} 'at' {0}",
+                                "long": "{1} 'at' {0}",
+                                "medium": "{1}, {0}",
+                                "short": "{1}, {0}",
+                                "availableFormats": {
+                                    "Hm": "HH:mm",
+                                    "Hms": "HH:mm:ss",
+                                    "hm": "h:mm a",
+                                    "hms": "h:mm:ss a",
+                                    "yMMMd": "MMM d, y",
+                                    "yw-count-one": "'week' w 'of' Y",
+                                    "yw-count-other": "'week' w 'of' Y"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }"#;
+    let provider = DatePatternsProvider::try_from(json).expect("The JSON should be valid");
+
+    let en_dates: DataPayload<gregory::DatePatternsV1Marker> = provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: key::GREGORY_DATE_PATTERNS_V1,
+                options: ResourceOptions {
+                    variant: None,
+                    langid: Some(langid!("en")),
+                },
+            },
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    let en_dates = en_dates.get();
+
+    assert_eq!("EEEE, MMMM d, y", en_dates.date.full);
+    assert_eq!("M/d/yy", en_dates.date.short);
+    assert_eq!("h:mm a", en_dates.time_h11_h12.short);
+    assert_eq!(CoarseHourCycle::H11H12, en_dates.preferred_hour_cycle);
+
+    // The dateTimeFormats glue
+    assert_eq!("{1} 'at' {0}", en_dates.datetime.length_patterns.full);
+    assert_eq!("{1}, {0}", en_dates.datetime.length_patterns.medium);
+
+    // The availableFormats
+    let skeleton = |s: &str| SkeletonV1::try_from(s).expect("Failed to create Skeleton");
+    let pattern = |s: &str| PatternV1::try_from(s).expect("Failed to create pattern");
+    let skeletons = &en_dates.datetime.skeletons.0;
+    assert_eq!(skeletons.len(), 5);
+    assert_eq!(Some(&pattern("HH:mm")), skeletons.get(&skeleton("Hm")));
+    assert_eq!(
+        Some(&pattern("MMM d, y")),
+        skeletons.get(&skeleton("yMMMd"))
+    );
+    // The skeletons with plural variants.
+    let plural_skeletons = &en_dates.datetime.plural_skeletons.0;
+    assert_eq!(plural_skeletons.len(), 1);
+    let patterns = plural_skeletons
+        .get(&skeleton("yw"))
+        .expect("Expected the plural skeleton yw");
+    assert_eq!(Some(pattern("'week' w 'of' Y")), patterns.one);
+    assert_eq!(pattern("'week' w 'of' Y"), patterns.other);
+
+    assert!(DatePatternsProvider::try_from("not json").is_err());
+}
+
+#[test]
+fn test_missing_other_plural_variant() {
+    let json = r#"{
+        "full": "{1} 'at' {0}",
+        "long": "{1} 'at' {0}",
+        "medium": "{1}, {0}",
+        "short": "{1}, {0}",
+        "availableFormats": {
+            "yMMMd": "MMM d, y",
+            "yw-count-one": "'week' w 'of' Y"
+        }
+    }"#;
+    let formats: cldr_json::DateTimeFormats =
+        serde_json::from_str(json).expect("The JSON should be valid");
+
+    // The `other` variant is the fallback of the `one` variant, so it can't be missing.
+    let error = gregory::patterns::DateTimeFormatsV1::try_from(&formats)
+        .expect_err("The other variant of yw is missing");
+    assert!(error.to_string().contains("Missing the other plural variant"));
+}
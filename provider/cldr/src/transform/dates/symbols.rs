@@ -108,6 +108,9 @@ impl From<&cldr_json::Dates> for gregory::DateSymbolsV1 {
             months: (&other.calendars.gregorian.months).into(),
             weekdays: (&other.calendars.gregorian.days).into(),
             day_periods: (&other.calendars.gregorian.day_periods).into(),
+            // TODO(#493): Support symbols for non-Gregorian calendars.
+            cyclic_years: None,
+            month_patterns: None,
         }
     }
 }
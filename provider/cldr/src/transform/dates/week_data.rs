@@ -0,0 +1,281 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::cldr_langid::CldrLangID;
+use crate::error::Error;
+use crate::reader::{get_subdirectories, open_reader};
+use crate::CldrPaths;
+use icu_datetime::provider::{key, week_data::*};
+use icu_locid::subtags::Region;
+use icu_locid::LanguageIdentifier;
+use icu_provider::iter::{IterableDataProviderCore, KeyedDataProvider};
+use icu_provider::prelude::*;
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+/// All keys that this module is able to produce.
+pub const ALL_KEYS: [ResourceKey; 1] = [key::WEEK_DATA_V1];
+
+/// A data provider reading from the CLDR JSON weekData.json file.
+///
+/// The week data is defined per region, so the data of a locale is the one of its region, or of
+/// the region of its likely subtags if it has none. The regions that are not listed use the data
+/// of the world, `001`.
+#[derive(PartialEq, Debug)]
+pub struct WeekDataProvider<'data> {
+    week_data: cldr_json::Resource,
+    likely_subtags: cldr_json::LikelySubtagsResource,
+    langids: Vec<CldrLangID>,
+    _phantom: PhantomData<&'data ()>, // placeholder for when we need the lifetime param
+}
+
+impl TryFrom<&dyn CldrPaths> for WeekDataProvider<'_> {
+    type Error = Error;
+    fn try_from(cldr_paths: &dyn CldrPaths) -> Result<Self, Self::Error> {
+        let supplemental = cldr_paths.cldr_core()?.join("supplemental");
+        let week_data: cldr_json::Resource = {
+            let path = supplemental.join("weekData.json");
+            serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?
+        };
+        let likely_subtags: cldr_json::LikelySubtagsResource = {
+            let path = supplemental.join("likelySubtags.json");
+            serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?
+        };
+
+        // The week data is provided for the same locales as the other date data.
+        let mut langids = vec![];
+        for dir in get_subdirectories(&cldr_paths.cldr_dates()?.join("main"))? {
+            let name = dir.file_name().and_then(|name| name.to_str()).unwrap_or("");
+            let langid: CldrLangID = name
+                .parse()
+                .map_err(|_| Error::Custom(format!("Invalid locale directory: {}", name), None))?;
+            langids.push(langid);
+        }
+        langids.sort();
+
+        Ok(Self {
+            week_data,
+            likely_subtags,
+            langids,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<'data> WeekDataProvider<'data> {
+    /// Returns the region whose week data is used by the `langid`.
+    fn get_region(&self, langid: &LanguageIdentifier) -> Option<Region> {
+        if langid.region.is_some() {
+            return langid.region;
+        }
+        // Search the likely subtags of the language and script, and then of the language.
+        let find = |script| {
+            let search = LanguageIdentifier {
+                language: langid.language,
+                script,
+                ..Default::default()
+            };
+            self.likely_subtags
+                .supplemental
+                .likely_subtags
+                .iter()
+                .find(|(from, _)| *from == search)
+                .and_then(|(_, to)| to.region)
+        };
+        find(langid.script).or_else(|| find(None))
+    }
+
+    fn get_week_data(&self, region: Option<Region>) -> Result<WeekDataV1, Error> {
+        let data = &self.week_data.supplemental.week_data;
+        let get = |map: &[(String, String)]| {
+            let by_key = |key: &str| map.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str());
+            region
+                .and_then(|region| by_key(region.as_str()))
+                .or_else(|| by_key("001"))
+                .map(str::to_string)
+        };
+        let first_day = get(&data.first_day)
+            .ok_or_else(|| Error::Custom("Missing firstDay of the world".to_string(), None))?;
+        let min_days = get(&data.min_days)
+            .ok_or_else(|| Error::Custom("Missing minDays of the world".to_string(), None))?;
+
+        Ok(WeekDataV1 {
+            first_weekday: match first_day.as_str() {
+                "mon" => 1,
+                "tue" => 2,
+                "wed" => 3,
+                "thu" => 4,
+                "fri" => 5,
+                "sat" => 6,
+                "sun" => 7,
+                _ => {
+                    return Err(Error::Custom(
+                        format!("Invalid firstDay: {}", first_day),
+                        None,
+                    ))
+                }
+            },
+            min_days_in_first_week: match min_days.parse() {
+                Ok(days @ 1..=7) => days,
+                _ => {
+                    return Err(Error::Custom(
+                        format!("Invalid minDays: {}", min_days),
+                        None,
+                    ))
+                }
+            },
+        })
+    }
+}
+
+impl<'data> KeyedDataProvider for WeekDataProvider<'data> {
+    fn supports_key(resc_key: &ResourceKey) -> Result<(), DataError> {
+        key::WEEK_DATA_V1.match_key(*resc_key)
+    }
+}
+
+impl<'data> DataProvider<'data, WeekDataV1Marker> for WeekDataProvider<'data> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, WeekDataV1Marker>, DataError> {
+        WeekDataProvider::supports_key(&req.resource_path.key)?;
+        let cldr_langid: CldrLangID = req.try_langid()?.clone().into();
+        if self.langids.binary_search(&cldr_langid).is_err() {
+            return Err(DataError::MissingResourceOptions(req.clone()));
+        }
+        let region = self.get_region(&cldr_langid.langid);
+        let week_data = self
+            .get_week_data(region)
+            .map_err(DataError::new_resc_error)?;
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+            },
+            payload: Some(DataPayload::from_owned(week_data)),
+        })
+    }
+}
+
+icu_provider::impl_dyn_provider!(WeekDataProvider<'data>, {
+    _ => WeekDataV1Marker,
+}, SERDE_SE, 'data);
+
+impl<'data> IterableDataProviderCore for WeekDataProvider<'data> {
+    #[allow(clippy::needless_collect)] // https://github.com/rust-lang/rust-clippy/issues/7526
+    fn supported_options_for_key(
+        &self,
+        _resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        let list: Vec<ResourceOptions> = self
+            .langids
+            .iter()
+            .map(|l| ResourceOptions {
+                variant: None,
+                langid: Some(l.langid.clone()),
+            })
+            .collect();
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+/// Serde structs for the CLDR JSON week data and likely subtags files.
+pub(self) mod cldr_json {
+    use icu_locid::LanguageIdentifier;
+    use serde::Deserialize;
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct WeekData {
+        #[serde(with = "tuple_vec_map", rename = "minDays")]
+        pub min_days: Vec<(String, String)>,
+        #[serde(with = "tuple_vec_map", rename = "firstDay")]
+        pub first_day: Vec<(String, String)>,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Supplemental {
+        #[serde(rename = "weekData")]
+        pub week_data: WeekData,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Resource {
+        pub supplemental: Supplemental,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct LikelySubtagsSupplemental {
+        #[serde(with = "tuple_vec_map", rename = "likelySubtags")]
+        pub likely_subtags: Vec<(LanguageIdentifier, LanguageIdentifier)>,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct LikelySubtagsResource {
+        pub supplemental: LikelySubtagsSupplemental,
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid_macros::langid;
+
+    let cldr_paths = crate::cldr_paths::for_test();
+    let provider = WeekDataProvider::try_from(&cldr_paths as &dyn CldrPaths).unwrap();
+    let load = |langid: LanguageIdentifier| -> WeekDataV1 {
+        let payload: DataPayload<WeekDataV1Marker> = provider
+            .load_payload(&DataRequest {
+                resource_path: ResourcePath {
+                    key: key::WEEK_DATA_V1,
+                    options: ResourceOptions {
+                        variant: None,
+                        langid: Some(langid),
+                    },
+                },
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+        *payload.get()
+    };
+
+    // The region of "en" is the US, where weeks start on Sunday.
+    assert_eq!(
+        load(langid!("en")),
+        WeekDataV1 {
+            first_weekday: 7,
+            min_days_in_first_week: 1,
+        }
+    );
+    assert_eq!(
+        load(langid!("fr")),
+        WeekDataV1 {
+            first_weekday: 1,
+            min_days_in_first_week: 4,
+        }
+    );
+    // Egypt starts the week on Saturday.
+    assert_eq!(
+        load(langid!("ar-EG")),
+        WeekDataV1 {
+            first_weekday: 6,
+            min_days_in_first_week: 1,
+        }
+    );
+    // The likely region of "sr-Cyrl" is the one of "sr", Serbia.
+    assert_eq!(
+        load(langid!("sr-Cyrl")),
+        WeekDataV1 {
+            first_weekday: 1,
+            min_days_in_first_week: 1,
+        }
+    );
+    // The data of the world.
+    assert_eq!(
+        load(langid!("en-001")),
+        WeekDataV1 {
+            first_weekday: 1,
+            min_days_in_first_week: 1,
+        }
+    );
+}
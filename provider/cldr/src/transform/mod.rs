@@ -10,7 +10,9 @@ mod plurals;
 mod time_zones;
 
 pub use aliases::AliasesProvider;
-pub use dates::{patterns::DatePatternsProvider, symbols::DateSymbolsProvider};
+pub use dates::{
+    patterns::DatePatternsProvider, symbols::DateSymbolsProvider, week_data::WeekDataProvider,
+};
 pub use likelysubtags::LikelySubtagsProvider;
 pub use numbers::NumbersProvider;
 pub use plurals::PluralsProvider;
@@ -29,6 +31,7 @@ pub fn get_all_cldr_keys() -> Vec<ResourceKey> {
     result.extend(&aliases::ALL_KEYS);
     result.extend(&dates::symbols::ALL_KEYS);
     result.extend(&dates::patterns::ALL_KEYS);
+    result.extend(&dates::week_data::ALL_KEYS);
     result.extend(&likelysubtags::ALL_KEYS);
     result.extend(&numbers::ALL_KEYS);
     result.extend(&plurals::ALL_KEYS);
@@ -42,6 +45,7 @@ pub struct CldrJsonDataProvider<'a, 'data> {
     aliases: LazyCldrProvider<AliasesProvider<'data>>,
     date_symbols: LazyCldrProvider<DateSymbolsProvider<'data>>,
     date_patterns: LazyCldrProvider<DatePatternsProvider<'data>>,
+    week_data: LazyCldrProvider<WeekDataProvider<'data>>,
     likelysubtags: LazyCldrProvider<LikelySubtagsProvider<'data>>,
     numbers: LazyCldrProvider<NumbersProvider>,
     plurals: LazyCldrProvider<PluralsProvider<'data>>,
@@ -55,6 +59,7 @@ impl<'a> CldrJsonDataProvider<'a, '_> {
             aliases: Default::default(),
             date_symbols: Default::default(),
             date_patterns: Default::default(),
+            week_data: Default::default(),
             likelysubtags: Default::default(),
             numbers: Default::default(),
             plurals: Default::default(),
@@ -77,6 +82,9 @@ impl<'a, 'data> DataProvider<'data, SerdeSeDataStructMarker> for CldrJsonDataPro
         if let Some(result) = self.date_patterns.try_load_serde(req, self.cldr_paths)? {
             return Ok(result);
         }
+        if let Some(result) = self.week_data.try_load_serde(req, self.cldr_paths)? {
+            return Ok(result);
+        }
         if let Some(result) = self.likelysubtags.try_load_serde(req, self.cldr_paths)? {
             return Ok(result);
         }
@@ -116,6 +124,12 @@ impl<'a> IterableDataProviderCore for CldrJsonDataProvider<'a, '_> {
         {
             return Ok(Box::new(resp.into_iter()));
         }
+        if let Some(resp) = self
+            .week_data
+            .try_supported_options(resc_key, self.cldr_paths)?
+        {
+            return Ok(Box::new(resp.into_iter()));
+        }
         if let Some(resp) = self
             .likelysubtags
             .try_supported_options(resc_key, self.cldr_paths)?
@@ -149,5 +163,6 @@ impl<'a, 'd> KeyedDataProvider for CldrJsonDataProvider<'a, 'd> {
         PluralsProvider::supports_key(resc_key)
             .or_else(|err| DateSymbolsProvider::or_else_supports_key(err, resc_key))
             .or_else(|err| DatePatternsProvider::or_else_supports_key(err, resc_key))
+            .or_else(|err| WeekDataProvider::or_else_supports_key(err, resc_key))
     }
 }
@@ -29,6 +29,94 @@ impl BinaryPropertiesDataProvider {
     }
 }
 
+/// The long names and other aliases of the binary properties, mapped to the short names used as
+/// the ResourceKey subcategory strings. See PropertyAliases.txt.
+const BINARY_PROPERTY_ALIASES: &[(&str, &str)] = &[
+    ("ASCII_Hex_Digit", "AHex"),
+    ("Alphabetic", "Alpha"),
+    ("Bidi_Control", "Bidi_C"),
+    ("Bidi_Mirrored", "Bidi_M"),
+    ("Case_Ignorable", "CI"),
+    ("Case_Sensitive", "Sensitive"),
+    ("Changes_When_Casefolded", "CWCF"),
+    ("Changes_When_Casemapped", "CWCM"),
+    ("Changes_When_Lowercased", "CWL"),
+    ("Changes_When_NFKC_Casefolded", "CWKCF"),
+    ("Changes_When_Titlecased", "CWT"),
+    ("Changes_When_Uppercased", "CWU"),
+    ("Default_Ignorable_Code_Point", "DI"),
+    ("Deprecated", "Dep"),
+    ("Diacritic", "Dia"),
+    ("Emoji_Component", "EComp"),
+    ("Emoji_Modifier", "EMod"),
+    ("Emoji_Modifier_Base", "EBase"),
+    ("Emoji_Presentation", "EPres"),
+    ("Extended_Pictographic", "ExtPict"),
+    ("Extender", "Ext"),
+    ("Full_Composition_Exclusion", "Comp_Ex"),
+    ("Grapheme_Base", "Gr_Base"),
+    ("Grapheme_Extend", "Gr_Ext"),
+    ("Grapheme_Link", "Gr_Link"),
+    ("Hex_Digit", "Hex"),
+    ("ID_Continue", "IDC"),
+    ("ID_Start", "IDS"),
+    ("IDS_Binary_Operator", "IDSB"),
+    ("IDS_Trinary_Operator", "IDST"),
+    ("Ideographic", "Ideo"),
+    ("Join_Control", "Join_C"),
+    ("Logical_Order_Exception", "LOE"),
+    ("Lowercase", "Lower"),
+    ("NFC_Inert", "nfcinert"),
+    ("NFD_Inert", "nfdinert"),
+    ("NFKC_Inert", "nfkcinert"),
+    ("NFKD_Inert", "nfkdinert"),
+    ("Noncharacter_Code_Point", "NChar"),
+    ("Pattern_Syntax", "Pat_Syn"),
+    ("Pattern_White_Space", "Pat_WS"),
+    ("Prepended_Concatenation_Mark", "PCM"),
+    ("Quotation_Mark", "QMark"),
+    ("Regional_Indicator", "RI"),
+    ("Segment_Starter", "segstart"),
+    ("Sentence_Terminal", "STerm"),
+    ("Soft_Dotted", "SD"),
+    ("Terminal_Punctuation", "Term"),
+    ("Unified_Ideograph", "UIdeo"),
+    ("Uppercase", "Upper"),
+    ("Variation_Selector", "VS"),
+    ("White_Space", "WSpace"),
+    ("space", "WSpace"),
+    ("XID_Continue", "XIDC"),
+    ("XID_Start", "XIDS"),
+];
+
+/// Normalizes a property name for loose matching, following UAX44-LM3: case, whitespace,
+/// underscores, hyphens and an initial "is" prefix are ignored.
+fn normalize_property_name(name: &str) -> String {
+    let normalized: String = name
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '_' && *c != '-')
+        .flat_map(char::to_lowercase)
+        .collect();
+    match normalized.strip_prefix("is") {
+        Some(stripped) => stripped.to_string(),
+        None => normalized,
+    }
+}
+
+/// Returns the ResourceKey of the binary property with the given name, matched loosely against
+/// the short name, the long name and the other aliases of the property.
+pub fn get_binary_property_key(name: &str) -> Option<ResourceKey> {
+    let name = normalize_property_name(name);
+    let short_name = BINARY_PROPERTY_ALIASES
+        .iter()
+        .find(|(alias, _)| normalize_property_name(alias) == name)
+        .map(|(_, short_name)| normalize_property_name(short_name))
+        .unwrap_or(name);
+    key::ALL_KEYS.iter().copied().find(|key| {
+        !key.sub_category.contains('=') && normalize_property_name(&key.sub_category) == short_name
+    })
+}
+
 impl<'data> DataProvider<'data, UnicodePropertyV1Marker> for BinaryPropertiesDataProvider {
     fn load_payload(
         &self,
@@ -97,3 +185,74 @@ fn test_basic() {
 
     assert!(!whitespace.contains('A'));
 }
+
+#[test]
+fn test_binary_property_names() {
+    assert_eq!(
+        get_binary_property_key("White_Space"),
+        Some(key::WHITE_SPACE_V1)
+    );
+    assert_eq!(get_binary_property_key("WSpace"), Some(key::WHITE_SPACE_V1));
+    assert_eq!(get_binary_property_key("space"), Some(key::WHITE_SPACE_V1));
+    assert_eq!(
+        get_binary_property_key("white-space"),
+        Some(key::WHITE_SPACE_V1)
+    );
+    assert_eq!(get_binary_property_key("isAlpha"), Some(key::ALPHABETIC_V1));
+    assert_eq!(
+        get_binary_property_key("NFC_Inert"),
+        Some(key::NFC_INERT_V1)
+    );
+    assert_eq!(get_binary_property_key("gc"), None);
+    assert_eq!(get_binary_property_key("Not_A_Property"), None);
+}
+
+#[test]
+fn test_default_ignorable_code_point() {
+    use icu_uniset::UnicodeSet;
+    use std::convert::TryInto;
+
+    let root_dir = icu_testdata::paths::data_root().join("uprops");
+    let provider = BinaryPropertiesDataProvider::new(root_dir);
+
+    let payload: DataPayload<'_, UnicodePropertyV1Marker> = provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: key::DEFAULT_IGNORABLE_CODE_POINT_V1,
+                options: ResourceOptions::default(),
+            },
+        })
+        .expect("The data should be valid")
+        .take_payload()
+        .expect("Loading was successful");
+
+    let ignorables: UnicodeSet = payload.get().clone().try_into().expect("Valid unicode set");
+
+    assert!(ignorables.contains('\u{200b}')); // U+200B ZERO WIDTH SPACE
+    assert!(ignorables.contains('\u{feff}')); // U+FEFF ZERO WIDTH NO-BREAK SPACE
+    assert!(ignorables.contains('\u{00ad}')); // U+00AD SOFT HYPHEN
+    assert!(ignorables.contains_u32(0xe0fff)); // Unassigned, but reserved as default ignorable
+
+    assert!(!ignorables.contains('A'));
+    assert!(!ignorables.contains(' '));
+}
+
+#[test]
+fn test_iter_ranges() {
+    let root_dir = icu_testdata::paths::data_root().join("uprops");
+    let provider = BinaryPropertiesDataProvider::new(root_dir);
+
+    let payload: DataPayload<'_, UnicodePropertyV1Marker> = provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: key::ASCII_HEX_DIGIT_V1,
+                options: ResourceOptions::default(),
+            },
+        })
+        .expect("The data should be valid")
+        .take_payload()
+        .expect("Loading was successful");
+
+    let ranges: Vec<_> = payload.get().iter_ranges().collect();
+    assert_eq!(ranges, vec![0x30..=0x39, 0x41..=0x46, 0x61..=0x66]);
+}
@@ -0,0 +1,112 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::error::Error;
+use crate::uprops_serde;
+use icu_provider::iter::IterableDataProviderCore;
+use icu_provider::prelude::*;
+use icu_uniset::provider::*;
+use std::fs;
+use std::path::PathBuf;
+use zerovec::ZeroVec;
+
+pub struct CaseFoldingDataProvider {
+    root_dir: PathBuf,
+}
+
+/// A data provider reading the simple case folding from a .toml file in the format of the ICU4C
+/// icuwriteuprops tool.
+impl CaseFoldingDataProvider {
+    pub fn new(root_dir: PathBuf) -> Self {
+        CaseFoldingDataProvider { root_dir }
+    }
+    fn get_toml_data(&self, name: &str) -> Result<uprops_serde::case_folding::Main, Error> {
+        let mut path: PathBuf = self.root_dir.clone().join(name);
+        path.set_extension("toml");
+        let toml_str = fs::read_to_string(&path).map_err(|e| Error::Io(e, path.clone()))?;
+        toml::from_str(&toml_str).map_err(|e| Error::Toml(e, path))
+    }
+}
+
+impl<'data> DataProvider<'data, UnicodeCaseFoldingV1Marker> for CaseFoldingDataProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, UnicodeCaseFoldingV1Marker>, DataError> {
+        if req.resource_path.key != key::SIMPLE_CASE_FOLDING_V1 {
+            return Err(DataError::MissingResourceKey(req.resource_path.key));
+        }
+
+        let toml_data: uprops_serde::case_folding::Main = self
+            .get_toml_data(&req.resource_path.key.sub_category)
+            .map_err(DataError::new_resc_error)?;
+
+        let mut mappings: Vec<(u32, u32)> = toml_data
+            .case_folding
+            .data
+            .mappings
+            .iter()
+            .map(|mapping| (mapping.c, mapping.f))
+            .collect();
+        mappings.sort_unstable();
+        let code_points: Vec<u32> = mappings.iter().map(|&(c, _)| c).collect();
+        let folded: Vec<u32> = mappings.iter().map(|&(_, f)| f).collect();
+
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+            },
+            payload: Some(DataPayload::from_owned(UnicodeCaseFoldingV1 {
+                code_points: ZeroVec::clone_from_slice(&code_points),
+                folded: ZeroVec::clone_from_slice(&folded),
+            })),
+        })
+    }
+}
+
+icu_provider::impl_dyn_provider!(CaseFoldingDataProvider, {
+    _ => UnicodeCaseFoldingV1Marker,
+}, SERDE_SE, 'data);
+
+impl IterableDataProviderCore for CaseFoldingDataProvider {
+    fn supported_options_for_key(
+        &self,
+        _resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        let list: Vec<ResourceOptions> = vec![ResourceOptions::default()];
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+#[test]
+fn test_simple_case_folding() {
+    let root_dir = icu_testdata::paths::data_root().join("uprops");
+    let provider = CaseFoldingDataProvider::new(root_dir);
+
+    let payload: DataPayload<'_, UnicodeCaseFoldingV1Marker> = provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: key::SIMPLE_CASE_FOLDING_V1,
+                options: ResourceOptions::default(),
+            },
+        })
+        .expect("The data should be valid")
+        .take_payload()
+        .expect("Loading was successful");
+
+    let case_folding = payload.get();
+    let fold = |ch: char| case_folding.case_fold(ch).collect::<String>();
+    assert_eq!(fold('A'), "a");
+    assert_eq!(fold('a'), "a");
+    assert_eq!(fold('\u{3A3}'), "\u{3C3}"); // GREEK CAPITAL LETTER SIGMA
+    assert_eq!(fold('\u{3C2}'), "\u{3C3}"); // GREEK SMALL LETTER FINAL SIGMA
+    assert_eq!(fold('\u{1E9E}'), "\u{DF}"); // LATIN CAPITAL LETTER SHARP S, status S
+    assert_eq!(fold('\u{1F88}'), "\u{1F80}"); // With PROSGEGRAMMENI, status S
+    assert_eq!(fold('\u{10400}'), "\u{10428}"); // DESERET CAPITAL LETTER LONG I
+
+    // The full case folding of these is out of scope: "ß" folds to "ss", and "İ" to "i̇", or to
+    // "i" with the Turkic mappings.
+    assert_eq!(fold('\u{DF}'), "\u{DF}");
+    assert_eq!(fold('\u{130}'), "\u{130}");
+}
@@ -7,10 +7,12 @@ use crate::uprops_serde;
 use icu_provider::iter::IterableDataProviderCore;
 use icu_provider::prelude::*;
 use icu_uniset::provider::*;
-use icu_uniset::UnicodeSetBuilder;
+use icu_uniset::{UnicodeSet, UnicodeSetBuilder};
 use std::borrow::Cow;
+use std::convert::TryFrom;
 use std::fs;
 use std::path::PathBuf;
+use zerovec::ZeroVec;
 
 pub struct EnumeratedPropertiesDataProvider {
     root_dir: PathBuf,
@@ -27,6 +29,24 @@ impl EnumeratedPropertiesDataProvider {
         let toml_str = fs::read_to_string(&path).map_err(|e| Error::Io(e, path.clone()))?;
         toml::from_str(&toml_str).map_err(|e| Error::Toml(e, path))
     }
+
+    /// Returns the value aliases of the enumerated property `prop_name`, read from the
+    /// value_aliases.toml file generated from PropertyValueAliases.txt.
+    fn get_value_aliases(
+        &self,
+        prop_name: &str,
+    ) -> Result<Vec<uprops_serde::value_aliases::PropertyValueAlias>, Error> {
+        let path: PathBuf = self.root_dir.join("value_aliases.toml");
+        let toml_str = fs::read_to_string(&path).map_err(|e| Error::Io(e, path.clone()))?;
+        let toml_data: uprops_serde::value_aliases::Main =
+            toml::from_str(&toml_str).map_err(|e| Error::Toml(e, path))?;
+        toml_data
+            .value_aliases
+            .into_iter()
+            .find(|aliases| aliases.short_name == prop_name)
+            .map(|aliases| aliases.values)
+            .ok_or_else(|| Error::MissingValueAliases(prop_name.to_string()))
+    }
 }
 
 fn expand_groupings<'a>(prop_name: &str, prop_val: &'a str) -> Vec<&'a str> {
@@ -62,6 +82,144 @@ fn expand_groupings<'a>(prop_name: &str, prop_val: &'a str) -> Vec<&'a str> {
     }
 }
 
+/// The default values of Bidi_Class for unassigned code points, from the `@missing` lines of
+/// DerivedBidiClass.txt. Unassigned code points outside of these ranges default to "L".
+const BIDI_CLASS_DEFAULTS: &[(u32, u32, &str)] = &[
+    (0x0590, 0x05FF, "R"),
+    (0x0600, 0x07BF, "AL"),
+    (0x07C0, 0x085F, "R"),
+    (0x0860, 0x08FF, "AL"),
+    (0x20A0, 0x20CF, "ET"),
+    (0xFB1D, 0xFB4F, "R"),
+    (0xFB50, 0xFDCF, "AL"),
+    (0xFDF0, 0xFDFF, "AL"),
+    (0xFE70, 0xFEFF, "AL"),
+    (0x10800, 0x10CFF, "R"),
+    (0x10D00, 0x10D3F, "AL"),
+    (0x10D40, 0x10EBF, "R"),
+    (0x10EC0, 0x10EFF, "AL"),
+    (0x10F00, 0x10F2F, "R"),
+    (0x10F30, 0x10F6F, "AL"),
+    (0x10F70, 0x10FFF, "R"),
+    (0x1E800, 0x1EC6F, "R"),
+    (0x1EC70, 0x1ECBF, "AL"),
+    (0x1ECC0, 0x1ECFF, "R"),
+    (0x1ED00, 0x1ED4F, "AL"),
+    (0x1ED50, 0x1EDFF, "R"),
+    (0x1EE00, 0x1EEFF, "AL"),
+    (0x1EF00, 0x1EFFF, "R"),
+    // Unassigned default ignorable code points
+    (0x2060, 0x206F, "BN"),
+    (0xFFF0, 0xFFF8, "BN"),
+    (0xE0000, 0xE0FFF, "BN"),
+    // Noncharacters, other than the last two code points of each plane
+    (0xFDD0, 0xFDEF, "BN"),
+];
+
+/// Returns the code points which have the Bidi_Class value `prop_value` when they are unassigned.
+fn get_bidi_class_defaults(prop_value: &str) -> UnicodeSet<'static> {
+    let mut builder = UnicodeSetBuilder::new();
+    let mut non_ltr_builder = UnicodeSetBuilder::new();
+    let noncharacters =
+        (0..=0x10).map(|plane| ((plane << 16) | 0xFFFE, (plane << 16) | 0xFFFF, "BN"));
+    for (a, b, name) in BIDI_CLASS_DEFAULTS.iter().copied().chain(noncharacters) {
+        if name == prop_value {
+            builder.add_range_u32(&(a..=b));
+        }
+        non_ltr_builder.add_range_u32(&(a..=b));
+    }
+    if prop_value == "L" {
+        non_ltr_builder.build().complement()
+    } else {
+        builder.build()
+    }
+}
+
+/// The Joining_Type value T (Transparent), which is the default for code points of
+/// General_Category Mn, Me, or Cf.
+const JOINING_TYPE_TRANSPARENT: u32 = 5;
+
+/// The Script value Zzzz (Unknown), which is the default for code points missing from the data.
+const SCRIPT_UNKNOWN: u8 = 103;
+
+/// Returns the value of the code points missing from the data of the property `prop_name`, which
+/// is 0, or Unknown for Script.
+fn default_value(prop_name: &str) -> u8 {
+    if prop_name == "sc" {
+        SCRIPT_UNKNOWN
+    } else {
+        0
+    }
+}
+
+impl EnumeratedPropertiesDataProvider {
+    /// Returns the code points which are not listed in ArabicShaping.txt and which have the
+    /// Joining_Type T, as derived from their General_Category. The other unlisted code points
+    /// have the Joining_Type U.
+    fn get_joining_type_defaults(
+        &self,
+        ranges: &[uprops_serde::enumerated::EnumeratedPropertyMapRange],
+    ) -> Result<UnicodeSet<'static>, DataError> {
+        let gc_data: uprops_serde::enumerated::Main = self
+            .get_toml_data("gc")
+            .map_err(DataError::new_resc_error)?;
+        let mut transparent_builder = UnicodeSetBuilder::new();
+        for range in gc_data.enum_property.data.ranges.iter() {
+            if matches!(range.name.as_str(), "Mn" | "Me" | "Cf") {
+                transparent_builder.add_range_u32(&(range.a..=range.b));
+            }
+        }
+        let mut listed_builder = UnicodeSetBuilder::new();
+        for range in ranges {
+            listed_builder.add_range_u32(&(range.a..=range.b));
+        }
+        Ok(transparent_builder
+            .build()
+            .difference(&listed_builder.build()))
+    }
+
+    /// Returns the distinct values of an enumerated property, with their short names from the
+    /// value aliases, sorted by value. `prop_name` is the short name of the property, such as
+    /// "gc".
+    ///
+    /// Only the values that some code point has are returned, so the aliases of the values
+    /// absent from the data are skipped. The value of the code points missing from the data is
+    /// included, even if no range in the data has it.
+    pub fn enumerated_values(&self, prop_name: &str) -> Result<Vec<(u8, String)>, DataError> {
+        let toml_data: uprops_serde::enumerated::Main = self
+            .get_toml_data(prop_name)
+            .map_err(DataError::new_resc_error)?;
+        let aliases = self
+            .get_value_aliases(prop_name)
+            .map_err(DataError::new_resc_error)?;
+
+        let mut ranges = toml_data.enum_property.data.ranges;
+        ranges.sort_unstable_by_key(|range| range.a);
+        let mut present: Vec<u32> = Vec::new();
+        let mut next_code_point = 0;
+        for range in ranges {
+            if range.a != next_code_point {
+                present.push(default_value(prop_name).into());
+            }
+            next_code_point = range.b + 1;
+            present.push(range.v);
+        }
+        if next_code_point <= (char::MAX as u32) {
+            present.push(default_value(prop_name).into());
+        }
+
+        let mut values: Vec<(u8, String)> = Vec::new();
+        for alias in aliases {
+            if present.contains(&alias.discr) {
+                let discr = u8::try_from(alias.discr).map_err(DataError::new_resc_error)?;
+                values.push((discr, alias.short));
+            }
+        }
+        values.sort_unstable();
+        Ok(values)
+    }
+}
+
 impl<'data> DataProvider<'data, UnicodePropertyV1Marker> for EnumeratedPropertiesDataProvider {
     fn load_payload(
         &self,
@@ -86,13 +244,22 @@ impl<'data> DataProvider<'data, UnicodePropertyV1Marker> for EnumeratedPropertie
         let valid_names = expand_groupings(prop_name, prop_value);
 
         let mut builder = UnicodeSetBuilder::new();
+        let mut assigned_builder = UnicodeSetBuilder::new();
         let ranges = toml_data.enum_property.data.ranges;
         for range in ranges {
             if valid_names.iter().any(|&name| name == range.name) {
                 builder.add_range_u32(&(range.a..=range.b));
             }
+            assigned_builder.add_range_u32(&(range.a..=range.b));
+        }
+        let mut uniset = builder.build();
+
+        // The Bidi_Class of the code points missing from the data depends on their block,
+        // rather than being a single default value.
+        if prop_name == "bc" {
+            let unassigned = assigned_builder.build().complement();
+            uniset = uniset.union(&get_bidi_class_defaults(prop_value).intersection(&unassigned));
         }
-        let uniset = builder.build();
 
         let name = Cow::from(toml_data.enum_property.data.long_name);
         Ok(DataResponse {
@@ -106,7 +273,90 @@ impl<'data> DataProvider<'data, UnicodePropertyV1Marker> for EnumeratedPropertie
     }
 }
 
+impl<'data> DataProvider<'data, UnicodePropertyMapV1Marker> for EnumeratedPropertiesDataProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, UnicodePropertyMapV1Marker>, DataError> {
+        // ResourceKey subcategory strings for property maps are the short name of the property.
+        let prop_name = &req.resource_path.key.sub_category;
+        if prop_name.contains('=') {
+            return Err(DataError::MissingResourceKey(req.resource_path.key));
+        }
+
+        let toml_data: uprops_serde::enumerated::Main = self
+            .get_toml_data(prop_name)
+            .map_err(DataError::new_resc_error)?;
+
+        let mut ranges: Vec<(u32, u32, u32)> = toml_data
+            .enum_property
+            .data
+            .ranges
+            .iter()
+            .map(|range| (range.a, range.b, range.v))
+            .collect();
+
+        // The code points missing from ArabicShaping.txt have the Joining_Type T or U depending
+        // on their General_Category, rather than a single default value.
+        if req.resource_path.key == key::JOINING_TYPE_V1 {
+            let transparent =
+                self.get_joining_type_defaults(&toml_data.enum_property.data.ranges)?;
+            ranges.extend(
+                transparent
+                    .iter_ranges()
+                    .map(|range| (*range.start(), *range.end(), JOINING_TYPE_TRANSPARENT)),
+            );
+            ranges.sort_unstable();
+        }
+
+        // Consecutive ranges with the same value are merged, and code points missing from the
+        // data have the default value of the property.
+        let default_value = default_value(prop_name);
+        let mut range_starts: Vec<u32> = Vec::new();
+        let mut values: Vec<u8> = Vec::new();
+        let mut next_code_point = 0;
+        for &(a, b, v) in ranges.iter() {
+            if a != next_code_point && values.last() != Some(&default_value) {
+                range_starts.push(next_code_point);
+                values.push(default_value);
+            }
+            let value = u8::try_from(v).map_err(DataError::new_resc_error)?;
+            if values.last() != Some(&value) {
+                range_starts.push(a);
+                values.push(value);
+            }
+            next_code_point = b + 1;
+        }
+        if next_code_point <= (char::MAX as u32) && values.last() != Some(&default_value) {
+            range_starts.push(next_code_point);
+            values.push(default_value);
+        }
+
+        let name = Cow::from(toml_data.enum_property.data.long_name);
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+            },
+            payload: Some(DataPayload::from_owned(UnicodePropertyMapV1 {
+                name,
+                range_starts: ZeroVec::clone_from_slice(&range_starts),
+                values: ZeroVec::clone_from_slice(&values),
+            })),
+        })
+    }
+}
+
 icu_provider::impl_dyn_provider!(EnumeratedPropertiesDataProvider, {
+    key::CANONICAL_COMBINING_CLASS_V1 => UnicodePropertyMapV1Marker,
+    key::GRAPHEME_CLUSTER_BREAK_V1 => UnicodePropertyMapV1Marker,
+    key::INDIC_POSITIONAL_CATEGORY_V1 => UnicodePropertyMapV1Marker,
+    key::INDIC_SYLLABIC_CATEGORY_V1 => UnicodePropertyMapV1Marker,
+    key::JOINING_GROUP_V1 => UnicodePropertyMapV1Marker,
+    key::JOINING_TYPE_V1 => UnicodePropertyMapV1Marker,
+    key::NUMERIC_TYPE_V1 => UnicodePropertyMapV1Marker,
+    key::SCRIPT_V1 => UnicodePropertyMapV1Marker,
+    key::SENTENCE_BREAK_V1 => UnicodePropertyMapV1Marker,
+    key::WORD_BREAK_V1 => UnicodePropertyMapV1Marker,
     _ => UnicodePropertyV1Marker,
 }, SERDE_SE, 'data);
 
@@ -120,6 +370,30 @@ impl IterableDataProviderCore for EnumeratedPropertiesDataProvider {
     }
 }
 
+#[cfg(test)]
+fn get_provider() -> EnumeratedPropertiesDataProvider {
+    let root_dir = icu_testdata::paths::data_root().join("uprops");
+    EnumeratedPropertiesDataProvider::new(root_dir)
+}
+
+#[cfg(test)]
+fn get_payload<'data, M>(key: ResourceKey) -> DataPayload<'data, M>
+where
+    M: DataMarker<'data>,
+    EnumeratedPropertiesDataProvider: DataProvider<'data, M>,
+{
+    get_provider()
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key,
+                options: ResourceOptions::default(),
+            },
+        })
+        .expect("The data should be valid")
+        .take_payload()
+        .expect("Loading was successful")
+}
+
 #[test]
 fn test_general_category() {
     use icu_uniset::UnicodeSet;
@@ -316,3 +590,274 @@ fn test_gc_surrogate() {
 
     assert!(!surrogates.contains('A'));
 }
+
+#[test]
+fn test_gc_letter_intersection() {
+    use icu_uniset::UnicodeSet;
+    use std::convert::TryInto;
+
+    let payload: DataPayload<'_, UnicodePropertyV1Marker> =
+        get_payload(key::GENERAL_CATEGORY_LETTER_V1);
+
+    let letters: UnicodeSet = payload.get().clone().try_into().expect("Valid unicode set");
+    let ascii = UnicodeSet::from_inversion_list_slice(&[0x0, 0x80]).expect("Valid unicode set");
+
+    let ascii_letters = letters.intersection(&ascii);
+    assert_eq!(
+        ascii_letters.get_inversion_list(),
+        vec![0x41, 0x5B, 0x61, 0x7B] // A - Z, a - z
+    );
+    assert!(!ascii_letters.contains('\u{00aa}')); // U+00AA FEMININE ORDINAL INDICATOR
+}
+
+#[test]
+fn test_bidi_class() {
+    use std::convert::TryInto;
+
+    let payload = get_payload::<UnicodePropertyV1Marker>(key::BIDI_CLASS_ARABIC_LETTER_V1);
+    let arabic_letters: UnicodeSet = payload.get().clone().try_into().expect("Valid unicode set");
+    assert!(arabic_letters.contains('\u{0627}')); // U+0627 ARABIC LETTER ALEF
+    assert!(arabic_letters.contains('\u{fefc}')); // U+FEFC ARABIC LIGATURE LAM WITH ALEF FINAL FORM
+    assert!(!arabic_letters.contains('\u{05d0}')); // U+05D0 HEBREW LETTER ALEF
+    assert!(!arabic_letters.contains('5'));
+
+    let payload = get_payload::<UnicodePropertyV1Marker>(key::BIDI_CLASS_EUROPEAN_NUMBER_V1);
+    let european_numbers: UnicodeSet = payload.get().clone().try_into().expect("Valid unicode set");
+    assert!(european_numbers.contains_range(&('0'..='9')));
+    assert!(!european_numbers.contains('\u{0665}')); // U+0665 ARABIC-INDIC DIGIT FIVE
+    assert!(!european_numbers.contains('A'));
+
+    // Unassigned code points default to a value depending on their block.
+    assert!(arabic_letters.contains_u32(0x07bf)); // Unassigned in the Arabic Supplement block
+
+    let payload = get_payload::<UnicodePropertyV1Marker>(key::BIDI_CLASS_LEFT_TO_RIGHT_V1);
+    let left_to_right: UnicodeSet = payload.get().clone().try_into().expect("Valid unicode set");
+    assert!(left_to_right.contains('A'));
+    assert!(left_to_right.contains_u32(0x0378)); // Unassigned in the Greek block
+    assert!(!left_to_right.contains_u32(0x07bf));
+
+    let payload = get_payload::<UnicodePropertyV1Marker>(key::BIDI_CLASS_RIGHT_TO_LEFT_V1);
+    let right_to_left: UnicodeSet = payload.get().clone().try_into().expect("Valid unicode set");
+    assert!(right_to_left.contains_u32(0x05ff)); // Unassigned in the Hebrew block
+
+    let payload = get_payload::<UnicodePropertyV1Marker>(key::BIDI_CLASS_EUROPEAN_TERMINATOR_V1);
+    let european_terminators: UnicodeSet =
+        payload.get().clone().try_into().expect("Valid unicode set");
+    assert!(european_terminators.contains_u32(0x20cf)); // Unassigned in the Currency Symbols block
+
+    let payload = get_payload::<UnicodePropertyV1Marker>(key::BIDI_CLASS_BOUNDARY_NEUTRAL_V1);
+    let boundary_neutrals: UnicodeSet =
+        payload.get().clone().try_into().expect("Valid unicode set");
+    assert!(boundary_neutrals.contains_u32(0xfdd0)); // Noncharacter
+    assert!(boundary_neutrals.contains_u32(0x10ffff)); // Noncharacter
+}
+
+#[test]
+fn test_canonical_combining_class() {
+    let payload: DataPayload<'_, UnicodePropertyMapV1Marker> =
+        get_payload(key::CANONICAL_COMBINING_CLASS_V1);
+
+    let ccc = payload.get();
+    assert_eq!(ccc.get('a' as u32), 0);
+    assert_eq!(ccc.get(0x0301), 230); // COMBINING ACUTE ACCENT
+    assert_eq!(ccc.get(0x0316), 220); // COMBINING GRAVE ACCENT BELOW
+    assert_eq!(ccc.get(0x094D), 9); // DEVANAGARI SIGN VIRAMA
+    assert_eq!(ccc.get(0x10FFFF), 0);
+}
+
+#[test]
+fn test_indic_categories() {
+    use icu_uniset::enum_props::{IndicPositionalCategory, IndicSyllabicCategory};
+
+    let payload = get_payload::<UnicodePropertyMapV1Marker>(key::INDIC_POSITIONAL_CATEGORY_V1);
+    let inpc = payload.get();
+    let get = |cp| IndicPositionalCategory::try_from(inpc.get(cp));
+    assert_eq!(get(0x093F), Ok(IndicPositionalCategory::Left)); // DEVANAGARI VOWEL SIGN I
+    assert_eq!(get(0x0940), Ok(IndicPositionalCategory::Right)); // DEVANAGARI VOWEL SIGN II
+    assert_eq!(get(0x0941), Ok(IndicPositionalCategory::Bottom)); // DEVANAGARI VOWEL SIGN U
+    assert_eq!(get(0x0947), Ok(IndicPositionalCategory::Top)); // DEVANAGARI VOWEL SIGN E
+    assert_eq!(get('a' as u32), Ok(IndicPositionalCategory::NA));
+
+    let payload = get_payload::<UnicodePropertyMapV1Marker>(key::INDIC_SYLLABIC_CATEGORY_V1);
+    let insc = payload.get();
+    let get = |cp| IndicSyllabicCategory::try_from(insc.get(cp));
+    assert_eq!(get(0x0915), Ok(IndicSyllabicCategory::Consonant)); // DEVANAGARI LETTER KA
+    assert_eq!(get(0x093F), Ok(IndicSyllabicCategory::VowelDependent));
+    assert_eq!(get(0x094D), Ok(IndicSyllabicCategory::Virama)); // DEVANAGARI SIGN VIRAMA
+    assert_eq!(get('a' as u32), Ok(IndicSyllabicCategory::Other));
+}
+
+#[test]
+fn test_joining_type_and_group() {
+    use icu_uniset::enum_props::{JoiningGroup, JoiningType};
+
+    let payload = get_payload::<UnicodePropertyMapV1Marker>(key::JOINING_TYPE_V1);
+    let jt = payload.get();
+    let get = |cp| JoiningType::try_from(jt.get(cp));
+    assert_eq!(get(0x0628), Ok(JoiningType::DualJoining)); // ARABIC LETTER BEH
+    assert_eq!(get(0x0627), Ok(JoiningType::RightJoining)); // ARABIC LETTER ALEF
+    assert_eq!(get(0x0640), Ok(JoiningType::JoinCausing)); // ARABIC TATWEEL
+    assert_eq!(get(' ' as u32), Ok(JoiningType::NonJoining));
+    // Not listed in ArabicShaping.txt, derived from General_Category
+    assert_eq!(get(0x064E), Ok(JoiningType::Transparent)); // ARABIC FATHA (Mn)
+    assert_eq!(get(0x0301), Ok(JoiningType::Transparent)); // COMBINING ACUTE ACCENT (Mn)
+    assert_eq!(get(0x200D), Ok(JoiningType::JoinCausing)); // ZERO WIDTH JOINER (Cf, listed)
+    assert_eq!(get(0x0600), Ok(JoiningType::NonJoining)); // ARABIC NUMBER SIGN (Cf, listed)
+    assert_eq!(get(0x10FFFF), Ok(JoiningType::NonJoining));
+
+    let payload = get_payload::<UnicodePropertyMapV1Marker>(key::JOINING_GROUP_V1);
+    let jg = payload.get();
+    let get = |cp| JoiningGroup::try_from(jg.get(cp));
+    assert_eq!(get(0x0628), Ok(JoiningGroup::Beh)); // ARABIC LETTER BEH
+    assert_eq!(get(0x06A9), Ok(JoiningGroup::Gaf)); // ARABIC LETTER KEHEH
+    assert_eq!(get(' ' as u32), Ok(JoiningGroup::NoJoiningGroup));
+}
+
+#[test]
+fn test_script_map() {
+    use icu_uniset::enum_props::Script;
+
+    let payload: DataPayload<'_, UnicodePropertyMapV1Marker> = get_payload(key::SCRIPT_V1);
+
+    let sc = payload.get();
+    let get = |cp| Script::try_from(sc.get(cp));
+    assert_eq!(get(0x03B1), Ok(Script::Greek)); // GREEK SMALL LETTER ALPHA
+    assert_eq!(get('5' as u32), Ok(Script::Common));
+    assert_eq!(get('a' as u32), Ok(Script::Latin));
+    assert_eq!(get(0x0E01), Ok(Script::Thai)); // THAI CHARACTER KO KAI
+    assert_eq!(get(0x0301), Ok(Script::Inherited)); // COMBINING ACUTE ACCENT
+    assert_eq!(get(0x0378), Ok(Script::Unknown)); // Unassigned in the Greek block
+    assert_eq!(get(0x10FFFF), Ok(Script::Unknown));
+}
+
+#[test]
+fn test_script_runs() {
+    use icu_uniset::enum_props::Script;
+    use icu_uniset::ScriptRunIterator;
+
+    let provider = get_provider();
+    let runs = |text: &str| -> Vec<_> {
+        ScriptRunIterator::new(text, &provider)
+            .expect("The data should be valid")
+            .collect()
+    };
+
+    // The trailing digits are part of the Greek run.
+    assert_eq!(
+        runs("abc\u{0391}\u{0392}\u{0393}123"),
+        vec![(0..3, Script::Latin), (3..12, Script::Greek)]
+    );
+    // Leading Common characters are part of the first run with another script.
+    assert_eq!(
+        runs("12, abc \u{03B1}\u{0301}"),
+        vec![(0..8, Script::Latin), (8..12, Script::Greek)]
+    );
+    assert_eq!(runs("123"), vec![(0..3, Script::Common)]);
+    assert_eq!(runs(""), vec![]);
+}
+
+#[test]
+fn test_enumerated_values() {
+    let provider = get_provider();
+
+    let values = provider
+        .enumerated_values("gc")
+        .expect("The data should be valid");
+    assert_eq!(values.len(), 30);
+    assert_eq!(values[0], (0, "Cn".to_string()));
+    assert!(values.contains(&(1, "Lu".to_string())));
+    assert!(values.contains(&(2, "Ll".to_string())));
+    assert!(values.contains(&(9, "Nd".to_string())));
+    assert!(values.contains(&(12, "Zs".to_string())));
+    // The groupings of General_Category are not values of the property.
+    assert!(!values.iter().any(|(_, name)| name == "L"));
+
+    let values = provider
+        .enumerated_values("jt")
+        .expect("The data should be valid");
+    assert_eq!(values.first(), Some(&(0, "U".to_string())));
+    assert_eq!(values.last(), Some(&(5, "T".to_string())));
+
+    // No code point listed in the Joining_Group data has the value No_Joining_Group, which is the
+    // value of all of the other code points.
+    let values = provider
+        .enumerated_values("jg")
+        .expect("The data should be valid");
+    assert_eq!(values[0], (0, "No_Joining_Group".to_string()));
+    assert!(values.contains(&(1, "Ain".to_string())));
+
+    // The Script data has no value aliases.
+    assert!(provider.enumerated_values("sc").is_err());
+    assert!(provider.enumerated_values("Not_A_Property").is_err());
+}
+
+#[test]
+fn test_enumerated_values_absent() {
+    let root_dir = std::env::temp_dir().join("icu4x_uprops_enumerated_values");
+    fs::create_dir_all(&root_dir).expect("The directory should be created");
+    let toml_str = r#"
+        [[enum_property]]
+        long_name = "Joining_Type"
+        short_name = "jt"
+        ranges = [
+          {a=0x620, b=0x620, v=2, name="D"},
+        ]
+    "#;
+    fs::write(root_dir.join("jt.toml"), toml_str).expect("The data should be written");
+    let toml_str = r#"
+        [[value_aliases]]
+        short_name = "jt"
+        values = [
+          {discr=0, long="Non_Joining", short="U"},
+          {discr=1, long="Join_Causing", short="C"},
+          {discr=2, long="Dual_Joining", short="D"},
+        ]
+    "#;
+    fs::write(root_dir.join("value_aliases.toml"), toml_str).expect("The data should be written");
+    let provider = EnumeratedPropertiesDataProvider::new(root_dir);
+
+    // No code point has the value Join_Causing, and the code points missing from the data have
+    // the value Non_Joining.
+    let values = provider
+        .enumerated_values("jt")
+        .expect("The data should be valid");
+    assert_eq!(values, vec![(0, "U".to_string()), (2, "D".to_string())]);
+}
+
+#[test]
+fn test_segmentation_properties() {
+    use icu_uniset::enum_props::{GraphemeClusterBreak, SentenceBreak, WordBreak};
+
+    let payload = get_payload::<UnicodePropertyMapV1Marker>(key::GRAPHEME_CLUSTER_BREAK_V1);
+    let gcb = payload.get();
+    let get = |cp| GraphemeClusterBreak::try_from(gcb.get(cp));
+    assert_eq!(get('\r' as u32), Ok(GraphemeClusterBreak::CR));
+    assert_eq!(get('\n' as u32), Ok(GraphemeClusterBreak::LF));
+    assert_eq!(get(0x0301), Ok(GraphemeClusterBreak::Extend)); // COMBINING ACUTE ACCENT
+    assert_eq!(get(0x200D), Ok(GraphemeClusterBreak::ZWJ)); // ZERO WIDTH JOINER
+    assert_eq!(get(0x1F1EF), Ok(GraphemeClusterBreak::RegionalIndicator));
+    assert_eq!(get(0xAC00), Ok(GraphemeClusterBreak::LV)); // HANGUL SYLLABLE GA
+    assert_eq!(get('a' as u32), Ok(GraphemeClusterBreak::Other));
+
+    let payload = get_payload::<UnicodePropertyMapV1Marker>(key::WORD_BREAK_V1);
+    let wb = payload.get();
+    let get = |cp| WordBreak::try_from(wb.get(cp));
+    assert_eq!(get('a' as u32), Ok(WordBreak::ALetter));
+    assert_eq!(get(0x05D0), Ok(WordBreak::HebrewLetter)); // HEBREW LETTER ALEF
+    assert_eq!(get(0x30A2), Ok(WordBreak::Katakana)); // KATAKANA LETTER A
+    assert_eq!(get('7' as u32), Ok(WordBreak::Numeric));
+    assert_eq!(get('\'' as u32), Ok(WordBreak::SingleQuote));
+    assert_eq!(get(' ' as u32), Ok(WordBreak::WSegSpace));
+    assert_eq!(get(0x2007), Ok(WordBreak::Other)); // FIGURE SPACE
+    assert_eq!(get(0x4E00), Ok(WordBreak::Other));
+
+    let payload = get_payload::<UnicodePropertyMapV1Marker>(key::SENTENCE_BREAK_V1);
+    let sb = payload.get();
+    let get = |cp| SentenceBreak::try_from(sb.get(cp));
+    assert_eq!(get('A' as u32), Ok(SentenceBreak::Upper));
+    assert_eq!(get('a' as u32), Ok(SentenceBreak::Lower));
+    assert_eq!(get('.' as u32), Ok(SentenceBreak::ATerm));
+    assert_eq!(get('?' as u32), Ok(SentenceBreak::STerm));
+    assert_eq!(get(',' as u32), Ok(SentenceBreak::SContinue));
+    assert_eq!(get(0x4E00), Ok(SentenceBreak::OLetter));
+}
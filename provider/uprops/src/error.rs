@@ -12,6 +12,10 @@ pub enum Error {
     Io(std::io::Error, PathBuf),
     #[displaydoc("{0}: {1:?}")]
     Toml(toml::de::Error, PathBuf),
+    #[displaydoc("Unknown property: {0}")]
+    UnknownProperty(String),
+    #[displaydoc("Missing value aliases for property: {0}")]
+    MissingValueAliases(String),
 }
 
 impl std::error::Error for Error {}
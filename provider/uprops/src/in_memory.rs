@@ -0,0 +1,128 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_provider::iter::IterableDataProviderCore;
+use icu_provider::prelude::*;
+use icu_uniset::provider::*;
+use icu_uniset::{UnicodeSet, UnicodeSetBuilder};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+
+/// A data provider serving properties whose code points are added programmatically, rather than
+/// read from .toml files. It is a drop-in replacement for [`PropertiesDataProvider`] in tests.
+///
+/// # Examples
+///
+/// ```
+/// use icu_provider::prelude::*;
+/// use icu_provider_uprops::InMemoryPropertiesDataProvider;
+/// use icu_uniset::provider::UnicodePropertyV1Marker;
+///
+/// const IS_FOO_KEY: ResourceKey = icu_provider::resource_key!(x, "test", "IsFoo", 1);
+///
+/// let mut provider = InMemoryPropertiesDataProvider::new();
+/// provider.insert_ranges(IS_FOO_KEY, &[0x41..=0x5A, 0x61..=0x7A]);
+///
+/// let payload: DataPayload<UnicodePropertyV1Marker> = provider
+///     .load_payload(&DataRequest::from(IS_FOO_KEY))
+///     .expect("Load should succeed")
+///     .take_payload()
+///     .expect("Data should be present");
+///
+/// assert!(payload.get().inv_list.contains('f'));
+/// assert!(!payload.get().inv_list.contains('0'));
+/// ```
+///
+/// [`PropertiesDataProvider`]: crate::PropertiesDataProvider
+#[derive(Default)]
+pub struct InMemoryPropertiesDataProvider {
+    properties: BTreeMap<ResourceKey, UnicodeSet<'static>>,
+}
+
+impl InMemoryPropertiesDataProvider {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the code points in `ranges` to the property with the key `key`. The property is
+    /// created if it doesn't exist yet.
+    pub fn insert_ranges(&mut self, key: ResourceKey, ranges: &[RangeInclusive<u32>]) {
+        let mut builder = UnicodeSetBuilder::new();
+        for range in ranges {
+            builder.add_range_u32(range);
+        }
+        let added = builder.build();
+        let uniset = match self.properties.get(&key) {
+            Some(existing) => existing.union(&added),
+            None => added,
+        };
+        self.properties.insert(key, uniset);
+    }
+}
+
+impl<'data> DataProvider<'data, UnicodePropertyV1Marker> for InMemoryPropertiesDataProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, UnicodePropertyV1Marker>, DataError> {
+        let key = req.resource_path.key;
+        let uniset = self
+            .properties
+            .get(&key)
+            .ok_or(DataError::MissingResourceKey(key))?;
+
+        let name = Cow::from(key.sub_category.to_string());
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+            },
+            payload: Some(DataPayload::from_owned(
+                UnicodePropertyV1::from_owned_uniset(uniset.clone(), name),
+            )),
+        })
+    }
+}
+
+icu_provider::impl_dyn_provider!(InMemoryPropertiesDataProvider, {
+    _ => UnicodePropertyV1Marker,
+}, SERDE_SE, 'data);
+
+impl IterableDataProviderCore for InMemoryPropertiesDataProvider {
+    fn supported_options_for_key(
+        &self,
+        _resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        let list: Vec<ResourceOptions> = vec![ResourceOptions::default()];
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+#[test]
+fn test_in_memory() {
+    const IS_FOO_KEY: ResourceKey = icu_provider::resource_key!(x, "test", "IsFoo", 1);
+
+    let mut provider = InMemoryPropertiesDataProvider::new();
+    provider.insert_ranges(IS_FOO_KEY, &[0x30..=0x39]);
+    provider.insert_ranges(IS_FOO_KEY, &[0x41..=0x46, 0x61..=0x66]);
+
+    let payload: DataPayload<'_, UnicodePropertyV1Marker> = provider
+        .load_payload(&DataRequest::from(IS_FOO_KEY))
+        .expect("The data should be valid")
+        .take_payload()
+        .expect("Loading was successful");
+
+    let is_foo = &payload.get().inv_list;
+    assert_eq!(payload.get().name, "IsFoo");
+    assert!(is_foo.contains('7'));
+    assert!(is_foo.contains('B'));
+    assert!(is_foo.contains('f'));
+    assert!(!is_foo.contains('g'));
+    assert_eq!(is_foo.size(), 22);
+
+    // Properties which were not added are missing.
+    let result: Result<DataResponse<'_, UnicodePropertyV1Marker>, DataError> =
+        provider.load_payload(&DataRequest::from(key::WHITE_SPACE_V1));
+    assert!(matches!(result, Err(DataError::MissingResourceKey(_))));
+}
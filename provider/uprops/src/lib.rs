@@ -8,6 +8,10 @@
 //! the necessary Unicode properties and then pass the path into the
 //! [`PropertiesDataProvider`].
 //!
+//! The TOML files of the properties which icuwriteuprops does not export, and
+//! the value aliases of the enumerated properties, are generated by
+//! `tools/generate_toml.py` in this crate.
+//!
 //! **Important:** This data provider implementation is not optimized
 //! for production use.  It is much more efficient if you use
 //! [`FsDataProvider`] or [`StaticDataProvider`] instead.
@@ -19,9 +23,13 @@
 //! [`PropertiesDataProvider`]: binary::PropertiesDataProvider
 
 mod binary;
+mod case_folding;
 mod enumerated;
 mod error;
+mod in_memory;
+mod numeric_values;
 mod provider;
 mod uprops_serde;
 
+pub use in_memory::InMemoryPropertiesDataProvider;
 pub use provider::PropertiesDataProvider;
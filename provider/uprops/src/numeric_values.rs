@@ -0,0 +1,109 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::error::Error;
+use crate::uprops_serde;
+use icu_provider::iter::IterableDataProviderCore;
+use icu_provider::prelude::*;
+use icu_uniset::provider::*;
+use std::fs;
+use std::path::PathBuf;
+use zerovec::ZeroVec;
+
+pub struct NumericValuesDataProvider {
+    root_dir: PathBuf,
+}
+
+/// A data provider reading the numeric values from a .toml file in the format of the ICU4C
+/// icuwriteuprops tool.
+impl NumericValuesDataProvider {
+    pub fn new(root_dir: PathBuf) -> Self {
+        NumericValuesDataProvider { root_dir }
+    }
+    fn get_toml_data(&self, name: &str) -> Result<uprops_serde::numeric_values::Main, Error> {
+        let mut path: PathBuf = self.root_dir.clone().join(name);
+        path.set_extension("toml");
+        let toml_str = fs::read_to_string(&path).map_err(|e| Error::Io(e, path.clone()))?;
+        toml::from_str(&toml_str).map_err(|e| Error::Toml(e, path))
+    }
+}
+
+impl<'data> DataProvider<'data, UnicodeNumericValuesV1Marker> for NumericValuesDataProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, UnicodeNumericValuesV1Marker>, DataError> {
+        if req.resource_path.key != key::NUMERIC_VALUE_V1 {
+            return Err(DataError::MissingResourceKey(req.resource_path.key));
+        }
+
+        let toml_data: uprops_serde::numeric_values::Main = self
+            .get_toml_data(&req.resource_path.key.sub_category)
+            .map_err(DataError::new_resc_error)?;
+
+        let mut values: Vec<(u32, i64, u32)> = toml_data
+            .numeric_values
+            .data
+            .values
+            .iter()
+            .map(|value| (value.c, value.n, value.d))
+            .collect();
+        values.sort_unstable();
+        let code_points: Vec<u32> = values.iter().map(|&(c, _, _)| c).collect();
+        let numerators: Vec<i64> = values.iter().map(|&(_, n, _)| n).collect();
+        let denominators: Vec<u32> = values.iter().map(|&(_, _, d)| d).collect();
+
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+            },
+            payload: Some(DataPayload::from_owned(UnicodeNumericValuesV1 {
+                code_points: ZeroVec::clone_from_slice(&code_points),
+                numerators: ZeroVec::clone_from_slice(&numerators),
+                denominators: ZeroVec::clone_from_slice(&denominators),
+            })),
+        })
+    }
+}
+
+icu_provider::impl_dyn_provider!(NumericValuesDataProvider, {
+    _ => UnicodeNumericValuesV1Marker,
+}, SERDE_SE, 'data);
+
+impl IterableDataProviderCore for NumericValuesDataProvider {
+    fn supported_options_for_key(
+        &self,
+        _resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        let list: Vec<ResourceOptions> = vec![ResourceOptions::default()];
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+#[test]
+fn test_numeric_values() {
+    let root_dir = icu_testdata::paths::data_root().join("uprops");
+    let provider = NumericValuesDataProvider::new(root_dir);
+
+    let payload: DataPayload<'_, UnicodeNumericValuesV1Marker> = provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: key::NUMERIC_VALUE_V1,
+                options: ResourceOptions::default(),
+            },
+        })
+        .expect("The data should be valid")
+        .take_payload()
+        .expect("Loading was successful");
+
+    let numeric_values = payload.get();
+    let get = |ch| numeric_values.numeric_value(ch);
+    assert_eq!(get('7'), Some((7, 1)));
+    assert_eq!(get('\u{660}'), Some((0, 1))); // ARABIC-INDIC DIGIT ZERO
+    assert_eq!(get('\u{96F}'), Some((9, 1))); // DEVANAGARI DIGIT NINE
+    assert_eq!(get('\u{BD}'), Some((1, 2))); // VULGAR FRACTION ONE HALF
+    assert_eq!(get('\u{F33}'), Some((-1, 2))); // TIBETAN DIGIT HALF ZERO
+    assert_eq!(get('\u{5146}'), Some((1_000_000_000_000, 1))); // CJK UNIFIED IDEOGRAPH-5146
+    assert_eq!(get('A'), None);
+}
@@ -2,24 +2,69 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-use crate::binary::BinaryPropertiesDataProvider;
+use crate::binary::{self, BinaryPropertiesDataProvider};
+use crate::case_folding::CaseFoldingDataProvider;
 use crate::enumerated::EnumeratedPropertiesDataProvider;
+use crate::error::Error;
+use crate::numeric_values::NumericValuesDataProvider;
 use icu_provider::iter::IterableDataProviderCore;
 use icu_provider::prelude::*;
-use icu_uniset::provider::UnicodePropertyV1Marker;
+use icu_uniset::provider::key;
+use icu_uniset::provider::{
+    UnicodeCaseFoldingV1Marker, UnicodeNumericValuesV1Marker, UnicodePropertyMapV1Marker,
+    UnicodePropertyV1Marker,
+};
 
 use std::path::PathBuf;
 
 pub struct PropertiesDataProvider {
     binary: BinaryPropertiesDataProvider,
     enumerated: EnumeratedPropertiesDataProvider,
+    case_folding: CaseFoldingDataProvider,
+    numeric_values: NumericValuesDataProvider,
 }
 
 impl PropertiesDataProvider {
     pub fn new(root_dir: PathBuf) -> Self {
         let binary = BinaryPropertiesDataProvider::new(root_dir.clone());
-        let enumerated = EnumeratedPropertiesDataProvider::new(root_dir);
-        Self { binary, enumerated }
+        let enumerated = EnumeratedPropertiesDataProvider::new(root_dir.clone());
+        let case_folding = CaseFoldingDataProvider::new(root_dir.clone());
+        let numeric_values = NumericValuesDataProvider::new(root_dir);
+        Self {
+            binary,
+            enumerated,
+            case_folding,
+            numeric_values,
+        }
+    }
+
+    /// Loads the set of code points of a binary property, given the name of the property at
+    /// runtime. The name is matched loosely against the short name, the long name and the other
+    /// aliases of the property, so "White_Space", "WSpace" and "white space" are equivalent.
+    ///
+    /// Returns an error if the name isn't a known binary property.
+    pub fn binary_set<'data>(
+        &self,
+        name: &str,
+    ) -> Result<DataPayload<'data, UnicodePropertyV1Marker>, DataError> {
+        let key = binary::get_binary_property_key(name)
+            .ok_or_else(|| DataError::new_resc_error(Error::UnknownProperty(name.to_string())))?;
+        self.binary
+            .load_payload(&DataRequest {
+                resource_path: ResourcePath {
+                    key,
+                    options: ResourceOptions::default(),
+                },
+            })?
+            .take_payload()
+    }
+
+    /// Returns the distinct values of an enumerated property present in the data, with their
+    /// short names, sorted by value. `prop_name` is the short name of the property, such as "gc".
+    ///
+    /// Returns an error if the property has no data or no value aliases.
+    pub fn enumerated_values(&self, prop_name: &str) -> Result<Vec<(u8, String)>, DataError> {
+        self.enumerated.enumerated_values(prop_name)
     }
 }
 
@@ -36,7 +81,46 @@ impl<'data> DataProvider<'data, UnicodePropertyV1Marker> for PropertiesDataProvi
     }
 }
 
+impl<'data> DataProvider<'data, UnicodePropertyMapV1Marker> for PropertiesDataProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, UnicodePropertyMapV1Marker>, DataError> {
+        self.enumerated.load_payload(req)
+    }
+}
+
+impl<'data> DataProvider<'data, UnicodeCaseFoldingV1Marker> for PropertiesDataProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, UnicodeCaseFoldingV1Marker>, DataError> {
+        self.case_folding.load_payload(req)
+    }
+}
+
+impl<'data> DataProvider<'data, UnicodeNumericValuesV1Marker> for PropertiesDataProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, UnicodeNumericValuesV1Marker>, DataError> {
+        self.numeric_values.load_payload(req)
+    }
+}
+
 icu_provider::impl_dyn_provider!(PropertiesDataProvider, {
+    key::CANONICAL_COMBINING_CLASS_V1 => UnicodePropertyMapV1Marker,
+    key::GRAPHEME_CLUSTER_BREAK_V1 => UnicodePropertyMapV1Marker,
+    key::INDIC_POSITIONAL_CATEGORY_V1 => UnicodePropertyMapV1Marker,
+    key::INDIC_SYLLABIC_CATEGORY_V1 => UnicodePropertyMapV1Marker,
+    key::JOINING_GROUP_V1 => UnicodePropertyMapV1Marker,
+    key::JOINING_TYPE_V1 => UnicodePropertyMapV1Marker,
+    key::NUMERIC_TYPE_V1 => UnicodePropertyMapV1Marker,
+    key::SCRIPT_V1 => UnicodePropertyMapV1Marker,
+    key::SENTENCE_BREAK_V1 => UnicodePropertyMapV1Marker,
+    key::WORD_BREAK_V1 => UnicodePropertyMapV1Marker,
+    key::SIMPLE_CASE_FOLDING_V1 => UnicodeCaseFoldingV1Marker,
+    key::NUMERIC_VALUE_V1 => UnicodeNumericValuesV1Marker,
     _ => UnicodePropertyV1Marker,
 }, SERDE_SE, 'data);
 
@@ -49,3 +133,63 @@ impl IterableDataProviderCore for PropertiesDataProvider {
         Ok(Box::new(list.into_iter()))
     }
 }
+
+#[test]
+fn test_binary_set() {
+    use icu_uniset::UnicodeSet;
+    use std::convert::TryInto;
+
+    let root_dir = icu_testdata::paths::data_root().join("uprops");
+    let provider = PropertiesDataProvider::new(root_dir);
+
+    let payload = provider
+        .binary_set("White_Space")
+        .expect("The data should be valid");
+    let whitespace: UnicodeSet = payload.get().clone().try_into().expect("Valid unicode set");
+    assert!(whitespace.contains(' '));
+    assert!(whitespace.contains('\u{3000}')); // U+3000 IDEOGRAPHIC SPACE
+    assert!(!whitespace.contains('A'));
+
+    let payload = provider
+        .binary_set("upper")
+        .expect("The data should be valid");
+    let uppercase: UnicodeSet = payload.get().clone().try_into().expect("Valid unicode set");
+    assert!(uppercase.contains('A'));
+    assert!(!uppercase.contains('a'));
+
+    let error = provider
+        .binary_set("Not_A_Property")
+        .expect_err("The property should be unknown");
+    assert!(error
+        .to_string()
+        .contains("Unknown property: Not_A_Property"));
+}
+
+#[test]
+fn test_numeric_type_and_value() {
+    use icu_uniset::enum_props::NumericType;
+    use icu_uniset::props::{get_numeric_type_map, get_numeric_values};
+    use std::convert::TryFrom;
+
+    let root_dir = icu_testdata::paths::data_root().join("uprops");
+    let provider = PropertiesDataProvider::new(root_dir);
+
+    let numeric_types = get_numeric_type_map(&provider).expect("The data should be valid");
+    let numeric_types = numeric_types.get();
+    let numeric_values = get_numeric_values(&provider).expect("The data should be valid");
+    let numeric_values = numeric_values.get();
+    let get = |ch: char| {
+        (
+            NumericType::try_from(numeric_types.get(ch as u32)),
+            numeric_values.numeric_value(ch),
+        )
+    };
+
+    // ARABIC-INDIC DIGIT ZERO
+    assert_eq!(get('\u{660}'), (Ok(NumericType::Decimal), Some((0, 1))));
+    // VULGAR FRACTION ONE HALF
+    assert_eq!(get('\u{BD}'), (Ok(NumericType::Numeric), Some((1, 2))));
+    // SUPERSCRIPT TWO
+    assert_eq!(get('\u{B2}'), (Ok(NumericType::Digit), Some((2, 1))));
+    assert_eq!(get('A'), (Ok(NumericType::None), None));
+}
@@ -48,3 +48,74 @@ pub mod enumerated {
         // omitted: enum_property.code_point_trie
     }
 }
+
+pub mod case_folding {
+    #[derive(serde::Deserialize)]
+    pub struct CaseFoldingMapping {
+        pub c: u32,
+        pub f: u32,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub struct CaseFolding {
+        pub long_name: String,
+        pub short_name: String,
+        pub mappings: Vec<CaseFoldingMapping>,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub struct Level1 {
+        pub data: CaseFolding,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub struct Main {
+        pub case_folding: Level1,
+    }
+}
+
+pub mod value_aliases {
+    #[derive(serde::Deserialize)]
+    pub struct PropertyValueAlias {
+        pub discr: u32,
+        pub long: String,
+        pub short: String,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub struct PropertyValueAliases {
+        pub short_name: String,
+        pub values: Vec<PropertyValueAlias>,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub struct Main {
+        pub value_aliases: Vec<PropertyValueAliases>,
+    }
+}
+
+pub mod numeric_values {
+    #[derive(serde::Deserialize)]
+    pub struct NumericValue {
+        pub c: u32,
+        pub n: i64,
+        pub d: u32,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub struct NumericValues {
+        pub long_name: String,
+        pub short_name: String,
+        pub values: Vec<NumericValue>,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub struct Level1 {
+        pub data: NumericValues,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub struct Main {
+        pub numeric_values: Level1,
+    }
+}
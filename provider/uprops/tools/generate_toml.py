@@ -0,0 +1,384 @@
+#!/usr/bin/env python3
+
+# This file is part of ICU4X. For terms of use, please see the file
+# called LICENSE at the top level of the ICU4X source tree
+# (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+# Generates the uprops TOML files of the properties which are not exported by
+# ICU4C's icuwriteuprops tool:
+#
+#   bc, ccc, jg, jt, nt, nv, scf, GCB, WB, SB, InSC, InPC
+#
+# and value_aliases, the names of the values of the enumerated properties.
+#
+# The data is read from Python's unicodedata module and from Perl's
+# Unicode::UCD module, which are compiled from the UCD files named in the
+# headers of the generated files. Both must be built from Unicode 14.0.0.
+#
+# Usage, from the root of the ICU4X source tree:
+#
+#   python3 provider/uprops/tools/generate_toml.py provider/testdata/data/uprops
+
+import os
+import subprocess
+import sys
+import unicodedata
+
+UNICODE_VERSION = "14.0.0"
+
+LICENSE_HEADER = """\
+# Copyright (C) 2021 and later: Unicode, Inc. and others.
+# License & terms of use: http://www.unicode.org/copyright.html
+#
+# file name: {short_name}
+#
+# machine-generated by: provider/uprops/tools/generate_toml.py
+# from the Unicode {version} {source}, as compiled into {module}
+"""
+
+PYTHON = "Python's unicodedata module"
+PERL = "Perl's Unicode::UCD module"
+
+# The discriminants of Bidi_Class, in the order of ICU4C's UCharDirection.
+BIDI_CLASS = [
+    "L", "R", "EN", "ES", "ET", "AN", "CS", "B", "S", "WS", "ON", "LRE", "LRO", "AL",
+    "RLE", "RLO", "PDF", "NSM", "BN", "FSI", "LRI", "RLI", "PDI",
+]
+
+# The short names of the Canonical_Combining_Class values which have one.
+CANONICAL_COMBINING_CLASS = {
+    0: "NR", 1: "OV", 6: "HANR", 7: "NK", 8: "KV", 9: "VR", 200: "ATBL", 202: "ATB",
+    214: "ATA", 216: "ATAR", 218: "BL", 220: "B", 222: "BR", 224: "L", 226: "R",
+    228: "AL", 230: "A", 232: "AR", 233: "DB", 234: "DA", 240: "IS",
+}
+
+# The discriminants of Joining_Type, in the order of ICU4C's UJoiningType.
+JOINING_TYPE = ["U", "C", "D", "L", "R", "T"]
+
+# The discriminants of Joining_Group, in the order of ICU4C's UJoiningGroup.
+JOINING_GROUP = """
+No_Joining_Group Ain Alaph Alef Beh Beth Dal Dalath_Rish E Feh Final_Semkath Gaf Gamal Hah
+Teh_Marbuta_Goal He Heh Heh_Goal Heth Kaf Kaph Knotted_Heh Lam Lamadh Meem Mim Noon Nun Pe
+Qaf Qaph Reh Reversed_Pe Sad Sadhe Seen Semkath Shin Swash_Kaf Syriac_Waw Tah Taw
+Teh_Marbuta Teth Waw Yeh Yeh_Barree Yeh_With_Tail Yudh Yudh_He Zain Fe Khaph Zhain
+Burushaski_Yeh_Barree Farsi_Yeh Nya Rohingya_Yeh Manichaean_Aleph Manichaean_Ayin
+Manichaean_Beth Manichaean_Daleth Manichaean_Dhamedh Manichaean_Five Manichaean_Gimel
+Manichaean_Heth Manichaean_Hundred Manichaean_Kaph Manichaean_Lamedh Manichaean_Mem
+Manichaean_Nun Manichaean_One Manichaean_Pe Manichaean_Qoph Manichaean_Resh
+Manichaean_Sadhe Manichaean_Samekh Manichaean_Taw Manichaean_Ten Manichaean_Teth
+Manichaean_Thamedh Manichaean_Twenty Manichaean_Waw Manichaean_Yodh Manichaean_Zayin
+Straight_Waw African_Feh African_Noon African_Qaf Malayalam_Bha Malayalam_Ja Malayalam_Lla
+Malayalam_Llla Malayalam_Nga Malayalam_Nna Malayalam_Nnna Malayalam_Nya Malayalam_Ra
+Malayalam_Ssa Malayalam_Tta Hanifi_Rohingya_Kinna_Ya Hanifi_Rohingya_Pa Thin_Yeh
+Vertical_Tail
+""".split()
+
+# The discriminants of Indic_Syllabic_Category, in the order of ICU4C's UIndicSyllabicCategory.
+INDIC_SYLLABIC_CATEGORY = """
+Other Avagraha Bindu Brahmi_Joining_Number Cantillation_Mark Consonant Consonant_Dead
+Consonant_Final Consonant_Head_Letter Consonant_Initial_Postfixed Consonant_Killer
+Consonant_Medial Consonant_Placeholder Consonant_Preceding_Repha Consonant_Prefixed
+Consonant_Subjoined Consonant_Succeeding_Repha Consonant_With_Stacker Gemination_Mark
+Invisible_Stacker Joiner Modifying_Letter Non_Joiner Nukta Number Number_Joiner Pure_Killer
+Register_Shifter Syllable_Modifier Tone_Letter Tone_Mark Virama Visarga Vowel
+Vowel_Dependent Vowel_Independent
+""".split()
+
+# The discriminants of Indic_Positional_Category, in the order of ICU4C's UIndicPositionalCategory.
+INDIC_POSITIONAL_CATEGORY = """
+NA Bottom Bottom_And_Left Bottom_And_Right Left Left_And_Right Overstruck Right Top
+Top_And_Bottom Top_And_Bottom_And_Right Top_And_Left Top_And_Left_And_Right Top_And_Right
+Visual_Order_Left Top_And_Bottom_And_Left
+""".split()
+
+# The discriminants and short names of the break properties, keyed by the long
+# names used by Unicode::UCD. The ExtPict_* values are Perl's split of a value
+# by Extended_Pictographic, which is a separate property in ICU4X.
+GRAPHEME_CLUSTER_BREAK = {
+    "Other": (0, "XX"), "Control": (1, "CN"), "CR": (2, "CR"), "Extend": (3, "EX"),
+    "L": (4, "L"), "LF": (5, "LF"), "LV": (6, "LV"), "LVT": (7, "LVT"), "T": (8, "T"),
+    "V": (9, "V"), "SpacingMark": (10, "SM"), "Prepend": (11, "PP"),
+    "Regional_Indicator": (12, "RI"), "ZWJ": (17, "ZWJ"), "ExtPict_XX": (0, "XX"),
+}
+
+WORD_BREAK = {
+    "Other": (0, "XX"), "ALetter": (1, "LE"), "Format": (2, "FO"), "Katakana": (3, "KA"),
+    "MidLetter": (4, "ML"), "MidNum": (5, "MN"), "Numeric": (6, "NU"),
+    "ExtendNumLet": (7, "EX"), "CR": (8, "CR"), "Extend": (9, "Extend"), "LF": (10, "LF"),
+    "MidNumLet": (11, "MB"), "Newline": (12, "NL"), "Regional_Indicator": (13, "RI"),
+    "Hebrew_Letter": (14, "HL"), "Single_Quote": (15, "SQ"), "Double_Quote": (16, "DQ"),
+    "ZWJ": (21, "ZWJ"), "ExtPict_XX": (0, "XX"), "ExtPict_LE": (1, "LE"),
+}
+
+SENTENCE_BREAK = {
+    "Other": (0, "XX"), "ATerm": (1, "AT"), "Close": (2, "CL"), "Format": (3, "FO"),
+    "Lower": (4, "LO"), "Numeric": (5, "NU"), "OLetter": (6, "LE"), "Sep": (7, "SE"),
+    "Sp": (8, "SP"), "STerm": (9, "ST"), "Upper": (10, "UP"), "CR": (11, "CR"),
+    "Extend": (12, "EX"), "LF": (13, "LF"), "SContinue": (14, "SC"),
+}
+
+# Perl tailors Word_Break by folding WSegSpace into Perl_Tailored_HSpace along
+# with other horizontal spaces. These are the WSegSpace code points.
+WSEG_SPACE = [(0x20, 0x20), (0x1680, 0x1680), (0x2000, 0x2006), (0x2008, 0x200A),
+              (0x205F, 0x205F), (0x3000, 0x3000)]
+
+NUMERIC_TYPE = {"Decimal": (1, "De"), "Digit": (2, "Di"), "Numeric": (3, "Nu")}
+
+# The short names of the values of the enumerated properties, in the order of
+# their discriminants in ICU4C, for the value aliases.
+VALUE_ALIASES = {
+    "bc": BIDI_CLASS,
+    "gc": """
+        Cn Lu Ll Lt Lm Lo Mn Me Mc Nd Nl No Zs Zl Zp Cc Cf Co Cs Pd Ps Pe Pc Po Sm Sc Sk So Pi Pf
+    """.split(),
+    "jg": JOINING_GROUP,
+    "jt": JOINING_TYPE,
+    "nt": ["None", "De", "Di", "Nu"],
+    "GCB": "XX CN CR EX L LF LV LVT T V SM PP RI EB EBG EM GAZ ZWJ".split(),
+    "WB": """
+        XX LE FO KA ML MN NU EX CR Extend LF MB NL RI HL SQ DQ EB EBG EM GAZ ZWJ WSegSpace
+    """.split(),
+    "SB": "XX AT CL FO LO NU LE SE SP ST UP CR EX LF SC".split(),
+    "InSC": INDIC_SYLLABIC_CATEGORY,
+    "InPC": INDIC_POSITIONAL_CATEGORY,
+}
+
+
+def perl(script, *args):
+    return subprocess.run(["perl", "-MUnicode::UCD", "-e", script, *args],
+                          check=True, capture_output=True, text=True).stdout
+
+
+def perl_invmap(prop):
+    """Returns the ranges (start, end, value) of `prop` from Unicode::UCD::prop_invmap,
+    and the format of its values."""
+    lines = perl("""
+        my ($list, $map, $format) = Unicode::UCD::prop_invmap($ARGV[0]);
+        print "$format\\n";
+        print "$list->[$_]\\t$map->[$_]\\n" for 0..$#$list;
+    """, prop).splitlines()
+    # The last entry starts past the end of the code space.
+    entries = [line.split("\t") for line in lines[1:] if int(line.split("\t")[0]) <= 0x10FFFF]
+    ranges = []
+    for i, (start, value) in enumerate(entries):
+        end = int(entries[i + 1][0]) - 1 if i + 1 < len(entries) else 0x10FFFF
+        ranges.append((int(start), end, value))
+    return ranges, lines[0]
+
+
+def perl_unicore_ranges(file_name):
+    """Returns the ranges (start, end, value) listed in a file of Perl's unicore/To directory."""
+    path = perl("use Config; print $Config{privlib}")
+    ranges = []
+    with open(os.path.join(path, "unicore", "To", file_name)) as f:
+        for line in f:
+            fields = line.rstrip("\n").split("\t")
+            if len(fields) != 3 or fields[2] == "END" or not fields[0]:
+                continue
+            try:
+                start = int(fields[0], 16)
+            except ValueError:
+                continue
+            end = int(fields[1], 16) if fields[1] else start
+            ranges.append((start, end, fields[2]))
+    return ranges
+
+
+def merge(ranges):
+    """Merges the adjacent ranges (start, end, value) which have the same value."""
+    merged = []
+    for start, end, value in ranges:
+        if merged and merged[-1][1] + 1 == start and merged[-1][2] == value:
+            merged[-1][1] = end
+        else:
+            merged.append([start, end, value])
+    return merged
+
+
+def ranges_of(values):
+    """Returns the ranges of the code points in the dict `values`."""
+    return merge((cp, cp, values[cp]) for cp in sorted(values))
+
+
+def header(short_name, source, module, notes=None):
+    out = LICENSE_HEADER.format(short_name=short_name, version=UNICODE_VERSION,
+                                source=source, module=module)
+    if notes:
+        out += "#\n" + "".join("# " + line + "\n" for line in notes)
+    return out + "\n"
+
+
+def enum_property(long_name, short_name, source, module, ranges, notes=None):
+    """`ranges` is a list of (start, end, (discriminant, name))."""
+    out = header(short_name, source, module, notes)
+    out += '[[enum_property]]\nlong_name = "%s"\nshort_name = "%s"\n' % (long_name, short_name)
+    out += "# Code points `a` through `b` have value `v`, corresponding to `name`.\nranges = [\n"
+    for start, end, (v, name) in ranges:
+        out += '  {a=0x%x, b=0x%x, v=%d, name="%s"},\n' % (start, end, v, name)
+    return out + "]\n"
+
+
+def bidi_class():
+    ranges = merge(
+        (cp, cp, unicodedata.bidirectional(chr(cp)))
+        for cp in range(0x110000)
+        if unicodedata.category(chr(cp)) != "Cn"
+    )
+    return enum_property(
+        "Bidi_Class", "bc", "UnicodeData.txt", PYTHON,
+        [(a, b, (BIDI_CLASS.index(v), v)) for a, b, v in ranges],
+        notes=["Unassigned code points are omitted. Their values are derived by the data",
+               "provider according to the default values listed in DerivedBidiClass.txt."])
+
+
+def canonical_combining_class():
+    ranges = merge((cp, cp, unicodedata.combining(chr(cp))) for cp in range(0x110000))
+    return enum_property(
+        "Canonical_Combining_Class", "ccc", "UnicodeData.txt", PYTHON,
+        [(a, b, (v, CANONICAL_COMBINING_CLASS.get(v, "CCC%d" % v))) for a, b, v in ranges])
+
+
+def joining_type():
+    listed = {}
+    for start, end, value in perl_unicore_ranges("Jt.pl"):
+        for cp in range(start, end + 1):
+            listed[cp] = value
+    # Only keep the values which differ from the default derived from General_Category.
+    explicit = {}
+    for cp in range(0x110000):
+        derived = "T" if unicodedata.category(chr(cp)) in ("Mn", "Me", "Cf") else "U"
+        value = listed.get(cp, "U")
+        if value != derived or (cp in listed and value != "T"):
+            explicit[cp] = value
+    return enum_property(
+        "Joining_Type", "jt", "ArabicShaping.txt", PERL,
+        [(a, b, (JOINING_TYPE.index(v), v)) for a, b, v in ranges_of(explicit)],
+        notes=["Only the explicitly listed code points are included. The others are of joining type T if their",
+               "General_Category is Mn, Me, or Cf, and U otherwise."])
+
+
+def joining_group():
+    groups = {}
+    for start, end, value in perl_unicore_ranges("Jg.pl"):
+        for cp in range(start, end + 1):
+            groups[cp] = value
+    return enum_property(
+        "Joining_Group", "jg", "ArabicShaping.txt", PERL,
+        [(a, b, (JOINING_GROUP.index(v), v)) for a, b, v in ranges_of(groups)])
+
+
+def indic_category(long_name, short_name, source, names):
+    ranges, _ = perl_invmap(short_name)
+    return enum_property(long_name, short_name, source, PERL,
+                         [(a, b, (names.index(v), v)) for a, b, v in ranges])
+
+
+def break_property(long_name, short_name, source, names):
+    ranges, _ = perl_invmap(short_name)
+    split = []
+    for start, end, value in ranges:
+        if value == "Perl_Tailored_HSpace":
+            for cp in range(start, end + 1):
+                wseg = any(a <= cp <= b for a, b in WSEG_SPACE)
+                split.append((cp, cp, (22, "WSegSpace") if wseg else (0, "XX")))
+        else:
+            split.append((start, end, names[value]))
+    # The default value XX is left out.
+    return enum_property(long_name, short_name, source, PERL,
+                         [(a, b, v) for a, b, v in merge(split) if v[0] != 0])
+
+
+def numeric_type():
+    ranges, _ = perl_invmap("nt")
+    return enum_property(
+        "Numeric_Type", "nt", "DerivedNumericType.txt", PERL,
+        [(a, b, NUMERIC_TYPE[v]) for a, b, v in ranges if v != "None"],
+        notes=["Only the code points with a numeric type are included. The others have the value None."])
+
+
+def numeric_values():
+    ranges, format = perl_invmap("nv")
+    assert format == "ar"
+    out = header("nv", "DerivedNumericValues.txt", PERL)
+    out += '[[numeric_values]]\nlong_name = "Numeric_Value"\nshort_name = "nv"\n'
+    out += "# Code point `c` has the numeric value `n` / `d`.\nvalues = [\n"
+    for start, end, value in ranges:
+        if value == "NaN":
+            continue
+        n, _, d = value.partition("/")
+        n, d = int(n), int(d or 1)
+        # Integer values increase along the range, fractions are given for single code points.
+        assert d == 1 or start == end
+        for cp in range(start, end + 1):
+            out += "  {c=0x%x, n=%d, d=%d},\n" % (cp, n + cp - start, d)
+    return out + "]\n"
+
+
+def simple_case_folding():
+    lines = perl("""
+        my $folds = Unicode::UCD::all_casefolds();
+        for my $c (sort { $a <=> $b } keys %$folds) {
+            my $simple = $folds->{$c}{simple};
+            printf("%X %s\\n", $c, $simple) if $simple ne "";
+        }
+    """).splitlines()
+    out = header("scf", "CaseFolding.txt", PERL)
+    out += '[[case_folding]]\nlong_name = "Simple_Case_Folding"\nshort_name = "scf"\n'
+    out += "# Code point `c` folds to `f`, from the mappings with the status C and S.\nmappings = [\n"
+    for line in lines:
+        c, f = line.split()
+        out += "  {c=0x%x, f=0x%x},\n" % (int(c, 16), int(f, 16))
+    return out + "]\n"
+
+
+def value_aliases():
+    sections = []
+    for prop, short_names in VALUE_ALIASES.items():
+        # The first alias is the short name, and the second one the long name.
+        long_names = perl("""
+            my $prop = shift;
+            print((Unicode::UCD::prop_value_aliases($prop, $_))[1], "\\n") for @ARGV;
+        """, prop, *short_names).splitlines()
+        assert len(long_names) == len(short_names), prop
+        out = '[[value_aliases]]\nshort_name = "%s"\n' % prop
+        out += "# Value `discr` has the long name `long` and the short name `short`.\nvalues = [\n"
+        for discr, (short, long) in enumerate(zip(short_names, long_names)):
+            out += '  {discr=%d, long="%s", short="%s"},\n' % (discr, long, short)
+        sections.append(out + "]\n")
+    return header("value_aliases", "PropertyValueAliases.txt", PERL) + "\n".join(sections)
+
+
+GENERATORS = {
+    "bc": bidi_class,
+    "ccc": canonical_combining_class,
+    "jg": joining_group,
+    "jt": joining_type,
+    "nt": numeric_type,
+    "nv": numeric_values,
+    "scf": simple_case_folding,
+    "GCB": lambda: break_property("Grapheme_Cluster_Break", "GCB", "GraphemeBreakProperty.txt",
+                                  GRAPHEME_CLUSTER_BREAK),
+    "WB": lambda: break_property("Word_Break", "WB", "WordBreakProperty.txt", WORD_BREAK),
+    "SB": lambda: break_property("Sentence_Break", "SB", "SentenceBreakProperty.txt", SENTENCE_BREAK),
+    "InSC": lambda: indic_category("Indic_Syllabic_Category", "InSC", "IndicSyllabicCategory.txt",
+                                   INDIC_SYLLABIC_CATEGORY),
+    "InPC": lambda: indic_category("Indic_Positional_Category", "InPC", "IndicPositionalCategory.txt",
+                                   INDIC_POSITIONAL_CATEGORY),
+    "value_aliases": value_aliases,
+}
+
+
+def main():
+    if len(sys.argv) != 2:
+        sys.exit("Usage: generate_toml.py <output directory>")
+    perl_version = perl("print Unicode::UCD::UnicodeVersion()")
+    for module, version in ((PYTHON, unicodedata.unidata_version), (PERL, perl_version)):
+        if version != UNICODE_VERSION:
+            sys.exit("%s has Unicode %s, expected %s" % (module, version, UNICODE_VERSION))
+    for short_name, generate in GENERATORS.items():
+        with open(os.path.join(sys.argv[1], short_name + ".toml"), "w") as f:
+            f.write(generate())
+
+
+if __name__ == "__main__":
+    main()